@@ -1,3 +1,76 @@
-fn main() {
-    println!("Hello, world!");
+use anyhow::{Result, anyhow, bail};
+use clap::Parser;
+use csaf::converter::{ConverterOptions, convert_csaf_2_0_to_2_1};
+use csaf::csaf::raw::HasParsed;
+use csaf::csaf2_0::loader::load_document;
+use csaf::schema::csaf2_1::schema::LabelOfTlp;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A converter from CSAF 2.0 to CSAF 2.1
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Path to the CSAF 2.0 document to convert
+    path: PathBuf,
+
+    /// Path to write the converted CSAF 2.1 document to, prints to stdout if omitted
+    #[arg(short = 'o', long)]
+    output: Option<PathBuf>,
+
+    /// TLP label to use if the document has none (mandatory in CSAF 2.1)
+    #[arg(long, default_value = "CLEAR")]
+    missing_tlp_label: LabelOfTlp,
+
+    /// License expression to add to documents without one
+    #[arg(long)]
+    license_expression: Option<String>,
+
+    /// Path to write the conversion report to as JSON, prints the changes to stderr if omitted
+    #[arg(short = 'r', long)]
+    report: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let document = load_document(args.path.as_path())?;
+    let document = document
+        .get_parsed()
+        .as_ref()
+        .map_err(|err| anyhow!("{} is not a valid CSAF 2.0 document: {err}", args.path.display()))?;
+
+    let options = ConverterOptions {
+        missing_tlp_label: args.missing_tlp_label,
+        license_expression: args.license_expression.clone(),
+    };
+    let conversion = convert_csaf_2_0_to_2_1(document, &options)?;
+
+    let converted = serde_json::to_string_pretty(&conversion.document)?;
+    match &args.output {
+        Some(output) => write_file(output, &converted)?,
+        None => println!("{converted}"),
+    }
+
+    match &args.report {
+        Some(report) => write_file(report, &serde_json::to_string_pretty(&conversion.report)?)?,
+        None => {
+            for change in &conversion.report.changes {
+                eprintln!("{change}");
+            }
+        },
+    }
+
+    // stdout may be the converted document, so the summary goes to stderr
+    if !conversion.report.is_lossless() {
+        eprintln!("{} change(s) need review.", conversion.report.changes.len());
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    if path.is_dir() {
+        bail!("{} is a directory", path.display());
+    }
+    Ok(fs::write(path, content)?)
 }
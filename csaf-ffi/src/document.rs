@@ -10,8 +10,10 @@ use csaf::csaf2_1::loader::load_document as load_2_1;
 use csaf::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
 use csaf::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
 use csaf::validation::{validate_by_preset, validate_by_test, validate_by_tests};
+use csaf::validation_context::{ValidationContext, WithContext};
 
 // Explicitly resolve ambiguity with same-named types from csaf::csaf_traits::*
+use crate::rules::RuleSet;
 use crate::types::CsafVersion;
 use crate::{CsafError, ValidationResult};
 
@@ -107,6 +109,44 @@ impl CsafDocument {
         Ok(result.into())
     }
 
+    /// Run validation with the given preset, running the rules alongside the tests of the
+    /// standard. The rules of the categories of the preset are added to its tests.
+    pub fn validate_with_rules(&self, preset: String, rules: Arc<RuleSet>) -> Result<ValidationResult, CsafError> {
+        let registry = rules.lock()?;
+        let context = ValidationContext::new().with_rules(&registry);
+        let guard = self.inner.lock().map_err(|_| CsafError::LoadError {
+            message: "lock poisoned".into(),
+        })?;
+        let result = match &*guard {
+            DocumentInner::V20(raw) => {
+                validate_by_preset(&WithContext::new(raw, context), &self.version_string, &preset)
+            },
+            DocumentInner::V21(raw) => {
+                validate_by_preset(&WithContext::new(raw, context), &self.version_string, &preset)
+            },
+        };
+        Ok(result.into())
+    }
+
+    /// Run specific validation tests and rules by their IDs.
+    pub fn run_tests_with_rules(
+        &self,
+        test_ids: Vec<String>,
+        rules: Arc<RuleSet>,
+    ) -> Result<ValidationResult, CsafError> {
+        let refs: Vec<&str> = test_ids.iter().map(|s| s.as_str()).collect();
+        let registry = rules.lock()?;
+        let context = ValidationContext::new().with_rules(&registry);
+        let guard = self.inner.lock().map_err(|_| CsafError::LoadError {
+            message: "lock poisoned".into(),
+        })?;
+        let result = match &*guard {
+            DocumentInner::V20(raw) => validate_by_tests(&WithContext::new(raw, context), &self.version_string, &refs),
+            DocumentInner::V21(raw) => validate_by_tests(&WithContext::new(raw, context), &self.version_string, &refs),
+        };
+        Ok(result.into())
+    }
+
     /// Run a single validation test by ID.
     pub fn run_test(&self, test_id: String) -> Result<crate::TestResult, CsafError> {
         let guard = self.inner.lock().map_err(|_| CsafError::LoadError {
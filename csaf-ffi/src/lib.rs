@@ -5,9 +5,12 @@
 
 use csaf::csaf2_0::loader::load_document as load_document_2_0;
 use csaf::csaf2_1::loader::load_document as load_document_2_1;
+use csaf::test_info::test_capabilities;
 use csaf::validation::validate_by_preset;
+use std::sync::Arc;
 
 pub mod document;
+pub mod rules;
 pub mod types;
 
 uniffi::setup_scaffolding!();
@@ -29,6 +32,9 @@ pub enum CsafError {
 
     #[error("Document load error: {message}")]
     LoadError { message: String },
+
+    #[error("Invalid rules: {message}")]
+    InvalidRules { message: String },
 }
 
 // ---------------------------------------------------------------------------
@@ -42,17 +48,41 @@ pub struct ValidationError {
     pub instance_path: String,
 }
 
+/// The reason why a test passed.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Passed {
+    /// The test passed successfully without any issues.
+    Success,
+    /// The test passed because there was no data to test against.
+    NoData { message: String },
+    /// The test passed because it does not apply to the document.
+    NotApplicable { message: String },
+}
+
+/// The reason why a test was skipped.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Skipped {
+    /// The document could not be deserialized, which the test needs.
+    DeserializationFailed { message: String },
+    /// A precondition of the test was not met.
+    PreconditionFailed { test_id: String, message: String },
+}
+
 /// Status of a single test execution.
 #[derive(Debug, Clone, uniffi::Enum)]
 pub enum TestResultStatus {
-    Success,
+    Success {
+        reason: Passed,
+    },
     Failure {
         errors: Vec<ValidationError>,
         warnings: Vec<ValidationError>,
         infos: Vec<ValidationError>,
     },
     NotFound,
-    Skipped,
+    Skipped {
+        reason: Skipped,
+    },
 }
 
 /// Result of a single validation test.
@@ -81,6 +111,38 @@ pub struct ValidationResult {
     pub num_not_found: u64,
 }
 
+/// Category of a test, which determines how a failure affects the validity of a document.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum TestCategory {
+    Mandatory,
+    Recommended,
+    Informative,
+}
+
+/// State of the implementation of a test in csaf-rs.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum ImplementationState {
+    /// The test is executed on the deserialized document.
+    Implemented,
+    /// The test operates on the raw JSON (which is always available through this FFI layer).
+    RawDocumentOnly,
+    /// The test is not implemented yet and not part of any preset.
+    NotImplemented,
+}
+
+/// A test of a CSAF version and whether csaf-rs implements it.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TestCapability {
+    /// The test ID as used in presets and test results, e.g. `6.1.27.3` or `schema`.
+    pub test_id: String,
+    /// The section of the standard defining the test.
+    pub section: String,
+    /// The title of the test in the standard.
+    pub title: Option<String>,
+    pub category: TestCategory,
+    pub state: ImplementationState,
+}
+
 // ---------------------------------------------------------------------------
 // Conversion helpers: csaf-rs types → FFI types
 // ---------------------------------------------------------------------------
@@ -94,10 +156,38 @@ impl From<&csaf::validation::ValidationError> for ValidationError {
     }
 }
 
+impl From<&csaf::validation::Passed> for Passed {
+    fn from(p: &csaf::validation::Passed) -> Self {
+        match p {
+            csaf::validation::Passed::Success => Self::Success,
+            csaf::validation::Passed::NoData { message } => Self::NoData {
+                message: message.clone(),
+            },
+            csaf::validation::Passed::NotApplicable { message } => Self::NotApplicable {
+                message: message.clone(),
+            },
+        }
+    }
+}
+
+impl From<&csaf::validation::Skipped> for Skipped {
+    fn from(s: &csaf::validation::Skipped) -> Self {
+        match s {
+            csaf::validation::Skipped::DeserializationFailed { message } => Self::DeserializationFailed {
+                message: message.clone(),
+            },
+            csaf::validation::Skipped::PreconditionFailed { test_id, message } => Self::PreconditionFailed {
+                test_id: test_id.clone(),
+                message: message.clone(),
+            },
+        }
+    }
+}
+
 impl From<&csaf::validation::TestResultStatus> for TestResultStatus {
     fn from(s: &csaf::validation::TestResultStatus) -> Self {
         match s {
-            csaf::validation::TestResultStatus::Success => Self::Success,
+            csaf::validation::TestResultStatus::Passed(passed) => Self::Success { reason: passed.into() },
             csaf::validation::TestResultStatus::Failure {
                 errors,
                 warnings,
@@ -108,7 +198,7 @@ impl From<&csaf::validation::TestResultStatus> for TestResultStatus {
                 infos: infos.iter().map(Into::into).collect(),
             },
             csaf::validation::TestResultStatus::NotFound => Self::NotFound,
-            csaf::validation::TestResultStatus::Skipped => Self::Skipped,
+            csaf::validation::TestResultStatus::Skipped(skipped) => Self::Skipped { reason: skipped.into() },
         }
     }
 }
@@ -136,6 +226,38 @@ impl From<csaf::validation::ValidationResult> for ValidationResult {
     }
 }
 
+impl From<&csaf::test_info::TestCategory> for TestCategory {
+    fn from(c: &csaf::test_info::TestCategory) -> Self {
+        match c {
+            csaf::test_info::TestCategory::Mandatory => Self::Mandatory,
+            csaf::test_info::TestCategory::Recommended => Self::Recommended,
+            csaf::test_info::TestCategory::Informative => Self::Informative,
+        }
+    }
+}
+
+impl From<&csaf::test_info::ImplementationState> for ImplementationState {
+    fn from(s: &csaf::test_info::ImplementationState) -> Self {
+        match s {
+            csaf::test_info::ImplementationState::Implemented => Self::Implemented,
+            csaf::test_info::ImplementationState::RawDocumentOnly => Self::RawDocumentOnly,
+            csaf::test_info::ImplementationState::NotImplemented => Self::NotImplemented,
+        }
+    }
+}
+
+impl From<&csaf::test_info::TestCapability> for TestCapability {
+    fn from(c: &csaf::test_info::TestCapability) -> Self {
+        Self {
+            test_id: c.test_id.to_string(),
+            section: c.section.to_string(),
+            title: c.title.map(str::to_string),
+            category: (&c.category).into(),
+            state: (&c.state).into(),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Exported functions
 // ---------------------------------------------------------------------------
@@ -186,6 +308,26 @@ pub fn validate_csaf(json_str: String, preset: String) -> Result<ValidationResul
     Ok(result.into())
 }
 
+/// Validate a CSAF document from a JSON string, running the rules alongside the tests of the
+/// standard.
+///
+/// Same as [`validate_csaf`], the rules of the categories of the preset are added to its tests,
+/// e.g. the informative rules to `full`.
+///
+/// # Arguments
+///
+/// * `json_str` - The CSAF document as a JSON string.
+/// * `preset`   - The validation preset: e.g. `"basic"`, `"extended"`, or `"full"`.
+/// * `rules`    - The rules to run, see [`RuleSet`](rules::RuleSet).
+#[uniffi::export]
+pub fn validate_csaf_with_rules(
+    json_str: String,
+    preset: String,
+    rules: Arc<rules::RuleSet>,
+) -> Result<ValidationResult, CsafError> {
+    document::CsafDocument::from_json(json_str)?.validate_with_rules(preset, rules)
+}
+
 /// Validate a CSAF 2.0 document from a JSON string.
 ///
 /// # Arguments
@@ -285,3 +427,16 @@ pub fn validate_csaf_2_1_to_json_string(json_str: String, preset: String) -> Res
     let result = validate_by_preset(&doc, "2.1", &preset);
     serde_json::to_string(&result).map_err(|e| CsafError::InvalidJson { message: e.to_string() })
 }
+
+/// List all tests of a CSAF version with their title, category and implementation state.
+///
+/// The presets only contain the implemented tests of this list.
+///
+/// # Arguments
+///
+/// * `version` - The CSAF version, `"2.0"` or `"2.1"`.
+#[uniffi::export]
+pub fn list_tests(version: String) -> Result<Vec<TestCapability>, CsafError> {
+    let capabilities = test_capabilities(&version).ok_or(CsafError::UnsupportedVersion { version })?;
+    Ok(capabilities.iter().map(Into::into).collect())
+}
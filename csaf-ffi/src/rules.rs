@@ -0,0 +1,61 @@
+//! RuleSet — opaque UniFFI object holding organization-specific rules.
+//!
+//! Rules are given as declarative rule files in JSON (see `csaf::declarative_rules`) and run
+//! alongside the tests of the standard by [`validate_csaf_with_rules`](crate::validate_csaf_with_rules)
+//! and the `*_with_rules` methods of [`CsafDocument`](crate::document::CsafDocument).
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use csaf::custom_rules::RuleRegistry;
+use csaf::declarative_rules::RuleFile;
+
+use crate::CsafError;
+
+/// A set of rules to run alongside the tests of the standard.
+///
+/// Each rule is part of the presets of its category, e.g. `full` for informative rules, and can
+/// be run by its ID like any other test.
+#[derive(uniffi::Object, Default)]
+pub struct RuleSet {
+    registry: Mutex<RuleRegistry>,
+}
+
+#[uniffi::export]
+impl RuleSet {
+    /// Create an empty rule set.
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Create a rule set from a declarative rule file in JSON.
+    #[uniffi::constructor]
+    pub fn from_json(json_str: String) -> Result<Arc<Self>, CsafError> {
+        let rules = Self::default();
+        rules.add_json(json_str)?;
+        Ok(Arc::new(rules))
+    }
+
+    /// Register the rules of a declarative rule file in JSON.
+    ///
+    /// Either all rules of the file are registered or none, e.g. if an ID is already registered.
+    pub fn add_json(&self, json_str: String) -> Result<(), CsafError> {
+        let file =
+            RuleFile::from_json_str(&json_str).map_err(|e| CsafError::InvalidRules { message: e.to_string() })?;
+        file.register(&mut *self.lock()?)
+            .map_err(|e| CsafError::InvalidRules { message: e.to_string() })
+    }
+
+    /// The IDs of the registered rules, in the order of registration.
+    pub fn rule_ids(&self) -> Result<Vec<String>, CsafError> {
+        Ok(self.lock()?.rules().iter().map(|rule| rule.id().to_string()).collect())
+    }
+}
+
+impl RuleSet {
+    pub(crate) fn lock(&self) -> Result<MutexGuard<'_, RuleRegistry>, CsafError> {
+        self.registry.lock().map_err(|_| CsafError::LoadError {
+            message: "lock poisoned".into(),
+        })
+    }
+}
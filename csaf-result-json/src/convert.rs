@@ -1,25 +1,31 @@
 use crate::result::{self, ResultJson, ResultT, ValidationMessageT};
 use anyhow::Result;
-use csaf::validation::TestResultStatus::{Failure, NotFound, Skipped, Success};
-use csaf::validation::{TestResult, ValidationError, ValidationResult};
+use csaf::validation::TestResultStatus::{Failure, NotFound, Passed, Skipped};
+use csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationResult};
 
 /// Build a [`ResultJson`] from a [`ValidationResult`] with the given primary test ID.
-pub fn build_testresult_json(result: &ValidationResult, primary_test_id: &str) -> Result<ResultJson> {
+///
+/// With `with_reasons`, the reason why a test passed without checking anything or was skipped is
+/// reported as an information on the document. The official expected results don't contain these.
+pub fn build_testresult_json(
+    result: &ValidationResult,
+    primary_test_id: &str,
+    with_reasons: bool,
+) -> Result<ResultJson> {
     let primary_test = result
         .test_results
         .iter()
         .find(|r| r.test_id == primary_test_id)
         .ok_or_else(|| anyhow::anyhow!("Test '{primary_test_id}' not found in validation results"))?;
 
-    let primary_result = convert_result(primary_test).ok_or_else(|| {
-        anyhow::anyhow!("Cannot create result for test '{primary_test_id}': test was not found/skipped")
-    })?;
+    let primary_result = convert_result(primary_test, with_reasons)
+        .ok_or_else(|| anyhow::anyhow!("Cannot create result for test '{primary_test_id}': test was not found"))?;
 
     let secondary_results: Vec<ResultT> = result
         .test_results
         .iter()
         .filter(|r| r.test_id != primary_test_id)
-        .filter_map(convert_result)
+        .filter_map(|r| convert_result(r, with_reasons))
         .filter(|r| !r.passed)
         .collect();
 
@@ -36,15 +42,17 @@ pub fn build_testresult_json(result: &ValidationResult, primary_test_id: &str) -
     })
 }
 
-/// Convert a [`TestResult`] to a [`ResultT`], returning `None` for tests that were not found or skipped.
-fn convert_result(test_result: &TestResult) -> Option<ResultT> {
+/// Convert a [`TestResult`] to a [`ResultT`], returning `None` for tests that were not found.
+///
+/// Skipped tests did not find anything, so they are reported as passed.
+fn convert_result(test_result: &TestResult, with_reasons: bool) -> Option<ResultT> {
     match &test_result.status {
-        Success => Some(ResultT {
+        Passed(_) | Skipped(_) => Some(ResultT {
             id: test_result.test_id.clone(),
             passed: true,
             errors: None,
             warnings: None,
-            infos: None,
+            infos: with_reasons.then(|| convert_reason(&test_result.status)).flatten(),
         }),
         Failure {
             errors,
@@ -57,7 +65,19 @@ fn convert_result(test_result: &TestResult) -> Option<ResultT> {
             warnings: convert_messages(warnings),
             infos: convert_messages(infos),
         }),
-        NotFound | Skipped => None,
+        NotFound => None,
+    }
+}
+
+/// Convert the reason of a passed or skipped test to an information on the document, or `None` if
+/// the test passed without a particular reason.
+fn convert_reason(status: &TestResultStatus) -> Option<Vec<ValidationMessageT>> {
+    match status {
+        Passed(csaf::validation::Passed::Success) => None,
+        status => Some(vec![ValidationMessageT {
+            instance_path: String::new(),
+            message: status.to_string(),
+        }]),
     }
 }
 
@@ -81,7 +101,7 @@ fn convert_messages(errors: &[ValidationError]) -> Option<Vec<ValidationMessageT
 #[cfg(test)]
 mod tests {
     use super::*;
-    use csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationResult};
+    use csaf::validation::{Passed, Skipped, TestResult, TestResultStatus, ValidationError, ValidationResult};
 
     fn make_error(path: &str, msg: &str) -> ValidationError {
         ValidationError {
@@ -112,9 +132,9 @@ mod tests {
     fn convert_result_success() {
         let tr = TestResult {
             test_id: "6.1.1".to_string(),
-            status: TestResultStatus::Success,
+            status: TestResultStatus::Passed(Passed::Success),
         };
-        let result = convert_result(&tr).expect("Success should yield Some");
+        let result = convert_result(&tr, true).expect("Success should yield Some");
         assert_eq!(result.id, "6.1.1");
         assert!(result.passed);
         assert!(result.errors.is_none());
@@ -132,7 +152,7 @@ mod tests {
                 infos: vec![],
             },
         };
-        let result = convert_result(&tr).expect("Failure should yield Some");
+        let result = convert_result(&tr, true).expect("Failure should yield Some");
         assert_eq!(result.id, "6.1.2");
         assert!(!result.passed);
         let errors = result.errors.expect("errors should be Some");
@@ -146,16 +166,46 @@ mod tests {
             test_id: "6.1.3".to_string(),
             status: TestResultStatus::NotFound,
         };
-        assert!(convert_result(&tr).is_none());
+        assert!(convert_result(&tr, true).is_none());
+    }
+
+    #[test]
+    fn convert_result_not_applicable_with_reason() {
+        let tr = TestResult {
+            test_id: "6.1.27.13".to_string(),
+            status: TestResultStatus::Passed(Passed::NotApplicable {
+                message: "The test only applies to VEX documents.".to_string(),
+            }),
+        };
+        let result = convert_result(&tr, true).expect("NotApplicable should yield Some");
+        assert!(result.passed);
+        let infos = result.infos.expect("infos should be Some");
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].instance_path, "");
+        assert_eq!(
+            infos[0].message,
+            "Passed (not applicable: The test only applies to VEX documents.)"
+        );
+        assert!(convert_result(&tr, false).expect("should yield Some").infos.is_none());
     }
 
     #[test]
-    fn convert_result_skipped_returns_none() {
+    fn convert_result_skipped_with_reason() {
         let tr = TestResult {
             test_id: "6.1.4".to_string(),
-            status: TestResultStatus::Skipped,
+            status: TestResultStatus::Skipped(Skipped::PreconditionFailed {
+                test_id: "6.1.4".to_string(),
+                message: "The revision history is empty.".to_string(),
+            }),
         };
-        assert!(convert_result(&tr).is_none());
+        let result = convert_result(&tr, true).expect("Skipped should yield Some");
+        assert_eq!(result.id, "6.1.4");
+        assert!(result.passed);
+        let infos = result.infos.expect("infos should be Some");
+        assert_eq!(
+            infos[0].message,
+            "Test skipped (precondition failed: The revision history is empty.)"
+        );
     }
 
     // --- build_testresult_json ---
@@ -171,7 +221,7 @@ mod tests {
             num_infos: 0,
             num_not_found: 0,
         };
-        assert!(build_testresult_json(&result, "6.1.1").is_err());
+        assert!(build_testresult_json(&result, "6.1.1", true).is_err());
     }
 
     #[test]
@@ -181,14 +231,14 @@ mod tests {
             version: "2.1".to_string(),
             test_results: vec![TestResult {
                 test_id: "6.1.1".to_string(),
-                status: TestResultStatus::Success,
+                status: TestResultStatus::Passed(Passed::Success),
             }],
             num_errors: 0,
             num_warnings: 0,
             num_infos: 0,
             num_not_found: 0,
         };
-        let json = build_testresult_json(&result, "6.1.1").expect("should succeed");
+        let json = build_testresult_json(&result, "6.1.1", true).expect("should succeed");
         assert!(json.overall_valid);
         assert_eq!(json.primary_result.id, "6.1.1");
         assert!(json.primary_result.passed);
@@ -203,7 +253,7 @@ mod tests {
             test_results: vec![
                 TestResult {
                     test_id: "6.1.1".to_string(),
-                    status: TestResultStatus::Success,
+                    status: TestResultStatus::Passed(Passed::Success),
                 },
                 TestResult {
                     test_id: "6.1.2".to_string(),
@@ -219,7 +269,7 @@ mod tests {
             num_infos: 0,
             num_not_found: 0,
         };
-        let json = build_testresult_json(&result, "6.1.1").expect("should succeed");
+        let json = build_testresult_json(&result, "6.1.1", true).expect("should succeed");
         assert!(!json.overall_valid);
         let secondaries = json.secondary_results.expect("should have secondaries");
         assert_eq!(secondaries.len(), 1);
@@ -235,11 +285,11 @@ mod tests {
             test_results: vec![
                 TestResult {
                     test_id: "6.1.1".to_string(),
-                    status: TestResultStatus::Success,
+                    status: TestResultStatus::Passed(Passed::Success),
                 },
                 TestResult {
                     test_id: "6.1.2".to_string(),
-                    status: TestResultStatus::Success,
+                    status: TestResultStatus::Passed(Passed::Success),
                 },
             ],
             num_errors: 0,
@@ -247,7 +297,7 @@ mod tests {
             num_infos: 0,
             num_not_found: 0,
         };
-        let json = build_testresult_json(&result, "6.1.1").expect("should succeed");
+        let json = build_testresult_json(&result, "6.1.1", true).expect("should succeed");
         // Passing secondary results are excluded
         assert!(json.secondary_results.is_none());
     }
@@ -89,7 +89,7 @@ fn main() -> Result<(), anyhow::Error> {
                 other => load_and_validate(file_path, other, &tests)?,
             };
 
-            let test_result = build_testresult_json(&result, test_id.as_str())?;
+            let test_result = build_testresult_json(&result, test_id.as_str(), true)?;
             println!("{}", serde_json::to_string_pretty(&test_result)?);
             Ok(())
         },
@@ -127,7 +127,7 @@ fn main() -> Result<(), anyhow::Error> {
                 other => load_and_validate(file_path, other, &tests)?,
             };
 
-            let actual = build_testresult_json(&result, expected.primary_result.id.as_str())?;
+            let actual = build_testresult_json(&result, expected.primary_result.id.as_str(), false)?;
             compare_result_jsons(&actual, &expected);
             Ok(())
         },
@@ -200,6 +200,62 @@ fn bench_full_validation(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark batch validation of all fixtures with the full preset on thread pools of different sizes.
+///
+/// With one job, this matches `full_validation`, the other sizes show how well validation scales
+/// across threads.
+#[cfg(feature = "parallel")]
+fn bench_batch_validation(c: &mut Criterion) {
+    use csaf::validation::{BatchOptions, validate_batch_by_tests};
+
+    let fixtures_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../csaf/csaf_2.1/test/validator/data");
+    let contents = load_fixture_contents(&collect_fixture_files(fixtures_dir));
+
+    if contents.is_empty() {
+        eprintln!("Warning: No CSAF 2.1 test fixtures found at {fixtures_dir}");
+        return;
+    }
+
+    let documents: Vec<_> = contents
+        .iter()
+        .filter_map(|(_name, content)| load_document_2_1(content).ok())
+        .collect();
+
+    let test_ids: Vec<&str> = [mandatory_tests_2_1(), recommended_tests_2_1(), informative_tests_2_1()]
+        .concat()
+        .into_iter()
+        .filter(|id| !SKIPPED_TESTS.contains(id))
+        .collect();
+
+    let mut group = c.benchmark_group("batch_validation");
+
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut job_counts = vec![1, 2, 4, available];
+    job_counts.sort_unstable();
+    job_counts.dedup();
+    for jobs in job_counts {
+        for parallel_tests in [false, true] {
+            let options = BatchOptions { jobs, parallel_tests };
+            let name = if parallel_tests {
+                format!("csaf_2_1_full_preset_{jobs}_jobs_parallel_tests")
+            } else {
+                format!("csaf_2_1_full_preset_{jobs}_jobs")
+            };
+            group.bench_function(name, |b| {
+                b.iter(|| black_box(validate_batch_by_tests(&documents, "2.1", &test_ids, &options)));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Batch validation requires the `parallel` feature.
+#[cfg(not(feature = "parallel"))]
+fn bench_batch_validation(_c: &mut Criterion) {
+    eprintln!("Warning: Skipping batch validation, enable the parallel feature to run it");
+}
+
 /// Benchmark parsing only (no validation).
 fn bench_parse_only(c: &mut Criterion) {
     let fixtures_dir_2_0 = concat!(env!("CARGO_MANIFEST_DIR"), "/../csaf/csaf_2.0/test/validator/data");
@@ -283,6 +339,6 @@ fn configured_criterion() -> Criterion {
 criterion_group! {
    name = benches;
    config = configured_criterion();
-   targets = bench_individual_tests_csaf_2_0, bench_individual_tests_csaf_2_1, bench_full_validation, bench_parse_only, bench_typed_parse
+   targets = bench_individual_tests_csaf_2_0, bench_individual_tests_csaf_2_1, bench_full_validation, bench_batch_validation, bench_parse_only, bench_typed_parse
 }
 criterion_main!(benches);
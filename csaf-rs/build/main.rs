@@ -1,8 +1,11 @@
+mod ssvc_decision_points;
 mod ssvc_validation;
 
 fn main() {
     println!("cargo:rerun-if-changed=build/main.rs");
+    println!("cargo:rerun-if-changed=build/ssvc_decision_points.rs");
     println!("cargo:rerun-if-changed=build/ssvc_validation.rs");
 
     ssvc_validation::validate_ssvc_lib_json();
+    ssvc_decision_points::generate_latest_decision_point_versions();
 }
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+/// A decision point version as `(major, minor, patch)`
+type Version = (u64, u64, u64);
+
+/// Derives the latest version of each SSVC decision point from the decision points bundled with
+/// the `ssvc` crate (the files behind its `DECISION_POINTS` lookup, which the crate does not
+/// export), and writes them as `LATEST_DECISION_POINT_VERSIONS` to `$OUT_DIR/ssvc_decision_points.rs`.
+pub fn generate_latest_decision_point_versions() {
+    let decision_points_dir = ssvc_package_dir().join("assets/ssvc_decision_points");
+    println!("cargo:rerun-if-changed={}", decision_points_dir.display());
+
+    // (base namespace, key) -> latest version
+    let mut latest: BTreeMap<(String, String), (Version, String)> = BTreeMap::new();
+    for path in json_files(&decision_points_dir) {
+        let content =
+            fs::read_to_string(&path).unwrap_or_else(|err| panic!("could not read {}: {err}", path.display()));
+        let decision_point: serde_json::Value =
+            serde_json::from_str(&content).unwrap_or_else(|err| panic!("{} is not valid JSON: {err}", path.display()));
+        let field = |name: &str| {
+            decision_point[name]
+                .as_str()
+                .unwrap_or_else(|| panic!("{} has no {name}", path.display()))
+                .to_string()
+        };
+        let (namespace, key, version) = (field("namespace"), field("key"), field("version"));
+        let base_namespace = namespace.split('#').next().unwrap_or_default().to_string();
+        let Some(parsed_version) = parse_version(&version) else {
+            panic!("{} has the invalid version {version}", path.display());
+        };
+        let entry = latest
+            .entry((base_namespace, key))
+            .or_insert_with(|| (parsed_version, version.clone()));
+        if parsed_version > entry.0 {
+            *entry = (parsed_version, version);
+        }
+    }
+
+    let mut generated = String::from(
+        "/// The latest versions of the SSVC decision points bundled with the `ssvc` crate, as\n\
+         /// `(base namespace, key, version)`.\n\
+         const LATEST_DECISION_POINT_VERSIONS: &[(&str, &str, &str)] = &[\n",
+    );
+    for ((namespace, key), (_, version)) in latest {
+        generated.push_str(&format!("    ({namespace:?}, {key:?}, {version:?}),\n"));
+    }
+    generated.push_str("];\n");
+    let out_path =
+        Path::new(&env::var("OUT_DIR").expect("OUT_DIR is set for build scripts")).join("ssvc_decision_points.rs");
+    fs::write(&out_path, generated).unwrap_or_else(|err| panic!("could not write {}: {err}", out_path.display()));
+}
+
+/// Finds the source directory of the `ssvc` package this crate depends on with `cargo metadata`.
+fn ssvc_package_dir() -> PathBuf {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = Path::new(&env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set")).join("Cargo.toml");
+    let output = Command::new(cargo)
+        .args(["metadata", "--format-version", "1", "--offline", "--manifest-path"])
+        .arg(&manifest)
+        .output()
+        .expect("could not run cargo metadata");
+    assert!(
+        output.status.success(),
+        "cargo metadata failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("cargo metadata returned invalid JSON");
+
+    // The ssvc package in the dependencies of this package
+    let package_id = |name: &str| {
+        metadata["packages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|package| package["name"] == name)
+            .filter_map(|package| package["id"].as_str())
+            .collect::<Vec<_>>()
+    };
+    let own_ids = package_id(&env::var("CARGO_PKG_NAME").expect("CARGO_PKG_NAME is set"));
+    let ssvc_ids = package_id("ssvc");
+    let ssvc_id = metadata["resolve"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|node| node["id"].as_str().is_some_and(|id| own_ids.contains(&id)))
+        .flat_map(|node| node["dependencies"].as_array().into_iter().flatten())
+        .filter_map(|dependency| dependency.as_str())
+        .find(|dependency| ssvc_ids.contains(dependency))
+        .expect("ssvc is a dependency");
+    let manifest_path = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|package| package["id"] == ssvc_id)
+        .and_then(|package| package["manifest_path"].as_str())
+        .expect("the ssvc package has a manifest path");
+    Path::new(manifest_path)
+        .parent()
+        .expect("a manifest path has a parent")
+        .to_path_buf()
+}
+
+fn json_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).unwrap_or_else(|err| panic!("could not read {}: {err}", dir.display())) {
+        let path = entry.expect("directory entries can be read").path();
+        if path.is_dir() {
+            files.extend(json_files(&path));
+        } else if path.extension().is_some_and(|extension| extension == "json") {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Parses an SSVC decision point version (`MAJOR.MINOR.PATCH`) into comparable parts.
+fn parse_version(version: &str) -> Option<Version> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => Some((major, minor, patch)),
+        _ => None,
+    }
+}
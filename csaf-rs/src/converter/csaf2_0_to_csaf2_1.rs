@@ -0,0 +1,478 @@
+use crate::converter::report::ConversionReport;
+use crate::helpers::CWE_ENTRIES;
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::{CommonSecurityAdvisoryFramework as Csaf21, LabelOfTlp};
+use serde_json::{Map, Value, json};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+const CSAF_2_1_SCHEMA_URL: &str = "https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json";
+
+/// Options for decisions the conversion rules leave to the converter.
+#[derive(Debug, Clone)]
+pub struct ConverterOptions {
+    /// TLP label to use if the CSAF 2.0 document has none, as it is mandatory in CSAF 2.1
+    pub missing_tlp_label: LabelOfTlp,
+    /// License expression to add to `/document/license_expression`, if the document has none
+    pub license_expression: Option<String>,
+}
+
+impl Default for ConverterOptions {
+    fn default() -> Self {
+        Self {
+            missing_tlp_label: LabelOfTlp::Clear,
+            license_expression: None,
+        }
+    }
+}
+
+/// A converted CSAF 2.1 document along with the report of all lossy or heuristic changes.
+#[derive(Debug, Clone)]
+pub struct Conversion {
+    pub document: Csaf21,
+    pub report: ConversionReport,
+}
+
+/// Error that prevented the conversion of a document.
+#[derive(Debug)]
+pub enum ConversionError {
+    /// The CSAF 2.0 document could not be serialized.
+    Serialization(serde_json::Error),
+    /// The converted document is not a valid CSAF 2.1 document according to the schema.
+    InvalidResult(serde_json::Error),
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ConversionError::Serialization(err) => write!(f, "Failed to serialize the CSAF 2.0 document: {err}"),
+            ConversionError::InvalidResult(err) => {
+                write!(f, "The converted document does not match the CSAF 2.1 schema: {err}")
+            },
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConversionError::Serialization(err) | ConversionError::InvalidResult(err) => Some(err),
+        }
+    }
+}
+
+/// Converts a CSAF 2.0 document into a CSAF 2.1 document.
+///
+/// This follows the conversion rules of the CSAF 2.1 standard:
+/// * `/$schema` and `/document/csaf_version` are set for CSAF 2.1
+/// * the TLP label `WHITE` becomes `CLEAR`, a missing TLP label is set from the [ConverterOptions]
+/// * `purl` of product identification helpers becomes the only item of `purls`
+/// * `/product_tree/relationships` become `/product_tree/product_paths`
+/// * the branch category `legacy`, which no longer exists, becomes `product_family`
+/// * `/vulnerabilities[]/cwe` becomes the only item of `cwes`, including the CWE version
+/// * `/vulnerabilities[]/scores` become `/vulnerabilities[]/metrics`
+/// * `/vulnerabilities[]/release_date` becomes `/vulnerabilities[]/disclosure_date`
+///
+/// Every change that loses information or had to be guessed is recorded in the returned
+/// [ConversionReport](crate::converter::ConversionReport).
+pub fn convert_csaf_2_0_to_2_1(doc: &Csaf20, options: &ConverterOptions) -> Result<Conversion, ConversionError> {
+    let mut report = ConversionReport::default();
+    let Value::Object(mut root) = serde_json::to_value(doc).map_err(ConversionError::Serialization)? else {
+        unreachable!("A CSAF document always serializes to a JSON object")
+    };
+
+    if let Some(Value::Object(document)) = root.get_mut("document") {
+        convert_document(document, options, &mut report);
+    }
+    if let Some(Value::Object(product_tree)) = root.get_mut("product_tree") {
+        convert_product_tree(product_tree, &mut report);
+    }
+    if let Some(Value::Array(vulnerabilities)) = root.get_mut("vulnerabilities") {
+        for (v_i, vulnerability) in vulnerabilities.iter_mut().enumerate() {
+            if let Value::Object(vulnerability) = vulnerability {
+                convert_vulnerability(vulnerability, v_i, &mut report);
+            }
+        }
+    }
+
+    root.insert("$schema".to_string(), json!(CSAF_2_1_SCHEMA_URL));
+
+    let document = serde_json::from_value(Value::Object(root)).map_err(ConversionError::InvalidResult)?;
+    Ok(Conversion { document, report })
+}
+
+fn convert_document(document: &mut Map<String, Value>, options: &ConverterOptions, report: &mut ConversionReport) {
+    document.insert("csaf_version".to_string(), json!("2.1"));
+
+    let missing_label = json!({ "label": options.missing_tlp_label });
+    match document.get_mut("distribution") {
+        Some(Value::Object(distribution)) => match distribution.get_mut("tlp") {
+            Some(Value::Object(tlp)) => {
+                if tlp.get("label").and_then(Value::as_str) == Some("WHITE") {
+                    tlp.insert("label".to_string(), json!("CLEAR"));
+                }
+            },
+            _ => {
+                distribution.insert("tlp".to_string(), missing_label);
+                report.heuristic(
+                    "/document/distribution/tlp",
+                    format!(
+                        "The document has no TLP label, which is mandatory in CSAF 2.1. It was set to {}.",
+                        options.missing_tlp_label
+                    ),
+                );
+            },
+        },
+        _ => {
+            insert_after(
+                document,
+                "csaf_version",
+                "distribution",
+                json!({ "tlp": missing_label }),
+            );
+            report.heuristic(
+                "/document/distribution",
+                format!(
+                    "The document has no distribution, which is mandatory in CSAF 2.1. The TLP label was set to {}.",
+                    options.missing_tlp_label
+                ),
+            );
+        },
+    }
+
+    if let Some(license_expression) = &options.license_expression
+        && !document.contains_key("license_expression")
+    {
+        insert_after(document, "lang", "license_expression", json!(license_expression));
+    }
+}
+
+fn convert_product_tree(product_tree: &mut Map<String, Value>, report: &mut ConversionReport) {
+    if let Some(Value::Array(branches)) = product_tree.get_mut("branches") {
+        convert_branches(branches, "/product_tree/branches", report);
+    }
+    if let Some(Value::Array(full_product_names)) = product_tree.get_mut("full_product_names") {
+        full_product_names.iter_mut().for_each(convert_full_product_name);
+    }
+    replace_key(product_tree, "relationships", |relationships| {
+        let Value::Array(relationships) = relationships else {
+            return vec![];
+        };
+        let product_paths = relationships.into_iter().map(convert_relationship).collect();
+        vec![("product_paths".to_string(), Value::Array(product_paths))]
+    });
+}
+
+fn convert_branches(branches: &mut [Value], path: &str, report: &mut ConversionReport) {
+    for (b_i, branch) in branches.iter_mut().enumerate() {
+        let Value::Object(branch) = branch else {
+            continue;
+        };
+        let branch_path = format!("{path}/{b_i}");
+        if branch.get("category").and_then(Value::as_str) == Some("legacy") {
+            branch.insert("category".to_string(), json!("product_family"));
+            report.lossy(
+                format!("{branch_path}/category"),
+                "The branch category 'legacy' does not exist in CSAF 2.1 and was replaced by 'product_family'.",
+            );
+        }
+        if let Some(product) = branch.get_mut("product") {
+            convert_full_product_name(product);
+        }
+        if let Some(Value::Array(sub_branches)) = branch.get_mut("branches") {
+            convert_branches(sub_branches, &format!("{branch_path}/branches"), report);
+        }
+    }
+}
+
+fn convert_full_product_name(full_product_name: &mut Value) {
+    if let Some(Value::Object(helper)) = full_product_name.get_mut("product_identification_helper") {
+        replace_key(helper, "purl", |purl| vec![("purls".to_string(), json!([purl]))]);
+    }
+}
+
+fn convert_relationship(relationship: Value) -> Value {
+    let Value::Object(mut relationship) = relationship else {
+        return relationship;
+    };
+    let mut full_product_name = relationship.remove("full_product_name").unwrap_or(Value::Null);
+    convert_full_product_name(&mut full_product_name);
+    json!({
+        "beginning_product_reference": relationship.remove("product_reference"),
+        "full_product_name": full_product_name,
+        "subpaths": [{
+            "category": relationship.remove("category"),
+            "next_product_reference": relationship.remove("relates_to_product_reference"),
+        }],
+    })
+}
+
+fn convert_vulnerability(vulnerability: &mut Map<String, Value>, v_i: usize, report: &mut ConversionReport) {
+    replace_key(vulnerability, "cwe", |cwe| {
+        vec![("cwes".to_string(), json!([convert_cwe(cwe, v_i, report)]))]
+    });
+    replace_key(vulnerability, "release_date", |release_date| {
+        vec![("disclosure_date".to_string(), release_date)]
+    });
+    replace_key(vulnerability, "scores", |scores| {
+        let Value::Array(scores) = scores else {
+            return vec![];
+        };
+        let metrics = scores.into_iter().map(convert_score).collect();
+        vec![("metrics".to_string(), Value::Array(metrics))]
+    });
+}
+
+/// Adds the CWE version, which is mandatory in CSAF 2.1.
+///
+/// The newest version in which both ID and name match is used. If there is no such version,
+/// the newest version containing the ID (or the newest version at all) is used and reported.
+fn convert_cwe(cwe: Value, v_i: usize, report: &mut ConversionReport) -> Value {
+    let Value::Object(mut cwe) = cwe else {
+        return cwe;
+    };
+    let id = cwe.get("id").and_then(Value::as_str).unwrap_or_default();
+    let name = cwe.get("name").and_then(Value::as_str).unwrap_or_default();
+    let path = format!("/vulnerabilities/{v_i}/cwe");
+
+    let best_match = CWE_ENTRIES
+        .iter()
+        .filter_map(|(version, data)| {
+            data.entries
+                .get(id)
+                .map(|entry| (version, entry.name == name, data.release_date))
+        })
+        .max_by_key(|(_, name_matches, release_date)| (*name_matches, *release_date));
+    let version = match best_match {
+        Some((version, true, _)) => Some(version),
+        Some((version, false, _)) => {
+            report.heuristic(
+                &path,
+                format!("The name of {id} does not match any known CWE version, used the newest version {version} containing {id}."),
+            );
+            Some(version)
+        },
+        None => {
+            let newest = CWE_ENTRIES
+                .iter()
+                .max_by_key(|(_, data)| data.release_date)
+                .map(|(version, _)| version);
+            if let Some(newest) = newest {
+                report.heuristic(
+                    &path,
+                    format!("{id} does not exist in any known CWE version, used the newest version {newest}."),
+                );
+            }
+            newest
+        },
+    };
+    if let Some(version) = version {
+        cwe.insert("version".to_string(), json!(version));
+    }
+    Value::Object(cwe)
+}
+
+fn convert_score(score: Value) -> Value {
+    let Value::Object(mut score) = score else {
+        return score;
+    };
+    let products = score.remove("products").unwrap_or_else(|| json!([]));
+    json!({
+        "content": score,
+        "products": products,
+    })
+}
+
+/// Replaces `key` in `map` by the entries returned by `convert`, keeping the position of the key.
+fn replace_key(map: &mut Map<String, Value>, key: &str, convert: impl FnOnce(Value) -> Vec<(String, Value)>) {
+    if !map.contains_key(key) {
+        return;
+    }
+    let mut convert = Some(convert);
+    *map = std::mem::take(map)
+        .into_iter()
+        .flat_map(|(k, v)| match convert.take_if(|_| k == key) {
+            Some(convert) => convert(v),
+            None => vec![(k, v)],
+        })
+        .collect();
+}
+
+/// Inserts `key` right after `after`, or at the end if `after` does not exist.
+fn insert_after(map: &mut Map<String, Value>, after: &str, key: &str, value: Value) {
+    if !map.contains_key(after) {
+        map.insert(key.to_string(), value);
+        return;
+    }
+    let mut value = Some(value);
+    *map = std::mem::take(map)
+        .into_iter()
+        .flat_map(|(k, v)| {
+            let inserted = if k == after {
+                value.take().map(|value| (key.to_string(), value))
+            } else {
+                None
+            };
+            std::iter::once((k, v)).chain(inserted)
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::report::ConversionChangeKind;
+
+    fn csaf_2_0(document: Value) -> Csaf20 {
+        let mut doc = json!({
+            "document": {
+                "category": "csaf_base",
+                "csaf_version": "2.0",
+                "publisher": {
+                    "category": "vendor",
+                    "name": "Example Company",
+                    "namespace": "https://example.com"
+                },
+                "title": "Conversion test",
+                "tracking": {
+                    "current_release_date": "2024-01-24T10:00:00.000Z",
+                    "id": "CSAF-CONVERSION-TEST",
+                    "initial_release_date": "2024-01-24T10:00:00.000Z",
+                    "revision_history": [
+                        { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." }
+                    ],
+                    "status": "final",
+                    "version": "1"
+                }
+            }
+        });
+        if let (Value::Object(doc), Value::Object(extra)) = (&mut doc, document) {
+            for (key, value) in extra {
+                match (doc.get_mut(&key), value) {
+                    (Some(Value::Object(existing)), Value::Object(value)) => existing.extend(value),
+                    (_, value) => {
+                        doc.insert(key, value);
+                    },
+                }
+            }
+        }
+        serde_json::from_value(doc).expect("test document should be valid CSAF 2.0")
+    }
+
+    fn convert(doc: &Csaf20) -> (Value, ConversionReport) {
+        let conversion = convert_csaf_2_0_to_2_1(doc, &ConverterOptions::default()).expect("conversion should succeed");
+        (serde_json::to_value(conversion.document).unwrap(), conversion.report)
+    }
+
+    #[test]
+    fn test_schema_version_and_tlp() {
+        let (converted, report) = convert(&csaf_2_0(json!({
+            "document": { "distribution": { "tlp": { "label": "WHITE" } } }
+        })));
+        assert_eq!(converted["$schema"], CSAF_2_1_SCHEMA_URL);
+        assert_eq!(converted["document"]["csaf_version"], "2.1");
+        assert_eq!(converted["document"]["distribution"]["tlp"]["label"], "CLEAR");
+        assert!(report.is_lossless());
+    }
+
+    #[test]
+    fn test_missing_distribution_is_reported() {
+        let doc = csaf_2_0(json!({}));
+        let options = ConverterOptions {
+            missing_tlp_label: LabelOfTlp::Amber,
+            license_expression: Some("MIT".to_string()),
+        };
+        let conversion = convert_csaf_2_0_to_2_1(&doc, &options).unwrap();
+        let converted = serde_json::to_value(conversion.document).unwrap();
+        assert_eq!(converted["document"]["distribution"]["tlp"]["label"], "AMBER");
+        assert_eq!(converted["document"]["license_expression"], "MIT");
+        assert_eq!(conversion.report.changes.len(), 1);
+        assert_eq!(conversion.report.changes[0].instance_path, "/document/distribution");
+        assert_eq!(conversion.report.changes[0].kind, ConversionChangeKind::Heuristic);
+    }
+
+    #[test]
+    fn test_product_tree() {
+        let (converted, report) = convert(&csaf_2_0(json!({
+            "document": { "distribution": { "tlp": { "label": "GREEN" } } },
+            "product_tree": {
+                "branches": [{
+                    "category": "vendor",
+                    "name": "Example Company",
+                    "branches": [{
+                        "category": "legacy",
+                        "name": "Old Product",
+                        "product": {
+                            "name": "Example Company Old Product",
+                            "product_id": "CSAFPID-0001",
+                            "product_identification_helper": { "purl": "pkg:generic/old-product" }
+                        }
+                    }]
+                }],
+                "full_product_names": [{ "name": "Example OS", "product_id": "CSAFPID-0002" }],
+                "relationships": [{
+                    "category": "installed_on",
+                    "full_product_name": { "name": "Old Product on Example OS", "product_id": "CSAFPID-0003" },
+                    "product_reference": "CSAFPID-0001",
+                    "relates_to_product_reference": "CSAFPID-0002"
+                }]
+            }
+        })));
+        let product_tree = &converted["product_tree"];
+        let legacy_branch = &product_tree["branches"][0]["branches"][0];
+        assert_eq!(legacy_branch["category"], "product_family");
+        assert_eq!(
+            legacy_branch["product"]["product_identification_helper"]["purls"],
+            json!(["pkg:generic/old-product"])
+        );
+        assert!(product_tree.get("relationships").is_none());
+        assert_eq!(
+            product_tree["product_paths"],
+            json!([{
+                "beginning_product_reference": "CSAFPID-0001",
+                "full_product_name": { "name": "Old Product on Example OS", "product_id": "CSAFPID-0003" },
+                "subpaths": [{ "category": "installed_on", "next_product_reference": "CSAFPID-0002" }]
+            }])
+        );
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(
+            report.changes[0].instance_path,
+            "/product_tree/branches/0/branches/0/category"
+        );
+        assert_eq!(report.changes[0].kind, ConversionChangeKind::Lossy);
+    }
+
+    #[test]
+    fn test_vulnerabilities() {
+        let (converted, report) = convert(&csaf_2_0(json!({
+            "document": { "distribution": { "tlp": { "label": "GREEN" } } },
+            "product_tree": {
+                "full_product_names": [{ "name": "Example Product", "product_id": "CSAFPID-0001" }]
+            },
+            "vulnerabilities": [{
+                "cwe": { "id": "CWE-79", "name": "Improper Neutralization of Input During Web Page Generation ('Cross-site Scripting')" },
+                "release_date": "2024-01-24T10:00:00.000Z",
+                "scores": [{
+                    "cvss_v3": {
+                        "version": "3.1",
+                        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                        "baseScore": 9.8,
+                        "baseSeverity": "CRITICAL"
+                    },
+                    "products": ["CSAFPID-0001"]
+                }]
+            }, {
+                "cwe": { "id": "CWE-79", "name": "Some outdated name" }
+            }]
+        })));
+        let vulnerability = &converted["vulnerabilities"][0];
+        assert!(vulnerability.get("cwe").is_none());
+        assert_eq!(vulnerability["cwes"][0]["id"], "CWE-79");
+        assert!(vulnerability["cwes"][0]["version"].is_string());
+        assert_eq!(vulnerability["disclosure_date"], "2024-01-24T10:00:00.000Z");
+        assert_eq!(vulnerability["metrics"][0]["products"], json!(["CSAFPID-0001"]));
+        assert_eq!(vulnerability["metrics"][0]["content"]["cvss_v3"]["baseScore"], 9.8);
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].instance_path, "/vulnerabilities/1/cwe");
+        assert_eq!(report.changes[0].kind, ConversionChangeKind::Heuristic);
+    }
+}
@@ -0,0 +1,1141 @@
+//! Import of CVRF 1.2 XML documents.
+//!
+//! CVRF documents are mapped to CSAF 2.0 following the rules for a "CVRF CSAF converter" of
+//! the CSAF 2.0 standard. A CSAF 2.1 document is created by additionally running the
+//! [CSAF 2.0 to 2.1 converter](crate::converter::convert_csaf_2_0_to_2_1) on the result.
+//!
+//! Elements are matched by their local name only, so it does not matter which namespace
+//! prefixes the CVRF document uses.
+
+use crate::converter::report::ConversionReport;
+use crate::converter::{ConversionError, ConverterOptions, convert_csaf_2_0_to_2_1};
+use crate::csaf::raw::{HasParsed, RawDocument};
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+use crate::validation::{ValidationResult, validate_by_preset};
+use chrono::{DateTime, NaiveDateTime};
+use cvss_rs::v2_0::CvssV2;
+use cvss_rs::v3::CvssV3;
+use roxmltree::Node;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// Options for values that CVRF documents do not contain.
+#[derive(Debug, Clone, Default)]
+pub struct CvrfImportOptions {
+    /// Value for `/document/publisher/name`
+    pub publisher_name: Option<String>,
+    /// Value for `/document/publisher/namespace`
+    pub publisher_namespace: Option<String>,
+    /// Options used when importing as CSAF 2.1
+    pub converter: ConverterOptions,
+}
+
+/// An imported CVRF document.
+#[derive(Debug)]
+pub struct CvrfImport<T> {
+    /// The resulting CSAF document, which is not guaranteed to be valid
+    pub document: RawDocument<T>,
+    /// All lossy or heuristic changes made during the import, paths refer to the CVRF document
+    pub warnings: ConversionReport,
+    /// Result of the `basic` preset on the resulting document
+    pub validation: ValidationResult,
+}
+
+/// Error that prevented the import of a CVRF document.
+#[derive(Debug)]
+pub enum CvrfImportError {
+    /// The input is not well-formed XML.
+    Xml(roxmltree::Error),
+    /// The root element is not `cvrfdoc`, contains the name of the actual root element.
+    NotCvrf(String),
+    /// The imported CSAF 2.0 document could not be parsed, so it cannot be converted to CSAF 2.1.
+    InvalidDocument(String),
+    /// The conversion from CSAF 2.0 to CSAF 2.1 failed.
+    Conversion(ConversionError),
+}
+
+impl Display for CvrfImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CvrfImportError::Xml(err) => write!(f, "Failed to parse CVRF document: {err}"),
+            CvrfImportError::NotCvrf(root) => write!(f, "Expected a CVRF document with root 'cvrfdoc', found '{root}'"),
+            CvrfImportError::InvalidDocument(err) => {
+                write!(f, "The imported document is not a valid CSAF 2.0 document: {err}")
+            },
+            CvrfImportError::Conversion(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for CvrfImportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CvrfImportError::Xml(err) => Some(err),
+            CvrfImportError::Conversion(err) => Some(err),
+            CvrfImportError::NotCvrf(_) | CvrfImportError::InvalidDocument(_) => None,
+        }
+    }
+}
+
+/// Imports a CVRF 1.2 document as CSAF 2.0 and runs the `basic` preset on the result.
+pub fn import_cvrf_as_csaf_2_0(xml: &str, options: &CvrfImportOptions) -> Result<CvrfImport<Csaf20>, CvrfImportError> {
+    let (json, warnings) = cvrf_to_csaf_2_0_json(xml, options)?;
+    let document = RawDocument::new(json);
+    let validation = validate_by_preset(&document, "2.0", "basic");
+    Ok(CvrfImport {
+        document,
+        warnings,
+        validation,
+    })
+}
+
+/// Imports a CVRF 1.2 document as CSAF 2.1 and runs the `basic` preset on the result.
+///
+/// In contrast to [import_cvrf_as_csaf_2_0], the intermediate CSAF 2.0 document has to be valid
+/// according to the schema, otherwise it cannot be converted.
+pub fn import_cvrf_as_csaf_2_1(xml: &str, options: &CvrfImportOptions) -> Result<CvrfImport<Csaf21>, CvrfImportError> {
+    let (json, mut warnings) = cvrf_to_csaf_2_0_json(xml, options)?;
+    let csaf_2_0 = RawDocument::<Csaf20>::new(json);
+    let parsed = csaf_2_0
+        .get_parsed()
+        .as_ref()
+        .map_err(|err| CvrfImportError::InvalidDocument(err.clone()))?;
+    let conversion = convert_csaf_2_0_to_2_1(parsed, &options.converter).map_err(CvrfImportError::Conversion)?;
+    // the conversion report refers to the intermediate CSAF 2.0 document
+    warnings.changes.extend(conversion.report.changes);
+
+    let json = serde_json::to_value(&conversion.document)
+        .map_err(|err| CvrfImportError::Conversion(ConversionError::Serialization(err)))?;
+    let document = RawDocument::new(json);
+    let validation = validate_by_preset(&document, "2.1", "basic");
+    Ok(CvrfImport {
+        document,
+        warnings,
+        validation,
+    })
+}
+
+fn cvrf_to_csaf_2_0_json(xml: &str, options: &CvrfImportOptions) -> Result<(Value, ConversionReport), CvrfImportError> {
+    let xml = roxmltree::Document::parse(xml).map_err(CvrfImportError::Xml)?;
+    let root = xml.root_element();
+    if root.tag_name().name() != "cvrfdoc" {
+        return Err(CvrfImportError::NotCvrf(root.tag_name().name().to_string()));
+    }
+
+    let mut importer = Importer {
+        options,
+        report: ConversionReport::default(),
+    };
+    let json = object([
+        ("document", Some(importer.document(root))),
+        (
+            "product_tree",
+            child(root, "ProductTree").map(|tree| importer.product_tree(tree)),
+        ),
+        (
+            "vulnerabilities",
+            Some(Value::Array(
+                children(root, "Vulnerability")
+                    .enumerate()
+                    .map(|(i, vulnerability)| {
+                        importer.vulnerability(vulnerability, &indexed("/cvrfdoc/Vulnerability", i))
+                    })
+                    .collect(),
+            )),
+        ),
+    ]);
+    Ok((json, importer.report))
+}
+
+struct Importer<'a> {
+    options: &'a CvrfImportOptions,
+    report: ConversionReport,
+}
+
+impl Importer<'_> {
+    fn document(&mut self, root: Node) -> Value {
+        let document_type = child_text(root, "DocumentType");
+        object([
+            (
+                "acknowledgments",
+                child(root, "Acknowledgments").map(|a| self.acknowledgments(a, "/cvrfdoc/Acknowledgments")),
+            ),
+            (
+                "aggregate_severity",
+                child(root, "AggregateSeverity").map(|severity| {
+                    object([
+                        ("namespace", severity.attribute("Namespace").map(|ns| json!(ns))),
+                        ("text", text(severity).map(Value::from)),
+                    ])
+                }),
+            ),
+            ("category", Some(json!(self.category(document_type.as_deref())))),
+            ("csaf_version", Some(json!("2.0"))),
+            (
+                "distribution",
+                child_text(root, "DocumentDistribution").map(|text| json!({ "text": text })),
+            ),
+            (
+                "lang",
+                root.attribute((roxmltree::NS_XML_URI, "lang")).map(|lang| json!(lang)),
+            ),
+            ("notes", child(root, "DocumentNotes").map(notes)),
+            ("publisher", Some(self.publisher(child(root, "DocumentPublisher")))),
+            ("references", child(root, "DocumentReferences").map(references)),
+            ("title", child_text(root, "DocumentTitle").map(Value::from)),
+            (
+                "tracking",
+                child(root, "DocumentTracking").map(|tracking| self.tracking(tracking)),
+            ),
+        ])
+    }
+
+    fn category(&mut self, document_type: Option<&str>) -> &'static str {
+        let normalized = document_type.map(|t| t.trim().to_lowercase().replace(['_', '-'], " "));
+        match normalized.as_deref() {
+            Some("security advisory" | "csaf security advisory") => "csaf_security_advisory",
+            Some("security incident response" | "csaf security incident response") => "csaf_security_incident_response",
+            Some("informational advisory" | "csaf informational advisory") => "csaf_informational_advisory",
+            Some("vex" | "csaf vex") => "csaf_vex",
+            _ => {
+                self.report.heuristic(
+                    "/cvrfdoc/DocumentType",
+                    format!(
+                        "The document type '{}' does not correspond to a CSAF profile, used 'csaf_base'.",
+                        document_type.unwrap_or_default()
+                    ),
+                );
+                "csaf_base"
+            },
+        }
+    }
+
+    fn publisher(&mut self, publisher: Option<Node>) -> Value {
+        if self.options.publisher_name.is_none() {
+            self.report.lossy(
+                "/cvrfdoc/DocumentPublisher",
+                "CVRF does not contain the name of the publisher and none was configured.",
+            );
+        }
+        if self.options.publisher_namespace.is_none() {
+            self.report.lossy(
+                "/cvrfdoc/DocumentPublisher",
+                "CVRF does not contain the namespace of the publisher and none was configured.",
+            );
+        }
+        object([
+            (
+                "category",
+                publisher
+                    .and_then(|p| p.attribute("Type"))
+                    .map(|t| json!(snake_case(t))),
+            ),
+            (
+                "contact_details",
+                publisher.and_then(|p| child_text(p, "ContactDetails")).map(Value::from),
+            ),
+            (
+                "issuing_authority",
+                publisher
+                    .and_then(|p| child_text(p, "IssuingAuthority"))
+                    .map(Value::from),
+            ),
+            ("name", self.options.publisher_name.as_ref().map(|name| json!(name))),
+            (
+                "namespace",
+                self.options
+                    .publisher_namespace
+                    .as_ref()
+                    .map(|namespace| json!(namespace)),
+            ),
+        ])
+    }
+
+    fn tracking(&mut self, tracking: Node) -> Value {
+        let path = "/cvrfdoc/DocumentTracking";
+        let identification = child(tracking, "Identification");
+        let revisions: Vec<Node> = child(tracking, "RevisionHistory")
+            .map(|history| children(history, "Revision").collect())
+            .unwrap_or_default();
+
+        // the document version and all revision numbers have to use the same versioning scheme
+        let version = child_text(tracking, "Version");
+        let numbers: Vec<Option<String>> = revisions.iter().map(|r| child_text(*r, "Number")).collect();
+        let (version, numbers) = self.version_numbers(version, numbers);
+
+        let revision_history = revisions
+            .iter()
+            .zip(numbers)
+            .enumerate()
+            .map(|(i, (revision, number))| {
+                let revision_path = indexed(&format!("{path}/RevisionHistory/Revision"), i);
+                object([
+                    (
+                        "date",
+                        child_text(*revision, "Date").map(|d| self.date(&d, &format!("{revision_path}/Date"))),
+                    ),
+                    ("number", number.map(Value::from)),
+                    ("summary", child_text(*revision, "Description").map(Value::from)),
+                ])
+            })
+            .collect();
+
+        object([
+            (
+                "aliases",
+                identification
+                    .map(|id| Value::Array(children(id, "Alias").filter_map(text).map(Value::from).collect())),
+            ),
+            (
+                "current_release_date",
+                child_text(tracking, "CurrentReleaseDate")
+                    .map(|d| self.date(&d, &format!("{path}/CurrentReleaseDate"))),
+            ),
+            (
+                "generator",
+                child(tracking, "Generator").map(|generator| {
+                    object([
+                        (
+                            "date",
+                            child_text(generator, "Date").map(|d| self.date(&d, &format!("{path}/Generator/Date"))),
+                        ),
+                        (
+                            "engine",
+                            child_text(generator, "Engine").map(|engine| json!({ "name": engine })),
+                        ),
+                    ])
+                }),
+            ),
+            (
+                "id",
+                identification.and_then(|id| child_text(id, "ID")).map(Value::from),
+            ),
+            (
+                "initial_release_date",
+                child_text(tracking, "InitialReleaseDate")
+                    .map(|d| self.date(&d, &format!("{path}/InitialReleaseDate"))),
+            ),
+            ("revision_history", Some(Value::Array(revision_history))),
+            (
+                "status",
+                child_text(tracking, "Status").map(|status| json!(snake_case(&status))),
+            ),
+            ("version", version.map(Value::from)),
+        ])
+    }
+
+    /// Converts the document version and the revision numbers to a common versioning scheme.
+    ///
+    /// Plain integers are kept as integer versioning, otherwise numbers with up to three parts
+    /// are padded to semantic versions (e.g. `1.2` becomes `1.2.0`). If this is not possible for
+    /// all numbers, integer versioning by the order of the revisions is used instead.
+    fn version_numbers(
+        &mut self,
+        version: Option<String>,
+        numbers: Vec<Option<String>>,
+    ) -> (Option<String>, Vec<Option<String>>) {
+        let all = || version.iter().chain(numbers.iter().flatten());
+        let parts = |number: &String| -> Option<Vec<u64>> { number.split('.').map(|part| part.parse().ok()).collect() };
+        if all().all(|number| parts(number).is_some_and(|p| p.len() == 1)) {
+            let int_ver = |number: Option<String>| number.and_then(|n| parts(&n)).map(|p| p[0].to_string());
+            return (int_ver(version), numbers.into_iter().map(int_ver).collect());
+        }
+        if all().all(|number| parts(number).is_some_and(|p| p.len() <= 3)) {
+            let sem_ver = |number: Option<String>| {
+                number.and_then(|n| parts(&n)).map(|mut p| {
+                    p.resize(3, 0);
+                    format!("{}.{}.{}", p[0], p[1], p[2])
+                })
+            };
+            return (sem_ver(version), numbers.into_iter().map(sem_ver).collect());
+        }
+
+        self.report.heuristic(
+            "/cvrfdoc/DocumentTracking/Version",
+            "The version numbers cannot be converted to semantic versioning, used integer versioning based on the order of the revisions.",
+        );
+        let count = numbers.len();
+        let numbers = (1..=count).map(|i| Some(i.to_string())).collect();
+        (Some(count.max(1).to_string()), numbers)
+    }
+
+    /// CVRF allows date-times without a timezone, which are interpreted as UTC.
+    fn date(&mut self, date: &str, path: &str) -> Value {
+        if DateTime::parse_from_rfc3339(date).is_err()
+            && NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+        {
+            self.report
+                .heuristic(path, format!("The date-time '{date}' has no timezone, assumed UTC."));
+            return json!(format!("{date}Z"));
+        }
+        json!(date)
+    }
+
+    fn acknowledgments(&mut self, acknowledgments: Node, path: &str) -> Value {
+        Value::Array(
+            children(acknowledgments, "Acknowledgment")
+                .enumerate()
+                .map(|(i, acknowledgment)| {
+                    let organizations: Vec<String> =
+                        children(acknowledgment, "Organization").filter_map(text).collect();
+                    if organizations.len() > 1 {
+                        self.report.lossy(
+                            indexed(&format!("{path}/Acknowledgment"), i),
+                            "CSAF only allows a single organization per acknowledgment, used the first one.",
+                        );
+                    }
+                    object([
+                        (
+                            "names",
+                            Some(Value::Array(
+                                children(acknowledgment, "Name")
+                                    .filter_map(text)
+                                    .map(Value::from)
+                                    .collect(),
+                            )),
+                        ),
+                        ("organization", organizations.into_iter().next().map(Value::from)),
+                        ("summary", child_text(acknowledgment, "Description").map(Value::from)),
+                        (
+                            "urls",
+                            Some(Value::Array(
+                                children(acknowledgment, "URL")
+                                    .filter_map(text)
+                                    .map(Value::from)
+                                    .collect(),
+                            )),
+                        ),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    fn product_tree(&mut self, tree: Node) -> Value {
+        object([
+            ("branches", Some(self.branches(tree, "/cvrfdoc/ProductTree"))),
+            (
+                "full_product_names",
+                Some(Value::Array(
+                    children(tree, "FullProductName").map(full_product_name).collect(),
+                )),
+            ),
+            (
+                "product_groups",
+                child(tree, "ProductGroups").map(|groups| {
+                    Value::Array(
+                        children(groups, "Group")
+                            .map(|group| {
+                                object([
+                                    ("group_id", group.attribute("GroupID").map(|id| json!(id))),
+                                    ("product_ids", Some(product_ids(group))),
+                                    ("summary", child_text(group, "Description").map(Value::from)),
+                                ])
+                            })
+                            .collect(),
+                    )
+                }),
+            ),
+            (
+                "relationships",
+                Some(Value::Array(
+                    children(tree, "Relationship")
+                        .map(|relationship| {
+                            object([
+                                (
+                                    "category",
+                                    relationship.attribute("RelationType").map(|t| json!(snake_case(t))),
+                                ),
+                                (
+                                    "full_product_name",
+                                    child(relationship, "FullProductName").map(full_product_name),
+                                ),
+                                (
+                                    "product_reference",
+                                    relationship.attribute("ProductReference").map(|r| json!(r)),
+                                ),
+                                (
+                                    "relates_to_product_reference",
+                                    relationship.attribute("RelatesToProductReference").map(|r| json!(r)),
+                                ),
+                            ])
+                        })
+                        .collect(),
+                )),
+            ),
+        ])
+    }
+
+    fn branches(&mut self, parent: Node, path: &str) -> Value {
+        Value::Array(
+            children(parent, "Branch")
+                .enumerate()
+                .map(|(i, branch)| {
+                    let branch_path = indexed(&format!("{path}/Branch"), i);
+                    let category = branch.attribute("Type").map(|category| match category {
+                        "Realm" | "Resource" => {
+                            self.report.lossy(
+                                &branch_path,
+                                format!("The branch type '{category}' does not exist in CSAF, used 'product_name'."),
+                            );
+                            "product_name".to_string()
+                        },
+                        _ => snake_case(category),
+                    });
+                    object([
+                        ("branches", Some(self.branches(branch, &branch_path))),
+                        ("category", category.map(Value::from)),
+                        ("name", branch.attribute("Name").map(|name| json!(name))),
+                        ("product", child(branch, "FullProductName").map(full_product_name)),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    fn vulnerability(&mut self, vulnerability: Node, path: &str) -> Value {
+        let cwes: Vec<Node> = children(vulnerability, "CWE").collect();
+        if cwes.len() > 1 {
+            self.report.lossy(
+                format!("{path}/CWE"),
+                "CSAF 2.0 only allows a single CWE per vulnerability, used the first one.",
+            );
+        }
+        let product_status = child(vulnerability, "ProductStatuses").map(|statuses| {
+            let mut product_status = Map::new();
+            for status in children(statuses, "Status") {
+                if let Some(category) = status.attribute("Type")
+                    && let Value::Array(ids) = product_ids(status)
+                    && let Value::Array(existing) =
+                        product_status.entry(snake_case(category)).or_insert_with(|| json!([]))
+                {
+                    existing.extend(ids);
+                }
+            }
+            Value::Object(product_status)
+        });
+
+        object([
+            (
+                "acknowledgments",
+                child(vulnerability, "Acknowledgments")
+                    .map(|a| self.acknowledgments(a, &format!("{path}/Acknowledgments"))),
+            ),
+            ("cve", child_text(vulnerability, "CVE").map(Value::from)),
+            (
+                "cwe",
+                cwes.first().map(|cwe| {
+                    object([
+                        ("id", cwe.attribute("ID").map(|id| json!(id))),
+                        ("name", text(*cwe).map(Value::from)),
+                    ])
+                }),
+            ),
+            (
+                "discovery_date",
+                child_text(vulnerability, "DiscoveryDate").map(|d| self.date(&d, &format!("{path}/DiscoveryDate"))),
+            ),
+            (
+                "ids",
+                Some(Value::Array(
+                    children(vulnerability, "ID")
+                        .map(|id| {
+                            object([
+                                ("system_name", id.attribute("SystemName").map(|name| json!(name))),
+                                ("text", text(id).map(Value::from)),
+                            ])
+                        })
+                        .collect(),
+                )),
+            ),
+            (
+                "involvements",
+                child(vulnerability, "Involvements").map(|involvements| {
+                    Value::Array(
+                        children(involvements, "Involvement")
+                            .map(|involvement| {
+                                object([
+                                    ("party", involvement.attribute("Party").map(|p| json!(snake_case(p)))),
+                                    ("status", involvement.attribute("Status").map(|s| json!(snake_case(s)))),
+                                    ("summary", child_text(involvement, "Description").map(Value::from)),
+                                ])
+                            })
+                            .collect(),
+                    )
+                }),
+            ),
+            ("notes", child(vulnerability, "Notes").map(notes)),
+            ("product_status", product_status.clone()),
+            ("references", child(vulnerability, "References").map(references)),
+            (
+                "release_date",
+                child_text(vulnerability, "ReleaseDate").map(|d| self.date(&d, &format!("{path}/ReleaseDate"))),
+            ),
+            (
+                "remediations",
+                child(vulnerability, "Remediations").map(|remediations| self.remediations(remediations, path)),
+            ),
+            (
+                "scores",
+                child(vulnerability, "CVSSScoreSets")
+                    .map(|score_sets| self.scores(score_sets, product_status.as_ref(), path)),
+            ),
+            (
+                "threats",
+                child(vulnerability, "Threats").map(|threats| {
+                    Value::Array(
+                        children(threats, "Threat")
+                            .enumerate()
+                            .map(|(i, threat)| {
+                                let threat_path = indexed(&format!("{path}/Threats/Threat"), i);
+                                object([
+                                    ("category", threat.attribute("Type").map(|t| json!(snake_case(t)))),
+                                    (
+                                        "date",
+                                        threat
+                                            .attribute("Date")
+                                            .map(|d| self.date(d, &format!("{threat_path}/@Date"))),
+                                    ),
+                                    ("details", child_text(threat, "Description").map(Value::from)),
+                                    ("group_ids", Some(group_ids(threat))),
+                                    ("product_ids", Some(product_ids(threat))),
+                                ])
+                            })
+                            .collect(),
+                    )
+                }),
+            ),
+            ("title", child_text(vulnerability, "Title").map(Value::from)),
+        ])
+    }
+
+    fn remediations(&mut self, remediations: Node, path: &str) -> Value {
+        Value::Array(
+            children(remediations, "Remediation")
+                .enumerate()
+                .map(|(i, remediation)| {
+                    let remediation_path = indexed(&format!("{path}/Remediations/Remediation"), i);
+                    let category = remediation.attribute("Type").map(|category| match category {
+                        "Will Not Fix" => "no_fix_planned".to_string(),
+                        _ => snake_case(category),
+                    });
+                    object([
+                        ("category", category.map(Value::from)),
+                        (
+                            "date",
+                            remediation
+                                .attribute("Date")
+                                .map(|d| self.date(d, &format!("{remediation_path}/@Date"))),
+                        ),
+                        ("details", child_text(remediation, "Description").map(Value::from)),
+                        (
+                            "entitlements",
+                            Some(Value::Array(
+                                children(remediation, "Entitlement")
+                                    .filter_map(text)
+                                    .map(Value::from)
+                                    .collect(),
+                            )),
+                        ),
+                        ("group_ids", Some(group_ids(remediation))),
+                        ("product_ids", Some(product_ids(remediation))),
+                        ("url", child_text(remediation, "URL").map(Value::from)),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    fn scores(&mut self, score_sets: Node, product_status: Option<&Value>, path: &str) -> Value {
+        Value::Array(
+            children(score_sets, "ScoreSet")
+                .enumerate()
+                .filter_map(|(i, score_set)| {
+                    let score_path = indexed(&format!("{path}/CVSSScoreSets/ScoreSet"), i);
+                    let mut products = product_ids(score_set);
+                    if products.as_array().is_some_and(Vec::is_empty) {
+                        // a score set without products applies to all affected products
+                        let affected: Vec<Value> = ["first_affected", "known_affected", "last_affected"]
+                            .iter()
+                            .filter_map(|status| product_status?.get(status)?.as_array())
+                            .flatten()
+                            .cloned()
+                            .collect();
+                        if affected.is_empty() {
+                            self.report.lossy(
+                                &score_path,
+                                "The score set has no products and there are no affected products, so it was dropped.",
+                            );
+                            return None;
+                        }
+                        self.report.heuristic(
+                            &score_path,
+                            "The score set has no products, used all affected products of the vulnerability.",
+                        );
+                        products = Value::Array(affected);
+                    }
+                    let score = object([
+                        ("cvss_v2", self.cvss_v2(score_set, &score_path)),
+                        ("cvss_v3", self.cvss_v3(score_set, &score_path)),
+                        ("products", Some(products)),
+                    ]);
+                    Some(score)
+                })
+                .collect(),
+        )
+    }
+
+    fn cvss_v2(&mut self, score_set: Node, path: &str) -> Option<Value> {
+        let base_score = child_text(score_set, "BaseScoreV2")?;
+        let Some(vector) = child_text(score_set, "VectorV2") else {
+            self.report.lossy(
+                format!("{path}/BaseScoreV2"),
+                "The CVSS v2 score has no vector, which is mandatory in CSAF, so it was dropped.",
+            );
+            return None;
+        };
+        Some(with_vector_metrics::<CvssV2>(object([
+            ("version", Some(json!("2.0"))),
+            ("vectorString", Some(json!(vector))),
+            ("baseScore", parse_score(&base_score)),
+            (
+                "temporalScore",
+                child_text(score_set, "TemporalScoreV2").and_then(|s| parse_score(&s)),
+            ),
+            (
+                "environmentalScore",
+                child_text(score_set, "EnvironmentalScoreV2").and_then(|s| parse_score(&s)),
+            ),
+        ])))
+    }
+
+    fn cvss_v3(&mut self, score_set: Node, path: &str) -> Option<Value> {
+        let base_score = child_text(score_set, "BaseScoreV3")?;
+        let Some(vector) = child_text(score_set, "VectorV3") else {
+            self.report.lossy(
+                format!("{path}/BaseScoreV3"),
+                "The CVSS v3 score has no vector, which is mandatory in CSAF, so it was dropped.",
+            );
+            return None;
+        };
+        let vector = if vector.starts_with("CVSS:3.") {
+            vector
+        } else {
+            self.report.heuristic(
+                format!("{path}/VectorV3"),
+                "The CVSS v3 vector has no version prefix, assumed CVSS 3.0.",
+            );
+            format!("CVSS:3.0/{vector}")
+        };
+        let Some(version) = vector.get("CVSS:".len().."CVSS:3.0".len()) else {
+            self.report.lossy(
+                format!("{path}/VectorV3"),
+                format!("The CVSS v3 vector '{vector}' is malformed, so the score was dropped."),
+            );
+            return None;
+        };
+        let base_score_value = parse_score(&base_score);
+        Some(with_vector_metrics::<CvssV3>(object([
+            ("version", Some(json!(version))),
+            ("vectorString", Some(json!(vector))),
+            ("baseScore", base_score_value.clone()),
+            (
+                "baseSeverity",
+                base_score_value
+                    .and_then(|s| s.as_f64())
+                    .map(|s| json!(cvss_v3_severity(s))),
+            ),
+            (
+                "temporalScore",
+                child_text(score_set, "TemporalScoreV3").and_then(|s| parse_score(&s)),
+            ),
+            (
+                "environmentalScore",
+                child_text(score_set, "EnvironmentalScoreV3").and_then(|s| parse_score(&s)),
+            ),
+        ])))
+    }
+}
+
+fn notes(notes: Node) -> Value {
+    Value::Array(
+        children(notes, "Note")
+            .map(|note| {
+                object([
+                    ("audience", note.attribute("Audience").map(|a| json!(a))),
+                    ("category", note.attribute("Type").map(|t| json!(snake_case(t)))),
+                    ("text", text(note).map(Value::from)),
+                    ("title", note.attribute("Title").map(|t| json!(t))),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn references(references: Node) -> Value {
+    Value::Array(
+        children(references, "Reference")
+            .map(|reference| {
+                object([
+                    ("category", reference.attribute("Type").map(|t| json!(snake_case(t)))),
+                    ("summary", child_text(reference, "Description").map(Value::from)),
+                    ("url", child_text(reference, "URL").map(Value::from)),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn full_product_name(full_product_name: Node) -> Value {
+    object([
+        ("name", text(full_product_name).map(Value::from)),
+        (
+            "product_id",
+            full_product_name.attribute("ProductID").map(|id| json!(id)),
+        ),
+        (
+            "product_identification_helper",
+            full_product_name.attribute("CPE").map(|cpe| json!({ "cpe": cpe })),
+        ),
+    ])
+}
+
+fn product_ids(node: Node) -> Value {
+    Value::Array(children(node, "ProductID").filter_map(text).map(Value::from).collect())
+}
+
+fn group_ids(node: Node) -> Value {
+    Value::Array(children(node, "GroupID").filter_map(text).map(Value::from).collect())
+}
+
+/// Adds the metrics of the vector as properties, as CSAF expects both to match (see 6.1.10).
+///
+/// Scores and severities are not derived from the vector but taken from the CVRF document.
+fn with_vector_metrics<T: FromStr + Serialize>(cvss: Value) -> Value {
+    let Value::Object(mut cvss) = cvss else {
+        return cvss;
+    };
+    let metrics = cvss
+        .get("vectorString")
+        .and_then(Value::as_str)
+        .and_then(|vector| T::from_str(vector).ok())
+        .and_then(|parsed| serde_json::to_value(parsed).ok());
+    if let Some(Value::Object(metrics)) = metrics {
+        for (key, value) in metrics {
+            if !key.ends_with("Score") && !key.ends_with("Severity") && !cvss.contains_key(&key) {
+                cvss.insert(key, value);
+            }
+        }
+    }
+    Value::Object(cvss)
+}
+
+fn parse_score(score: &str) -> Option<Value> {
+    score.parse::<f64>().ok().map(Value::from)
+}
+
+fn cvss_v3_severity(score: f64) -> &'static str {
+    match score {
+        0.0 => "NONE",
+        s if s < 4.0 => "LOW",
+        s if s < 7.0 => "MEDIUM",
+        s if s < 9.0 => "HIGH",
+        _ => "CRITICAL",
+    }
+}
+
+/// Converts CVRF enumeration values like `Known Not Affected` to CSAF ones like `known_not_affected`.
+fn snake_case(value: &str) -> String {
+    value.trim().to_lowercase().replace(' ', "_")
+}
+
+fn indexed(path: &str, i: usize) -> String {
+    format!("{path}[{}]", i + 1)
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+fn children<'a, 'input>(node: Node<'a, 'input>, name: &str) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn text(node: Node) -> Option<String> {
+    node.text()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(String::from)
+}
+
+fn child_text(node: Node, name: &str) -> Option<String> {
+    child(node, name).and_then(text)
+}
+
+/// Builds a JSON object, leaving out missing values and empty arrays.
+fn object<const N: usize>(entries: [(&str, Option<Value>); N]) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .filter_map(|(key, value)| match value {
+                Some(Value::Array(items)) if items.is_empty() => None,
+                Some(value) => Some((key.to_string(), value)),
+                None => None,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::report::ConversionChangeKind;
+
+    const CVRF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<cvrfdoc xmlns="http://www.icasi.org/CVRF/schema/cvrf/1.2"
+         xmlns:prod="http://www.icasi.org/CVRF/schema/prod/1.2"
+         xmlns:vuln="http://www.icasi.org/CVRF/schema/vuln/1.2"
+         xml:lang="en">
+  <DocumentTitle>Example Company Vulnerability in Product A</DocumentTitle>
+  <DocumentType>Security Advisory</DocumentType>
+  <DocumentPublisher Type="Vendor">
+    <ContactDetails>security@example.com</ContactDetails>
+    <IssuingAuthority>Example Company PSIRT</IssuingAuthority>
+  </DocumentPublisher>
+  <DocumentTracking>
+    <Identification>
+      <ID>EXAMPLE-2024-0001</ID>
+    </Identification>
+    <Status>Final</Status>
+    <Version>1.1</Version>
+    <RevisionHistory>
+      <Revision>
+        <Number>1.0</Number>
+        <Date>2024-01-24T10:00:00</Date>
+        <Description>Initial version.</Description>
+      </Revision>
+      <Revision>
+        <Number>1.1</Number>
+        <Date>2024-02-01T10:00:00Z</Date>
+        <Description>Added fixed version.</Description>
+      </Revision>
+    </RevisionHistory>
+    <InitialReleaseDate>2024-01-24T10:00:00Z</InitialReleaseDate>
+    <CurrentReleaseDate>2024-02-01T10:00:00Z</CurrentReleaseDate>
+  </DocumentTracking>
+  <DocumentNotes>
+    <Note Type="Legal Disclaimer" Ordinal="1" Title="Terms of use">Use at your own risk.</Note>
+  </DocumentNotes>
+  <prod:ProductTree>
+    <prod:Branch Type="Vendor" Name="Example Company">
+      <prod:Branch Type="Product Name" Name="Product A">
+        <prod:Branch Type="Product Version" Name="1.0">
+          <prod:FullProductName ProductID="CSAFPID-0001">Product A 1.0</prod:FullProductName>
+        </prod:Branch>
+        <prod:Branch Type="Product Version" Name="1.1">
+          <prod:FullProductName ProductID="CSAFPID-0002" CPE="cpe:/a:example:product_a:1.1">Product A 1.1</prod:FullProductName>
+        </prod:Branch>
+      </prod:Branch>
+    </prod:Branch>
+    <prod:FullProductName ProductID="CSAFPID-0003">Example OS</prod:FullProductName>
+    <prod:Relationship ProductReference="CSAFPID-0001" RelationType="Installed On" RelatesToProductReference="CSAFPID-0003">
+      <prod:FullProductName ProductID="CSAFPID-0004">Product A 1.0 on Example OS</prod:FullProductName>
+    </prod:Relationship>
+  </prod:ProductTree>
+  <vuln:Vulnerability Ordinal="1">
+    <vuln:CVE>CVE-2024-0001</vuln:CVE>
+    <vuln:CWE ID="CWE-79">Improper Neutralization of Input During Web Page Generation ('Cross-site Scripting')</vuln:CWE>
+    <vuln:CWE ID="CWE-80">Improper Neutralization of Script-Related HTML Tags in a Web Page (Basic XSS)</vuln:CWE>
+    <vuln:ProductStatuses>
+      <vuln:Status Type="Known Affected">
+        <vuln:ProductID>CSAFPID-0001</vuln:ProductID>
+      </vuln:Status>
+      <vuln:Status Type="Fixed">
+        <vuln:ProductID>CSAFPID-0002</vuln:ProductID>
+      </vuln:Status>
+    </vuln:ProductStatuses>
+    <vuln:CVSSScoreSets>
+      <vuln:ScoreSet>
+        <vuln:BaseScoreV3>6.1</vuln:BaseScoreV3>
+        <vuln:VectorV3>CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N</vuln:VectorV3>
+      </vuln:ScoreSet>
+    </vuln:CVSSScoreSets>
+    <vuln:Remediations>
+      <vuln:Remediation Type="Vendor Fix">
+        <vuln:Description>Update to version 1.1.</vuln:Description>
+        <vuln:ProductID>CSAFPID-0001</vuln:ProductID>
+      </vuln:Remediation>
+    </vuln:Remediations>
+  </vuln:Vulnerability>
+</cvrfdoc>"#;
+
+    fn options() -> CvrfImportOptions {
+        CvrfImportOptions {
+            publisher_name: Some("Example Company".to_string()),
+            publisher_namespace: Some("https://example.com".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_import_as_csaf_2_0() {
+        let import = import_cvrf_as_csaf_2_0(CVRF, &options()).expect("import should succeed");
+        let json = import.document.get_json();
+
+        assert_eq!(json["document"]["category"], "csaf_security_advisory");
+        assert_eq!(json["document"]["lang"], "en");
+        assert_eq!(json["document"]["notes"][0]["category"], "legal_disclaimer");
+        assert_eq!(json["document"]["publisher"]["category"], "vendor");
+        assert_eq!(json["document"]["tracking"]["version"], "1.1.0");
+        assert_eq!(json["document"]["tracking"]["revision_history"][0]["number"], "1.0.0");
+        assert_eq!(
+            json["document"]["tracking"]["revision_history"][0]["date"],
+            "2024-01-24T10:00:00Z"
+        );
+        assert_eq!(
+            json["product_tree"]["branches"][0]["branches"][0]["branches"][1]["product"],
+            json!({
+                "name": "Product A 1.1",
+                "product_id": "CSAFPID-0002",
+                "product_identification_helper": { "cpe": "cpe:/a:example:product_a:1.1" }
+            })
+        );
+        assert_eq!(json["product_tree"]["relationships"][0]["category"], "installed_on");
+        let vulnerability = &json["vulnerabilities"][0];
+        assert_eq!(vulnerability["cwe"]["id"], "CWE-79");
+        assert_eq!(
+            vulnerability["product_status"]["known_affected"],
+            json!(["CSAFPID-0001"])
+        );
+        assert_eq!(vulnerability["product_status"]["fixed"], json!(["CSAFPID-0002"]));
+        assert_eq!(vulnerability["remediations"][0]["category"], "vendor_fix");
+        assert_eq!(vulnerability["scores"][0]["cvss_v3"]["baseSeverity"], "MEDIUM");
+        assert_eq!(vulnerability["scores"][0]["cvss_v3"]["attackVector"], "NETWORK");
+        assert_eq!(vulnerability["scores"][0]["products"], json!(["CSAFPID-0001"]));
+
+        assert!(import.document.get_parsed().is_ok());
+        assert_eq!(
+            import
+                .warnings
+                .changes
+                .iter()
+                .map(|change| (change.instance_path.as_str(), change.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "/cvrfdoc/DocumentTracking/RevisionHistory/Revision[1]/Date",
+                    ConversionChangeKind::Heuristic
+                ),
+                ("/cvrfdoc/Vulnerability[1]/CWE", ConversionChangeKind::Lossy),
+                (
+                    "/cvrfdoc/Vulnerability[1]/CVSSScoreSets/ScoreSet[1]",
+                    ConversionChangeKind::Heuristic
+                ),
+            ]
+        );
+        assert!(!import.validation.test_results.is_empty());
+    }
+
+    #[test]
+    fn test_import_as_csaf_2_1() {
+        let import = import_cvrf_as_csaf_2_1(CVRF, &options()).expect("import should succeed");
+        let json = import.document.get_json();
+        assert_eq!(json["document"]["csaf_version"], "2.1");
+        assert_eq!(json["vulnerabilities"][0]["cwes"][0]["id"], "CWE-79");
+        assert!(json["product_tree"]["product_paths"].is_array());
+        assert!(import.document.get_parsed().is_ok());
+        // the missing TLP label is reported by the CSAF 2.1 conversion
+        assert!(
+            import
+                .warnings
+                .changes
+                .iter()
+                .any(|change| change.instance_path == "/document/distribution")
+        );
+    }
+
+    #[test]
+    fn test_missing_publisher_is_reported() {
+        let import = import_cvrf_as_csaf_2_0(CVRF, &CvrfImportOptions::default()).unwrap();
+        assert!(
+            import.document.get_json()["document"]["publisher"]
+                .get("name")
+                .is_none()
+        );
+        assert_eq!(
+            import
+                .warnings
+                .changes
+                .iter()
+                .filter(|change| change.instance_path == "/cvrfdoc/DocumentPublisher")
+                .count(),
+            2
+        );
+        assert!(matches!(
+            import_cvrf_as_csaf_2_1(CVRF, &CvrfImportOptions::default()),
+            Err(CvrfImportError::InvalidDocument(_))
+        ));
+    }
+
+    #[test]
+    fn test_version_numbers() {
+        let mut importer = Importer {
+            options: &CvrfImportOptions::default(),
+            report: ConversionReport::default(),
+        };
+        let some = |v: &str| Some(v.to_string());
+        assert_eq!(
+            importer.version_numbers(some("2"), vec![some("1"), some("2")]),
+            (some("2"), vec![some("1"), some("2")])
+        );
+        assert_eq!(
+            importer.version_numbers(some("1.1"), vec![some("1"), some("1.1")]),
+            (some("1.1.0"), vec![some("1.0.0"), some("1.1.0")])
+        );
+        assert!(importer.report.is_lossless());
+        assert_eq!(
+            importer.version_numbers(some("1.0.0.1"), vec![some("1.0-beta"), some("1.0.0.1")]),
+            (some("2"), vec![some("1"), some("2")])
+        );
+        assert_eq!(importer.report.changes.len(), 1);
+    }
+
+    #[test]
+    fn test_malformed_cvss_v3_vector() {
+        let mut importer = Importer {
+            options: &CvrfImportOptions::default(),
+            report: ConversionReport::default(),
+        };
+        // a truncated vector and a multibyte character inside the version are dropped, not sliced
+        for vector in ["CVSS:3.", "CVSS:3.\u{e9}/AV:N"] {
+            let xml = format!("<ScoreSet><BaseScoreV3>6.1</BaseScoreV3><VectorV3>{vector}</VectorV3></ScoreSet>");
+            let score_set = roxmltree::Document::parse(&xml).unwrap();
+            assert_eq!(importer.cvss_v3(score_set.root_element(), "/ScoreSet"), None);
+        }
+        assert_eq!(
+            importer
+                .report
+                .changes
+                .iter()
+                .map(|change| (change.instance_path.as_str(), change.kind))
+                .collect::<Vec<_>>(),
+            vec![("/ScoreSet/VectorV3", ConversionChangeKind::Lossy); 2]
+        );
+    }
+
+    #[test]
+    fn test_not_cvrf() {
+        assert!(matches!(
+            import_cvrf_as_csaf_2_0("<rss/>", &options()),
+            Err(CvrfImportError::NotCvrf(root)) if root == "rss"
+        ));
+        assert!(matches!(
+            import_cvrf_as_csaf_2_0("<cvrfdoc>", &options()),
+            Err(CvrfImportError::Xml(_))
+        ));
+    }
+}
@@ -1,3 +1,10 @@
+mod csaf2_0_to_csaf2_1;
+pub mod cvrf;
+mod report;
+
+pub use csaf2_0_to_csaf2_1::{Conversion, ConversionError, ConverterOptions, convert_csaf_2_0_to_2_1};
+pub use report::{ConversionChange, ConversionChangeKind, ConversionReport};
+
 use crate::csaf_traits::{CsafTrait, CsafVersion, DocumentTrait};
 
 /// Checks if a CSAF document is version 2.0
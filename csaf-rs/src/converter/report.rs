@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Kind of a change the converter had to make, which could not be done losslessly.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionChangeKind {
+    /// Information of the original document was dropped or replaced by something less specific.
+    Lossy,
+    /// The converter had to guess a value, which should be reviewed by a human.
+    Heuristic,
+}
+
+/// A single lossy or heuristic change made during conversion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConversionChange {
+    /// JSON pointer to the affected element in the original document
+    pub instance_path: String,
+    /// Whether the change lost information or was guessed
+    pub kind: ConversionChangeKind,
+    /// Human-readable description of the change
+    pub message: String,
+}
+
+/// Report of all lossy or heuristic changes made while converting a single document.
+///
+/// Changes that follow directly from the conversion rules (e.g. renaming `release_date` to
+/// `disclosure_date`) are not reported, as they do not need a review.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConversionReport {
+    pub changes: Vec<ConversionChange>,
+}
+
+impl ConversionReport {
+    /// Returns true if the conversion did not need any lossy or heuristic changes.
+    pub fn is_lossless(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub(crate) fn lossy(&mut self, instance_path: impl Into<String>, message: impl Into<String>) {
+        self.push(ConversionChangeKind::Lossy, instance_path.into(), message.into());
+    }
+
+    pub(crate) fn heuristic(&mut self, instance_path: impl Into<String>, message: impl Into<String>) {
+        self.push(ConversionChangeKind::Heuristic, instance_path.into(), message.into());
+    }
+
+    fn push(&mut self, kind: ConversionChangeKind, instance_path: String, message: String) {
+        self.changes.push(ConversionChange {
+            instance_path,
+            kind,
+            message,
+        });
+    }
+}
+
+impl Display for ConversionChangeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ConversionChangeKind::Lossy => write!(f, "lossy"),
+            ConversionChangeKind::Heuristic => write!(f, "heuristic"),
+        }
+    }
+}
+
+impl Display for ConversionChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} [{}]: {}", self.instance_path, self.kind, self.message)
+    }
+}
@@ -1,9 +1,8 @@
 /// Macro to skip a validation test if the document status is NOT one of the provided variants.
 ///
 /// If the document's status does not match any of the provided variants,
-/// the macro returns `Ok(())` early (i.e. the test is skipped).
-///
-/// TODO #409 this will be wasSkipped later, now unified in one place :)
+/// the macro returns early with [Passed::NotApplicable](crate::validation_result::Passed::NotApplicable),
+/// so the caller has to return a [TestResult](crate::validation_result::TestResult).
 ///
 /// # Usage
 ///
@@ -15,16 +14,20 @@
 /// Expands to the equivalent of:
 /// ```ignore
 /// if !matches!(status, DocumentStatus::Final | DocumentStatus::Interim) {
-///     return Ok(());
+///     return TestResult::not_applicable("...");
 /// }
 /// ```
 macro_rules! skip_if_document_status_is_not {
     ($status:expr, $($variant:ident),+ $(,)?) => {
+        let status = &$status;
         if !matches!(
-            $status,
+            status,
             $(crate::schema::csaf2_1::schema::DocumentStatus::$variant)|+
         ) {
-            return Ok(());
+            return crate::validation_result::TestResult::not_applicable(format!(
+                "The test only applies to documents with status {}, but the document status is {status}.",
+                [$(crate::schema::csaf2_1::schema::DocumentStatus::$variant.to_string()),+].join(" or ")
+            ));
         }
     };
 }
@@ -34,32 +37,55 @@ pub(crate) use skip_if_document_status_is_not;
 #[cfg(test)]
 mod tests {
     use crate::schema::csaf2_1::schema::DocumentStatus;
+    use crate::validation_result::{Passed, TestResult};
+
+    // an empty failure is just used as a marker for "did not skip"
+    const NOT_SKIPPED: TestResult = TestResult::Failed(vec![]);
 
-    fn run_skip_if_is_not_interim_or_final(status: DocumentStatus) -> Result<(), ()> {
+    fn run_skip_if_is_not_interim_or_final(status: DocumentStatus) -> TestResult {
         skip_if_document_status_is_not!(status, Interim, Final);
-        Err(()) // err is just used as a marker for "did not skip"
+        NOT_SKIPPED
     }
 
-    fn run_skip_if_is_not_draft(status: DocumentStatus) -> Result<(), ()> {
+    fn run_skip_if_is_not_draft(status: DocumentStatus) -> TestResult {
         skip_if_document_status_is_not!(status, Draft);
-        Err(()) // err is just used as a marker for "did not skip"
+        NOT_SKIPPED
+    }
+
+    fn is_not_applicable(result: TestResult) -> bool {
+        matches!(result, TestResult::Passed(Passed::NotApplicable { .. }))
     }
 
     #[test]
     fn test_doc_status_final() {
-        assert_eq!(run_skip_if_is_not_interim_or_final(DocumentStatus::Final), Err(()));
-        assert_eq!(run_skip_if_is_not_draft(DocumentStatus::Final), Ok(()));
+        assert_eq!(run_skip_if_is_not_interim_or_final(DocumentStatus::Final), NOT_SKIPPED);
+        assert!(is_not_applicable(run_skip_if_is_not_draft(DocumentStatus::Final)));
     }
 
     #[test]
     fn test_doc_status_interim() {
-        assert_eq!(run_skip_if_is_not_interim_or_final(DocumentStatus::Interim), Err(()));
-        assert_eq!(run_skip_if_is_not_draft(DocumentStatus::Interim), Ok(()));
+        assert_eq!(
+            run_skip_if_is_not_interim_or_final(DocumentStatus::Interim),
+            NOT_SKIPPED
+        );
+        assert!(is_not_applicable(run_skip_if_is_not_draft(DocumentStatus::Interim)));
     }
 
     #[test]
     fn test_doc_status_draft() {
-        assert_eq!(run_skip_if_is_not_interim_or_final(DocumentStatus::Draft), Ok(()));
-        assert_eq!(run_skip_if_is_not_draft(DocumentStatus::Draft), Err(()));
+        assert!(is_not_applicable(run_skip_if_is_not_interim_or_final(
+            DocumentStatus::Draft
+        )));
+        assert_eq!(run_skip_if_is_not_draft(DocumentStatus::Draft), NOT_SKIPPED);
+    }
+
+    #[test]
+    fn test_not_applicable_message() {
+        assert_eq!(
+            run_skip_if_is_not_interim_or_final(DocumentStatus::Draft),
+            TestResult::not_applicable(
+                "The test only applies to documents with status interim or final, but the document status is draft."
+            )
+        );
     }
 }
@@ -1,13 +1,14 @@
-use std::cell::OnceCell;
+use std::sync::OnceLock;
 
 use serde::de::DeserializeOwned;
 
-use crate::validation::{TestFinding, TestResult, TestResultStatus, Validatable};
+use crate::validation::{TestResult, TestResultStatus, Validatable};
+use crate::validation_result;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RawDocument<T> {
     raw: serde_json::Value,
-    parsed: OnceCell<Result<T, String>>,
+    parsed: OnceLock<Result<T, String>>,
 }
 
 impl<T> RawDocument<T> {
@@ -23,7 +24,7 @@ where
     pub fn new(raw: serde_json::Value) -> Self {
         Self {
             raw,
-            parsed: OnceCell::new(),
+            parsed: OnceLock::new(),
         }
     }
 }
@@ -38,11 +39,20 @@ where
         self.parsed
             .get_or_init(|| T::deserialize(&self.raw).map_err(|e| e.to_string()))
     }
+
+    fn get_raw_json(&self) -> Option<&serde_json::Value> {
+        Some(&self.raw)
+    }
 }
 
 pub trait HasParsed {
     type Parsed;
     fn get_parsed(&self) -> &Result<Self::Parsed, String>;
+
+    /// Returns the raw JSON the document was deserialized from, if it is kept.
+    fn get_raw_json(&self) -> Option<&serde_json::Value> {
+        None
+    }
 }
 
 pub trait RawValidatable {
@@ -59,6 +69,11 @@ where
     T: HasParsed + RawValidatable,
     T::Parsed: Validatable,
 {
+    /// Returns all tests of the CSAF version with the state of their implementation
+    fn test_capabilities() -> Vec<crate::test_info::TestCapability> {
+        T::Parsed::test_capabilities()
+    }
+
     /// Returns the test IDs belonging to a preset
     fn tests_in_preset(preset: &str) -> Option<Vec<&'static str>> {
         T::Parsed::tests_in_preset(preset)
@@ -73,9 +88,9 @@ where
 
         match self.get_parsed() {
             Ok(parsed) => parsed.run_test(test_id),
-            Err(_) => TestResult {
+            Err(message) => TestResult {
                 test_id: test_id.to_string(),
-                status: crate::validation::TestResultStatus::Skipped,
+                status: validation_result::TestResult::deserialization_failed(message.as_str()).into(),
             },
         }
     }
@@ -86,11 +101,11 @@ where
     T: crate::test_validation::TestValidator<CSAF>,
     CSAF: DeserializeOwned,
 {
-    fn validate(&self, doc: &RawDocument<CSAF>) -> Result<(), Vec<TestFinding>> {
+    fn validate(&self, doc: &RawDocument<CSAF>) -> validation_result::TestResult {
         let parsed = doc.get_parsed();
         match parsed {
             Ok(parsed_doc) => self.validate(parsed_doc),
-            Err(_) => Ok(()),
+            Err(message) => validation_result::TestResult::deserialization_failed(message.as_str()),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{WithOptionalGroupIds, WithOptionalProductIds};
+use crate::csaf_traits::{ReferenceTrait, WithOptionalGroupIds, WithOptionalProductIds};
 
 /// Central helper function for extracting group references.
 ///
@@ -112,6 +112,57 @@ impl<T: WithOptionalProductIds> ExtractProductReferences<T> for Vec<T> {
     }
 }
 
+/// Central helper function for extracting reference URLs.
+///
+/// This function implements the core logic for extracting URLs and their JSON paths from an
+/// iterator of items that implement `ReferenceTrait`, keeping only the references accepted by
+/// `filter`.
+///
+/// # Arguments
+///
+/// * `items` - An iterator over items that implement `ReferenceTrait`
+/// * `path_prefix` - A string representing the prefix for the JSON path (e.g., "/document/references")
+/// * `filter` - A predicate selecting the references to extract, e.g. by their category
+///
+/// # Returns
+///
+/// A vector of tuples containing (url, json_path) for each selected reference.
+fn extract_reference_url_impl<'a, T: ReferenceTrait + 'a>(
+    items: impl Iterator<Item = &'a T>,
+    path_prefix: &str,
+    filter: impl Fn(&T) -> bool,
+) -> Vec<(String, String)> {
+    items
+        .enumerate()
+        .filter(|(_, item)| filter(item))
+        .map(|(index, item)| (item.get_url().to_owned(), format!("{path_prefix}/{index}/url")))
+        .collect()
+}
+
+/// Extension trait for extracting reference URLs from collections where T implements ReferenceTrait.
+///
+/// This trait provides a generic method to extract the URLs of references, returning them as
+/// tuples of (url, json_path).
+///
+/// Implemented for:
+/// - `Option<&Vec<T>>`
+/// - `Vec<T>`
+pub trait ExtractReferenceUrls<T: ReferenceTrait> {
+    fn extract_reference_urls(&self, path_prefix: &str, filter: impl Fn(&T) -> bool) -> Vec<(String, String)>;
+}
+
+impl<T: ReferenceTrait> ExtractReferenceUrls<T> for Option<&Vec<T>> {
+    fn extract_reference_urls(&self, path_prefix: &str, filter: impl Fn(&T) -> bool) -> Vec<(String, String)> {
+        extract_reference_url_impl(self.iter().flat_map(|x| x.iter()), path_prefix, filter)
+    }
+}
+
+impl<T: ReferenceTrait> ExtractReferenceUrls<T> for Vec<T> {
+    fn extract_reference_urls(&self, path_prefix: &str, filter: impl Fn(&T) -> bool) -> Vec<(String, String)> {
+        extract_reference_url_impl(self.iter(), path_prefix, filter)
+    }
+}
+
 /// Generates individual group/product reference extraction methods and aggregated
 /// `get_all_group_references` / `get_all_product_references` methods from a single
 /// field declaration.
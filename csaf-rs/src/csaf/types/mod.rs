@@ -6,4 +6,5 @@ pub mod csaf_product_id_helper_number;
 pub mod csaf_vuln_metric;
 pub mod language;
 pub mod purl;
+pub mod vers;
 pub mod version_number;
@@ -0,0 +1,197 @@
+pub use crate::csaf::types::vers::valid_vers_range::ValidVersRange;
+pub use crate::csaf::types::vers::vers_error::{VersParseError, VersParseErrorKind};
+use crate::csaf::types::vers::{VersComparator, VersConstraint, VersioningScheme};
+use std::cmp::Ordering;
+
+/// Represents a parsed version range specifier (vers) that is either valid or invalid.
+///
+/// See <https://github.com/package-url/purl-spec/blob/main/VERSION-RANGE-SPEC.rst> for the
+/// specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsafVersRange {
+    /// A successfully parsed and validated vers range.
+    Valid(ValidVersRange),
+    /// A vers range that failed parsing or validation.
+    Invalid(VersParseError),
+}
+
+impl CsafVersRange {
+    fn parse(vers_str: &str) -> CsafVersRange {
+        match Self::parse_parts(vers_str) {
+            Ok((scheme, constraints)) => Self::Valid(ValidVersRange::new(vers_str.to_owned(), scheme, constraints)),
+            Err(kind) => Self::Invalid(VersParseError::new(vers_str, kind)),
+        }
+    }
+
+    fn parse_parts(vers_str: &str) -> Result<(VersioningScheme, Vec<VersConstraint>), VersParseErrorKind> {
+        // whitespace is not significant in vers
+        let vers: String = vers_str.chars().filter(|c| !c.is_whitespace()).collect();
+
+        let rest = match vers.split_once(':') {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("vers") => rest,
+            _ => return Err(VersParseErrorKind::MissingScheme),
+        };
+        let (scheme_name, constraints_str) = match rest.split_once('/') {
+            Some(parts) => parts,
+            None if rest.is_empty() => return Err(VersParseErrorKind::MissingVersioningScheme),
+            None => return Err(VersParseErrorKind::MissingConstraints),
+        };
+        if scheme_name.is_empty() {
+            return Err(VersParseErrorKind::MissingVersioningScheme);
+        }
+        let scheme_name = scheme_name.to_ascii_lowercase();
+        let is_valid_scheme_name = scheme_name.starts_with(|c: char| c.is_ascii_lowercase() || ".-+".contains(c))
+            && scheme_name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || ".-+".contains(c));
+        if !is_valid_scheme_name {
+            return Err(VersParseErrorKind::InvalidVersioningScheme(scheme_name));
+        }
+        let scheme = VersioningScheme::from_name(&scheme_name);
+
+        if constraints_str.is_empty() {
+            return Err(VersParseErrorKind::MissingConstraints);
+        }
+        if constraints_str == "*" {
+            return Ok((scheme, Vec::new()));
+        }
+
+        let mut constraints = Vec::new();
+        for constraint in constraints_str.split('|') {
+            if constraint.is_empty() {
+                return Err(VersParseErrorKind::EmptyConstraint);
+            }
+            if constraint == "*" {
+                return Err(VersParseErrorKind::StarWithOtherConstraints);
+            }
+            let (symbol, version) =
+                constraint.split_at(constraint.find(|c| !"<>=!".contains(c)).unwrap_or(constraint.len()));
+            let comparator = VersComparator::from_symbol(symbol)
+                .ok_or_else(|| VersParseErrorKind::InvalidComparator(symbol.to_owned()))?;
+            if version.is_empty() {
+                return Err(VersParseErrorKind::MissingVersion(constraint.to_owned()));
+            }
+            if !scheme.is_valid_version(version) {
+                return Err(VersParseErrorKind::InvalidVersion(scheme_name, version.to_owned()));
+            }
+            constraints.push(VersConstraint::new(comparator, version.to_owned()));
+        }
+
+        constraints.sort_by(|a, b| scheme.compare_versions(a.version(), b.version()));
+
+        if let Some(duplicate) = constraints
+            .windows(2)
+            .find(|pair| scheme.compare_versions(pair[0].version(), pair[1].version()) == Ordering::Equal)
+        {
+            return Err(VersParseErrorKind::DuplicateVersion(duplicate[1].version().to_owned()));
+        }
+
+        Self::check_comparator_sequence(&constraints)?;
+
+        Ok((scheme, constraints))
+    }
+
+    /// Checks that the sorted constraints, ignoring `!=`, describe non-overlapping ranges:
+    /// `>` and `>=` may only be followed by `<` or `<=`, and `<` and `<=` may only be followed by
+    /// `=`, `>` or `>=`.
+    fn check_comparator_sequence(constraints: &[VersConstraint]) -> Result<(), VersParseErrorKind> {
+        let relevant: Vec<&VersConstraint> = constraints
+            .iter()
+            .filter(|c| c.comparator() != VersComparator::NotEqual)
+            .collect();
+        for pair in relevant.windows(2) {
+            let (current, next) = (pair[0].comparator(), pair[1].comparator());
+            let is_valid = if current.is_lower_bound() {
+                next.is_upper_bound()
+            } else if current.is_upper_bound() {
+                !next.is_upper_bound()
+            } else {
+                true
+            };
+            if !is_valid {
+                return Err(VersParseErrorKind::InvalidComparatorSequence(
+                    pair[0].to_string(),
+                    pair[1].to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for CsafVersRange {
+    fn from(vers: &str) -> Self {
+        Self::parse(vers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn valid(vers: &str) -> ValidVersRange {
+        match CsafVersRange::from(vers) {
+            CsafVersRange::Valid(valid) => valid,
+            CsafVersRange::Invalid(err) => panic!("Expected {vers} to be valid, but got: {}", err.kind()),
+        }
+    }
+
+    #[rstest]
+    #[case::canonical("vers:semver/>=1.0.0|<2.0.0", "vers:semver/>=1.0.0|<2.0.0")]
+    #[case::whitespace_and_case("VERS:SemVer/ >=1.0.0 | <2.0.0 ", "vers:semver/>=1.0.0|<2.0.0")]
+    #[case::unsorted("vers:npm/<2.0.0|>=1.0.0", "vers:npm/>=1.0.0|<2.0.0")]
+    #[case::explicit_equal("vers:generic/=1.2|=1.10", "vers:generic/1.2|1.10")]
+    #[case::star("vers:deb/*", "vers:deb/*")]
+    #[case::unknown_scheme("vers:gem/>=2.2.0|!=2.2.1|<2.3.0", "vers:gem/>=2.2.0|!=2.2.1|<2.3.0")]
+    #[case::deb("vers:deb/>=1.0~rc1|<1.0-1", "vers:deb/>=1.0~rc1|<1.0-1")]
+    #[case::maven("vers:maven/<1.0|>=1.0-sp1|<1.1-SNAPSHOT", "vers:maven/<1.0|>=1.0-sp1|<1.1-SNAPSHOT")]
+    #[case::pypi("vers:pypi/>=1.0rc1|<1.0.post1", "vers:pypi/>=1.0rc1|<1.0.post1")]
+    fn test_normalization(#[case] vers: &str, #[case] normalized: &str) {
+        assert_eq!(valid(vers).normalized_vers(), normalized);
+    }
+
+    #[rstest]
+    #[case::missing_scheme("semver/1.0.0", VersParseErrorKind::MissingScheme)]
+    #[case::missing_versioning_scheme("vers:/1.0.0", VersParseErrorKind::MissingVersioningScheme)]
+    #[case::invalid_versioning_scheme("vers:np_m/1.0.0", VersParseErrorKind::InvalidVersioningScheme("np_m".to_string()))]
+    #[case::missing_constraints("vers:npm/", VersParseErrorKind::MissingConstraints)]
+    #[case::empty_constraint("vers:npm/1.0.0||2.0.0", VersParseErrorKind::EmptyConstraint)]
+    #[case::invalid_comparator("vers:npm/=>1.0.0", VersParseErrorKind::InvalidComparator("=>".to_string()))]
+    #[case::missing_version("vers:npm/>=", VersParseErrorKind::MissingVersion(">=".to_string()))]
+    #[case::invalid_semver("vers:semver/1.0", VersParseErrorKind::InvalidVersion("semver".to_string(), "1.0".to_string()))]
+    #[case::star_with_others("vers:npm/*|1.0.0", VersParseErrorKind::StarWithOtherConstraints)]
+    #[case::duplicate_version("vers:maven/>=1.0|<1.0.0", VersParseErrorKind::DuplicateVersion("1.0.0".to_string()))]
+    #[case::consecutive_lower_bounds("vers:generic/>=1.0|>=2.0", VersParseErrorKind::InvalidComparatorSequence(">=1.0".to_string(), ">=2.0".to_string()))]
+    #[case::consecutive_upper_bounds("vers:generic/<1.0|<=2.0", VersParseErrorKind::InvalidComparatorSequence("<1.0".to_string(), "<=2.0".to_string()))]
+    #[case::lower_bound_before_equal("vers:generic/>1.0|2.0", VersParseErrorKind::InvalidComparatorSequence(">1.0".to_string(), "2.0".to_string()))]
+    fn test_invalid(#[case] vers: &str, #[case] expected: VersParseErrorKind) {
+        match CsafVersRange::from(vers) {
+            CsafVersRange::Invalid(err) => assert_eq!(err.kind(), &expected),
+            CsafVersRange::Valid(valid) => panic!("Expected {vers} to be invalid, but got {valid}"),
+        }
+    }
+
+    #[rstest]
+    #[case("vers:semver/>=1.0.0|<2.0.0", "1.5.0", Some(true))]
+    #[case("vers:semver/>=1.0.0|<2.0.0", "2.0.0", Some(false))]
+    #[case("vers:semver/>=1.0.0|<2.0.0", "2.0.0-rc.1", Some(true))]
+    #[case("vers:semver/>=1.0.0|<2.0.0", "1.5", None)]
+    #[case("vers:semver/<1.0.0|>=2.0.0", "3.0.0", Some(true))]
+    #[case("vers:semver/<1.0.0|>=2.0.0", "1.5.0", Some(false))]
+    #[case("vers:npm/>=1.0.0|!=1.2.3|<2.0.0", "1.2.3", Some(false))]
+    #[case("vers:npm/!=1.2.3", "1.2.4", Some(true))]
+    #[case("vers:generic/1.2|1.10", "1.10", Some(true))]
+    #[case("vers:generic/1.2|1.10", "1.3", Some(false))]
+    #[case("vers:generic/1.0|>=2.0|<=2.5|>3.0", "2.5", Some(true))]
+    #[case("vers:generic/1.0|>=2.0|<=2.5|>3.0", "3.0", Some(false))]
+    #[case("vers:generic/1.0|>=2.0|<=2.5|>3.0", "42", Some(true))]
+    #[case("vers:deb/>=7.88.1-10|<8.13.0-5~bpo12+1", "7.88.1-10+deb12u12", Some(true))]
+    #[case("vers:rpm/<=1.0^git1", "1.0", Some(true))]
+    #[case("vers:maven/>=1.0|<1.1", "1.1-SNAPSHOT", Some(true))]
+    #[case("vers:pypi/>=1.0|<2.0", "2.0rc1", Some(true))]
+    #[case("vers:pypi/*", "0.1", Some(true))]
+    fn test_contains(#[case] vers: &str, #[case] version: &str, #[case] expected: Option<bool>) {
+        assert_eq!(valid(vers).contains(version), expected);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod csaf_vers_range;
+mod valid_vers_range;
+mod vers_constraint;
+mod vers_error;
+mod version_compare;
+mod versioning_scheme;
+
+pub use valid_vers_range::ValidVersRange;
+pub use vers_constraint::{VersComparator, VersConstraint};
+pub use vers_error::{VersParseError, VersParseErrorKind};
+pub use versioning_scheme::VersioningScheme;
@@ -0,0 +1,116 @@
+use crate::csaf::types::vers::{VersComparator, VersConstraint, VersioningScheme};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidVersRange {
+    original_vers: String,
+    normalized_vers: String,
+    scheme: VersioningScheme,
+    /// Constraints sorted by version. Empty if the range is `*`.
+    constraints: Vec<VersConstraint>,
+}
+
+impl ValidVersRange {
+    pub(super) fn new(original_vers: String, scheme: VersioningScheme, constraints: Vec<VersConstraint>) -> Self {
+        let normalized_constraints = if constraints.is_empty() {
+            "*".to_owned()
+        } else {
+            constraints.iter().map(|c| c.to_string()).collect::<Vec<_>>().join("|")
+        };
+        Self {
+            normalized_vers: format!("vers:{scheme}/{normalized_constraints}"),
+            original_vers,
+            scheme,
+            constraints,
+        }
+    }
+
+    pub fn original_vers(&self) -> &str {
+        &self.original_vers
+    }
+
+    /// Returns the normalized vers string: lowercase versioning scheme, no whitespace, constraints
+    /// sorted by version and the `=` comparator omitted.
+    pub fn normalized_vers(&self) -> &str {
+        &self.normalized_vers
+    }
+
+    pub fn scheme(&self) -> &VersioningScheme {
+        &self.scheme
+    }
+
+    /// Returns the constraints sorted by version. Empty if the range matches all versions (`*`).
+    pub fn constraints(&self) -> &[VersConstraint] {
+        &self.constraints
+    }
+
+    /// Returns true if the range matches all versions (`*`).
+    pub fn matches_all_versions(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    /// Checks whether `version` is contained in this range.
+    ///
+    /// Returns `None` if `version` is not a valid version of the range's versioning scheme.
+    pub fn contains(&self, version: &str) -> Option<bool> {
+        if !self.scheme.is_valid_version(version) {
+            return None;
+        }
+        if self.matches_all_versions() {
+            return Some(true);
+        }
+
+        let cmp = |constraint: &VersConstraint| self.scheme.compare_versions(version, constraint.version());
+        let satisfies = |constraint: &VersConstraint| {
+            let ordering = cmp(constraint);
+            match constraint.comparator() {
+                VersComparator::Equal => ordering == Ordering::Equal,
+                VersComparator::NotEqual => ordering != Ordering::Equal,
+                VersComparator::LessThan => ordering == Ordering::Less,
+                VersComparator::LessThanOrEqual => ordering != Ordering::Greater,
+                VersComparator::GreaterThan => ordering == Ordering::Greater,
+                VersComparator::GreaterThanOrEqual => ordering != Ordering::Less,
+            }
+        };
+
+        let (excluded, constraints): (Vec<_>, Vec<_>) = self
+            .constraints
+            .iter()
+            .partition(|c| c.comparator() == VersComparator::NotEqual);
+        if excluded.iter().any(|c| !satisfies(c)) {
+            return Some(false);
+        }
+        // A range consisting only of `!=` constraints contains every other version.
+        if constraints.is_empty() {
+            return Some(true);
+        }
+
+        // The constraints are sorted and validated, so lower bounds are always followed by their
+        // upper bound (if any), and upper bounds not preceded by a lower bound are unbounded below.
+        for (i, constraint) in constraints.iter().enumerate() {
+            let comparator = constraint.comparator();
+            let contained = if comparator == VersComparator::Equal {
+                satisfies(constraint)
+            } else if comparator.is_lower_bound() {
+                satisfies(constraint)
+                    && constraints
+                        .get(i + 1)
+                        .is_none_or(|next| !next.comparator().is_upper_bound() || satisfies(next))
+            } else {
+                let follows_lower_bound = i > 0 && constraints[i - 1].comparator().is_lower_bound();
+                !follows_lower_bound && satisfies(constraint)
+            };
+            if contained {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
+}
+
+impl Display for ValidVersRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (normalized: {})", self.original_vers, self.normalized_vers)
+    }
+}
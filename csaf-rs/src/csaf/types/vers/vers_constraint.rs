@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+/// Comparator of a single vers constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VersComparator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl VersComparator {
+    /// Parses a comparator. An empty string is the implicit `=`.
+    pub(super) fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "" | "=" => Some(Self::Equal),
+            "!=" => Some(Self::NotEqual),
+            "<" => Some(Self::LessThan),
+            "<=" => Some(Self::LessThanOrEqual),
+            ">" => Some(Self::GreaterThan),
+            ">=" => Some(Self::GreaterThanOrEqual),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Equal => "=",
+            Self::NotEqual => "!=",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        }
+    }
+
+    pub(super) fn is_lower_bound(&self) -> bool {
+        matches!(self, Self::GreaterThan | Self::GreaterThanOrEqual)
+    }
+
+    pub(super) fn is_upper_bound(&self) -> bool {
+        matches!(self, Self::LessThan | Self::LessThanOrEqual)
+    }
+}
+
+impl Display for VersComparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// A single `<comparator><version>` constraint of a vers range.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VersConstraint {
+    comparator: VersComparator,
+    version: String,
+}
+
+impl VersConstraint {
+    pub(super) fn new(comparator: VersComparator, version: String) -> Self {
+        Self { comparator, version }
+    }
+
+    pub fn comparator(&self) -> VersComparator {
+        self.comparator
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+/// Displays the constraint in its normalized form, where the `=` comparator is omitted.
+impl Display for VersConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.comparator {
+            VersComparator::Equal => write!(f, "{}", self.version),
+            comparator => write!(f, "{comparator}{}", self.version),
+        }
+    }
+}
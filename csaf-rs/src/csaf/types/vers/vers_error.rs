@@ -0,0 +1,73 @@
+use crate::validation::{IntoTestFindingError, TestFinding, TestFindingData};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersParseError {
+    original_vers: String,
+    kind: VersParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersParseErrorKind {
+    MissingScheme,
+    MissingVersioningScheme,
+    InvalidVersioningScheme(String),
+    MissingConstraints,
+    EmptyConstraint,
+    InvalidComparator(String),
+    MissingVersion(String),
+    InvalidVersion(String, String),
+    StarWithOtherConstraints,
+    DuplicateVersion(String),
+    InvalidComparatorSequence(String, String),
+}
+
+impl VersParseError {
+    /// Private constructor
+    pub(super) fn new(vers_str: &str, kind: VersParseErrorKind) -> Self {
+        Self {
+            original_vers: vers_str.to_owned(),
+            kind,
+        }
+    }
+
+    pub fn kind(&self) -> &VersParseErrorKind {
+        &self.kind
+    }
+
+    /// Returns the raw input vers string that failed parsing.
+    pub fn original_vers(&self) -> &str {
+        self.original_vers.as_str()
+    }
+}
+
+impl IntoTestFindingError for VersParseError {
+    fn into_test_finding_error(self, instance_path: &str) -> TestFinding {
+        TestFinding::Error(TestFindingData {
+            message: format!("Invalid vers format: {}, Error: {}", self.original_vers, self.kind),
+            instance_path: instance_path.to_string(),
+        })
+    }
+}
+
+impl Display for VersParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingScheme => write!(f, "missing scheme \"vers:\""),
+            Self::MissingVersioningScheme => write!(f, "missing versioning scheme"),
+            Self::InvalidVersioningScheme(scheme) => write!(f, "invalid versioning scheme: {scheme:?}"),
+            Self::MissingConstraints => write!(f, "missing version constraints"),
+            Self::EmptyConstraint => write!(f, "empty version constraint"),
+            Self::InvalidComparator(comparator) => write!(f, "invalid comparator: {comparator:?}"),
+            Self::MissingVersion(constraint) => write!(f, "missing version in constraint {constraint:?}"),
+            Self::InvalidVersion(scheme, version) => {
+                write!(f, "invalid version for versioning scheme {scheme:?}: {version:?}")
+            },
+            Self::StarWithOtherConstraints => write!(f, "\"*\" must be the only constraint"),
+            Self::DuplicateVersion(version) => write!(f, "version {version:?} is used in more than one constraint"),
+            Self::InvalidComparatorSequence(first, second) => {
+                write!(f, "constraint {second:?} must not follow constraint {first:?}")
+            },
+        }
+    }
+}
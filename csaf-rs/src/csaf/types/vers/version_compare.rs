@@ -0,0 +1,503 @@
+//! Version comparison algorithms of the versioning schemes supported by [super::VersioningScheme].
+//!
+//! The implementations follow the reference algorithms of the respective ecosystems closely
+//! enough for range matching, but do not try to reproduce every quirk of the original tools.
+
+use regex::Regex;
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+/// Compares two strings consisting only of ASCII digits by their numeric value, without being
+/// limited by the size of an integer type.
+fn cmp_numeric(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Splits `input` at the first position where `predicate` no longer holds.
+fn split_while(input: &str, predicate: impl Fn(char) -> bool) -> (&str, &str) {
+    input.split_at(input.find(|c| !predicate(c)).unwrap_or(input.len()))
+}
+
+/// Returns the epoch prefix (`<epoch>:`) and the remainder of a deb or rpm version.
+fn split_epoch(version: &str) -> (&str, &str) {
+    match version.split_once(':') {
+        Some((epoch, rest)) if !epoch.is_empty() && epoch.chars().all(|c| c.is_ascii_digit()) => (epoch, rest),
+        _ => ("0", version),
+    }
+}
+
+// generic
+
+#[derive(Debug, PartialEq, Eq)]
+enum GenericSegment<'a> {
+    Numeric(&'a str),
+    Text(&'a str),
+}
+
+impl Ord for GenericSegment<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Numeric(a), Self::Numeric(b)) => cmp_numeric(a, b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            (Self::Numeric(_), Self::Text(_)) => Ordering::Greater,
+            (Self::Text(_), Self::Numeric(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for GenericSegment<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn generic_segments(version: &str) -> Vec<GenericSegment<'_>> {
+    let mut segments = Vec::new();
+    for part in version
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|p| !p.is_empty())
+    {
+        let mut rest = part;
+        while !rest.is_empty() {
+            if rest.starts_with(|c: char| c.is_ascii_digit()) {
+                let (digits, tail) = split_while(rest, |c| c.is_ascii_digit());
+                segments.push(GenericSegment::Numeric(digits));
+                rest = tail;
+            } else {
+                let (text, tail) = split_while(rest, |c| !c.is_ascii_digit());
+                segments.push(GenericSegment::Text(text));
+                rest = tail;
+            }
+        }
+    }
+    segments
+}
+
+/// Compares versions segment by segment, where segments are separated by any non-alphanumeric
+/// character or by a change between digits and letters. Numeric segments are compared by value,
+/// textual ones lexicographically.
+pub(super) fn cmp_generic(a: &str, b: &str) -> Ordering {
+    generic_segments(a).cmp(&generic_segments(b))
+}
+
+// deb
+
+/// Sort weight of a character in `dpkg`s version comparison: `~` sorts before everything, even
+/// the end of the string, letters sort before all other non-digits.
+fn deb_order(c: Option<char>) -> i32 {
+    match c {
+        None => 0,
+        Some('~') => -1,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+/// Port of `verrevcmp` from `dpkg`.
+fn deb_verrevcmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    while !a.is_empty() || !b.is_empty() {
+        loop {
+            let a_first = a.chars().next();
+            let b_first = b.chars().next();
+            let a_non_digit = a_first.is_some_and(|c| !c.is_ascii_digit());
+            let b_non_digit = b_first.is_some_and(|c| !c.is_ascii_digit());
+            if !a_non_digit && !b_non_digit {
+                break;
+            }
+            let ordering = deb_order(a_first).cmp(&deb_order(b_first));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            a = &a[a_first.map_or(0, char::len_utf8)..];
+            b = &b[b_first.map_or(0, char::len_utf8)..];
+        }
+        let (a_digits, a_rest) = split_while(a, |c| c.is_ascii_digit());
+        let (b_digits, b_rest) = split_while(b, |c| c.is_ascii_digit());
+        let ordering = cmp_numeric(a_digits, b_digits);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        (a, b) = (a_rest, b_rest);
+    }
+    Ordering::Equal
+}
+
+/// Splits a Debian version into epoch, upstream version and Debian revision.
+fn deb_parts(version: &str) -> (&str, &str, &str) {
+    let (epoch, rest) = split_epoch(version);
+    match rest.rsplit_once('-') {
+        Some((upstream, revision)) => (epoch, upstream, revision),
+        None => (epoch, rest, ""),
+    }
+}
+
+/// Compares Debian package versions (`[epoch:]upstream_version[-debian_revision]`) like `dpkg`.
+pub(super) fn cmp_deb(a: &str, b: &str) -> Ordering {
+    let (a_epoch, a_upstream, a_revision) = deb_parts(a);
+    let (b_epoch, b_upstream, b_revision) = deb_parts(b);
+    cmp_numeric(a_epoch, b_epoch)
+        .then_with(|| deb_verrevcmp(a_upstream, b_upstream))
+        .then_with(|| deb_verrevcmp(a_revision, b_revision))
+}
+
+// rpm
+
+/// Port of `rpmvercmp` from `rpm`, including the handling of `~` (sorts before everything) and
+/// `^` (sorts after the end of the string, but before everything else).
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    let is_separator = |c: char| !c.is_ascii_alphanumeric() && c != '~' && c != '^';
+    let (mut a, mut b) = (a, b);
+    loop {
+        a = a.trim_start_matches(is_separator);
+        b = b.trim_start_matches(is_separator);
+
+        if a.starts_with('~') || b.starts_with('~') {
+            if !a.starts_with('~') {
+                return Ordering::Greater;
+            }
+            if !b.starts_with('~') {
+                return Ordering::Less;
+            }
+            (a, b) = (&a[1..], &b[1..]);
+            continue;
+        }
+
+        if a.starts_with('^') || b.starts_with('^') {
+            if a.is_empty() {
+                return Ordering::Less;
+            }
+            if b.is_empty() {
+                return Ordering::Greater;
+            }
+            if !a.starts_with('^') {
+                return Ordering::Greater;
+            }
+            if !b.starts_with('^') {
+                return Ordering::Less;
+            }
+            (a, b) = (&a[1..], &b[1..]);
+            continue;
+        }
+
+        if a.is_empty() || b.is_empty() {
+            break;
+        }
+
+        let is_numeric = a.starts_with(|c: char| c.is_ascii_digit());
+        let predicate = |c: char| {
+            if is_numeric {
+                c.is_ascii_digit()
+            } else {
+                c.is_ascii_alphabetic()
+            }
+        };
+        let (a_segment, a_rest) = split_while(a, predicate);
+        let (b_segment, b_rest) = split_while(b, predicate);
+
+        // numeric segments are always newer than alphabetic ones
+        if b_segment.is_empty() {
+            return if is_numeric { Ordering::Greater } else { Ordering::Less };
+        }
+
+        let ordering = if is_numeric {
+            cmp_numeric(a_segment, b_segment)
+        } else {
+            a_segment.cmp(b_segment)
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        (a, b) = (a_rest, b_rest);
+    }
+
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (false, _) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    }
+}
+
+/// Compares RPM versions (`[epoch:]version[-release]`). The release is only compared if both
+/// versions have one.
+pub(super) fn cmp_rpm(a: &str, b: &str) -> Ordering {
+    let (a_epoch, a_rest) = split_epoch(a);
+    let (b_epoch, b_rest) = split_epoch(b);
+    let ordering = cmp_numeric(a_epoch, b_epoch);
+    if ordering != Ordering::Equal {
+        return ordering;
+    }
+    match (a_rest.rsplit_once('-'), b_rest.rsplit_once('-')) {
+        (Some((a_version, a_release)), Some((b_version, b_release))) => {
+            rpmvercmp(a_version, b_version).then_with(|| rpmvercmp(a_release, b_release))
+        },
+        (Some((a_version, _)), None) => rpmvercmp(a_version, b_rest),
+        (None, Some((b_version, _))) => rpmvercmp(a_rest, b_version),
+        (None, None) => rpmvercmp(a_rest, b_rest),
+    }
+}
+
+// maven
+
+#[derive(Debug)]
+enum MavenItem {
+    Numeric(String),
+    Qualifier(String),
+}
+
+/// Rank of well-known Maven qualifiers. Unknown qualifiers sort after all known ones.
+fn maven_qualifier_rank(qualifier: &str) -> usize {
+    match qualifier {
+        "alpha" | "a" => 0,
+        "beta" | "b" => 1,
+        "milestone" | "m" => 2,
+        "rc" | "cr" => 3,
+        "snapshot" => 4,
+        "" | "ga" | "final" | "release" => 5,
+        "sp" => 6,
+        _ => 7,
+    }
+}
+
+fn cmp_maven_qualifier(a: &str, b: &str) -> Ordering {
+    let (a_rank, b_rank) = (maven_qualifier_rank(a), maven_qualifier_rank(b));
+    a_rank
+        .cmp(&b_rank)
+        .then_with(|| if a_rank == 7 { a.cmp(b) } else { Ordering::Equal })
+}
+
+fn maven_items(version: &str) -> Vec<MavenItem> {
+    let version = version.to_ascii_lowercase();
+    let mut items: Vec<MavenItem> = generic_segments(&version)
+        .into_iter()
+        .map(|segment| match segment {
+            GenericSegment::Numeric(digits) => MavenItem::Numeric(digits.to_owned()),
+            GenericSegment::Text(text) => MavenItem::Qualifier(text.to_owned()),
+        })
+        .collect();
+    // trailing "null" items (zeros and release qualifiers) do not change the version
+    while let Some(last) = items.last() {
+        let is_null = match last {
+            MavenItem::Numeric(digits) => digits.trim_start_matches('0').is_empty(),
+            MavenItem::Qualifier(qualifier) => maven_qualifier_rank(qualifier) == 5,
+        };
+        if !is_null {
+            break;
+        }
+        items.pop();
+    }
+    items
+}
+
+fn cmp_maven_item(a: Option<&MavenItem>, b: Option<&MavenItem>) -> Ordering {
+    match (a, b) {
+        (Some(MavenItem::Numeric(a)), Some(MavenItem::Numeric(b))) => cmp_numeric(a, b),
+        (Some(MavenItem::Qualifier(a)), Some(MavenItem::Qualifier(b))) => cmp_maven_qualifier(a, b),
+        (Some(MavenItem::Numeric(_)), Some(MavenItem::Qualifier(_))) => Ordering::Greater,
+        (Some(MavenItem::Qualifier(_)), Some(MavenItem::Numeric(_))) => Ordering::Less,
+        (Some(MavenItem::Numeric(a)), None) => cmp_numeric(a, "0"),
+        (None, Some(MavenItem::Numeric(b))) => cmp_numeric("0", b),
+        (Some(MavenItem::Qualifier(a)), None) => cmp_maven_qualifier(a, ""),
+        (None, Some(MavenItem::Qualifier(b))) => cmp_maven_qualifier("", b),
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Compares Maven versions in the spirit of Maven's `ComparableVersion`: numbers are compared by
+/// value, qualifiers by their well-known order (`alpha` < `beta` < `milestone` < `rc` <
+/// `snapshot` < release < `sp`) and trailing zeros are insignificant.
+pub(super) fn cmp_maven(a: &str, b: &str) -> Ordering {
+    let (a_items, b_items) = (maven_items(a), maven_items(b));
+    (0..a_items.len().max(b_items.len()))
+        .map(|i| cmp_maven_item(a_items.get(i), b_items.get(i)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+// pypi
+
+static PEP440_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?ix)^v?
+        (?:(?P<epoch>[0-9]+)!)?
+        (?P<release>[0-9]+(?:\.[0-9]+)*)
+        (?:[-_.]?(?P<pre_label>alpha|a|beta|b|preview|pre|c|rc)[-_.]?(?P<pre_number>[0-9]+)?)?
+        (?:-(?P<post_implicit>[0-9]+)|[-_.]?(?P<post_label>post|rev|r)[-_.]?(?P<post_number>[0-9]+)?)?
+        (?:[-_.]?(?P<dev_label>dev)[-_.]?(?P<dev_number>[0-9]+)?)?
+        (?:\+(?P<local>[a-z0-9]+(?:[-_.][a-z0-9]+)*))?
+        $",
+    )
+    .unwrap()
+});
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PypiLocalSegment {
+    Text(String),
+    Numeric(u128),
+}
+
+/// Sort key of a PEP 440 version. The field order matches the comparison order of the spec.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct PypiVersionKey {
+    epoch: u128,
+    release: Vec<u128>,
+    /// `(phase, number)`: phase `0` is a dev release without pre-release, `1`-`3` are
+    /// alpha, beta and release candidate, `4` is no pre-release at all.
+    pre: (u8, u128),
+    post: Option<u128>,
+    /// `None` (no dev release) sorts after every dev release.
+    dev: (bool, u128),
+    local: Option<Vec<PypiLocalSegment>>,
+}
+
+fn parse_pypi_version(version: &str) -> Option<PypiVersionKey> {
+    let captures = PEP440_REGEX.captures(version.trim())?;
+    let number = |name: &str| captures.name(name).map(|m| m.as_str().parse::<u128>());
+
+    let epoch = number("epoch").transpose().ok()?.unwrap_or(0);
+    let mut release = captures["release"]
+        .split('.')
+        .map(|part| part.parse::<u128>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    while release.len() > 1 && release.last() == Some(&0) {
+        release.pop();
+    }
+
+    let post = match (captures.name("post_implicit"), captures.name("post_label")) {
+        (Some(_), _) => Some(number("post_implicit")?.ok()?),
+        (None, Some(_)) => Some(number("post_number").transpose().ok()?.unwrap_or(0)),
+        (None, None) => None,
+    };
+    let dev = match captures.name("dev_label") {
+        Some(_) => (false, number("dev_number").transpose().ok()?.unwrap_or(0)),
+        None => (true, 0),
+    };
+    let pre = match captures.name("pre_label").map(|m| m.as_str().to_ascii_lowercase()) {
+        Some(label) => {
+            let phase = match label.as_str() {
+                "a" | "alpha" => 1,
+                "b" | "beta" => 2,
+                _ => 3,
+            };
+            (phase, number("pre_number").transpose().ok()?.unwrap_or(0))
+        },
+        // a dev release of a final version sorts before its pre-releases
+        None if post.is_none() && !dev.0 => (0, 0),
+        None => (4, 0),
+    };
+    let local = captures.name("local").map(|local| {
+        local
+            .as_str()
+            .to_ascii_lowercase()
+            .split(['-', '_', '.'])
+            .map(|segment| match segment.parse::<u128>() {
+                Ok(number) => PypiLocalSegment::Numeric(number),
+                Err(_) => PypiLocalSegment::Text(segment.to_owned()),
+            })
+            .collect()
+    });
+
+    Some(PypiVersionKey {
+        epoch,
+        release,
+        pre,
+        post,
+        dev,
+        local,
+    })
+}
+
+/// Returns true if `version` is a valid PEP 440 version.
+pub(super) fn is_valid_pypi(version: &str) -> bool {
+    parse_pypi_version(version).is_some()
+}
+
+/// Compares PEP 440 versions. Invalid versions sort before all valid ones.
+pub(super) fn cmp_pypi(a: &str, b: &str) -> Ordering {
+    parse_pypi_version(a).cmp(&parse_pypi_version(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.0", "1.0.1")]
+    #[case("1.2", "1.10")]
+    #[case("1.0-beta", "1.0.1")]
+    #[case("2.0a", "2.0.0")]
+    fn test_cmp_generic(#[case] lower: &str, #[case] higher: &str) {
+        assert_eq!(cmp_generic(lower, higher), Ordering::Less);
+        assert_eq!(cmp_generic(higher, lower), Ordering::Greater);
+    }
+
+    #[rstest]
+    #[case("1.0~rc1", "1.0")]
+    #[case("1.0", "1.0-1")]
+    #[case("1.0-1", "1.0-1+deb12u1")]
+    #[case("7.88.1-10+deb12u12", "8.13.0-5~bpo12+1")]
+    #[case("9.9", "1:0.1")]
+    #[case("1.0a", "1.0+")]
+    fn test_cmp_deb(#[case] lower: &str, #[case] higher: &str) {
+        assert_eq!(cmp_deb(lower, higher), Ordering::Less);
+        assert_eq!(cmp_deb(higher, lower), Ordering::Greater);
+    }
+
+    #[rstest]
+    #[case("1.0~rc1", "1.0")]
+    #[case("1.0", "1.0^git1")]
+    #[case("1.0^git1", "1.0.1")]
+    #[case("1.0a", "1.0.1")]
+    #[case("1.0-1.el9", "1.0-2.el9")]
+    #[case("2.0", "1:1.0")]
+    fn test_cmp_rpm(#[case] lower: &str, #[case] higher: &str) {
+        assert_eq!(cmp_rpm(lower, higher), Ordering::Less);
+        assert_eq!(cmp_rpm(higher, lower), Ordering::Greater);
+    }
+
+    #[rstest]
+    #[case("1.0-alpha-1", "1.0-beta")]
+    #[case("1.0-rc1", "1.0-SNAPSHOT")]
+    #[case("1.0-SNAPSHOT", "1.0")]
+    #[case("1.0", "1.0-sp1")]
+    #[case("1.0-sp1", "1.0.1")]
+    #[case("1.9", "1.10")]
+    fn test_cmp_maven(#[case] lower: &str, #[case] higher: &str) {
+        assert_eq!(cmp_maven(lower, higher), Ordering::Less);
+        assert_eq!(cmp_maven(higher, lower), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cmp_maven_equal() {
+        assert_eq!(cmp_maven("1.0.0", "1"), Ordering::Equal);
+        assert_eq!(cmp_maven("1.0-final", "1.0.ga"), Ordering::Equal);
+    }
+
+    #[rstest]
+    #[case("1.0.dev1", "1.0a1")]
+    #[case("1.0a1", "1.0b1")]
+    #[case("1.0rc1", "1.0")]
+    #[case("1.0", "1.0.post1")]
+    #[case("1.0.post1.dev1", "1.0.post1")]
+    #[case("1.0", "1.0+local.1")]
+    #[case("1.0.post1", "1.1.dev0")]
+    #[case("9.0", "1!0.1")]
+    fn test_cmp_pypi(#[case] lower: &str, #[case] higher: &str) {
+        assert_eq!(cmp_pypi(lower, higher), Ordering::Less);
+        assert_eq!(cmp_pypi(higher, lower), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_pypi_normalization() {
+        assert_eq!(cmp_pypi("1.0.0", "1"), Ordering::Equal);
+        assert_eq!(cmp_pypi("1.0-alpha.1", "1.0a1"), Ordering::Equal);
+        assert!(!is_valid_pypi("1.0-foo"));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::csaf::types::vers::version_compare::{cmp_deb, cmp_generic, cmp_maven, cmp_pypi, cmp_rpm, is_valid_pypi};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// The versioning scheme of a vers range, i.e. the part between `vers:` and the first `/`.
+///
+/// Schemes without a dedicated comparison are kept as [VersioningScheme::Other] and compared
+/// like [VersioningScheme::Generic].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VersioningScheme {
+    Semver,
+    Generic,
+    Deb,
+    Rpm,
+    Maven,
+    Npm,
+    Pypi,
+    Other(String),
+}
+
+impl VersioningScheme {
+    /// Creates the scheme from its (already lowercased) name.
+    pub(super) fn from_name(name: &str) -> Self {
+        match name {
+            "semver" => Self::Semver,
+            "generic" => Self::Generic,
+            "deb" => Self::Deb,
+            "rpm" => Self::Rpm,
+            "maven" => Self::Maven,
+            "npm" => Self::Npm,
+            "pypi" => Self::Pypi,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+
+    /// Returns the name of the scheme as used in a vers string.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Semver => "semver",
+            Self::Generic => "generic",
+            Self::Deb => "deb",
+            Self::Rpm => "rpm",
+            Self::Maven => "maven",
+            Self::Npm => "npm",
+            Self::Pypi => "pypi",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Returns true if `version` is a valid version of this scheme.
+    ///
+    /// Only `semver`, `npm` and `pypi` define a strict version syntax, all other schemes accept
+    /// any non-empty version.
+    pub fn is_valid_version(&self, version: &str) -> bool {
+        match self {
+            Self::Semver | Self::Npm => semver::Version::parse(version).is_ok(),
+            Self::Pypi => is_valid_pypi(version),
+            _ => !version.is_empty(),
+        }
+    }
+
+    /// Compares two versions according to the rules of this scheme. Both versions should be
+    /// valid (see [VersioningScheme::is_valid_version]).
+    pub fn compare_versions(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Semver | Self::Npm => match (semver::Version::parse(a), semver::Version::parse(b)) {
+                (Ok(a), Ok(b)) => a.cmp_precedence(&b),
+                _ => cmp_generic(a, b),
+            },
+            Self::Deb => cmp_deb(a, b),
+            Self::Rpm => cmp_rpm(a, b),
+            Self::Maven => cmp_maven(a, b),
+            Self::Pypi => cmp_pypi(a, b),
+            Self::Generic | Self::Other(_) => cmp_generic(a, b),
+        }
+    }
+}
+
+impl Display for VersioningScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
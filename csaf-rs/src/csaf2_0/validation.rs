@@ -1,164 +1,119 @@
 use crate::csaf::raw::{RawDocument, RawValidatable};
 use crate::csaf2_0::testcases::*;
 use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework;
-use crate::test_validation::TestValidator;
-use crate::validation::{TestFinding, TestResult, TestResultStatus, Validatable, ValidationError};
+use crate::test_info::TestCapability;
+use crate::test_validation::TestRunner::{Parsed, Raw};
+use crate::test_validation::{
+    TestTable, TestValidator, capabilities_from_table, common_preset, run_parsed_test, run_raw_test,
+};
+use crate::validation::{TestResult, Validatable};
 use crate::validations::test_schema::validate_schema_csaf_2_0;
 
-fn to_test_result(test_id: &str, result: Option<Result<(), Vec<crate::validation::TestFinding>>>) -> TestResult {
-    TestResult {
-        test_id: test_id.to_string(),
-        status: match result {
-            None => TestResultStatus::NotFound,
-            Some(Ok(())) => TestResultStatus::Success,
-            Some(Err(data)) => TestResultStatus::Failure {
-                errors: data
-                    .iter()
-                    .filter(|f| matches!(f, TestFinding::Error(_)))
-                    .map(ValidationError::from)
-                    .collect(),
-                warnings: data
-                    .iter()
-                    .filter(|f| matches!(f, TestFinding::Warning(_)))
-                    .map(ValidationError::from)
-                    .collect(),
-                infos: data
-                    .iter()
-                    .filter(|f| matches!(f, TestFinding::Information(_)))
-                    .map(ValidationError::from)
-                    .collect(),
-            },
-        },
-    }
-}
+/// All tests implemented for CSAF 2.0. Tests of the standard which are not listed here are reported
+/// as not implemented by [Validatable::test_capabilities] and are not part of any preset.
+static TESTS: &TestTable<CommonSecurityAdvisoryFramework> = &[
+    ("schema", Raw(|doc| validate_schema_csaf_2_0(doc).into())),
+    // mandatory tests
+    ("6.1.1", Parsed(|doc| ValidatorForTest6_1_1.validate(doc))),
+    ("6.1.2", Parsed(|doc| ValidatorForTest6_1_2.validate(doc))),
+    ("6.1.3", Parsed(|doc| ValidatorForTest6_1_3.validate(doc))),
+    ("6.1.4", Parsed(|doc| ValidatorForTest6_1_4.validate(doc))),
+    ("6.1.5", Parsed(|doc| ValidatorForTest6_1_5.validate(doc))),
+    ("6.1.6", Parsed(|doc| ValidatorForTest6_1_6.validate(doc))),
+    ("6.1.7", Parsed(|doc| ValidatorForTest6_1_7.validate(doc))),
+    ("6.1.8", Parsed(|doc| ValidatorForTest6_1_8.validate(doc))),
+    ("6.1.9", Parsed(|doc| ValidatorForTest6_1_9.validate(doc))),
+    ("6.1.10", Parsed(|doc| ValidatorForTest6_1_10.validate(doc))),
+    ("6.1.11", Parsed(|doc| ValidatorForTest6_1_11.validate(doc))),
+    ("6.1.12", Parsed(|doc| ValidatorForTest6_1_12.validate(doc))),
+    ("6.1.13", Parsed(|doc| ValidatorForTest6_1_13.validate(doc))),
+    ("6.1.14", Parsed(|doc| ValidatorForTest6_1_14.validate(doc))),
+    ("6.1.15", Parsed(|doc| ValidatorForTest6_1_15.validate(doc))),
+    ("6.1.16", Parsed(|doc| ValidatorForTest6_1_16.validate(doc))),
+    ("6.1.17", Parsed(|doc| ValidatorForTest6_1_17.validate(doc))),
+    ("6.1.18", Parsed(|doc| ValidatorForTest6_1_18.validate(doc))),
+    ("6.1.19", Parsed(|doc| ValidatorForTest6_1_19.validate(doc))),
+    ("6.1.20", Parsed(|doc| ValidatorForTest6_1_20.validate(doc))),
+    ("6.1.21", Parsed(|doc| ValidatorForTest6_1_21.validate(doc))),
+    ("6.1.22", Parsed(|doc| ValidatorForTest6_1_22.validate(doc))),
+    ("6.1.23", Parsed(|doc| ValidatorForTest6_1_23.validate(doc))),
+    ("6.1.24", Parsed(|doc| ValidatorForTest6_1_24.validate(doc))),
+    ("6.1.25", Parsed(|doc| ValidatorForTest6_1_25.validate(doc))),
+    ("6.1.26", Parsed(|doc| ValidatorForTest6_1_26.validate(doc))),
+    ("6.1.27.1", Parsed(|doc| ValidatorForTest6_1_27_1.validate(doc))),
+    ("6.1.27.2", Parsed(|doc| ValidatorForTest6_1_27_2.validate(doc))),
+    ("6.1.27.3", Parsed(|doc| ValidatorForTest6_1_27_3.validate(doc))),
+    ("6.1.27.4", Parsed(|doc| ValidatorForTest6_1_27_4.validate(doc))),
+    ("6.1.27.5", Parsed(|doc| ValidatorForTest6_1_27_5.validate(doc))),
+    ("6.1.27.6", Parsed(|doc| ValidatorForTest6_1_27_6.validate(doc))),
+    ("6.1.27.7", Parsed(|doc| ValidatorForTest6_1_27_7.validate(doc))),
+    ("6.1.27.8", Parsed(|doc| ValidatorForTest6_1_27_8.validate(doc))),
+    ("6.1.27.9", Parsed(|doc| ValidatorForTest6_1_27_9.validate(doc))),
+    ("6.1.27.10", Parsed(|doc| ValidatorForTest6_1_27_10.validate(doc))),
+    ("6.1.27.11", Parsed(|doc| ValidatorForTest6_1_27_11.validate(doc))),
+    ("6.1.28", Parsed(|doc| ValidatorForTest6_1_28.validate(doc))),
+    ("6.1.29", Parsed(|doc| ValidatorForTest6_1_29.validate(doc))),
+    ("6.1.30", Parsed(|doc| ValidatorForTest6_1_30.validate(doc))),
+    ("6.1.31", Parsed(|doc| ValidatorForTest6_1_31.validate(doc))),
+    ("6.1.32", Parsed(|doc| ValidatorForTest6_1_32.validate(doc))),
+    ("6.1.33", Parsed(|doc| ValidatorForTest6_1_33.validate(doc))),
+    // recommended tests
+    ("6.2.1", Parsed(|doc| ValidatorForTest6_2_1.validate(doc))),
+    ("6.2.2", Parsed(|doc| ValidatorForTest6_2_2.validate(doc))),
+    ("6.2.3", Parsed(|doc| ValidatorForTest6_2_3.validate(doc))),
+    ("6.2.4", Parsed(|doc| ValidatorForTest6_2_4.validate(doc))),
+    ("6.2.5", Parsed(|doc| ValidatorForTest6_2_5.validate(doc))),
+    ("6.2.6", Parsed(|doc| ValidatorForTest6_2_6.validate(doc))),
+    ("6.2.7", Parsed(|doc| ValidatorForTest6_2_7.validate(doc))),
+    ("6.2.8", Parsed(|doc| ValidatorForTest6_2_8.validate(doc))),
+    ("6.2.9", Parsed(|doc| ValidatorForTest6_2_9.validate(doc))),
+    ("6.2.10", Parsed(|doc| ValidatorForTest6_2_10.validate(doc))),
+    ("6.2.11", Parsed(|doc| ValidatorForTest6_2_11.validate(doc))),
+    ("6.2.12", Parsed(|doc| ValidatorForTest6_2_12.validate(doc))),
+    ("6.2.13", Raw(|doc| ValidatorForTest6_2_13.validate(doc))),
+    ("6.2.14", Parsed(|doc| ValidatorForTest6_2_14.validate(doc))),
+    ("6.2.15", Parsed(|doc| ValidatorForTest6_2_15.validate(doc))),
+    ("6.2.16", Parsed(|doc| ValidatorForTest6_2_16.validate(doc))),
+    ("6.2.17", Parsed(|doc| ValidatorForTest6_2_17.validate(doc))),
+    ("6.2.18", Parsed(|doc| ValidatorForTest6_2_18.validate(doc))),
+    ("6.2.19", Parsed(|doc| ValidatorForTest6_2_19.validate(doc))),
+    ("6.2.20", Raw(|doc| ValidatorForTest6_2_20.validate(doc))),
+    // informative tests
+    ("6.3.1", Parsed(|doc| ValidatorForTest6_3_1.validate(doc))),
+    ("6.3.2", Parsed(|doc| ValidatorForTest6_3_2.validate(doc))),
+    ("6.3.3", Parsed(|doc| ValidatorForTest6_3_3.validate(doc))),
+    ("6.3.4", Parsed(|doc| ValidatorForTest6_3_4.validate(doc))),
+    ("6.3.5", Parsed(|doc| ValidatorForTest6_3_5.validate(doc))),
+    ("6.3.6", Parsed(|doc| ValidatorForTest6_3_6.validate(doc))),
+    ("6.3.7", Parsed(|doc| ValidatorForTest6_3_7.validate(doc))),
+    ("6.3.8", Parsed(|doc| ValidatorForTest6_3_8.validate(doc))),
+    ("6.3.9", Parsed(|doc| ValidatorForTest6_3_9.validate(doc))),
+    ("6.3.10", Parsed(|doc| ValidatorForTest6_3_10.validate(doc))),
+    ("6.3.11", Parsed(|doc| ValidatorForTest6_3_11.validate(doc))),
+];
 
 impl Validatable for CommonSecurityAdvisoryFramework {
+    fn test_capabilities() -> Vec<TestCapability> {
+        let spec_tests = [
+            vec!["schema"],
+            mandatory_tests(),
+            recommended_tests(),
+            informative_tests(),
+        ];
+        capabilities_from_table(TESTS, "2.0", spec_tests.concat())
+    }
+
     fn tests_in_preset(preset: &str) -> Option<Vec<&'static str>> {
-        match preset {
-            "basic" => Some([vec!["schema"], mandatory_tests()].concat()),
-            "extended" => Some([vec!["schema"], mandatory_tests(), recommended_tests()].concat()),
-            "full" => Some(
-                [
-                    vec!["schema"],
-                    mandatory_tests(),
-                    recommended_tests(),
-                    informative_tests(),
-                ]
-                .concat(),
-            ),
-            _ => None,
-        }
+        common_preset(&Self::test_capabilities(), preset)
     }
 
     fn run_test(&self, test_id: &str) -> TestResult {
-        to_test_result(
-            test_id,
-            match test_id {
-                // mandatory tests
-                "6.1.1" => Some(ValidatorForTest6_1_1.validate(self)),
-                "6.1.2" => Some(ValidatorForTest6_1_2.validate(self)),
-                "6.1.3" => Some(ValidatorForTest6_1_3.validate(self)),
-                "6.1.4" => Some(ValidatorForTest6_1_4.validate(self)),
-                "6.1.5" => Some(ValidatorForTest6_1_5.validate(self)),
-                "6.1.6" => Some(ValidatorForTest6_1_6.validate(self)),
-                "6.1.7" => Some(ValidatorForTest6_1_7.validate(self)),
-                "6.1.8" => Some(ValidatorForTest6_1_8.validate(self)),
-                "6.1.9" => Some(ValidatorForTest6_1_9.validate(self)),
-                "6.1.10" => Some(ValidatorForTest6_1_10.validate(self)),
-                "6.1.11" => Some(ValidatorForTest6_1_11.validate(self)),
-                "6.1.12" => Some(ValidatorForTest6_1_12.validate(self)),
-                "6.1.13" => Some(ValidatorForTest6_1_13.validate(self)),
-                "6.1.14" => Some(ValidatorForTest6_1_14.validate(self)),
-                "6.1.15" => Some(ValidatorForTest6_1_15.validate(self)),
-                "6.1.16" => Some(ValidatorForTest6_1_16.validate(self)),
-                "6.1.17" => Some(ValidatorForTest6_1_17.validate(self)),
-                "6.1.18" => Some(ValidatorForTest6_1_18.validate(self)),
-                "6.1.19" => Some(ValidatorForTest6_1_19.validate(self)),
-                "6.1.20" => Some(ValidatorForTest6_1_20.validate(self)),
-                "6.1.21" => Some(ValidatorForTest6_1_21.validate(self)),
-                "6.1.22" => Some(ValidatorForTest6_1_22.validate(self)),
-                "6.1.23" => Some(ValidatorForTest6_1_23.validate(self)),
-                "6.1.24" => Some(ValidatorForTest6_1_24.validate(self)),
-                "6.1.25" => Some(ValidatorForTest6_1_25.validate(self)),
-                "6.1.26" => Some(ValidatorForTest6_1_26.validate(self)),
-                "6.1.27.1" => Some(ValidatorForTest6_1_27_1.validate(self)),
-                "6.1.27.2" => Some(ValidatorForTest6_1_27_2.validate(self)),
-                "6.1.27.3" => Some(ValidatorForTest6_1_27_3.validate(self)),
-                "6.1.27.4" => Some(ValidatorForTest6_1_27_4.validate(self)),
-                "6.1.27.5" => Some(ValidatorForTest6_1_27_5.validate(self)),
-                "6.1.27.6" => Some(ValidatorForTest6_1_27_6.validate(self)),
-                "6.1.27.7" => Some(ValidatorForTest6_1_27_7.validate(self)),
-                "6.1.27.8" => Some(ValidatorForTest6_1_27_8.validate(self)),
-                "6.1.27.9" => Some(ValidatorForTest6_1_27_9.validate(self)),
-                "6.1.27.10" => Some(ValidatorForTest6_1_27_10.validate(self)),
-                "6.1.27.11" => Some(ValidatorForTest6_1_27_11.validate(self)),
-                "6.1.28" => Some(ValidatorForTest6_1_28.validate(self)),
-                "6.1.29" => Some(ValidatorForTest6_1_29.validate(self)),
-                "6.1.30" => Some(ValidatorForTest6_1_30.validate(self)),
-                "6.1.31" => Some(ValidatorForTest6_1_31.validate(self)),
-                "6.1.32" => Some(ValidatorForTest6_1_32.validate(self)),
-                "6.1.33" => Some(ValidatorForTest6_1_33.validate(self)),
-                // optional
-                "6.2.1" => Some(ValidatorForTest6_2_1.validate(self)),
-                "6.2.2" => Some(ValidatorForTest6_2_2.validate(self)),
-                "6.2.3" => Some(ValidatorForTest6_2_3.validate(self)),
-                "6.2.4" => Some(ValidatorForTest6_2_4.validate(self)),
-                "6.2.5" => Some(ValidatorForTest6_2_5.validate(self)),
-                "6.2.6" => Some(ValidatorForTest6_2_6.validate(self)),
-                "6.2.7" => Some(ValidatorForTest6_2_7.validate(self)),
-                "6.2.8" => Some(ValidatorForTest6_2_8.validate(self)),
-                "6.2.9" => Some(ValidatorForTest6_2_9.validate(self)),
-                "6.2.10" => Some(ValidatorForTest6_2_10.validate(self)),
-                "6.2.11" => Some(ValidatorForTest6_2_11.validate(self)),
-                "6.2.12" => Some(ValidatorForTest6_2_12.validate(self)),
-                "6.2.13" => {
-                    // see below in RawValidatable
-                    return TestResult {
-                        test_id: test_id.to_string(),
-                        status: TestResultStatus::Skipped,
-                    };
-                },
-                "6.2.14" => Some(ValidatorForTest6_2_14.validate(self)),
-                "6.2.15" => Some(ValidatorForTest6_2_15.validate(self)),
-                "6.2.16" => Some(ValidatorForTest6_2_16.validate(self)),
-                "6.2.17" => Some(ValidatorForTest6_2_17.validate(self)),
-                "6.2.18" => Some(ValidatorForTest6_2_18.validate(self)),
-                "6.2.19" => None, // Some(ValidatorForTest6_2_19.validate(self)),
-                "6.2.20" => {
-                    // see below in RawValidatable
-                    return TestResult {
-                        test_id: test_id.to_string(),
-                        status: TestResultStatus::Skipped,
-                    };
-                },
-                // informative tests
-                "6.3.1" => Some(ValidatorForTest6_3_1.validate(self)),
-                "6.3.2" => Some(ValidatorForTest6_3_2.validate(self)),
-                "6.3.3" => Some(ValidatorForTest6_3_3.validate(self)),
-                "6.3.4" => Some(ValidatorForTest6_3_4.validate(self)),
-                "6.3.5" => Some(ValidatorForTest6_3_5.validate(self)),
-                "6.3.6" => None, // Some(ValidatorForTest6_3_6.validate(self)),
-                "6.3.7" => None, // Some(ValidatorForTest6_3_7.validate(self)),
-                "6.3.8" => None, // Some(ValidatorForTest6_3_8.validate(self)),
-                "6.3.9" => Some(ValidatorForTest6_3_9.validate(self)),
-                "6.3.10" => Some(ValidatorForTest6_3_10.validate(self)),
-                "6.3.11" => Some(ValidatorForTest6_3_11.validate(self)),
-                _ => None,
-            },
-        )
+        run_parsed_test(TESTS, self, test_id)
     }
 }
 
 impl RawValidatable for RawDocument<CommonSecurityAdvisoryFramework> {
     fn run_raw_test(&self, test_id: &str) -> TestResult {
-        to_test_result(
-            test_id,
-            match test_id {
-                "schema" => Some(validate_schema_csaf_2_0(self)),
-                "6.2.13" => Some(ValidatorForTest6_2_13.validate(self)),
-                "6.2.20" => Some(ValidatorForTest6_2_20.validate(self)),
-                _ => None,
-            },
-        )
+        run_raw_test(TESTS, self, test_id)
     }
 }
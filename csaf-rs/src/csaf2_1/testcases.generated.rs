@@ -782,7 +782,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-03.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-03.json"), (case_04, "04",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-04.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-04.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-04.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-27-13-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-27-13-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-12.json",
@@ -794,7 +796,11 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-15.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-15.json"), (case_16, "16",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-16.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-16.json")]
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-27-13-16.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-27-13-s11.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-27-13-s11.json"), (case_s12, "s12",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-27-13-s12.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-27-13-s12.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_1_27_14, ValidatorForTest6_1_27_14, ExpectedResults_6_1_27_14, id :
@@ -1106,7 +1112,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-08.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-08.json"), (case_09, "09",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-09.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-09.json"), (case_20, "20",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-09.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-37-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-37-s01.json"), (case_20, "20",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-20.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-20.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-37-11.json",
@@ -1317,7 +1325,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-08.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-08.json"), (case_09, "09",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-09.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-09.json"), (case_21, "21",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-09.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-48-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-48-s01.json"), (case_21, "21",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-21.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-21.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-48-11.json",
@@ -1350,7 +1360,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-02.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-02.json"), (case_03, "03",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-03.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-03.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-03.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-49-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-49-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-49-12.json",
@@ -1373,7 +1385,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-05.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-05.json"), (case_06, "06",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-06.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-06.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-06.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-50-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-50-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-12.json",
@@ -1385,7 +1399,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-15.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-15.json"), (case_16, "16",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-16.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-16.json")]
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-50-16.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-50-s11.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-50-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_1_51, ValidatorForTest6_1_51, ExpectedResults_6_1_51, id : "6.1.51", doc_type :
@@ -1542,13 +1558,19 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-01.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-01.json"), (case_02, "02",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-02.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-02.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-02.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-59-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-59-s01.json"), (case_s02, "s02",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-59-s02.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-59-s02.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-12.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-12.json"), (case_13, "13",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-13.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-13.json")]
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-59-13.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-59-s11.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-59-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_1_60_1, ValidatorForTest6_1_60_1, ExpectedResults_6_1_60_1, id : "6.1.60.1",
@@ -1557,11 +1579,15 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-01.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-01.json"), (case_02, "02",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-02.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-02.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-02.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-01-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-01-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-12.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-12.json")]
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-01-12.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-01-s11.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-01-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_1_60_2, ValidatorForTest6_1_60_2, ExpectedResults_6_1_60_2, id : "6.1.60.2",
@@ -1570,11 +1596,15 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-01.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-01.json"), (case_02, "02",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-02.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-02.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-02.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-02-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-02-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-12.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-12.json")]
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-02-12.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-02-s11.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-02-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_1_60_3, ValidatorForTest6_1_60_3, ExpectedResults_6_1_60_3, id : "6.1.60.3",
@@ -1583,11 +1613,15 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-01.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-01.json"), (case_02, "02",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-02.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-02.json"), (case_11, "11",
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-02.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-03-s01.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-03-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-11.json",
     "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-12.json",
-    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-12.json")]
+    "mandatory/oasis_csaf_tc-csaf_2_1-2024-6-1-60-03-12.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-03-s11.json",
+    "mandatory/csaf-rs_csaf-csaf_2_1-6-1-60-03-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_1_61, ValidatorForTest6_1_61, ExpectedResults_6_1_61, id : "6.1.61", doc_type :
@@ -1836,7 +1870,11 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-07.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-07.json"), (case_08, "08",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-08.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-08.json"), (case_11, "11",
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-08.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-19-s01.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-19-s01.json"), (case_s02, "s02",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-19-s02.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-19-s02.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-11.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-12.json",
@@ -1854,7 +1892,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-18.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-18.json"), (case_19, "19",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-19.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-19.json")]
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-19-19.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-19-s11.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-19-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_2_20, ValidatorForTest6_2_20, ExpectedResults_6_2_20, id : "6.2.20", doc_type :
@@ -1992,13 +2032,19 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-02.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-02.json"), (case_03, "03",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-03.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-03.json"), (case_11, "11",
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-03.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-27-s01.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-27-s01.json"), (case_s02, "s02",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-27-s02.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-27-s02.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-11.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-12.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-12.json"), (case_13, "13",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-13.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-13.json")]
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-27-13.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-27-s11.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-27-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_2_28, ValidatorForTest6_2_28, ExpectedResults_6_2_28, id : "6.2.28", doc_type :
@@ -2106,11 +2152,15 @@ crate::macros::define_csaf_test!(
     crate ::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework, version : "V2_1",
     cases : [(case_01, "01",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-01.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-01.json"), (case_11, "11",
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-01.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-35-s01.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-35-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-11.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-12.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-12.json")]
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-35-12.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-35-s11.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-35-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_2_36, ValidatorForTest6_2_36, ExpectedResults_6_2_36, id : "6.2.36", doc_type :
@@ -2136,13 +2186,17 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-02.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-02.json"), (case_03, "03",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-03.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-03.json"), (case_11, "11",
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-03.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-37-s01.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-37-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-11.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-12.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-12.json"), (case_13, "13",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-13.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-13.json")]
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-37-13.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-37-s11.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-37-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_2_38, ValidatorForTest6_2_38, ExpectedResults_6_2_38, id : "6.2.38", doc_type :
@@ -2174,7 +2228,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-01-12.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-01-12.json"), (case_13, "13",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-01-13.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-01-13.json")]
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-01-13.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-39-01-s11.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-39-01-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_2_39_2, ValidatorForTest6_2_39_2, ExpectedResults_6_2_39_2, id : "6.2.39.2",
@@ -2203,11 +2259,17 @@ crate::macros::define_csaf_test!(
     doc_type : crate ::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework, version
     : "V2_1", cases : [(case_01, "01",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-01.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-01.json"), (case_11, "11",
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-01.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-39-04-s01.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-39-04-s01.json"), (case_s02, "s02",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-39-04-s02.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-39-04-s02.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-11.json",
     "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-12.json",
-    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-12.json")]
+    "recommended/oasis_csaf_tc-csaf_2_1-2024-6-2-39-04-12.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/recommended/csaf-rs_csaf-csaf_2_1-6-2-39-04-s11.json",
+    "recommended/csaf-rs_csaf-csaf_2_1-6-2-39-04-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_2_39_5, ValidatorForTest6_2_39_5, ExpectedResults_6_2_39_5, id : "6.2.39.5",
@@ -2473,7 +2535,9 @@ crate::macros::define_csaf_test!(
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-03.json",
     "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-03.json"), (case_04, "04",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-04.json",
-    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-04.json"), (case_11, "11",
+    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-04.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/informative/csaf-rs_csaf-csaf_2_1-6-3-01-s01.json",
+    "informative/csaf-rs_csaf-csaf_2_1-6-3-01-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-11.json",
     "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-11.json"), (case_12, "12",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-01-12.json",
@@ -2645,18 +2709,26 @@ crate::macros::define_csaf_test!(
     crate ::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework, version : "V2_1",
     cases : [(case_01, "01",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-13-01.json",
-    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-13-01.json"), (case_11, "11",
+    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-13-01.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/informative/csaf-rs_csaf-csaf_2_1-6-3-13-s01.json",
+    "informative/csaf-rs_csaf-csaf_2_1-6-3-13-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-13-11.json",
-    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-13-11.json")]
+    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-13-11.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/informative/csaf-rs_csaf-csaf_2_1-6-3-13-s11.json",
+    "informative/csaf-rs_csaf-csaf_2_1-6-3-13-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_3_14, ValidatorForTest6_3_14, ExpectedResults_6_3_14, id : "6.3.14", doc_type :
     crate ::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework, version : "V2_1",
     cases : [(case_01, "01",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-14-01.json",
-    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-14-01.json"), (case_11, "11",
+    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-14-01.json"), (case_s01, "s01",
+    "../type-generator/assets/tests/csaf_2.1/informative/csaf-rs_csaf-csaf_2_1-6-3-14-s01.json",
+    "informative/csaf-rs_csaf-csaf_2_1-6-3-14-s01.json"), (case_11, "11",
     "../csaf/csaf_2.1/test/validator/data/informative/oasis_csaf_tc-csaf_2_1-2024-6-3-14-11.json",
-    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-14-11.json")]
+    "informative/oasis_csaf_tc-csaf_2_1-2024-6-3-14-11.json"), (case_s11, "s11",
+    "../type-generator/assets/tests/csaf_2.1/informative/csaf-rs_csaf-csaf_2_1-6-3-14-s11.json",
+    "informative/csaf-rs_csaf-csaf_2_1-6-3-14-s11.json")]
 );
 crate::macros::define_csaf_test!(
     Test6_3_15, ValidatorForTest6_3_15, ExpectedResults_6_3_15, id : "6.3.15", doc_type :
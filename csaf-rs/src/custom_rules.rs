@@ -0,0 +1,411 @@
+//! Organization-specific rules, which run alongside the tests of the standard.
+//!
+//! A rule implements [CsafRule] over the version-agnostic [CsafTrait], so it runs on documents
+//! of all CSAF versions. It is registered with its own ID, title and category in a
+//! [RuleRegistry] by [RuleRegistry::register_rule]. A document validated with the registry in its
+//! [ValidationContext](crate::validation_context::ValidationContext) runs the rules as part of the
+//! presets of their category (e.g. `full` for informative rules) and by their ID like any other
+//! test, and [user-defined presets](crate::preset_config) can refer to them when resolved
+//! [with the registry](crate::preset_config::PresetConfig::resolve_with_rules).
+//!
+//! Rules which check the raw JSON instead, like the [declarative rules](crate::declarative_rules),
+//! implement [JsonRule] and are registered by [RuleRegistry::register_json_rule]. Like the tests
+//! operating on the raw JSON, they can only be executed on a [RawDocument](crate::csaf::raw::RawDocument).
+//!
+//! IDs of the standard (`schema` and IDs starting with a digit) and preset names are reserved, a
+//! prefix like `acme.1` avoids clashes between organizations.
+//!
+//! ```
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::csaf_traits::{CsafTrait, DocumentTrait, PublisherTrait};
+//! use csaf::custom_rules::{CsafRule, RuleRegistry};
+//! use csaf::test_info::TestCategory;
+//! use csaf::validation::{TestFinding, TestFindingData, validate_by_tests};
+//! use csaf::validation_context::{ValidationContext, WithContext};
+//! use csaf::validation_result::TestResult;
+//!
+//! struct PublisherNamespace;
+//!
+//! impl CsafRule for PublisherNamespace {
+//!     fn validate<Doc: CsafTrait>(&self, doc: &Doc) -> TestResult {
+//!         if doc.get_document().get_publisher().get_namespace() == "https://psirt.acme.com" {
+//!             return Ok(()).into();
+//!         }
+//!         Err(vec![TestFinding::Error(TestFindingData {
+//!             message: "The publisher namespace must be https://psirt.acme.com".to_string(),
+//!             instance_path: "/document/publisher/namespace".to_string(),
+//!         })])
+//!         .into()
+//!     }
+//! }
+//!
+//! let mut rules = RuleRegistry::new();
+//! rules
+//!     .register_rule("acme.doc.1", "Publisher Namespace", TestCategory::Mandatory, PublisherNamespace)
+//!     .unwrap();
+//!
+//! let document = load_document(r#"{"document": {"csaf_version": "2.1"}}"#).unwrap();
+//! let with_rules = WithContext::new(&document, ValidationContext::new().with_rules(&rules));
+//! let result = validate_by_tests(&with_rules, "2.1", &["acme.doc.1"]);
+//! assert_eq!(result.test_results[0].test_id, "acme.doc.1");
+//! ```
+
+use crate::csaf::raw::HasParsed;
+use crate::csaf_traits::CsafTrait;
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf2_0;
+use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf2_1;
+use crate::test_info::{ImplementationState, TestCategory, test_title};
+use crate::test_validation::{TestValidator, preset_categories};
+use crate::validation::Validatable;
+use crate::validation_result::TestResult;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::sync::Arc;
+
+/// A rule that validates documents of all CSAF versions.
+pub trait CsafRule: Send + Sync + 'static {
+    /// Validate a CSAF document according to this rule, see [TestValidator::validate].
+    fn validate<Doc: CsafTrait>(&self, doc: &Doc) -> TestResult;
+}
+
+/// A rule that validates the raw JSON of documents of all CSAF versions.
+pub trait JsonRule: Send + Sync + 'static {
+    /// Validate the JSON of a CSAF document according to this rule, see [TestValidator::validate].
+    fn validate(&self, json: &serde_json::Value) -> TestResult;
+}
+
+/// Adapts a [CsafRule] to the [TestValidator] of a specific CSAF version.
+struct RuleValidator<R>(Arc<R>);
+
+impl<R: CsafRule, Doc: CsafTrait> TestValidator<Doc> for RuleValidator<R> {
+    fn validate(&self, doc: &Doc) -> TestResult {
+        CsafRule::validate(self.0.as_ref(), doc)
+    }
+}
+
+/// A rule registered in a [RuleRegistry].
+pub struct CustomRule {
+    id: String,
+    title: String,
+    category: TestCategory,
+    validator: RuleValidators,
+}
+
+/// Implementation of a registered rule.
+enum RuleValidators {
+    /// A [CsafRule] with a validator for each CSAF version.
+    Parsed {
+        csaf2_0: Box<dyn TestValidator<Csaf2_0> + Send + Sync>,
+        csaf2_1: Box<dyn TestValidator<Csaf2_1> + Send + Sync>,
+    },
+    /// A [JsonRule].
+    Json(Box<dyn JsonRule>),
+}
+
+impl CustomRule {
+    /// The ID of the rule as used in presets and test results
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The title of the rule
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Whether a failure of the rule is mandatory, recommended or informative
+    pub fn category(&self) -> TestCategory {
+        self.category
+    }
+
+    /// Whether the rule runs on all documents or only on a [RawDocument](crate::csaf::raw::RawDocument)
+    pub fn state(&self) -> ImplementationState {
+        match self.validator {
+            RuleValidators::Parsed { .. } => ImplementationState::Implemented,
+            RuleValidators::Json(_) => ImplementationState::RawDocumentOnly,
+        }
+    }
+
+    /// Runs the rule on the deserialized document or on the raw JSON, depending on its kind.
+    pub(crate) fn run<D>(&self, document: &D) -> TestResult
+    where
+        D: HasParsed,
+        D::Parsed: RuleTarget,
+    {
+        match &self.validator {
+            RuleValidators::Parsed { .. } => match document.get_parsed() {
+                Ok(parsed) => D::Parsed::validator(self)
+                    .expect("rules on the deserialized document have a validator for each version")
+                    .validate(parsed),
+                Err(message) => TestResult::deserialization_failed(message.as_str()),
+            },
+            RuleValidators::Json(json_rule) => match document.get_raw_json() {
+                Some(json) => JsonRule::validate(json_rule.as_ref(), json),
+                None => TestResult::precondition_failed(format!(
+                    "Rule {} operates on the raw JSON document and can only be executed on a RawDocument.",
+                    self.id
+                )),
+            },
+        }
+    }
+}
+
+impl Debug for CustomRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CustomRule")
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .field("category", &self.category)
+            .field("state", &self.state())
+            .finish()
+    }
+}
+
+/// The rules to run alongside the tests of the standard, in the order of registration.
+#[derive(Debug, Default)]
+pub struct RuleRegistry {
+    rules: Vec<CustomRule>,
+}
+
+/// Error while registering a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistrationError {
+    /// The ID is empty, belongs to the standard or is the name of a preset.
+    ReservedId(String),
+    /// A rule with this ID is already registered.
+    DuplicateId(String),
+}
+
+impl Display for RegistrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            RegistrationError::ReservedId(id) => write!(f, "the rule ID {id} is reserved"),
+            RegistrationError::DuplicateId(id) => write!(f, "a rule with ID {id} is already registered"),
+        }
+    }
+}
+
+impl std::error::Error for RegistrationError {}
+
+/// Returns whether the ID is empty, belongs to the standard or is the name of a preset.
+fn is_reserved(id: &str) -> bool {
+    !id.starts_with(|c: char| c.is_ascii_alphabetic())
+        || id == "schema"
+        || Csaf2_0::tests_in_preset(id).is_some()
+        || Csaf2_1::tests_in_preset(id).is_some()
+}
+
+impl RuleRegistry {
+    /// Create an empty registry.
+    pub const fn new() -> Self {
+        RuleRegistry { rules: Vec::new() }
+    }
+
+    /// Register a rule with its ID, title and category, which determines the presets it is part of
+    /// and the severity of a failure in reports like SARIF.
+    pub fn register_rule(
+        &mut self,
+        id: &str,
+        title: &str,
+        category: TestCategory,
+        rule: impl CsafRule,
+    ) -> Result<(), RegistrationError> {
+        let rule = Arc::new(rule);
+        let validator = RuleValidators::Parsed {
+            csaf2_0: Box::new(RuleValidator(rule.clone())),
+            csaf2_1: Box::new(RuleValidator(rule)),
+        };
+        self.register(id, title, category, validator)
+    }
+
+    /// Register a rule on the raw JSON with its ID, title and category, see [Self::register_rule].
+    pub fn register_json_rule(
+        &mut self,
+        id: &str,
+        title: &str,
+        category: TestCategory,
+        rule: impl JsonRule,
+    ) -> Result<(), RegistrationError> {
+        self.register(id, title, category, RuleValidators::Json(Box::new(rule)))
+    }
+
+    /// Checks whether a rule with this ID can be registered, without registering it.
+    pub fn check_id(&self, id: &str) -> Result<(), RegistrationError> {
+        if is_reserved(id) {
+            return Err(RegistrationError::ReservedId(id.to_string()));
+        }
+        if self.contains(id) {
+            return Err(RegistrationError::DuplicateId(id.to_string()));
+        }
+        Ok(())
+    }
+
+    fn register(
+        &mut self,
+        id: &str,
+        title: &str,
+        category: TestCategory,
+        validator: RuleValidators,
+    ) -> Result<(), RegistrationError> {
+        self.check_id(id)?;
+        self.rules.push(CustomRule {
+            id: id.to_string(),
+            title: title.to_string(),
+            category,
+            validator,
+        });
+        Ok(())
+    }
+
+    /// Returns the registered rules, in the order of registration.
+    pub fn rules(&self) -> &[CustomRule] {
+        &self.rules
+    }
+
+    /// Returns the rule with this ID.
+    pub fn get(&self, id: &str) -> Option<&CustomRule> {
+        self.rules.iter().find(|rule| rule.id == id)
+    }
+
+    /// Returns whether a rule with this ID is registered.
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Returns the title of a test of the standard (see [test_title]) or of a registered rule.
+    pub fn test_title<'a>(&'a self, version: &str, test_id: &str) -> Option<&'a str> {
+        test_title(version, test_id).or_else(|| self.get(test_id).map(CustomRule::title))
+    }
+
+    /// Returns the category of a test of the standard (see [TestCategory::of]) or of a registered
+    /// rule.
+    pub fn test_category(&self, test_id: &str) -> Option<TestCategory> {
+        TestCategory::of(test_id).or_else(|| self.get(test_id).map(CustomRule::category))
+    }
+
+    /// Adds the rules of the categories of a preset to its tests, e.g. the informative rules to the
+    /// tests of `full`.
+    pub fn extend_preset<'a>(&'a self, preset: &str, mut tests: Vec<&'a str>) -> Vec<&'a str> {
+        // `external-request-free` of CSAF 2.1 is `full` without the tests requesting URLs
+        let categories = match preset {
+            "external-request-free" => preset_categories("full"),
+            preset => preset_categories(preset),
+        }
+        .unwrap_or_default();
+        tests.extend(
+            (self.rules.iter())
+                .filter(|rule| categories.contains(&rule.category))
+                .map(CustomRule::id),
+        );
+        tests
+    }
+}
+
+/// A document type of a CSAF version, which registered rules can be run on.
+pub trait RuleTarget: Sized {
+    /// Returns the validator of a rule for this CSAF version, or `None` for rules on the raw JSON.
+    fn validator(rule: &CustomRule) -> Option<&(dyn TestValidator<Self> + Send + Sync)>;
+}
+
+impl RuleTarget for Csaf2_0 {
+    fn validator(rule: &CustomRule) -> Option<&(dyn TestValidator<Self> + Send + Sync)> {
+        match &rule.validator {
+            RuleValidators::Parsed { csaf2_0, .. } => Some(csaf2_0.as_ref()),
+            RuleValidators::Json(_) => None,
+        }
+    }
+}
+
+impl RuleTarget for Csaf2_1 {
+    fn validator(rule: &CustomRule) -> Option<&(dyn TestValidator<Self> + Send + Sync)> {
+        match &rule.validator {
+            RuleValidators::Parsed { csaf2_1, .. } => Some(csaf2_1.as_ref()),
+            RuleValidators::Json(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf_traits::{ContentTrait, MetricTrait, VulnerabilityTrait};
+    use crate::validation::{TestFinding, TestFindingData, TestResultStatus, validate_by_preset, validate_by_tests};
+    use crate::validation_context::{ValidationContext, WithContext};
+    use crate::validations::utils::csaf_2_1_template;
+
+    /// Every vulnerability needs a CVSS v4 score.
+    struct CvssV4Required;
+
+    impl CsafRule for CvssV4Required {
+        fn validate<Doc: CsafTrait>(&self, doc: &Doc) -> TestResult {
+            let errors: Vec<_> = (doc.get_vulnerabilities().iter().enumerate())
+                .filter(|(_, vulnerability)| {
+                    !vulnerability
+                        .get_metrics()
+                        .is_some_and(|metrics| metrics.iter().any(|m| m.get_content().get_cvss_v4().is_some()))
+                })
+                .map(|(i, _)| {
+                    TestFinding::Warning(TestFindingData {
+                        message: "The vulnerability has no CVSS v4 score".to_string(),
+                        instance_path: format!("/vulnerabilities/{i}"),
+                    })
+                })
+                .collect();
+            if errors.is_empty() { Ok(()) } else { Err(errors) }.into()
+        }
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        let mut rules = RuleRegistry::new();
+        rules
+            .register_rule(
+                "test.cvss4",
+                "CVSS v4 Required",
+                TestCategory::Informative,
+                CvssV4Required,
+            )
+            .unwrap();
+        assert_eq!(
+            rules.register_rule("test.cvss4", "Again", TestCategory::Informative, CvssV4Required),
+            Err(RegistrationError::DuplicateId("test.cvss4".to_string()))
+        );
+        for reserved in ["6.4.1", "schema", "full", ""] {
+            assert_eq!(
+                rules.register_rule(reserved, "Reserved", TestCategory::Informative, CvssV4Required),
+                Err(RegistrationError::ReservedId(reserved.to_string()))
+            );
+        }
+        assert_eq!(rules.rules().len(), 1);
+        assert_eq!(rules.test_title("2.1", "test.cvss4"), Some("CVSS v4 Required"));
+        assert_eq!(
+            rules.test_title("2.1", "6.1.1"),
+            Some("Missing Definition of Product ID")
+        );
+        assert_eq!(rules.test_category("test.cvss4"), Some(TestCategory::Informative));
+        assert_eq!(TestCategory::of("test.cvss4"), None);
+
+        let document = csaf_2_1_template(|json| {
+            json["vulnerabilities"] = serde_json::json!([{"cve": "CVE-2024-0001"}]);
+        });
+        let with_rules = WithContext::new(&document, ValidationContext::new().with_rules(&rules));
+        assert!(with_rules.preset_tests("full").unwrap().contains(&"test.cvss4"));
+        assert!(!with_rules.preset_tests("basic").unwrap().contains(&"test.cvss4"));
+        assert!(with_rules.preset_tests("unknown").is_none());
+
+        let result = validate_by_tests(&with_rules, "2.1", &["test.cvss4"]);
+        assert_eq!(result.num_warnings, 1);
+        let result = validate_by_preset(&with_rules, "2.1", "informative");
+        assert!(
+            result
+                .test_results
+                .iter()
+                .any(|test_result| test_result.test_id == "test.cvss4")
+        );
+
+        // Without the registry, the rule is unknown
+        let result = validate_by_tests(&document, "2.1", &["test.cvss4"]);
+        assert_eq!(result.test_results[0].status, TestResultStatus::NotFound);
+        let result = validate_by_tests(&with_rules, "2.1", &["test.unknown"]);
+        assert_eq!(result.test_results[0].status, TestResultStatus::NotFound);
+    }
+}
@@ -0,0 +1,593 @@
+//! Declarative rules, which check the raw JSON of a document with simple assertions.
+//!
+//! A [RuleFile] lists rules in TOML or JSON. Each rule selects values by a `path`, either a JSON
+//! pointer (`/vulnerabilities/*/notes`) or a JSONPath (`$.vulnerabilities[*].notes`), where `*`
+//! selects all items of an array or all properties of an object. Every selected value is checked
+//! against the assertions of the rule:
+//!
+//! * `exists`: whether the value must exist (the default) or must not exist
+//! * `equals`: the value must equal the given value
+//! * `one_of`: the value must equal one of the given values
+//! * `pattern`: the value must be a string matching the regular expression completely
+//! * `contains`: the value must be an array containing an item that matches the given value, where
+//!   objects match if all given properties match
+//!
+//! ```toml
+//! [[rules]]
+//! id = "acme.1"
+//! title = "Generator Engine"
+//! category = "mandatory"
+//! path = "$.document.tracking.generator.engine.name"
+//! equals = "AcmeGen"
+//!
+//! [[rules]]
+//! id = "acme.2"
+//! title = "Vulnerability Description"
+//! category = "recommended"
+//! path = "/vulnerabilities/*/notes"
+//! contains = { category = "description" }
+//! ```
+//!
+//! A missing value fails the rule unless it is `optional`, while wildcards which select nothing
+//! (also because a property above them is missing) do not. Findings have the `severity` of the rule, by default according to its category
+//! (mandatory: error, recommended: warning, informative: information), and the instance path of
+//! the value, or of the first missing property.
+//!
+//! Rules are [registered](RuleFile::register) in a [RuleRegistry] as [custom rules](crate::custom_rules)
+//! on the raw JSON, after which they take part in presets and results of documents validated with
+//! the registry in their [ValidationContext](crate::validation_context::ValidationContext) like
+//! the tests of the standard.
+//!
+//! ```
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::custom_rules::RuleRegistry;
+//! use csaf::declarative_rules::RuleFile;
+//! use csaf::validation::validate_by_tests;
+//! use csaf::validation_context::{ValidationContext, WithContext};
+//!
+//! let rules = RuleFile::from_json_str(
+//!     r#"{"rules": [{"id": "example.lang", "title": "English", "path": "/document/lang", "equals": "en"}]}"#,
+//! )
+//! .unwrap();
+//! let mut registry = RuleRegistry::new();
+//! rules.register(&mut registry).unwrap();
+//!
+//! let document = load_document(r#"{"document": {"csaf_version": "2.1", "lang": "de"}}"#).unwrap();
+//! let with_rules = WithContext::new(&document, ValidationContext::new().with_rules(&registry));
+//! let result = validate_by_tests(&with_rules, "2.1", &["example.lang"]);
+//! assert_eq!(result.num_infos, 1);
+//! ```
+
+use crate::custom_rules::{JsonRule, RegistrationError, RuleRegistry};
+use crate::preset_config::Severity;
+use crate::test_info::TestCategory;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+
+/// A list of declarative rules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleFile {
+    #[serde(default)]
+    pub rules: Vec<DeclarativeRule>,
+}
+
+fn default_category() -> TestCategory {
+    TestCategory::Informative
+}
+
+/// A rule checking the values selected by a path, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeclarativeRule {
+    /// ID of the rule, e.g. `acme.1`
+    pub id: String,
+    /// Title of the rule, shown in reports
+    pub title: String,
+    /// Category of the rule, informative by default
+    #[serde(default = "default_category")]
+    pub category: TestCategory,
+    /// Severity of the findings, by default according to the category
+    #[serde(default)]
+    pub severity: Option<Severity>,
+    /// JSON pointer or JSONPath of the values to check, `*` selects all items or properties
+    pub path: String,
+    /// Whether missing values are accepted
+    #[serde(default)]
+    pub optional: bool,
+    /// Whether the values must exist (the default) or must not exist
+    #[serde(default)]
+    pub exists: Option<bool>,
+    /// The values must equal this value
+    #[serde(default)]
+    pub equals: Option<Value>,
+    /// The values must equal one of these values
+    #[serde(default)]
+    pub one_of: Option<Vec<Value>>,
+    /// The values must be strings completely matching this regular expression
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// The values must be arrays containing an item which matches this value
+    #[serde(default)]
+    pub contains: Option<Value>,
+    /// Message of the findings instead of the generated one
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Error while loading or registering a [RuleFile].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleFileError {
+    /// The file could not be read, with its path and the error.
+    Io(String, String),
+    /// The file is not a valid rule file.
+    Parse(String),
+    /// The file format (by extension) is not supported, e.g. TOML without the `toml` feature.
+    UnsupportedFormat(String),
+    /// The path of a rule is invalid, with the rule ID and the error.
+    InvalidPath(String, String),
+    /// The pattern of a rule is not a valid regular expression, with the rule ID and the error.
+    InvalidPattern(String, String),
+    /// A rule could not be registered.
+    Registration(RegistrationError),
+}
+
+impl Display for RuleFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            RuleFileError::Io(path, error) => write!(f, "could not read {path}: {error}"),
+            RuleFileError::Parse(error) => write!(f, "invalid rule file: {error}"),
+            RuleFileError::UnsupportedFormat(format) => write!(f, "unsupported rule file format: {format}"),
+            RuleFileError::InvalidPath(id, error) => write!(f, "invalid path of rule {id}: {error}"),
+            RuleFileError::InvalidPattern(id, error) => write!(f, "invalid pattern of rule {id}: {error}"),
+            RuleFileError::Registration(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for RuleFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RuleFileError::Registration(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl RuleFile {
+    /// Parses a rule file from JSON.
+    pub fn from_json_str(json: &str) -> Result<Self, RuleFileError> {
+        serde_json::from_str(json).map_err(|err| RuleFileError::Parse(err.to_string()))
+    }
+
+    /// Parses a rule file from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(toml: &str) -> Result<Self, RuleFileError> {
+        toml::from_str(toml).map_err(|err| RuleFileError::Parse(err.to_string()))
+    }
+
+    /// Loads a rule file from a `.toml` or `.json` file.
+    pub fn load(path: &Path) -> Result<Self, RuleFileError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| RuleFileError::Io(path.display().to_string(), err.to_string()))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json_str(&text),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml_str(&text),
+            other => Err(RuleFileError::UnsupportedFormat(other.unwrap_or_default().to_string())),
+        }
+    }
+
+    /// Compiles all rules, so that invalid rules are reported before any is registered.
+    pub fn compile(&self) -> Result<Vec<CompiledRule>, RuleFileError> {
+        self.rules.iter().map(CompiledRule::new).collect()
+    }
+
+    /// Registers all rules in the registry as [custom rules](crate::custom_rules) on the raw JSON.
+    ///
+    /// Either all rules are registered or none: invalid rules and IDs which are reserved, repeated
+    /// in the file or already registered are reported before any rule is registered.
+    pub fn register(&self, registry: &mut RuleRegistry) -> Result<(), RuleFileError> {
+        let compiled = self.compile()?;
+        for (index, rule) in self.rules.iter().enumerate() {
+            registry.check_id(&rule.id).map_err(RuleFileError::Registration)?;
+            if self.rules[..index].iter().any(|other| other.id == rule.id) {
+                return Err(RuleFileError::Registration(RegistrationError::DuplicateId(
+                    rule.id.clone(),
+                )));
+            }
+        }
+        for rule in compiled {
+            let (id, title, category) = (rule.rule.id.clone(), rule.rule.title.clone(), rule.rule.category);
+            registry
+                .register_json_rule(&id, &title, category, rule)
+                .map_err(RuleFileError::Registration)?;
+        }
+        Ok(())
+    }
+}
+
+/// A segment of a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// A property, or an array index if it is a number
+    Key(String),
+    /// All items of an array or properties of an object
+    Wildcard,
+}
+
+/// Parses a JSON pointer (`/a/*/b`) or a JSONPath (`$.a[*].b`) into segments.
+fn parse_path(path: &str) -> Result<Vec<Segment>, String> {
+    if let Some(json_path) = path.strip_prefix('$') {
+        return parse_json_path(json_path);
+    }
+    if path.is_empty() {
+        return Ok(vec![]);
+    }
+    let Some(pointer) = path.strip_prefix('/') else {
+        return Err("expected a JSON pointer starting with `/` or a JSONPath starting with `$`".to_string());
+    };
+    Ok(pointer
+        .split('/')
+        .map(|segment| match segment {
+            "*" => Segment::Wildcard,
+            key => Segment::Key(key.replace("~1", "/").replace("~0", "~")),
+        })
+        .collect())
+}
+
+/// Parses the part of a JSONPath after the `$`, supporting `.key`, `.*`, `[n]`, `[*]` and
+/// `['key']`.
+fn parse_json_path(mut rest: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+            segments.push(match &after_dot[..end] {
+                "" => return Err("empty property name".to_string()),
+                "*" => Segment::Wildcard,
+                key => Segment::Key(key.to_string()),
+            });
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let end = after_bracket.find(']').ok_or("missing `]`")?;
+            let selector = &after_bracket[..end];
+            segments.push(match selector {
+                "*" => Segment::Wildcard,
+                _ if selector.len() >= 2
+                    && ((selector.starts_with('\'') && selector.ends_with('\''))
+                        || (selector.starts_with('"') && selector.ends_with('"'))) =>
+                {
+                    Segment::Key(selector[1..selector.len() - 1].to_string())
+                },
+                index if index.parse::<usize>().is_ok() => Segment::Key(index.to_string()),
+                _ => return Err(format!("unsupported selector [{selector}]")),
+            });
+            rest = &after_bracket[end + 1..];
+        } else {
+            return Err(format!("expected `.` or `[` at `{rest}`"));
+        }
+    }
+    Ok(segments)
+}
+
+fn escape_pointer_segment(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Collects the values selected by the segments with their instance paths. A missing property is
+/// collected as `None` with the instance path of the property, unless there is a wildcard below it.
+fn select<'a>(value: &'a Value, segments: &[Segment], path: String, selected: &mut Vec<(String, Option<&'a Value>)>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        selected.push((path, Some(value)));
+        return;
+    };
+    match (segment, value) {
+        (Segment::Wildcard, Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate() {
+                select(item, rest, format!("{path}/{i}"), selected);
+            }
+        },
+        (Segment::Wildcard, Value::Object(properties)) => {
+            for (key, item) in properties {
+                select(item, rest, format!("{path}/{}", escape_pointer_segment(key)), selected);
+            }
+        },
+        (Segment::Wildcard, _) => {},
+        (Segment::Key(key), _) => {
+            let child = match value {
+                Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+                Value::Object(properties) => properties.get(key),
+                _ => None,
+            };
+            let child_path = format!("{path}/{}", escape_pointer_segment(key));
+            match child {
+                Some(child) => select(child, rest, child_path, selected),
+                // a wildcard below a missing property selects nothing
+                None if rest.contains(&Segment::Wildcard) => {},
+                None => selected.push((child_path, None)),
+            }
+        },
+    }
+}
+
+/// Returns whether the value matches the expected one, where objects match if all expected
+/// properties match.
+fn matches_subset(value: &Value, expected: &Value) -> bool {
+    match (value, expected) {
+        (Value::Object(properties), Value::Object(expected)) => expected
+            .iter()
+            .all(|(key, expected)| properties.get(key).is_some_and(|value| matches_subset(value, expected))),
+        _ => value == expected,
+    }
+}
+
+/// A [DeclarativeRule] with its path and pattern compiled.
+pub struct CompiledRule {
+    rule: DeclarativeRule,
+    segments: Vec<Segment>,
+    pattern: Option<Regex>,
+}
+
+impl CompiledRule {
+    /// Compiles the path and pattern of a rule.
+    pub fn new(rule: &DeclarativeRule) -> Result<Self, RuleFileError> {
+        let segments = parse_path(&rule.path).map_err(|err| RuleFileError::InvalidPath(rule.id.clone(), err))?;
+        let pattern = (rule.pattern.as_ref())
+            .map(|pattern| Regex::new(&format!("^(?:{pattern})$")))
+            .transpose()
+            .map_err(|err| RuleFileError::InvalidPattern(rule.id.clone(), err.to_string()))?;
+        Ok(CompiledRule {
+            rule: rule.clone(),
+            segments,
+            pattern,
+        })
+    }
+
+    /// Returns why the value violates the rule, if it does.
+    fn violation(&self, value: Option<&Value>) -> Option<String> {
+        let rule = &self.rule;
+        let Some(value) = value else {
+            let accepted = rule.optional || rule.exists == Some(false);
+            return (!accepted).then(|| format!("`{}` is missing", rule.path));
+        };
+        if rule.exists == Some(false) {
+            return Some(format!("`{}` must not exist", rule.path));
+        }
+        if let Some(expected) = &rule.equals
+            && value != expected
+        {
+            return Some(format!("`{}` must equal {expected} (was {value})", rule.path));
+        }
+        if let Some(allowed) = &rule.one_of
+            && !allowed.contains(value)
+        {
+            let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            return Some(format!(
+                "`{}` must be one of {} (was {value})",
+                rule.path,
+                allowed.join(", ")
+            ));
+        }
+        if let Some(pattern) = &self.pattern
+            && !value.as_str().is_some_and(|value| pattern.is_match(value))
+        {
+            let pattern = rule.pattern.as_deref().unwrap_or_default();
+            return Some(format!("`{}` must match `{pattern}` (was {value})", rule.path));
+        }
+        if let Some(expected) = &rule.contains
+            && !value
+                .as_array()
+                .is_some_and(|items| items.iter().any(|item| matches_subset(item, expected)))
+        {
+            return Some(format!("`{}` must contain an item matching {expected}", rule.path));
+        }
+        None
+    }
+
+    fn finding(&self, message: String, instance_path: String) -> TestFinding {
+        let data = TestFindingData {
+            message: self.rule.message.clone().unwrap_or(message),
+            instance_path,
+        };
+        let severity = self.rule.severity.unwrap_or(match self.rule.category {
+            TestCategory::Mandatory => Severity::Error,
+            TestCategory::Recommended => Severity::Warning,
+            TestCategory::Informative => Severity::Information,
+        });
+        match severity {
+            Severity::Error => TestFinding::Error(data),
+            Severity::Warning => TestFinding::Warning(data),
+            Severity::Information => TestFinding::Information(data),
+        }
+    }
+}
+
+impl JsonRule for CompiledRule {
+    fn validate(&self, json: &Value) -> TestResult {
+        let mut selected = vec![];
+        select(json, &self.segments, String::new(), &mut selected);
+        if selected.is_empty() {
+            return TestResult::no_data(format!("The document does not contain `{}`.", self.rule.path));
+        }
+        let findings: Vec<TestFinding> = selected
+            .into_iter()
+            .filter_map(|(instance_path, value)| {
+                let message = self.violation(value)?;
+                Some(self.finding(message, instance_path))
+            })
+            .collect();
+        if findings.is_empty() { Ok(()) } else { Err(findings) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Passed;
+    use serde_json::json;
+
+    fn run(rule: Value, document: &Value) -> TestResult {
+        let mut rule_json = json!({"id": "test.rule", "title": "Test Rule"});
+        rule_json
+            .as_object_mut()
+            .unwrap()
+            .extend(rule.as_object().unwrap().clone());
+        let rule: DeclarativeRule = serde_json::from_value(rule_json).unwrap();
+        CompiledRule::new(&rule).unwrap().validate(document)
+    }
+
+    fn error(message: &str, instance_path: &str) -> TestResult {
+        Err(vec![TestFinding::Error(TestFindingData {
+            message: message.to_string(),
+            instance_path: instance_path.to_string(),
+        })])
+        .into()
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let key = |key: &str| Segment::Key(key.to_string());
+        let expected = vec![key("vulnerabilities"), Segment::Wildcard, key("notes"), key("0")];
+        assert_eq!(parse_path("/vulnerabilities/*/notes/0").unwrap(), expected);
+        assert_eq!(parse_path("$.vulnerabilities[*].notes[0]").unwrap(), expected);
+        assert_eq!(parse_path("$.vulnerabilities.*['notes'][0]").unwrap(), expected);
+        assert_eq!(parse_path("/a~1b~0c").unwrap(), vec![key("a/b~c")]);
+        assert_eq!(parse_path("$").unwrap(), vec![]);
+        assert!(parse_path("document").is_err());
+        assert!(parse_path("$.a[?(@.b)]").is_err());
+        assert!(parse_path("$.a[0").is_err());
+    }
+
+    #[test]
+    fn test_declarative_rule() {
+        let document = json!({
+            "document": {"tracking": {"generator": {"engine": {"name": "OtherGen"}}}},
+            "vulnerabilities": [
+                {"notes": [{"category": "description", "text": "..."}]},
+                {"notes": [{"category": "summary", "text": "..."}]},
+                {},
+            ],
+        });
+        let equals =
+            json!({"path": "$.document.tracking.generator.engine.name", "equals": "AcmeGen", "category": "mandatory"});
+        assert_eq!(
+            run(equals, &document),
+            error(
+                r#"`$.document.tracking.generator.engine.name` must equal "AcmeGen" (was "OtherGen")"#,
+                "/document/tracking/generator/engine/name"
+            )
+        );
+
+        let contains =
+            json!({"path": "/vulnerabilities/*/notes", "contains": {"category": "description"}, "severity": "error"});
+        let TestResult::Failed(findings) = run(contains.clone(), &document) else {
+            panic!("expected findings");
+        };
+        let paths: Vec<_> = findings.iter().map(|f| f.get_data().instance_path.as_str()).collect();
+        assert_eq!(paths, ["/vulnerabilities/1/notes", "/vulnerabilities/2/notes"]);
+        assert_eq!(
+            run(contains, &json!({"document": {}})),
+            TestResult::no_data("The document does not contain `/vulnerabilities/*/notes`.")
+        );
+
+        // missing values are reported at the first missing property
+        let pattern = json!({"path": "/document/lang", "pattern": "en(-[A-Z]{2})?", "category": "mandatory", "message": "English only"});
+        assert_eq!(run(pattern.clone(), &document), error("English only", "/document/lang"));
+        assert_eq!(
+            run(pattern.clone(), &json!({"document": {"lang": "en-US"}})),
+            TestResult::Passed(Passed::Success)
+        );
+        assert!(matches!(
+            run(pattern, &json!({"document": {"lang": "de-en"}})),
+            TestResult::Failed(_)
+        ));
+
+        let optional = json!({"path": "/document/lang", "one_of": ["en", "de"], "optional": true});
+        assert_eq!(run(optional.clone(), &document), TestResult::Passed(Passed::Success));
+        assert!(matches!(
+            run(optional, &json!({"document": {"lang": "fr"}})),
+            TestResult::Failed(_)
+        ));
+
+        let forbidden = json!({"path": "/document/tracking/generator", "exists": false});
+        assert_eq!(
+            run(forbidden, &document),
+            Err(vec![TestFinding::Information(TestFindingData {
+                message: "`/document/tracking/generator` must not exist".to_string(),
+                instance_path: "/document/tracking/generator".to_string(),
+            })])
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let rules = RuleFile::from_json_str(
+            r#"{"rules": [{"id": "test.declarative", "title": "Declarative", "path": "/document/lang"}]}"#,
+        )
+        .unwrap();
+        let mut registry = RuleRegistry::new();
+        rules.register(&mut registry).unwrap();
+        assert_eq!(
+            rules.register(&mut registry),
+            Err(RuleFileError::Registration(RegistrationError::DuplicateId(
+                "test.declarative".to_string()
+            )))
+        );
+
+        let document = crate::validations::utils::csaf_2_1_template(|json| {
+            json["document"].as_object_mut().unwrap().remove("lang");
+        });
+        let with_rules = crate::validation_context::WithContext::new(
+            &document,
+            crate::validation_context::ValidationContext::new().with_rules(&registry),
+        );
+        let result = crate::validation::validate_by_tests(&with_rules, "2.1", &["test.declarative"]);
+        assert_eq!(result.num_infos, 1);
+
+        let invalid = RuleFile::from_json_str(
+            r#"{"rules": [{"id": "test.invalid", "title": "Invalid", "path": "/a", "pattern": "("}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            invalid.register(&mut registry),
+            Err(RuleFileError::InvalidPattern(..))
+        ));
+
+        // Nothing is registered if one of the rules cannot be
+        let partly_duplicate = RuleFile::from_json_str(
+            r#"{"rules": [
+                {"id": "test.new", "title": "New", "path": "/document/lang"},
+                {"id": "test.declarative", "title": "Again", "path": "/document/lang"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            partly_duplicate.register(&mut registry),
+            Err(RuleFileError::Registration(RegistrationError::DuplicateId(
+                "test.declarative".to_string()
+            )))
+        );
+        let repeated = RuleFile::from_json_str(
+            r#"{"rules": [
+                {"id": "test.new", "title": "New", "path": "/document/lang"},
+                {"id": "test.new", "title": "Again", "path": "/document/lang"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            repeated.register(&mut registry),
+            Err(RuleFileError::Registration(RegistrationError::DuplicateId(
+                "test.new".to_string()
+            )))
+        );
+        assert!(!registry.contains("test.new"));
+        assert_eq!(registry.rules().len(), 1);
+    }
+}
@@ -0,0 +1,287 @@
+//! Hash files which accompany distributed CSAF documents (requirement 7.1.18).
+//!
+//! A document `esa-2024-001.json` is distributed with `esa-2024-001.json.sha256` and/or
+//! `esa-2024-001.json.sha512`, which contain its hash in the format of `sha256sum`:
+//!
+//! ```text
+//! 4d1cb1a2...e8f0  esa-2024-001.json
+//! ```
+//!
+//! [`write_sidecars`] computes and writes these files, [`verify_sidecars`] checks the existing
+//! ones against the document, including that they name the document's file.
+//!
+//! ```no_run
+//! use csaf::csaf::types::csaf_hash_algo::CsafHashAlgorithm;
+//! use csaf::hash_sidecar::{verify_sidecars, write_sidecars};
+//! use std::path::Path;
+//!
+//! let document = Path::new("white/2024/esa-2024-001.json");
+//! write_sidecars(document, &[CsafHashAlgorithm::Sha256, CsafHashAlgorithm::Sha512]).unwrap();
+//! for (algorithm, result) in verify_sidecars(document) {
+//!     assert!(result.is_ok(), "{algorithm}");
+//! }
+//! ```
+
+use crate::csaf::types::csaf_hash_algo::CsafHashAlgorithm;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The algorithms of the hash files named in the standard, in the order they are checked.
+pub const SIDECAR_ALGORITHMS: [CsafHashAlgorithm; 2] = [CsafHashAlgorithm::Sha256, CsafHashAlgorithm::Sha512];
+
+/// Error while computing, writing or verifying a hash file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SidecarError {
+    /// A file could not be read or written
+    Io(PathBuf, String),
+    /// Hashes of this algorithm cannot be computed
+    UnsupportedAlgorithm(CsafHashAlgorithm),
+    /// The hash file does not exist
+    Missing(PathBuf),
+    /// The hash file is not in the format `<hash>  <file name>`
+    Malformed(PathBuf),
+    /// The hash file names another file than the document
+    FileNameMismatch {
+        sidecar: PathBuf,
+        expected: String,
+        found: String,
+    },
+    /// The hash in the hash file does not match the document
+    HashMismatch {
+        sidecar: PathBuf,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl SidecarError {
+    /// The file the error refers to, if any.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            SidecarError::Io(path, _) | SidecarError::Missing(path) | SidecarError::Malformed(path) => Some(path),
+            SidecarError::FileNameMismatch { sidecar, .. } | SidecarError::HashMismatch { sidecar, .. } => {
+                Some(sidecar)
+            },
+            SidecarError::UnsupportedAlgorithm(_) => None,
+        }
+    }
+}
+
+/// The messages do not contain the [path](SidecarError::path) of the file.
+impl Display for SidecarError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SidecarError::Io(_, err) => write!(f, "Could not access the file: {err}"),
+            SidecarError::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "Hashes of the algorithm {algorithm} are not supported")
+            },
+            SidecarError::Missing(_) => write!(f, "The hash file is missing"),
+            SidecarError::Malformed(_) => write!(f, "The hash file is not in the format '<hash>  <file name>'"),
+            SidecarError::FileNameMismatch { expected, found, .. } => {
+                write!(f, "The hash file is for '{found}' instead of '{expected}'")
+            },
+            SidecarError::HashMismatch { expected, actual, .. } => {
+                write!(
+                    f,
+                    "The hash {expected} does not match the hash {actual} of the document"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for SidecarError {}
+
+/// Compute the hash of the content as lowercase hexadecimal string.
+///
+/// Supports the algorithms of the SHA-2 family.
+pub fn compute_hash(algorithm: &CsafHashAlgorithm, content: &[u8]) -> Result<String, SidecarError> {
+    Ok(match algorithm.normalize() {
+        CsafHashAlgorithm::Sha224 => format!("{:x}", Sha224::digest(content)),
+        CsafHashAlgorithm::Sha256 => format!("{:x}", Sha256::digest(content)),
+        CsafHashAlgorithm::Sha384 => format!("{:x}", Sha384::digest(content)),
+        CsafHashAlgorithm::Sha512 => format!("{:x}", Sha512::digest(content)),
+        CsafHashAlgorithm::Sha512_224 => format!("{:x}", Sha512_224::digest(content)),
+        CsafHashAlgorithm::Sha512_256 => format!("{:x}", Sha512_256::digest(content)),
+        other => return Err(SidecarError::UnsupportedAlgorithm(other)),
+    })
+}
+
+/// The path of the hash file of the document, e.g. `esa-2024-001.json.sha256`.
+pub fn sidecar_path(document: &Path, algorithm: &CsafHashAlgorithm) -> PathBuf {
+    let mut file_name = document.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{algorithm}"));
+    document.with_file_name(file_name)
+}
+
+/// The content of a hash file in the format of `sha256sum`.
+pub fn sidecar_content(hash: &str, file_name: &str) -> String {
+    format!("{hash}  {file_name}\n")
+}
+
+/// Compute the hashes of the document and write a hash file for each algorithm.
+///
+/// Returns the paths of the written files.
+pub fn write_sidecars(document: &Path, algorithms: &[CsafHashAlgorithm]) -> Result<Vec<PathBuf>, SidecarError> {
+    let content = read(document)?;
+    let file_name = file_name(document);
+    algorithms
+        .iter()
+        .map(|algorithm| {
+            let path = sidecar_path(document, algorithm);
+            let hash = compute_hash(algorithm, &content)?;
+            fs::write(&path, sidecar_content(&hash, &file_name))
+                .map_err(|err| SidecarError::Io(path.clone(), err.to_string()))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Verify the hash file of the given algorithm against the document.
+pub fn verify_sidecar(document: &Path, algorithm: &CsafHashAlgorithm) -> Result<(), SidecarError> {
+    verify_sidecar_content(document, &read(document)?, algorithm)
+}
+
+/// Verify all existing hash files of the [algorithms named in the standard](SIDECAR_ALGORITHMS).
+///
+/// Returns the result for each existing hash file, none if there is no hash file at all.
+pub fn verify_sidecars(document: &Path) -> Vec<(CsafHashAlgorithm, Result<(), SidecarError>)> {
+    let algorithms: Vec<_> = SIDECAR_ALGORITHMS
+        .into_iter()
+        .filter(|algorithm| sidecar_path(document, algorithm).is_file())
+        .collect();
+    if algorithms.is_empty() {
+        return Vec::new();
+    }
+    let content = read(document);
+    algorithms
+        .into_iter()
+        .map(|algorithm| {
+            let result = match &content {
+                Ok(content) => verify_sidecar_content(document, content, &algorithm),
+                Err(err) => Err(err.clone()),
+            };
+            (algorithm, result)
+        })
+        .collect()
+}
+
+fn verify_sidecar_content(document: &Path, content: &[u8], algorithm: &CsafHashAlgorithm) -> Result<(), SidecarError> {
+    let sidecar = sidecar_path(document, algorithm);
+    if !sidecar.is_file() {
+        return Err(SidecarError::Missing(sidecar));
+    }
+    let line = fs::read_to_string(&sidecar).map_err(|err| SidecarError::Io(sidecar.clone(), err.to_string()))?;
+    // The hash, a space, and a space or `*` (binary mode) before the file name
+    let Some((expected, found)) = line.lines().next().and_then(|line| line.split_once(' ')) else {
+        return Err(SidecarError::Malformed(sidecar));
+    };
+    let found = found.strip_prefix([' ', '*']).unwrap_or(found);
+    let file_name = file_name(document);
+    if found != file_name {
+        return Err(SidecarError::FileNameMismatch {
+            sidecar,
+            expected: file_name,
+            found: found.to_string(),
+        });
+    }
+    let actual = compute_hash(algorithm, content)?;
+    if !expected.eq_ignore_ascii_case(&actual) {
+        return Err(SidecarError::HashMismatch {
+            sidecar,
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+fn read(document: &Path) -> Result<Vec<u8>, SidecarError> {
+    fs::read(document).map_err(|err| SidecarError::Io(document.to_path_buf(), err.to_string()))
+}
+
+fn file_name(document: &Path) -> String {
+    document.file_name().unwrap_or_default().to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_hash() {
+        assert_eq!(
+            compute_hash(&CsafHashAlgorithm::Sha256, b"abc").unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(compute_hash(&CsafHashAlgorithm::Sha512, b"").unwrap().len(), 128);
+        assert_eq!(
+            compute_hash(&CsafHashAlgorithm::Md5, b"abc"),
+            Err(SidecarError::UnsupportedAlgorithm(CsafHashAlgorithm::Md5))
+        );
+    }
+
+    #[test]
+    fn test_write_and_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let document = dir.path().join("esa-2024-001.json");
+        fs::write(&document, "{}").unwrap();
+        assert_eq!(verify_sidecars(&document), vec![]);
+
+        let written = write_sidecars(&document, &SIDECAR_ALGORITHMS).unwrap();
+        assert_eq!(
+            written,
+            [
+                dir.path().join("esa-2024-001.json.sha256"),
+                dir.path().join("esa-2024-001.json.sha512")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(&written[0]).unwrap(),
+            "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a  esa-2024-001.json\n"
+        );
+        let results = verify_sidecars(&document);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+
+        // Binary mode of sha256sum
+        fs::write(
+            &written[0],
+            "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a *esa-2024-001.json\n",
+        )
+        .unwrap();
+        assert_eq!(verify_sidecar(&document, &CsafHashAlgorithm::Sha256), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_mismatches() {
+        let dir = tempfile::tempdir().unwrap();
+        let document = dir.path().join("esa-2024-001.json");
+        fs::write(&document, "{}").unwrap();
+        let sidecar = sidecar_path(&document, &CsafHashAlgorithm::Sha256);
+
+        fs::write(&sidecar, sidecar_content(&"0".repeat(64), "esa-2024-002.json")).unwrap();
+        assert!(matches!(
+            verify_sidecar(&document, &CsafHashAlgorithm::Sha256),
+            Err(SidecarError::FileNameMismatch { found, .. }) if found == "esa-2024-002.json"
+        ));
+
+        fs::write(&sidecar, sidecar_content(&"0".repeat(64), "esa-2024-001.json")).unwrap();
+        assert!(matches!(
+            verify_sidecar(&document, &CsafHashAlgorithm::Sha256),
+            Err(SidecarError::HashMismatch { .. })
+        ));
+
+        fs::write(&sidecar, "").unwrap();
+        assert_eq!(
+            verify_sidecar(&document, &CsafHashAlgorithm::Sha256),
+            Err(SidecarError::Malformed(sidecar))
+        );
+        assert_eq!(
+            verify_sidecar(&document, &CsafHashAlgorithm::Sha512),
+            Err(SidecarError::Missing(dir.path().join("esa-2024-001.json.sha512")))
+        );
+    }
+}
@@ -4,17 +4,31 @@ pub mod csaf;
 pub mod csaf2_0;
 pub mod csaf2_1;
 pub mod csaf_traits;
+pub mod custom_rules;
 pub(crate) mod cvss;
+pub mod declarative_rules;
+pub mod hash_sidecar;
 pub mod helpers;
 pub mod json;
 pub(crate) mod macros;
+pub mod preset_config;
+pub mod provider;
+pub mod provider_directory;
+pub mod rolie;
 pub mod schema;
+#[cfg(feature = "openpgp")]
+pub mod signature;
+pub mod source_map;
+pub mod spell_check;
+pub mod test_info;
 #[cfg(test)]
 pub mod test_result_comparison;
 #[cfg(test)]
 pub mod test_structure;
 pub mod test_validation;
+pub mod url_resolver;
 pub mod validation;
+pub mod validation_context;
 pub mod validation_result;
 pub mod validations;
 
@@ -58,18 +58,20 @@ macro_rules! define_csaf_test {
             /// * `doc` - The CSAF document to validate
             ///
             /// # Returns
-            /// * `Ok(())` if validation passes
-            /// * `Err(Vec<TestFinding>)` if validation fails
+            /// * The [TestResult](crate::validation_result::TestResult) of the validator, including
+            ///   the reason why it passed or was skipped
             pub fn validate(
                 &self,
                 doc: &crate::csaf::raw::RawDocument<$doc_type>,
-            ) -> Result<(), Vec<crate::validation::TestFinding>> {
+            ) -> crate::validation_result::TestResult {
                 let validator = V::default();
                 validator.validate(doc)
             }
         }
 
         /// Expected results for each test case of this test, keyed by case name.
+        ///
+        /// `Ok(())` is expected to match any passed or skipped result, regardless of the reason.
         #[cfg(test)]
         #[derive(Debug)]
         #[allow(non_camel_case_types)]
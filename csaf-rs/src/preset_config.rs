@@ -0,0 +1,529 @@
+//! User-defined validation presets, loaded from a TOML or JSON file.
+//!
+//! Besides the built-in presets (`basic`, `extended`, `full`, ...), an organization can define its
+//! own policy in a [PresetConfig]. A preset [extends](PresetDefinition::extends) built-in or other
+//! user-defined presets, adds and removes single tests, overrides the severity of the findings of
+//! a test and suppresses findings at specific instance paths:
+//!
+//! ```toml
+//! [presets.psirt]
+//! extends = ["basic"]
+//! tests = ["6.2.1", "6.2.11"]
+//! exclude = ["6.1.27.3"]
+//! severity = { "6.2.11" = "error" }
+//!
+//! [[presets.psirt.suppress]]
+//! test = "6.2.1"
+//! instance_path = "/document/tracking/revision_history/0"
+//! reason = "The first revision predates our tracking"
+//! ```
+//!
+//! TOML files need the `toml` feature, JSON files have the same structure. A preset is resolved
+//! against the tests of a CSAF version, which fails if it refers to unknown presets or tests, so a
+//! [ResolvedPreset] only contains implemented tests.
+//!
+//! ```
+//! use csaf::csaf::raw::RawDocument;
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::preset_config::PresetConfig;
+//! use csaf::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+//!
+//! let config = PresetConfig::from_json_str(
+//!     r#"{"presets": {"psirt": {"extends": ["basic"], "exclude": ["schema"], "severity": {"6.1.1": "warning"}}}}"#,
+//! )
+//! .unwrap();
+//! let preset = config
+//!     .resolve::<RawDocument<CommonSecurityAdvisoryFramework>>("psirt")
+//!     .unwrap();
+//! assert!(!preset.tests.contains(&"schema"));
+//!
+//! let document = load_document(r#"{"document": {"csaf_version": "2.1"}}"#).unwrap();
+//! let result = preset.validate(&document, "2.1");
+//! assert_eq!(result.test_results.len(), preset.tests.len());
+//! ```
+
+use crate::custom_rules::{CustomRule, RuleRegistry};
+use crate::validation::{
+    Passed, TestResult, TestResultStatus, Validatable, ValidationError, ValidationResult, aggregate_test_results,
+    validate_by_tests,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+
+/// A set of user-defined presets by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetConfig {
+    #[serde(default)]
+    pub presets: BTreeMap<String, PresetDefinition>,
+}
+
+/// Definition of a user-defined preset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetDefinition {
+    /// Built-in or user-defined presets whose tests, severity overrides and suppressions are
+    /// inherited, in this order
+    #[serde(default)]
+    pub extends: Vec<String>,
+    /// Additional test IDs
+    #[serde(default)]
+    pub tests: Vec<String>,
+    /// Test IDs to remove, also from the inherited tests
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Severity of all findings of a test by test ID, overriding inherited ones
+    #[serde(default)]
+    pub severity: BTreeMap<String, Severity>,
+    /// Findings to suppress, in addition to the inherited ones
+    #[serde(default)]
+    pub suppress: Vec<Suppression>,
+}
+
+/// Severity of a finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    #[serde(alias = "info")]
+    Information,
+}
+
+/// Suppresses the findings at an instance path and below, e.g. because they were reviewed and
+/// accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suppression {
+    /// Only suppress findings of this test, otherwise findings of all tests
+    #[serde(default)]
+    pub test: Option<String>,
+    /// Instance path of the findings, e.g. `/vulnerabilities/0`, which also matches all paths below
+    pub instance_path: String,
+    /// Why the findings are suppressed, for documentation only
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl Suppression {
+    /// Returns whether this suppresses the given finding of the given test.
+    pub fn matches(&self, test_id: &str, instance_path: &str) -> bool {
+        self.test.as_deref().is_none_or(|test| test == test_id)
+            && instance_path
+                .strip_prefix(self.instance_path.trim_end_matches('/'))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+}
+
+/// Error while loading or resolving a [PresetConfig].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresetConfigError {
+    /// The file could not be read, with its path and the error.
+    Io(String, String),
+    /// The file is not a valid preset configuration.
+    Parse(String),
+    /// The file format (by extension) is not supported, e.g. TOML without the `toml` feature.
+    UnsupportedFormat(String),
+    /// A preset is neither user-defined nor built-in.
+    UnknownPreset(String),
+    /// A user-defined preset has the name of a built-in preset.
+    ShadowsBuiltinPreset(String),
+    /// Presets extend each other in a cycle, starting and ending with the same preset.
+    Cycle(Vec<String>),
+    /// A preset refers to test IDs which do not exist in the CSAF version.
+    UnknownTests { preset: String, tests: Vec<String> },
+    /// A preset contains tests which are not implemented yet, see
+    /// [test_capabilities](crate::test_info::test_capabilities).
+    NotImplemented { preset: String, tests: Vec<String> },
+}
+
+impl Display for PresetConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PresetConfigError::Io(path, error) => write!(f, "could not read {path}: {error}"),
+            PresetConfigError::Parse(error) => write!(f, "invalid preset configuration: {error}"),
+            PresetConfigError::UnsupportedFormat(format) => write!(f, "unsupported preset file format: {format}"),
+            PresetConfigError::UnknownPreset(name) => write!(f, "unknown preset {name}"),
+            PresetConfigError::ShadowsBuiltinPreset(name) => {
+                write!(f, "preset {name} has the name of a built-in preset")
+            },
+            PresetConfigError::Cycle(names) => write!(f, "presets extend each other: {}", names.join(" -> ")),
+            PresetConfigError::UnknownTests { preset, tests } => {
+                write!(f, "preset {preset} refers to unknown test(s) {}", tests.join(", "))
+            },
+            PresetConfigError::NotImplemented { preset, tests } => {
+                write!(
+                    f,
+                    "preset {preset} contains test(s) {} which are not implemented",
+                    tests.join(", ")
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for PresetConfigError {}
+
+/// A preset resolved against the tests of a CSAF version, and the registered rules it borrows the
+/// IDs of.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolvedPreset<'a> {
+    /// The implemented tests to run, in order
+    pub tests: Vec<&'a str>,
+    /// Severity of all findings of a test by test ID
+    pub severity: BTreeMap<String, Severity>,
+    /// Findings to suppress
+    pub suppress: Vec<Suppression>,
+}
+
+impl PresetConfig {
+    /// Parses a preset configuration from JSON.
+    pub fn from_json_str(json: &str) -> Result<Self, PresetConfigError> {
+        serde_json::from_str(json).map_err(|err| PresetConfigError::Parse(err.to_string()))
+    }
+
+    /// Parses a preset configuration from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(toml: &str) -> Result<Self, PresetConfigError> {
+        toml::from_str(toml).map_err(|err| PresetConfigError::Parse(err.to_string()))
+    }
+
+    /// Loads a preset configuration from a `.toml` or `.json` file.
+    pub fn load(path: &Path) -> Result<Self, PresetConfigError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| PresetConfigError::Io(path.display().to_string(), err.to_string()))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json_str(&text),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml_str(&text),
+            other => Err(PresetConfigError::UnsupportedFormat(
+                other.unwrap_or_default().to_string(),
+            )),
+        }
+    }
+
+    /// Returns whether a preset with this name is user-defined.
+    pub fn contains(&self, name: &str) -> bool {
+        self.presets.contains_key(name)
+    }
+
+    /// Resolves a user-defined or built-in preset against the tests of the CSAF version of `V`.
+    pub fn resolve<V: Validatable>(&self, name: &str) -> Result<ResolvedPreset<'static>, PresetConfigError> {
+        static NO_RULES: RuleRegistry = RuleRegistry::new();
+        self.resolve_with_rules::<V>(name, &NO_RULES)
+    }
+
+    /// Resolves a user-defined or built-in preset against the tests of the CSAF version of `V` and
+    /// the registered rules, which user-defined presets can refer to like tests and which are part
+    /// of the built-in presets of their category.
+    pub fn resolve_with_rules<'a, V: Validatable>(
+        &self,
+        name: &str,
+        rules: &'a RuleRegistry,
+    ) -> Result<ResolvedPreset<'a>, PresetConfigError> {
+        self.resolve_recursive::<V>(name, rules, &mut vec![])
+    }
+
+    fn resolve_recursive<'a, V: Validatable>(
+        &self,
+        name: &str,
+        rules: &'a RuleRegistry,
+        chain: &mut Vec<String>,
+    ) -> Result<ResolvedPreset<'a>, PresetConfigError> {
+        let Some(definition) = self.presets.get(name) else {
+            return match V::tests_in_preset(name) {
+                Some(tests) => Ok(ResolvedPreset {
+                    tests: rules.extend_preset(name, tests),
+                    ..Default::default()
+                }),
+                None => Err(PresetConfigError::UnknownPreset(name.to_string())),
+            };
+        };
+        if V::tests_in_preset(name).is_some() {
+            return Err(PresetConfigError::ShadowsBuiltinPreset(name.to_string()));
+        }
+        if chain.iter().any(|parent| parent == name) {
+            let mut cycle = chain.clone();
+            cycle.push(name.to_string());
+            return Err(PresetConfigError::Cycle(cycle));
+        }
+
+        chain.push(name.to_string());
+        let mut resolved = ResolvedPreset::default();
+        for parent in &definition.extends {
+            resolved.merge(self.resolve_recursive::<V>(parent, rules, chain)?);
+        }
+        chain.pop();
+
+        let capabilities = V::test_capabilities();
+        let referenced = (definition.tests.iter())
+            .chain(&definition.exclude)
+            .chain(definition.severity.keys())
+            .chain(
+                definition
+                    .suppress
+                    .iter()
+                    .filter_map(|suppression| suppression.test.as_ref()),
+            );
+        let mut unknown: Vec<String> = referenced
+            .filter(|test_id| !capabilities.iter().any(|capability| &capability.test_id == test_id))
+            .filter(|test_id| !rules.contains(test_id))
+            .cloned()
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            unknown.dedup();
+            return Err(PresetConfigError::UnknownTests {
+                preset: name.to_string(),
+                tests: unknown,
+            });
+        }
+        let not_implemented: Vec<String> = (definition.tests.iter())
+            .filter(|test_id| {
+                capabilities
+                    .iter()
+                    .any(|capability| &capability.test_id == test_id && !capability.state.is_implemented())
+            })
+            .cloned()
+            .collect();
+        if !not_implemented.is_empty() {
+            return Err(PresetConfigError::NotImplemented {
+                preset: name.to_string(),
+                tests: not_implemented,
+            });
+        }
+
+        let own_tests = (capabilities.iter().map(|capability| capability.test_id))
+            .chain(rules.rules().iter().map(CustomRule::id))
+            .filter(|test_id| definition.tests.iter().any(|test| test == test_id));
+        resolved.merge(ResolvedPreset {
+            tests: own_tests.collect(),
+            severity: definition.severity.clone(),
+            suppress: definition.suppress.clone(),
+        });
+        resolved
+            .tests
+            .retain(|test_id| !definition.exclude.iter().any(|excluded| excluded == test_id));
+        Ok(resolved)
+    }
+}
+
+impl<'a> ResolvedPreset<'a> {
+    /// Adds the tests, severity overrides and suppressions of another preset, whose severity
+    /// overrides take precedence.
+    pub fn merge(&mut self, other: ResolvedPreset<'a>) {
+        for test_id in other.tests {
+            if !self.tests.contains(&test_id) {
+                self.tests.push(test_id);
+            }
+        }
+        self.severity.extend(other.severity);
+        self.suppress.extend(other.suppress);
+    }
+
+    /// Runs the tests of this preset and applies its severity overrides and suppressions.
+    pub fn validate(&self, target: &impl Validatable, version: &str) -> ValidationResult {
+        self.apply(validate_by_tests(target, version, &self.tests))
+    }
+
+    /// Applies the severity overrides and suppressions of this preset to a validation result.
+    ///
+    /// Tests whose findings are all suppressed pass.
+    pub fn apply(&self, result: ValidationResult) -> ValidationResult {
+        let test_results = result
+            .test_results
+            .into_iter()
+            .map(|test_result| self.apply_to_test_result(test_result))
+            .collect();
+        aggregate_test_results(&result.version, test_results)
+    }
+
+    fn apply_to_test_result(&self, test_result: TestResult) -> TestResult {
+        let TestResultStatus::Failure {
+            errors,
+            warnings,
+            infos,
+        } = test_result.status
+        else {
+            return test_result;
+        };
+        let test_id = test_result.test_id;
+        let keep = |findings: Vec<ValidationError>| -> Vec<ValidationError> {
+            findings
+                .into_iter()
+                .filter(|finding| {
+                    !self
+                        .suppress
+                        .iter()
+                        .any(|suppression| suppression.matches(&test_id, &finding.instance_path))
+                })
+                .collect()
+        };
+        let (mut errors, mut warnings, mut infos) = (keep(errors), keep(warnings), keep(infos));
+        if let Some(severity) = self.severity.get(&test_id) {
+            let findings = [errors, warnings, infos].concat();
+            (errors, warnings, infos) = (vec![], vec![], vec![]);
+            match severity {
+                Severity::Error => errors = findings,
+                Severity::Warning => warnings = findings,
+                Severity::Information => infos = findings,
+            }
+        }
+        let status = if errors.is_empty() && warnings.is_empty() && infos.is_empty() {
+            TestResultStatus::Passed(Passed::Success)
+        } else {
+            TestResultStatus::Failure {
+                errors,
+                warnings,
+                infos,
+            }
+        };
+        TestResult { test_id, status }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::raw::RawDocument;
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+    use crate::validations::utils::csaf_2_1_template;
+
+    type Csaf2_1 = RawDocument<CommonSecurityAdvisoryFramework>;
+
+    fn config(json: serde_json::Value) -> PresetConfig {
+        PresetConfig::from_json_str(&json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_extends() {
+        let config = config(serde_json::json!({"presets": {
+            "base": {"extends": ["basic"], "exclude": ["6.1.1"], "severity": {"6.2.1": "info"}},
+            "psirt": {
+                "extends": ["base"],
+                "tests": ["6.2.1", "6.2.11"],
+                "severity": {"6.2.11": "error"},
+                "suppress": [{"test": "6.2.1", "instance_path": "/document"}],
+            },
+        }}));
+        let basic = Csaf2_1::tests_in_preset("basic").unwrap();
+        let psirt = config.resolve::<Csaf2_1>("psirt").unwrap();
+        assert_eq!(psirt.tests.len(), basic.len() + 1);
+        assert!(!psirt.tests.contains(&"6.1.1"));
+        assert_eq!(&psirt.tests[psirt.tests.len() - 2..], ["6.2.1", "6.2.11"]);
+        assert_eq!(psirt.severity.get("6.2.1"), Some(&Severity::Information));
+        assert_eq!(psirt.severity.get("6.2.11"), Some(&Severity::Error));
+        assert_eq!(psirt.suppress.len(), 1);
+
+        // built-in presets resolve to their tests
+        assert_eq!(config.resolve::<Csaf2_1>("basic").unwrap().tests, basic);
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let config = config(serde_json::json!({"presets": {
+            "a": {"extends": ["b"]},
+            "b": {"extends": ["a"]},
+            "basic": {},
+            "missing": {"extends": ["nothing"]},
+            "unknown": {"tests": ["6.1.1", "9.9.9", "8.8.8"], "exclude": ["9.9.9"]},
+            "not-implemented": {"tests": ["6.2.40"]},
+        }}));
+        let resolve = |name| config.resolve::<Csaf2_1>(name).unwrap_err();
+        assert_eq!(
+            resolve("a"),
+            PresetConfigError::Cycle(vec!["a".into(), "b".into(), "a".into()])
+        );
+        assert_eq!(
+            resolve("basic"),
+            PresetConfigError::ShadowsBuiltinPreset("basic".into())
+        );
+        assert_eq!(resolve("missing"), PresetConfigError::UnknownPreset("nothing".into()));
+        assert_eq!(resolve("nothing"), PresetConfigError::UnknownPreset("nothing".into()));
+        assert_eq!(
+            resolve("unknown"),
+            PresetConfigError::UnknownTests {
+                preset: "unknown".into(),
+                tests: vec!["8.8.8".into(), "9.9.9".into()]
+            }
+        );
+        assert_eq!(
+            resolve("not-implemented"),
+            PresetConfigError::NotImplemented {
+                preset: "not-implemented".into(),
+                tests: vec!["6.2.40".into()]
+            }
+        );
+        assert!(matches!(
+            PresetConfig::from_json_str(r#"{"presets": {"a": {"test": []}}}"#),
+            Err(PresetConfigError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_suppression_matches() {
+        let suppression = Suppression {
+            test: Some("6.1.1".to_string()),
+            instance_path: "/vulnerabilities/1".to_string(),
+            reason: None,
+        };
+        assert!(suppression.matches("6.1.1", "/vulnerabilities/1"));
+        assert!(suppression.matches("6.1.1", "/vulnerabilities/1/cwe"));
+        assert!(!suppression.matches("6.1.1", "/vulnerabilities/10"));
+        assert!(!suppression.matches("6.1.2", "/vulnerabilities/1"));
+    }
+
+    #[test]
+    fn test_apply() {
+        let document = csaf_2_1_template(|json| {
+            json["document"]["tracking"]["revision_history"][0]["number"] = "2".into();
+            json["document"]["tracking"]["version"] = "1".into();
+        });
+        let result = validate_by_tests(&document, "2.1", &["6.1.16"]);
+        assert_eq!(result.num_errors, 1);
+
+        let escalated = ResolvedPreset {
+            tests: vec!["6.1.16"],
+            severity: BTreeMap::from([("6.1.16".to_string(), Severity::Warning)]),
+            suppress: vec![],
+        };
+        let result = escalated.validate(&document, "2.1");
+        assert!(result.success);
+        assert_eq!((result.num_errors, result.num_warnings), (0, 1));
+
+        let suppressed = ResolvedPreset {
+            suppress: vec![Suppression {
+                test: None,
+                instance_path: "/".to_string(),
+                reason: None,
+            }],
+            ..escalated
+        };
+        let result = suppressed.validate(&document, "2.1");
+        assert_eq!((result.num_errors, result.num_warnings), (0, 0));
+        assert_eq!(result.test_results[0].status, TestResultStatus::Passed(Passed::Success));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str() {
+        let config = PresetConfig::from_toml_str(
+            r#"
+            [presets.psirt]
+            extends = ["basic"]
+            severity = { "6.2.11" = "error" }
+
+            [[presets.psirt.suppress]]
+            instance_path = "/vulnerabilities/0"
+            reason = "accepted"
+            "#,
+        )
+        .unwrap();
+        let psirt = &config.presets["psirt"];
+        assert_eq!(psirt.extends, ["basic"]);
+        assert_eq!(psirt.severity["6.2.11"], Severity::Error);
+        assert_eq!(psirt.suppress[0].reason.as_deref(), Some("accepted"));
+    }
+}
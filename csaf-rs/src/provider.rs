@@ -0,0 +1,491 @@
+//! Provider metadata (`provider-metadata.json`) of CSAF publishers, providers and trusted providers.
+//!
+//! [`load_provider_metadata`] loads the metadata of either CSAF version from any [`JsonSource`],
+//! choosing the types by its `metadata_version`. [`ProviderMetadata::validate`] checks the
+//! distribution requirements which can be verified from the file alone, without accessing the
+//! listed URLs:
+//!
+//! * the `canonical_url` uses HTTPS and ends with `/provider-metadata.json`
+//! * `last_updated` is a valid date-time
+//! * providers and trusted providers list at least one distribution, each with a directory URL
+//!   or ROLIE feeds
+//! * directory URLs use HTTPS and end with `/`, ROLIE feeds, categories and services use HTTPS,
+//!   and no feed URL or TLP label is listed twice
+//! * trusted providers list public OpenPGP keys, each with a fingerprint
+//! * fingerprints consist of at least 40 hexadecimal digits and are unique, key URLs use HTTPS
+//!
+//! ```
+//! use csaf::provider::{ProviderRole, load_provider_metadata};
+//!
+//! let metadata = load_provider_metadata(
+//!     r#"{
+//!         "canonical_url": "https://www.example.com/.well-known/csaf/provider-metadata.json",
+//!         "distributions": [{"directory_url": "https://www.example.com/.well-known/csaf/"}],
+//!         "last_updated": "2024-01-24T10:00:00.000Z",
+//!         "list_on_CSAF_aggregators": true,
+//!         "metadata_version": "2.1",
+//!         "mirror_on_CSAF_aggregators": true,
+//!         "publisher": {"category": "vendor", "name": "Example Company", "namespace": "https://www.example.com"},
+//!         "role": "csaf_trusted_provider"
+//!     }"#,
+//! )
+//! .unwrap();
+//! assert_eq!(metadata.role(), ProviderRole::TrustedProvider);
+//!
+//! let findings = metadata.validate();
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].instance_path, "/public_openpgp_keys");
+//! ```
+
+use crate::json::JsonSource;
+use crate::preset_config::Severity;
+use crate::schema::csaf2_0::provider_metadata as provider2_0;
+use crate::schema::csaf2_1::provider_metadata as provider2_1;
+use chrono::DateTime;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io;
+
+/// Provider metadata of a supported CSAF version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProviderMetadata {
+    Csaf2_0(provider2_0::CsafProviderMetadata),
+    Csaf2_1(provider2_1::CsafProviderMetadata),
+}
+
+/// Load provider metadata, detecting the version by its `metadata_version`.
+pub fn load_provider_metadata<T: JsonSource>(source: T) -> io::Result<ProviderMetadata> {
+    let json: serde_json::Value = source.parse()?;
+    let version = json
+        .get("metadata_version")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Could not detect the provider metadata version. Make sure the document has a 'metadata_version' field",
+            )
+        })?;
+    match version.as_str() {
+        "2.0" => Ok(ProviderMetadata::Csaf2_0(json.parse()?)),
+        "2.1" => Ok(ProviderMetadata::Csaf2_1(json.parse()?)),
+        version => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unsupported provider metadata version: {version}"),
+        )),
+    }
+}
+
+/// Evaluates the expression for the metadata of either version, whose types share field names.
+macro_rules! with_metadata {
+    ($self:expr, $metadata:ident => $expr:expr) => {
+        match $self {
+            ProviderMetadata::Csaf2_0($metadata) => $expr,
+            ProviderMetadata::Csaf2_1($metadata) => $expr,
+        }
+    };
+}
+
+/// The role of the issuing party, in ascending order of the requirements it has to fulfill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProviderRole {
+    Publisher,
+    Provider,
+    TrustedProvider,
+}
+
+impl Display for ProviderRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ProviderRole::Publisher => write!(f, "csaf_publisher"),
+            ProviderRole::Provider => write!(f, "csaf_provider"),
+            ProviderRole::TrustedProvider => write!(f, "csaf_trusted_provider"),
+        }
+    }
+}
+
+/// A distribution of the CSAF documents, version-independent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distribution<'a> {
+    pub directory_url: Option<&'a str>,
+    pub rolie: Option<Rolie<'a>>,
+}
+
+/// ROLIE distribution of the CSAF documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rolie<'a> {
+    pub categories: Vec<&'a str>,
+    pub feeds: Vec<RolieFeed<'a>>,
+    pub services: Vec<&'a str>,
+}
+
+/// A ROLIE feed of the CSAF documents with one TLP label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RolieFeed<'a> {
+    pub summary: Option<&'a str>,
+    pub tlp_label: String,
+    pub url: &'a str,
+}
+
+/// A public OpenPGP key used to sign the CSAF documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenPgpKey<'a> {
+    pub fingerprint: Option<&'a str>,
+    pub url: &'a str,
+}
+
+impl ProviderMetadata {
+    /// The `metadata_version`.
+    pub fn version(&self) -> &'static str {
+        match self {
+            ProviderMetadata::Csaf2_0(_) => "2.0",
+            ProviderMetadata::Csaf2_1(_) => "2.1",
+        }
+    }
+
+    /// The role of the issuing party.
+    pub fn role(&self) -> ProviderRole {
+        match self {
+            ProviderMetadata::Csaf2_0(metadata) => match metadata.role {
+                provider2_0::RoleOfTheIssuingParty::CsafPublisher => ProviderRole::Publisher,
+                provider2_0::RoleOfTheIssuingParty::CsafProvider => ProviderRole::Provider,
+                provider2_0::RoleOfTheIssuingParty::CsafTrustedProvider => ProviderRole::TrustedProvider,
+            },
+            ProviderMetadata::Csaf2_1(metadata) => match metadata.role {
+                provider2_1::RoleOfTheIssuingParty::CsafPublisher => ProviderRole::Publisher,
+                provider2_1::RoleOfTheIssuingParty::CsafProvider => ProviderRole::Provider,
+                provider2_1::RoleOfTheIssuingParty::CsafTrustedProvider => ProviderRole::TrustedProvider,
+            },
+        }
+    }
+
+    /// The URL of the metadata itself.
+    pub fn canonical_url(&self) -> &str {
+        with_metadata!(self, metadata => &metadata.canonical_url)
+    }
+
+    /// The date-time of the last update, as given.
+    pub fn last_updated(&self) -> &str {
+        with_metadata!(self, metadata => &metadata.last_updated)
+    }
+
+    /// The name of the publisher.
+    pub fn publisher_name(&self) -> &str {
+        with_metadata!(self, metadata => &metadata.publisher.name)
+    }
+
+    /// The public OpenPGP keys used to sign the CSAF documents.
+    pub fn public_openpgp_keys(&self) -> Vec<OpenPgpKey<'_>> {
+        with_metadata!(self, metadata => metadata
+            .public_openpgp_keys
+            .iter()
+            .map(|key| OpenPgpKey {
+                fingerprint: key.fingerprint.as_deref(),
+                url: &key.url,
+            })
+            .collect())
+    }
+
+    /// The distributions of the CSAF documents.
+    pub fn distributions(&self) -> Vec<Distribution<'_>> {
+        with_metadata!(self, metadata => metadata
+            .distributions
+            .iter()
+            .flatten()
+            .map(|distribution| Distribution {
+                directory_url: distribution.directory_url.as_deref(),
+                rolie: distribution.rolie.as_ref().map(|rolie| Rolie {
+                    categories: rolie.categories.iter().flatten().map(|url| url.as_str()).collect(),
+                    feeds: rolie
+                        .feeds
+                        .iter()
+                        .map(|feed| RolieFeed {
+                            summary: feed.summary.as_deref().map(String::as_str),
+                            tlp_label: feed.tlp_label.to_string(),
+                            url: &feed.url,
+                        })
+                        .collect(),
+                    services: rolie.services.iter().flatten().map(|url| url.as_str()).collect(),
+                }),
+            })
+            .collect())
+    }
+
+    /// Check the distribution requirements which can be verified from the metadata alone.
+    ///
+    /// Returns all findings, an empty list if the metadata fulfills the requirements of its role.
+    pub fn validate(&self) -> Vec<ProviderFinding> {
+        let mut findings = Vec::new();
+        let role = self.role();
+
+        let canonical_url = self.canonical_url();
+        check_https(canonical_url, "/canonical_url", &mut findings);
+        if !canonical_url.ends_with("/provider-metadata.json") {
+            findings.push(ProviderFinding::error(
+                format!("The canonical URL '{canonical_url}' does not end with '/provider-metadata.json'"),
+                "/canonical_url",
+            ));
+        }
+
+        if DateTime::parse_from_rfc3339(self.last_updated()).is_err() {
+            findings.push(ProviderFinding::error(
+                format!("'{}' is not a valid date-time", self.last_updated()),
+                "/last_updated",
+            ));
+        }
+
+        check_distributions(&self.distributions(), role, &mut findings);
+        check_public_openpgp_keys(&self.public_openpgp_keys(), role, &mut findings);
+
+        findings
+    }
+}
+
+/// A violated requirement of the provider metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderFinding {
+    pub severity: Severity,
+    pub message: String,
+    pub instance_path: String,
+}
+
+impl ProviderFinding {
+    fn error(message: impl Into<String>, instance_path: impl Into<String>) -> Self {
+        ProviderFinding {
+            severity: Severity::Error,
+            message: message.into(),
+            instance_path: instance_path.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>, instance_path: impl Into<String>) -> Self {
+        ProviderFinding {
+            severity: Severity::Warning,
+            message: message.into(),
+            instance_path: instance_path.into(),
+        }
+    }
+}
+
+impl Display for ProviderFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} ({})", self.message, self.instance_path)
+    }
+}
+
+/// Adds an error if the URL does not use HTTPS.
+fn check_https(url: &str, instance_path: &str, findings: &mut Vec<ProviderFinding>) {
+    if !url.starts_with("https://") {
+        findings.push(ProviderFinding::error(
+            format!("The URL '{url}' does not use HTTPS"),
+            instance_path,
+        ));
+    }
+}
+
+fn check_distributions(distributions: &[Distribution], role: ProviderRole, findings: &mut Vec<ProviderFinding>) {
+    if role >= ProviderRole::Provider && distributions.is_empty() {
+        findings.push(ProviderFinding::error(
+            format!("A {role} must list at least one distribution"),
+            "/distributions",
+        ));
+    }
+
+    let mut feed_urls = HashSet::new();
+    let mut tlp_labels = HashSet::new();
+    for (d, distribution) in distributions.iter().enumerate() {
+        let path = format!("/distributions/{d}");
+        if distribution.directory_url.is_none() && distribution.rolie.is_none() {
+            findings.push(ProviderFinding::error(
+                "The distribution has neither a directory URL nor ROLIE feeds",
+                &path,
+            ));
+        }
+
+        if let Some(directory_url) = distribution.directory_url {
+            let path = format!("{path}/directory_url");
+            check_https(directory_url, &path, findings);
+            if !directory_url.ends_with('/') {
+                findings.push(ProviderFinding::error(
+                    format!("The directory URL '{directory_url}' does not end with '/'"),
+                    path,
+                ));
+            }
+        }
+
+        let Some(rolie) = &distribution.rolie else {
+            continue;
+        };
+        for (f, feed) in rolie.feeds.iter().enumerate() {
+            let path = format!("{path}/rolie/feeds/{f}");
+            check_https(feed.url, &format!("{path}/url"), findings);
+            if !feed_urls.insert(feed.url) {
+                findings.push(ProviderFinding::error(
+                    format!("The feed URL '{}' is listed more than once", feed.url),
+                    format!("{path}/url"),
+                ));
+            }
+            if !tlp_labels.insert(feed.tlp_label.as_str()) {
+                findings.push(ProviderFinding::warning(
+                    format!("More than one feed has the TLP label {}", feed.tlp_label),
+                    format!("{path}/tlp_label"),
+                ));
+            }
+        }
+        for (kind, urls) in [("categories", &rolie.categories), ("services", &rolie.services)] {
+            for (u, url) in urls.iter().enumerate() {
+                check_https(url, &format!("{path}/rolie/{kind}/{u}"), findings);
+            }
+        }
+    }
+}
+
+fn check_public_openpgp_keys(keys: &[OpenPgpKey], role: ProviderRole, findings: &mut Vec<ProviderFinding>) {
+    if role == ProviderRole::TrustedProvider && keys.is_empty() {
+        findings.push(ProviderFinding::error(
+            format!("A {role} must list the public OpenPGP keys used for signing"),
+            "/public_openpgp_keys",
+        ));
+    }
+
+    let mut fingerprints = HashSet::new();
+    for (k, key) in keys.iter().enumerate() {
+        let path = format!("/public_openpgp_keys/{k}");
+        check_https(key.url, &format!("{path}/url"), findings);
+        let Some(fingerprint) = key.fingerprint else {
+            if role == ProviderRole::TrustedProvider {
+                findings.push(ProviderFinding::error(
+                    format!("A {role} must give the fingerprint of each public OpenPGP key"),
+                    path,
+                ));
+            }
+            continue;
+        };
+        let path = format!("{path}/fingerprint");
+        if fingerprint.len() < 40 || !fingerprint.chars().all(|c| c.is_ascii_hexdigit()) {
+            findings.push(ProviderFinding::error(
+                format!("The fingerprint '{fingerprint}' does not consist of at least 40 hexadecimal digits"),
+                path,
+            ));
+        } else if !fingerprints.insert(fingerprint.to_ascii_uppercase()) {
+            findings.push(ProviderFinding::warning(
+                format!("The fingerprint '{fingerprint}' is listed more than once"),
+                path,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    const FINGERPRINT: &str = "8F5F267907B2C4559DB360DB2294BA7D2B2298B1";
+
+    fn trusted_provider(version: &str) -> Value {
+        json!({
+            "canonical_url": "https://www.example.com/.well-known/csaf/provider-metadata.json",
+            "distributions": [
+                {"directory_url": "https://www.example.com/.well-known/csaf/"},
+                {"rolie": {"feeds": [
+                    {"tlp_label": "GREEN", "url": "https://www.example.com/.well-known/csaf/feed-tlp-green.json"},
+                ]}},
+            ],
+            "last_updated": "2024-01-24T10:00:00.000Z",
+            "list_on_CSAF_aggregators": true,
+            "metadata_version": version,
+            "mirror_on_CSAF_aggregators": true,
+            "public_openpgp_keys": [
+                {"fingerprint": FINGERPRINT, "url": "https://www.example.com/.well-known/csaf/openpgp/key.asc"},
+            ],
+            "publisher": {"category": "vendor", "name": "Example Company", "namespace": "https://www.example.com"},
+            "role": "csaf_trusted_provider",
+        })
+    }
+
+    fn instance_paths(findings: &[ProviderFinding]) -> Vec<&str> {
+        findings.iter().map(|f| f.instance_path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_load_both_versions() {
+        for version in ["2.0", "2.1"] {
+            let metadata = load_provider_metadata(trusted_provider(version)).unwrap();
+            assert_eq!(metadata.version(), version);
+            assert_eq!(metadata.role(), ProviderRole::TrustedProvider);
+            assert_eq!(metadata.publisher_name(), "Example Company");
+            assert_eq!(metadata.distributions().len(), 2);
+            assert_eq!(metadata.public_openpgp_keys()[0].fingerprint, Some(FINGERPRINT));
+            assert_eq!(metadata.validate(), vec![], "{version}");
+        }
+
+        let mut json = trusted_provider("2.2");
+        assert!(load_provider_metadata(json.clone()).is_err());
+        json["metadata_version"] = json!("2.0");
+        json["distributions"][1]["rolie"]["feeds"][0]["tlp_label"] = json!("CLEAR");
+        assert!(load_provider_metadata(json).is_err());
+    }
+
+    #[test]
+    fn test_urls() {
+        let mut json = trusted_provider("2.1");
+        json["canonical_url"] = json!("http://www.example.com/csaf/metadata.json");
+        json["distributions"][0]["directory_url"] = json!("https://www.example.com/.well-known/csaf");
+        json["public_openpgp_keys"][0]["url"] = json!("http://www.example.com/key.asc");
+        let findings = load_provider_metadata(json).unwrap().validate();
+        assert_eq!(
+            instance_paths(&findings),
+            [
+                "/canonical_url",
+                "/canonical_url",
+                "/distributions/0/directory_url",
+                "/public_openpgp_keys/0/url",
+            ]
+        );
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+    }
+
+    #[test]
+    fn test_role_requirements() {
+        let mut json = trusted_provider("2.1");
+        json["distributions"] = json!([{}]);
+        json["public_openpgp_keys"] = json!([]);
+        let findings = load_provider_metadata(json.clone()).unwrap().validate();
+        assert_eq!(instance_paths(&findings), ["/distributions/0", "/public_openpgp_keys"]);
+
+        json["distributions"] = json!([]);
+        json["public_openpgp_keys"] = json!([{"url": "https://www.example.com/key.asc"}]);
+        let findings = load_provider_metadata(json.clone()).unwrap().validate();
+        assert_eq!(instance_paths(&findings), ["/distributions", "/public_openpgp_keys/0"]);
+
+        json["role"] = json!("csaf_publisher");
+        assert_eq!(load_provider_metadata(json).unwrap().validate(), vec![]);
+    }
+
+    #[test]
+    fn test_fingerprints_and_feeds() {
+        let mut json = trusted_provider("2.0");
+        json["public_openpgp_keys"] = json!([
+            {"fingerprint": FINGERPRINT, "url": "https://www.example.com/key.asc"},
+            {"fingerprint": FINGERPRINT.to_lowercase(), "url": "https://www.example.com/key2.asc"},
+            {"fingerprint": "2294BA7D2B2298B1", "url": "https://www.example.com/key3.asc"},
+        ]);
+        let feed = json!({"tlp_label": "GREEN", "url": "https://www.example.com/.well-known/csaf/feed-tlp-green.json"});
+        json["distributions"][1]["rolie"]["feeds"] = json!([feed, feed]);
+        let findings = load_provider_metadata(json).unwrap().validate();
+        let severities: Vec<_> = findings
+            .iter()
+            .map(|f| (f.instance_path.as_str(), f.severity))
+            .collect();
+        assert_eq!(
+            severities,
+            [
+                ("/distributions/1/rolie/feeds/1/url", Severity::Error),
+                ("/distributions/1/rolie/feeds/1/tlp_label", Severity::Warning),
+                ("/public_openpgp_keys/1/fingerprint", Severity::Warning),
+                ("/public_openpgp_keys/2/fingerprint", Severity::Error),
+            ]
+        );
+    }
+}
@@ -0,0 +1,715 @@
+//! Offline check of a local copy of a provider's directory tree.
+//!
+//! [`check_provider_directory`] takes the directory which is served as `/.well-known/csaf/` and
+//! checks the distribution requirements of section 7.1 which can be verified on disk. Each
+//! folder named after a TLP label (e.g. `white` or `clear`) is checked as a directory-based
+//! distribution, with the CSAF documents in `<tlp>/<year>/<canonical-filename>.json`:
+//!
+//! * 7.1.1: each document can be loaded as a CSAF document (use the validator for the tests)
+//! * 7.1.2: its file name is the canonical filename derived from `/document/tracking/id`
+//! * 7.1.7: `provider-metadata.json` exists and fulfills the requirements checked by
+//!   [`ProviderMetadata::validate`](crate::provider::ProviderMetadata::validate), and the directory URLs below it exist
+//! * 7.1.11: the year folder is the year of the `initial_release_date`, and the TLP folder is
+//!   named after the TLP label of the document
+//! * 7.1.12: `index.txt` lists exactly the documents of the distribution
+//! * 7.1.13: `changes.csv` lists exactly the documents with their `current_release_date`,
+//!   most recent first
+//! * 7.1.18: each document has a `.sha256` and/or `.sha512` file, which matches the document
+//! * 7.1.19: each document has an `.asc` signature (which is not verified)
+//!
+//! JSON files directly inside a TLP folder (e.g. ROLIE feeds) and other folders are not checked.
+//!
+//! ```no_run
+//! use csaf::provider_directory::check_provider_directory;
+//! use std::path::Path;
+//!
+//! let report = check_provider_directory(Path::new("/var/www/.well-known/csaf")).unwrap();
+//! for requirement in &report.requirements {
+//!     let state = if requirement.passed() { "passed" } else { "failed" };
+//!     println!("{} {state}", requirement.requirement);
+//!     for finding in &requirement.findings {
+//!         println!("  {finding}");
+//!     }
+//! }
+//! ```
+
+use crate::csaf::loader::detect_version_with;
+use crate::csaf::raw::HasParsed;
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, TlpTrait, TrackingTrait};
+use crate::csaf2_0::loader::load_document as load_document_2_0;
+use crate::csaf2_1::loader::load_document as load_document_2_1;
+use crate::hash_sidecar::verify_sidecars;
+use crate::preset_config::Severity;
+use crate::provider::load_provider_metadata;
+use crate::schema::csaf2_1::schema::LabelOfTlp;
+use chrono::{DateTime, Datelike, FixedOffset};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A distribution requirement of section 7.1 which can be checked offline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DistributionRequirement {
+    ValidCsafDocument,
+    Filename,
+    ProviderMetadata,
+    OneFolderPerYear,
+    IndexTxt,
+    ChangesCsv,
+    Integrity,
+    Signatures,
+}
+
+impl DistributionRequirement {
+    /// All requirements, in the order of the standard.
+    pub const ALL: [DistributionRequirement; 8] = [
+        DistributionRequirement::ValidCsafDocument,
+        DistributionRequirement::Filename,
+        DistributionRequirement::ProviderMetadata,
+        DistributionRequirement::OneFolderPerYear,
+        DistributionRequirement::IndexTxt,
+        DistributionRequirement::ChangesCsv,
+        DistributionRequirement::Integrity,
+        DistributionRequirement::Signatures,
+    ];
+
+    /// The section of the requirement, e.g. `7.1.12`.
+    pub fn section(&self) -> &'static str {
+        match self {
+            DistributionRequirement::ValidCsafDocument => "7.1.1",
+            DistributionRequirement::Filename => "7.1.2",
+            DistributionRequirement::ProviderMetadata => "7.1.7",
+            DistributionRequirement::OneFolderPerYear => "7.1.11",
+            DistributionRequirement::IndexTxt => "7.1.12",
+            DistributionRequirement::ChangesCsv => "7.1.13",
+            DistributionRequirement::Integrity => "7.1.18",
+            DistributionRequirement::Signatures => "7.1.19",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            DistributionRequirement::ValidCsafDocument => "Valid CSAF document",
+            DistributionRequirement::Filename => "Filename",
+            DistributionRequirement::ProviderMetadata => "provider-metadata.json",
+            DistributionRequirement::OneFolderPerYear => "One folder per year",
+            DistributionRequirement::IndexTxt => "index.txt",
+            DistributionRequirement::ChangesCsv => "changes.csv",
+            DistributionRequirement::Integrity => "Integrity",
+            DistributionRequirement::Signatures => "Signatures",
+        }
+    }
+}
+
+/// Serialized with its section and title.
+impl Serialize for DistributionRequirement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut requirement = serializer.serialize_struct("DistributionRequirement", 2)?;
+        requirement.serialize_field("section", self.section())?;
+        requirement.serialize_field("title", self.title())?;
+        requirement.end()
+    }
+}
+
+impl Display for DistributionRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {}", self.section(), self.title())
+    }
+}
+
+/// A violation of a requirement by a file, given relative to the checked directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DirectoryFinding {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl Display for DirectoryFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// The findings of a single requirement, which passed if there are none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequirementResult {
+    pub requirement: DistributionRequirement,
+    pub findings: Vec<DirectoryFinding>,
+}
+
+impl RequirementResult {
+    pub fn passed(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// Result of checking a provider directory, with a result for each of
+/// [all requirements](DistributionRequirement::ALL).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProviderDirectoryReport {
+    /// Number of CSAF documents found in the year folders
+    pub documents: usize,
+    pub requirements: Vec<RequirementResult>,
+}
+
+impl ProviderDirectoryReport {
+    /// Whether all requirements passed.
+    pub fn passed(&self) -> bool {
+        self.requirements.iter().all(RequirementResult::passed)
+    }
+
+    /// The result of the given requirement.
+    pub fn requirement(&self, requirement: DistributionRequirement) -> &RequirementResult {
+        self.requirements
+            .iter()
+            .find(|result| result.requirement == requirement)
+            .expect("the report contains all requirements")
+    }
+}
+
+/// Folder names of directory-based distributions, by TLP label.
+const TLP_FOLDERS: [&str; 7] = ["white", "clear", "green", "amber", "amber+strict", "red", "unlabeled"];
+
+/// Check the provider directory tree below `root` against the distribution requirements.
+///
+/// Fails only if `root` or one of its TLP folders cannot be read, everything else is reported
+/// as findings.
+pub fn check_provider_directory(root: &Path) -> io::Result<ProviderDirectoryReport> {
+    let mut checker = Checker {
+        root,
+        findings: BTreeMap::new(),
+        documents: 0,
+    };
+    checker.check_provider_metadata();
+
+    let mut tlp_folders = Vec::new();
+    for entry in sorted_entries(root)? {
+        let name = entry.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+        if entry.is_dir() && TLP_FOLDERS.contains(&name.as_str()) {
+            tlp_folders.push(entry);
+        }
+    }
+    if tlp_folders.is_empty() {
+        checker.fail(
+            DistributionRequirement::OneFolderPerYear,
+            root,
+            format!("No TLP folder ({}) found", TLP_FOLDERS.join(", ")),
+        );
+    }
+    for folder in &tlp_folders {
+        checker.check_distribution(folder)?;
+    }
+
+    let Checker {
+        mut findings,
+        documents,
+        ..
+    } = checker;
+    Ok(ProviderDirectoryReport {
+        documents,
+        requirements: DistributionRequirement::ALL
+            .into_iter()
+            .map(|requirement| RequirementResult {
+                requirement,
+                findings: findings.remove(&requirement).unwrap_or_default(),
+            })
+            .collect(),
+    })
+}
+
+/// The entries of a directory, sorted by path.
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// The information of a CSAF document needed for the checks.
+struct DocumentInfo {
+    canonical_filename: String,
+    initial_release_date: CsafDateTime,
+    current_release_date: CsafDateTime,
+    tlp_label: Option<LabelOfTlp>,
+}
+
+impl DocumentInfo {
+    fn of<Doc: CsafTrait>(doc: &Doc) -> Self {
+        let document = doc.get_document();
+        let tracking = document.get_tracking();
+        DocumentInfo {
+            canonical_filename: tracking.get_canonical_filename(),
+            initial_release_date: tracking.get_initial_release_date(),
+            current_release_date: tracking.get_current_release_date(),
+            tlp_label: document
+                .get_distribution_20()
+                .and_then(|distribution| distribution.get_tlp_20())
+                .map(|tlp| tlp.get_label()),
+        }
+    }
+
+    /// Loads a CSAF document of any supported version.
+    fn load(path: &Path) -> Result<Self, String> {
+        let loaded = detect_version_with(path).map_err(|e| e.to_string())?;
+        match loaded.version.as_str() {
+            "2.0" => {
+                let document = load_document_2_0(loaded.data).map_err(|e| e.to_string())?;
+                Ok(Self::of(document.get_parsed().as_ref()?))
+            },
+            "2.1" => {
+                let document = load_document_2_1(loaded.data).map_err(|e| e.to_string())?;
+                Ok(Self::of(document.get_parsed().as_ref()?))
+            },
+            version => Err(format!("Unsupported CSAF version: {version}")),
+        }
+    }
+}
+
+struct Checker<'a> {
+    root: &'a Path,
+    findings: BTreeMap<DistributionRequirement, Vec<DirectoryFinding>>,
+    documents: usize,
+}
+
+impl Checker<'_> {
+    fn fail(&mut self, requirement: DistributionRequirement, path: &Path, message: impl Into<String>) {
+        let path = path.strip_prefix(self.root).unwrap_or(path).to_path_buf();
+        self.findings.entry(requirement).or_default().push(DirectoryFinding {
+            path,
+            message: message.into(),
+        });
+    }
+
+    fn check_provider_metadata(&mut self) {
+        let requirement = DistributionRequirement::ProviderMetadata;
+        let path = self.root.join("provider-metadata.json");
+        if !path.is_file() {
+            self.fail(requirement, &path, "The provider metadata is missing");
+            return;
+        }
+        let metadata = match load_provider_metadata(path.as_path()) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                self.fail(
+                    requirement,
+                    &path,
+                    format!("Could not load the provider metadata: {err}"),
+                );
+                return;
+            },
+        };
+        for finding in metadata.validate() {
+            if finding.severity == Severity::Error {
+                self.fail(requirement, &path, finding.to_string());
+            }
+        }
+
+        // Directory URLs below the provider metadata have to exist in this tree
+        let Some(base_url) = metadata.canonical_url().strip_suffix("provider-metadata.json") else {
+            return;
+        };
+        for distribution in metadata.distributions() {
+            if let Some(folder) = distribution
+                .directory_url
+                .and_then(|url| url.strip_prefix(base_url))
+                .filter(|folder| !self.root.join(folder).is_dir())
+            {
+                self.fail(
+                    requirement,
+                    &path,
+                    format!("The folder '{folder}' of the directory URL does not exist"),
+                );
+            }
+        }
+    }
+
+    /// Check a directory-based distribution, i.e. a TLP folder.
+    fn check_distribution(&mut self, folder: &Path) -> io::Result<()> {
+        // Documents by their path relative to the TLP folder, e.g. `2024/esa-2024-001.json`
+        let mut documents = BTreeMap::new();
+        for year_folder in sorted_entries(folder)? {
+            let year = year_folder
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            if !year_folder.is_dir() || year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+            for path in sorted_entries(&year_folder)? {
+                if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                    let info = self.check_document(folder, &path, &year);
+                    documents.insert(format!("{year}/{name}"), info);
+                }
+            }
+        }
+        self.documents += documents.len();
+
+        self.check_index_txt(folder, &documents);
+        self.check_changes_csv(folder, &documents);
+        Ok(())
+    }
+
+    /// Check a single document and its companion files, returning its information if it loads.
+    fn check_document(&mut self, folder: &Path, path: &Path, year: &str) -> Option<DocumentInfo> {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        self.check_hashes(path);
+        if !path.with_file_name(format!("{name}.asc")).is_file() {
+            self.fail(
+                DistributionRequirement::Signatures,
+                path,
+                "The signature file (.asc) is missing",
+            );
+        }
+
+        let info = match DocumentInfo::load(path) {
+            Ok(info) => info,
+            Err(err) => {
+                self.fail(
+                    DistributionRequirement::ValidCsafDocument,
+                    path,
+                    format!("Could not load the CSAF document: {err}"),
+                );
+                return None;
+            },
+        };
+
+        if name != info.canonical_filename {
+            self.fail(
+                DistributionRequirement::Filename,
+                path,
+                format!(
+                    "The file name is not the canonical filename '{}'",
+                    info.canonical_filename
+                ),
+            );
+        }
+
+        match &info.initial_release_date {
+            CsafDateTime::Valid(date) if date.get_as_fixed_offset().year().to_string() == year => {},
+            CsafDateTime::Valid(date) => self.fail(
+                DistributionRequirement::OneFolderPerYear,
+                path,
+                format!(
+                    "The document is in the folder '{year}', but was initially released in {}",
+                    date.get_as_fixed_offset().year()
+                ),
+            ),
+            CsafDateTime::Invalid(_) => self.fail(
+                DistributionRequirement::OneFolderPerYear,
+                path,
+                format!(
+                    "The initial release date '{}' is not a valid date-time",
+                    info.initial_release_date.get_raw_string()
+                ),
+            ),
+        }
+
+        if let Some(label) = info.tlp_label {
+            let tlp_folder = folder.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+            let expected: &[&str] = match label {
+                LabelOfTlp::Clear => &["clear", "white"],
+                LabelOfTlp::Green => &["green"],
+                LabelOfTlp::Amber => &["amber"],
+                LabelOfTlp::AmberStrict => &["amber+strict"],
+                LabelOfTlp::Red => &["red"],
+            };
+            if !expected.contains(&tlp_folder.as_str()) {
+                self.fail(
+                    DistributionRequirement::OneFolderPerYear,
+                    path,
+                    format!("The document is in the TLP folder '{tlp_folder}', but has the TLP label {label}"),
+                );
+            }
+        }
+
+        Some(info)
+    }
+
+    /// Check the `.sha256` and `.sha512` files of a document, of which at least one has to exist.
+    fn check_hashes(&mut self, path: &Path) {
+        let results = verify_sidecars(path);
+        if results.is_empty() {
+            self.fail(
+                DistributionRequirement::Integrity,
+                path,
+                "No hash file (.sha256 or .sha512) exists",
+            );
+        }
+        for (_, result) in results {
+            if let Err(err) = result {
+                self.fail(
+                    DistributionRequirement::Integrity,
+                    err.path().unwrap_or(path),
+                    err.to_string(),
+                );
+            }
+        }
+    }
+
+    fn check_index_txt(&mut self, folder: &Path, documents: &BTreeMap<String, Option<DocumentInfo>>) {
+        let requirement = DistributionRequirement::IndexTxt;
+        let path = folder.join("index.txt");
+        let Ok(content) = fs::read_to_string(&path) else {
+            self.fail(requirement, &path, "index.txt is missing");
+            return;
+        };
+
+        let mut listed = BTreeSet::new();
+        for entry in content.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if !listed.insert(entry) {
+                self.fail(requirement, &path, format!("'{entry}' is listed more than once"));
+            } else if !documents.contains_key(entry) {
+                self.fail(requirement, &path, format!("'{entry}' is listed, but does not exist"));
+            }
+        }
+        for document in documents.keys().filter(|document| !listed.contains(document.as_str())) {
+            self.fail(requirement, &path, format!("'{document}' is not listed"));
+        }
+    }
+
+    fn check_changes_csv(&mut self, folder: &Path, documents: &BTreeMap<String, Option<DocumentInfo>>) {
+        let requirement = DistributionRequirement::ChangesCsv;
+        let path = folder.join("changes.csv");
+        let Ok(content) = fs::read_to_string(&path) else {
+            self.fail(requirement, &path, "changes.csv is missing");
+            return;
+        };
+
+        let mut listed = BTreeSet::new();
+        let mut previous: Option<DateTime<FixedOffset>> = None;
+        for (line_number, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let line_number = line_number + 1;
+            let fields: Vec<_> = line.split(',').map(|field| field.trim().trim_matches('"')).collect();
+            let [entry, date] = fields[..] else {
+                self.fail(
+                    requirement,
+                    &path,
+                    format!("Line {line_number} does not consist of a file name and a date-time"),
+                );
+                continue;
+            };
+            let Ok(date) = DateTime::parse_from_rfc3339(date) else {
+                self.fail(
+                    requirement,
+                    &path,
+                    format!("Line {line_number}: '{date}' is not a valid date-time"),
+                );
+                continue;
+            };
+            if previous.is_some_and(|previous| date > previous) {
+                self.fail(
+                    requirement,
+                    &path,
+                    format!(
+                        "Line {line_number} is more recent than the line before, the most recent change has to be first"
+                    ),
+                );
+            }
+            previous = Some(date);
+
+            if !listed.insert(entry) {
+                self.fail(requirement, &path, format!("'{entry}' is listed more than once"));
+                continue;
+            }
+            match documents.get(entry) {
+                None => self.fail(requirement, &path, format!("'{entry}' is listed, but does not exist")),
+                Some(Some(info)) => match &info.current_release_date {
+                    CsafDateTime::Valid(current) if *current.get_as_fixed_offset() != date => self.fail(
+                        requirement,
+                        &path,
+                        format!(
+                            "'{entry}' is listed with {}, but its current release date is {}",
+                            date.to_rfc3339(),
+                            current.get_raw_string()
+                        ),
+                    ),
+                    _ => {},
+                },
+                Some(None) => {},
+            }
+        }
+        for document in documents.keys().filter(|document| !listed.contains(document.as_str())) {
+            self.fail(requirement, &path, format!("'{document}' is not listed"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::types::csaf_hash_algo::CsafHashAlgorithm;
+    use crate::hash_sidecar::{compute_hash, sidecar_content};
+    use serde_json::{Value, json};
+
+    const NAME: &str = "csaf-rs_csaf-csaf_2_1-template.json";
+
+    fn template() -> Value {
+        let template = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../type-generator/assets/tests/csaf_2.1/csaf-rs_csaf-csaf_2_1-TEMPLATE.json"
+        );
+        serde_json::from_str(&fs::read_to_string(template).unwrap()).unwrap()
+    }
+
+    /// Write a provider tree with the template document, which fulfills all requirements.
+    fn provider_tree() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let provider_metadata = json!({
+            "canonical_url": "https://www.example.com/.well-known/csaf/provider-metadata.json",
+            "distributions": [{"directory_url": "https://www.example.com/.well-known/csaf/clear/"}],
+            "last_updated": "2024-01-24T10:00:00.000Z",
+            "list_on_CSAF_aggregators": true,
+            "metadata_version": "2.1",
+            "mirror_on_CSAF_aggregators": true,
+            "publisher": {"category": "vendor", "name": "Example Company", "namespace": "https://www.example.com"},
+            "role": "csaf_provider",
+        });
+        fs::write(
+            root.path().join("provider-metadata.json"),
+            provider_metadata.to_string(),
+        )
+        .unwrap();
+
+        let year_folder = root.path().join("clear/2024");
+        fs::create_dir_all(&year_folder).unwrap();
+        write_document(&year_folder.join(NAME), &template());
+        fs::write(root.path().join("clear/index.txt"), format!("2024/{NAME}\n")).unwrap();
+        fs::write(
+            root.path().join("clear/changes.csv"),
+            format!("\"2024/{NAME}\",\"2024-01-24T10:00:00.000Z\"\n"),
+        )
+        .unwrap();
+        root
+    }
+
+    /// Write a document with its hash and signature files.
+    fn write_document(path: &Path, document: &Value) {
+        let content = serde_json::to_vec_pretty(document).unwrap();
+        let name = path.file_name().unwrap().to_string_lossy();
+        fs::write(path, &content).unwrap();
+        fs::write(
+            path.with_file_name(format!("{name}.sha256")),
+            sidecar_content(&compute_hash(&CsafHashAlgorithm::Sha256, &content).unwrap(), &name),
+        )
+        .unwrap();
+        fs::write(path.with_file_name(format!("{name}.asc")), "signature").unwrap();
+    }
+
+    fn failed(report: &ProviderDirectoryReport) -> Vec<(&'static str, String)> {
+        report
+            .requirements
+            .iter()
+            .flat_map(|result| {
+                result
+                    .findings
+                    .iter()
+                    .map(|finding| (result.requirement.section(), finding.path.display().to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_tree() {
+        let root = provider_tree();
+        let report = check_provider_directory(root.path()).unwrap();
+        assert_eq!(failed(&report), vec![]);
+        assert!(report.passed());
+        assert_eq!(report.documents, 1);
+        assert_eq!(report.requirements.len(), DistributionRequirement::ALL.len());
+    }
+
+    #[test]
+    fn test_layout() {
+        let root = provider_tree();
+        let document = root.path().join("clear/2024").join(NAME);
+        fs::remove_file(&document).unwrap();
+        let mut json = template();
+        json["document"]["tracking"]["initial_release_date"] = json!("2023-12-24T10:00:00.000Z");
+        json["document"]["distribution"]["tlp"]["label"] = json!("GREEN");
+        write_document(&root.path().join("clear/2024/other.json"), &json);
+        fs::write(root.path().join("clear/2024/other.json.sha256"), "0000  wrong.json\n").unwrap();
+        fs::remove_file(root.path().join("clear/2024/other.json.asc")).unwrap();
+
+        let report = check_provider_directory(root.path()).unwrap();
+        assert_eq!(
+            failed(&report),
+            [
+                ("7.1.2", "clear/2024/other.json".to_string()),
+                ("7.1.11", "clear/2024/other.json".to_string()),
+                ("7.1.11", "clear/2024/other.json".to_string()),
+                ("7.1.12", "clear/index.txt".to_string()),
+                ("7.1.12", "clear/index.txt".to_string()),
+                ("7.1.13", "clear/changes.csv".to_string()),
+                ("7.1.13", "clear/changes.csv".to_string()),
+                ("7.1.18", "clear/2024/other.json.sha256".to_string()),
+                ("7.1.19", "clear/2024/other.json".to_string()),
+            ]
+        );
+        assert!(!report.requirement(DistributionRequirement::Filename).passed());
+        assert!(report.requirement(DistributionRequirement::ProviderMetadata).passed());
+    }
+
+    #[test]
+    fn test_changes_csv_order_and_dates() {
+        let root = provider_tree();
+        let mut json = template();
+        json["document"]["tracking"]["id"] = json!("CSAF-RS_CSAF-CSAF_2_1-SECOND");
+        json["document"]["tracking"]["current_release_date"] = json!("2024-03-01T10:00:00.000Z");
+        write_document(&root.path().join("clear/2024/csaf-rs_csaf-csaf_2_1-second.json"), &json);
+        let second = "2024/csaf-rs_csaf-csaf_2_1-second.json";
+        fs::write(root.path().join("clear/index.txt"), format!("2024/{NAME}\n{second}\n")).unwrap();
+
+        // Oldest change first, and a wrong date for the second document
+        fs::write(
+            root.path().join("clear/changes.csv"),
+            format!("\"2024/{NAME}\",\"2024-01-24T10:00:00.000Z\"\n\"{second}\",\"2024-02-01T10:00:00.000Z\"\n"),
+        )
+        .unwrap();
+        let report = check_provider_directory(root.path()).unwrap();
+        let findings = &report.requirement(DistributionRequirement::ChangesCsv).findings;
+        assert_eq!(findings.len(), 2, "{findings:?}");
+        assert!(findings[0].message.starts_with("Line 2 is more recent"));
+        assert!(
+            findings[1]
+                .message
+                .contains("its current release date is 2024-03-01T10:00:00.000Z")
+        );
+
+        fs::write(
+            root.path().join("clear/changes.csv"),
+            format!("\"{second}\",\"2024-03-01T10:00:00.000Z\"\n\"2024/{NAME}\",\"2024-01-24T10:00:00.000Z\"\n"),
+        )
+        .unwrap();
+        assert!(check_provider_directory(root.path()).unwrap().passed());
+    }
+
+    #[test]
+    fn test_missing_files() {
+        let root = tempfile::tempdir().unwrap();
+        let report = check_provider_directory(root.path()).unwrap();
+        assert_eq!(
+            failed(&report),
+            [
+                ("7.1.7", "provider-metadata.json".to_string()),
+                ("7.1.11", String::new())
+            ]
+        );
+
+        let root = provider_tree();
+        fs::remove_file(root.path().join("clear/index.txt")).unwrap();
+        fs::remove_file(root.path().join("clear/changes.csv")).unwrap();
+        fs::remove_file(root.path().join("clear/2024").join(format!("{NAME}.sha256"))).unwrap();
+        fs::rename(root.path().join("clear"), root.path().join("green")).unwrap();
+        let report = check_provider_directory(root.path()).unwrap();
+        let sections: Vec<_> = failed(&report).into_iter().map(|(section, _)| section).collect();
+        assert_eq!(sections, ["7.1.7", "7.1.11", "7.1.12", "7.1.13", "7.1.18"]);
+    }
+}
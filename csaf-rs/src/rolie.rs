@@ -0,0 +1,628 @@
+//! ROLIE feeds of CSAF documents (requirements 7.1.20 to 7.1.22).
+//!
+//! A provider publishes one feed per TLP label, e.g. `clear/clear-feed.json`, which lists the
+//! documents of that label with links to them, their hash files and their signatures. A service
+//! document lists the feeds, and category documents list the terms the documents are grouped by.
+//!
+//! [`FeedBuilder`] builds the feed of one TLP label from loaded documents, [`TlpFeeds`]
+//! partitions documents of all labels into their feeds. The information of each entry is taken
+//! from the document itself, the URLs follow the layout `<tlp>/<year>/<canonical-filename>`:
+//!
+//! ```no_run
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::rolie::TlpFeeds;
+//! use std::path::Path;
+//!
+//! let mut feeds = TlpFeeds::new("https://example.com/.well-known/csaf/");
+//! let document = load_document(Path::new("clear/2024/esa-2024-001.json")).unwrap();
+//! feeds.add(&document).unwrap();
+//! for feed in feeds.build() {
+//!     println!("{}: {} entries", feed.feed.title, feed.feed.entry.len());
+//! }
+//! ```
+//!
+//! Feeds of other providers are loaded with [`load_feed`], whose
+//! [`downloads`](FeedDocument::downloads) are the documents to download:
+//!
+//! ```no_run
+//! use csaf::rolie::load_feed;
+//! use std::path::Path;
+//!
+//! let feed = load_feed(Path::new("clear-feed.json")).unwrap();
+//! for download in feed.downloads() {
+//!     println!("{} {}", download.id, download.url);
+//! }
+//! ```
+
+use crate::csaf::raw::{HasParsed, RawDocument};
+use crate::csaf::types::csaf_datetime::CsafDateTime;
+use crate::csaf::types::csaf_hash_algo::CsafHashAlgorithm;
+use crate::csaf_traits::{
+    CsafTrait, CsafVersion, DistributionTrait, DocumentTrait, NoteTrait, PublisherTrait, TlpTrait, TrackingTrait,
+};
+use crate::hash_sidecar::SIDECAR_ALGORITHMS;
+use crate::json::JsonSource;
+use crate::schema::csaf2_1::schema::{LabelOfTlp, NoteCategory};
+use crate::validations::utils::validation_schema_urls::{CSAF_2_0_SCHEMA_URL, CSAF_2_1_SCHEMA_URL};
+use chrono::{Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io;
+
+/// The scheme of the category which marks feeds of CSAF documents.
+pub const INFORMATION_TYPE_SCHEME: &str = "urn:ietf:params:rolie:category:information-type";
+
+/// A ROLIE feed document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedDocument {
+    pub feed: Feed,
+}
+
+/// A ROLIE feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Feed {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub link: Vec<Link>,
+    #[serde(default)]
+    pub category: Vec<Category>,
+    pub updated: String,
+    #[serde(default)]
+    pub entry: Vec<Entry>,
+}
+
+/// A link of a feed or entry, e.g. `self`, `hash` or `signature`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Link {
+    pub rel: String,
+    pub href: String,
+}
+
+/// A category of a feed or collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Category {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<String>,
+    pub term: String,
+}
+
+/// An entry of a feed, which describes one CSAF document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub link: Vec<Link>,
+    #[serde(default)]
+    pub published: String,
+    pub updated: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Summary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<Format>,
+}
+
+/// The summary of an entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Summary {
+    pub content: String,
+}
+
+/// The location of the CSAF document of an entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Content {
+    #[serde(rename = "type")]
+    pub content_type: String,
+    pub src: String,
+}
+
+/// The JSON schema and CSAF version of the document of an entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Format {
+    pub schema: String,
+    pub version: String,
+}
+
+/// A ROLIE service document, which lists the feeds of a provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceDocument {
+    pub service: Service,
+}
+
+/// The workspaces of a service document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Service {
+    pub workspace: Vec<Workspace>,
+}
+
+/// A workspace of a service document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub title: String,
+    #[serde(default)]
+    pub collection: Vec<Collection>,
+}
+
+/// A feed listed in a service document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Collection {
+    pub title: String,
+    pub href: String,
+    pub categories: Categories,
+}
+
+/// A list of categories.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Categories {
+    #[serde(default)]
+    pub category: Vec<Category>,
+}
+
+/// A ROLIE category document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryDocument {
+    pub categories: Categories,
+}
+
+impl ServiceDocument {
+    /// A service document with one workspace listing the feeds by the URL of their `self` link.
+    pub fn from_feeds(title: &str, feeds: &[FeedDocument]) -> Self {
+        let collection = feeds
+            .iter()
+            .map(|document| Collection {
+                title: document.feed.title.clone(),
+                href: document.feed.self_link().unwrap_or_default().to_string(),
+                categories: Categories {
+                    category: vec![csaf_category()],
+                },
+            })
+            .collect();
+        ServiceDocument {
+            service: Service {
+                workspace: vec![Workspace {
+                    title: title.to_string(),
+                    collection,
+                }],
+            },
+        }
+    }
+}
+
+impl CategoryDocument {
+    /// A category document with the given terms, sorted and without duplicates.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(terms: I) -> Self {
+        let mut terms: Vec<String> = terms.into_iter().map(Into::into).collect();
+        terms.sort();
+        terms.dedup();
+        CategoryDocument {
+            categories: Categories {
+                category: terms.into_iter().map(|term| Category { scheme: None, term }).collect(),
+            },
+        }
+    }
+}
+
+/// A CSAF document listed in a feed, with the URLs to download it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download<'a> {
+    /// The tracking ID of the document
+    pub id: &'a str,
+    /// The release date of the document's latest version
+    pub updated: &'a str,
+    /// The URL of the document
+    pub url: &'a str,
+    /// The URLs of its hash files
+    pub hash_urls: Vec<&'a str>,
+    /// The URL of its signature, if any
+    pub signature_url: Option<&'a str>,
+}
+
+impl Feed {
+    /// The URL the feed is published at.
+    pub fn self_link(&self) -> Option<&str> {
+        find_link(&self.link, "self")
+    }
+}
+
+impl FeedDocument {
+    /// The documents listed in the feed.
+    ///
+    /// The URL of a document is the `src` of the entry's content, or its `self` link. Entries
+    /// without either are skipped.
+    pub fn downloads(&self) -> Vec<Download<'_>> {
+        self.feed
+            .entry
+            .iter()
+            .filter_map(|entry| {
+                let url = match &entry.content {
+                    Some(content) => Some(content.src.as_str()),
+                    None => find_link(&entry.link, "self"),
+                }?;
+                Some(Download {
+                    id: &entry.id,
+                    updated: &entry.updated,
+                    url,
+                    hash_urls: entry
+                        .link
+                        .iter()
+                        .filter(|link| link.rel == "hash")
+                        .map(|link| link.href.as_str())
+                        .collect(),
+                    signature_url: find_link(&entry.link, "signature"),
+                })
+            })
+            .collect()
+    }
+}
+
+fn find_link<'a>(links: &'a [Link], rel: &str) -> Option<&'a str> {
+    links.iter().find(|link| link.rel == rel).map(|link| link.href.as_str())
+}
+
+fn csaf_category() -> Category {
+    Category {
+        scheme: Some(INFORMATION_TYPE_SCHEME.to_string()),
+        term: "csaf".to_string(),
+    }
+}
+
+/// Load a ROLIE feed from any JSON source.
+pub fn load_feed<T: JsonSource>(source: T) -> io::Result<FeedDocument> {
+    source.parse()
+}
+
+/// Load a ROLIE service document from any JSON source.
+pub fn load_service_document<T: JsonSource>(source: T) -> io::Result<ServiceDocument> {
+    source.parse()
+}
+
+/// Load a ROLIE category document from any JSON source.
+pub fn load_category_document<T: JsonSource>(source: T) -> io::Result<CategoryDocument> {
+    source.parse()
+}
+
+/// The name of the feed of a TLP label, e.g. `clear-feed.json` or `unlabeled-feed.json`.
+pub fn feed_file_name(tlp_label: Option<LabelOfTlp>) -> String {
+    format!("{}-feed.json", tlp_folder(tlp_label))
+}
+
+/// The folder of a TLP label, e.g. `clear`, `amber+strict` or `unlabeled`.
+fn tlp_folder(tlp_label: Option<LabelOfTlp>) -> String {
+    match tlp_label {
+        Some(label) => label.to_string().to_lowercase(),
+        None => "unlabeled".to_string(),
+    }
+}
+
+/// Error while adding a document to a feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RolieError {
+    /// The document could not be parsed
+    Document(String),
+    /// The document has another TLP label than the feed
+    TlpMismatch {
+        id: String,
+        expected: Option<LabelOfTlp>,
+        found: Option<LabelOfTlp>,
+    },
+}
+
+impl Display for RolieError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let label =
+            |label: &Option<LabelOfTlp>| label.map_or("no TLP label".to_string(), |label| format!("TLP:{label}"));
+        match self {
+            RolieError::Document(err) => write!(f, "The document could not be parsed: {err}"),
+            RolieError::TlpMismatch { id, expected, found } => write!(
+                f,
+                "The document {id} has {} instead of {} of the feed",
+                label(found),
+                label(expected)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RolieError {}
+
+/// Builds the feed of the documents with one TLP label.
+#[derive(Debug, Clone)]
+pub struct FeedBuilder {
+    tlp_label: Option<LabelOfTlp>,
+    base_url: String,
+    hash_algorithms: Vec<CsafHashAlgorithm>,
+    signatures: bool,
+    publisher_name: Option<String>,
+    entries: Vec<(CsafDateTime, Entry)>,
+}
+
+impl FeedBuilder {
+    /// A builder for the feed of the TLP label (`None` for documents without one), whose
+    /// documents are published below `base_url`, the URL of the TLP folder.
+    ///
+    /// The feed is published as `<base_url>/<tlp>-feed.json`, and its entries link to
+    /// `<base_url>/<year>/<canonical-filename>` with the year of the initial release, to the
+    /// `.sha256` and `.sha512` hash files and the `.asc` signature of the document.
+    pub fn new(tlp_label: Option<LabelOfTlp>, base_url: &str) -> Self {
+        FeedBuilder {
+            tlp_label,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            hash_algorithms: SIDECAR_ALGORITHMS.to_vec(),
+            signatures: true,
+            publisher_name: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Link the hash files of these algorithms instead of both `.sha256` and `.sha512`.
+    pub fn with_hash_algorithms(mut self, algorithms: &[CsafHashAlgorithm]) -> Self {
+        self.hash_algorithms = algorithms.to_vec();
+        self
+    }
+
+    /// Whether to link the `.asc` signatures of the documents, which is the default.
+    pub fn with_signatures(mut self, signatures: bool) -> Self {
+        self.signatures = signatures;
+        self
+    }
+
+    /// Add an entry for the document, which must have the TLP label of the feed.
+    pub fn add<T>(&mut self, document: &RawDocument<T>) -> Result<(), RolieError>
+    where
+        T: CsafTrait + serde::de::DeserializeOwned,
+    {
+        let doc = document
+            .get_parsed()
+            .as_ref()
+            .map_err(|err| RolieError::Document(err.clone()))?;
+        let document = doc.get_document();
+        let tracking = document.get_tracking();
+        let found = tlp_label(doc);
+        if found != self.tlp_label {
+            return Err(RolieError::TlpMismatch {
+                id: tracking.get_id().to_string(),
+                expected: self.tlp_label,
+                found,
+            });
+        }
+        self.publisher_name
+            .get_or_insert_with(|| document.get_publisher().get_name().to_string());
+
+        let initial_release_date = tracking.get_initial_release_date();
+        let year = match &initial_release_date {
+            CsafDateTime::Valid(date) => date.get_as_fixed_offset().year().to_string(),
+            CsafDateTime::Invalid(_) => "unknown".to_string(),
+        };
+        let url = format!("{}/{year}/{}", self.base_url, tracking.get_canonical_filename());
+
+        let mut link = vec![Link {
+            rel: "self".to_string(),
+            href: url.clone(),
+        }];
+        for algorithm in &self.hash_algorithms {
+            link.push(Link {
+                rel: "hash".to_string(),
+                href: format!("{url}.{algorithm}"),
+            });
+        }
+        if self.signatures {
+            link.push(Link {
+                rel: "signature".to_string(),
+                href: format!("{url}.asc"),
+            });
+        }
+
+        let (schema, version) = match document.get_csaf_version() {
+            CsafVersion::X20 => (CSAF_2_0_SCHEMA_URL, "2.0"),
+            CsafVersion::X21 => (CSAF_2_1_SCHEMA_URL, "2.1"),
+        };
+        let summary = document
+            .get_notes()
+            .and_then(|notes| notes.iter().find(|note| note.get_category() == NoteCategory::Summary))
+            .map(|note| Summary {
+                content: note.get_text().to_string(),
+            });
+        let current_release_date = tracking.get_current_release_date();
+        let entry = Entry {
+            id: tracking.get_id().to_string(),
+            title: document.get_title().to_string(),
+            link,
+            published: initial_release_date.get_raw_string().to_string(),
+            updated: current_release_date.get_raw_string().to_string(),
+            summary,
+            content: Some(Content {
+                content_type: "application/json".to_string(),
+                src: url,
+            }),
+            format: Some(Format {
+                schema: schema.to_string(),
+                version: version.to_string(),
+            }),
+        };
+        self.entries.push((current_release_date, entry));
+        Ok(())
+    }
+
+    /// Build the feed, with the most recently updated entries first.
+    ///
+    /// The feed was updated with its most recent entry, or now if it has none.
+    pub fn build(self) -> FeedDocument {
+        let FeedBuilder {
+            tlp_label,
+            base_url,
+            publisher_name,
+            mut entries,
+            ..
+        } = self;
+        entries.sort_by(|(a, entry_a), (b, entry_b)| b.cmp(a).then_with(|| entry_a.id.cmp(&entry_b.id)));
+        let updated = match entries.first() {
+            Some((_, entry)) => entry.updated.clone(),
+            None => Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+        };
+
+        let folder = tlp_folder(tlp_label);
+        let label = match tlp_label {
+            Some(label) => format!("TLP:{label}"),
+            None => "unlabeled".to_string(),
+        };
+        let title = match publisher_name {
+            Some(name) => format!("{name} CSAF feed ({label})"),
+            None => format!("CSAF feed ({label})"),
+        };
+        FeedDocument {
+            feed: Feed {
+                id: format!("csaf-feed-tlp-{folder}"),
+                title,
+                link: vec![Link {
+                    rel: "self".to_string(),
+                    href: format!("{base_url}/{}", feed_file_name(tlp_label)),
+                }],
+                category: vec![csaf_category()],
+                updated,
+                entry: entries.into_iter().map(|(_, entry)| entry).collect(),
+            },
+        }
+    }
+}
+
+/// The TLP label of a document, `None` for CSAF 2.0 documents without one.
+fn tlp_label<T: CsafTrait>(doc: &T) -> Option<LabelOfTlp> {
+    doc.get_document()
+        .get_distribution_20()
+        .and_then(|distribution| distribution.get_tlp_20())
+        .map(|tlp| tlp.get_label())
+}
+
+/// Partitions documents into the feeds of their TLP labels.
+#[derive(Debug, Clone)]
+pub struct TlpFeeds {
+    base_url: String,
+    feeds: BTreeMap<String, FeedBuilder>,
+}
+
+impl TlpFeeds {
+    /// The feeds of a provider whose TLP folders are below `base_url`, the URL of
+    /// `/.well-known/csaf/`. See [`FeedBuilder::new`] for the URLs of the feeds and entries.
+    pub fn new(base_url: &str) -> Self {
+        TlpFeeds {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            feeds: BTreeMap::new(),
+        }
+    }
+
+    /// Add an entry for the document to the feed of its TLP label.
+    pub fn add<T>(&mut self, document: &RawDocument<T>) -> Result<(), RolieError>
+    where
+        T: CsafTrait + serde::de::DeserializeOwned,
+    {
+        let doc = document
+            .get_parsed()
+            .as_ref()
+            .map_err(|err| RolieError::Document(err.clone()))?;
+        let label = tlp_label(doc);
+        let folder = tlp_folder(label);
+        let base_url = format!("{}/{folder}", self.base_url);
+        self.feeds
+            .entry(folder)
+            .or_insert_with(|| FeedBuilder::new(label, &base_url))
+            .add(document)
+    }
+
+    /// Build the feeds, sorted by their TLP folder.
+    pub fn build(self) -> Vec<FeedDocument> {
+        self.feeds.into_values().map(FeedBuilder::build).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validations::utils::csaf_2_1_template;
+    use std::path::{Path, PathBuf};
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/rolie");
+    const BASE_URL: &str = "https://example.com/.well-known/csaf";
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(FIXTURES).join(name)
+    }
+
+    #[test]
+    fn test_generate_feed() {
+        let template = csaf_2_1_template(|_| {});
+        let summary = csaf_2_1_template(|json| {
+            json["document"]["tracking"]["id"] = "ESA-2024-002".into();
+            json["document"]["tracking"]["current_release_date"] = "2024-02-01T10:00:00.000Z".into();
+            json["document"]["notes"] = serde_json::json!([
+                {"category": "summary", "text": "Vulnerabilities fixed in ABC 1.0.1"}
+            ]);
+        });
+        let green = csaf_2_1_template(|json| {
+            json["document"]["distribution"]["tlp"]["label"] = "GREEN".into();
+        });
+
+        let mut feeds = TlpFeeds::new(BASE_URL);
+        for document in [&template, &summary, &green] {
+            feeds.add(document).unwrap();
+        }
+        let feeds = feeds.build();
+        assert_eq!(feeds.len(), 2);
+        let expected: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(fixture("clear-feed.json")).unwrap()).unwrap();
+        assert_eq!(serde_json::to_value(&feeds[0]).unwrap(), expected);
+        assert_eq!(
+            feeds[1].feed.self_link(),
+            Some("https://example.com/.well-known/csaf/green/green-feed.json")
+        );
+
+        let mut feed = FeedBuilder::new(Some(LabelOfTlp::Clear), &format!("{BASE_URL}/clear"));
+        assert_eq!(
+            feed.add(&green),
+            Err(RolieError::TlpMismatch {
+                id: "CSAF-RS_CSAF-CSAF_2_1-TEMPLATE".to_string(),
+                expected: Some(LabelOfTlp::Clear),
+                found: Some(LabelOfTlp::Green),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_feed() {
+        let feed = load_feed(&fixture("white-feed.json")).unwrap();
+        let downloads = feed.downloads();
+        assert_eq!(downloads.len(), 2);
+        assert_eq!(downloads[0].id, "2020-ESA-001");
+        assert_eq!(
+            downloads[0].url,
+            "https://psirt.domain.tld/advisories/csaf/2020/2020-esa-001.json"
+        );
+        assert_eq!(
+            downloads[0].hash_urls,
+            ["https://psirt.domain.tld/advisories/csaf/2020/2020-esa-001.json.sha512"]
+        );
+        assert_eq!(
+            downloads[0].signature_url,
+            Some("https://psirt.domain.tld/advisories/csaf/2020/2020-esa-001.json.asc")
+        );
+        // The entry without content is downloaded from its self link
+        assert_eq!(
+            downloads[1].url,
+            "https://psirt.domain.tld/advisories/csaf/2021/2021-esa-002.json"
+        );
+        assert_eq!(downloads[1].signature_url, None);
+    }
+
+    #[test]
+    fn test_service_and_category_documents() {
+        let feed = load_feed(&fixture("white-feed.json")).unwrap();
+        let service = ServiceDocument::from_feeds("Public CSAF feed", &[feed]);
+        assert_eq!(service, load_service_document(&fixture("service.json")).unwrap());
+
+        let categories = CategoryDocument::new(["Product B", "Product A", "Product B"]);
+        assert_eq!(categories, load_category_document(&fixture("category.json")).unwrap());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod provider_metadata;
 pub mod schema;
 #[cfg(test)]
 pub mod testcases_schema;
@@ -0,0 +1,2108 @@
+/*!
+ * This file is automatically generated by type-generator.
+ * Do not edit manually!
+ * Re-generate with: cargo run --locked -p type-generator
+ */
+#![allow(clippy::all)]
+#![cfg_attr(any(), rustfmt::skip)]
+#![allow(rustdoc::all)]
+/// Error types.
+pub mod error {
+    /// Error from a `TryFrom` or `FromStr` implementation.
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(
+            &self,
+            f: &mut ::std::fmt::Formatter<'_>,
+        ) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(
+            &self,
+            f: &mut ::std::fmt::Formatter<'_>,
+        ) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+///Provides information about the category of publisher releasing the document.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Category of publisher",
+///  "description": "Provides information about the category of publisher releasing the document.",
+///  "type": "string",
+///  "enum": [
+///    "coordinator",
+///    "discoverer",
+///    "multiplier",
+///    "other",
+///    "translator",
+///    "user",
+///    "vendor"
+///  ]
+///}
+/// ```
+/// </details>
+#[derive(
+    ::serde::Deserialize,
+    ::serde::Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd
+)]
+pub enum CategoryOfPublisher {
+    #[serde(rename = "coordinator")]
+    Coordinator,
+    #[serde(rename = "discoverer")]
+    Discoverer,
+    #[serde(rename = "multiplier")]
+    Multiplier,
+    #[serde(rename = "other")]
+    Other,
+    #[serde(rename = "translator")]
+    Translator,
+    #[serde(rename = "user")]
+    User,
+    #[serde(rename = "vendor")]
+    Vendor,
+}
+impl ::std::fmt::Display for CategoryOfPublisher {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Coordinator => f.write_str("coordinator"),
+            Self::Discoverer => f.write_str("discoverer"),
+            Self::Multiplier => f.write_str("multiplier"),
+            Self::Other => f.write_str("other"),
+            Self::Translator => f.write_str("translator"),
+            Self::User => f.write_str("user"),
+            Self::Vendor => f.write_str("vendor"),
+        }
+    }
+}
+impl ::std::str::FromStr for CategoryOfPublisher {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "coordinator" => Ok(Self::Coordinator),
+            "discoverer" => Ok(Self::Discoverer),
+            "multiplier" => Ok(Self::Multiplier),
+            "other" => Ok(Self::Other),
+            "translator" => Ok(Self::Translator),
+            "user" => Ok(Self::User),
+            "vendor" => Ok(Self::Vendor),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for CategoryOfPublisher {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for CategoryOfPublisher {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for CategoryOfPublisher {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+///Information on how to contact the publisher, possibly including details such as web sites, email addresses, phone numbers, and postal mail addresses.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Contact details",
+///  "description": "Information on how to contact the publisher, possibly including details such as web sites, email addresses, phone numbers, and postal mail addresses.",
+///  "examples": [
+///    "Example Company can be reached at contact_us@example.com, or via our website at https://www.example.com/contact."
+///  ],
+///  "type": "string",
+///  "minLength": 1
+///}
+/// ```
+/// </details>
+#[derive(::serde::Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct ContactDetails(::std::string::String);
+impl ::std::ops::Deref for ContactDetails {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<ContactDetails> for ::std::string::String {
+    fn from(value: ContactDetails) -> Self {
+        value.0
+    }
+}
+impl ::std::str::FromStr for ContactDetails {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        if value.chars().count() < 1usize {
+            return Err("shorter than 1 characters".into());
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::convert::TryFrom<&str> for ContactDetails {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for ContactDetails {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for ContactDetails {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for ContactDetails {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        ::std::string::String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| {
+                <D::Error as ::serde::de::Error>::custom(e.to_string())
+            })
+    }
+}
+///Representation of metadata information of a CSAF provider as a JSON document.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "$id": "https://docs.oasis-open.org/csaf/csaf/v2.1/schema/provider.json",
+///  "title": "CSAF provider metadata",
+///  "description": "Representation of metadata information of a CSAF provider as a JSON document.",
+///  "type": "object",
+///  "required": [
+///    "canonical_url",
+///    "last_updated",
+///    "list_on_CSAF_aggregators",
+///    "metadata_version",
+///    "mirror_on_CSAF_aggregators",
+///    "publisher",
+///    "role"
+///  ],
+///  "properties": {
+///    "canonical_url": {
+///      "type": "string"
+///    },
+///    "distributions": {
+///      "title": "List of Distribution",
+///      "description": "Contains a list of used distribution mechanisms.",
+///      "type": "array",
+///      "items": {
+///        "title": "Distribution",
+///        "description": "Contains the information of a used distribution mechanism.",
+///        "type": "object",
+///        "minProperties": 1,
+///        "properties": {
+///          "directory_url": {
+///            "type": "string"
+///          },
+///          "rolie": {
+///            "title": "ROLIE",
+///            "description": "Contains all information for ROLIE distribution.",
+///            "type": "object",
+///            "required": [
+///              "feeds"
+///            ],
+///            "properties": {
+///              "categories": {
+///                "title": "List of ROLIE category document URLs",
+///                "description": "Contains a list of URLs which contain ROLIE category documents.",
+///                "type": "array",
+///                "items": {
+///                  "title": "ROLIE category document URL",
+///                  "description": "Contains a URL of a ROLIE category document.",
+///                  "$ref": "#/$defs/json_url_t"
+///                },
+///                "minItems": 1,
+///                "uniqueItems": true
+///              },
+///              "feeds": {
+///                "title": "List of ROLIE feeds",
+///                "description": "Contains a list of information about ROLIE feeds.",
+///                "type": "array",
+///                "items": {
+///                  "title": "ROLIE feed",
+///                  "description": "Contains information about the ROLIE feed.",
+///                  "type": "object",
+///                  "required": [
+///                    "tlp_label",
+///                    "url"
+///                  ],
+///                  "properties": {
+///                    "summary": {
+///                      "title": "Summary of the feed",
+///                      "description": "Contains a summary of the feed.",
+///                      "examples": [
+///                        "All TLP:CLEAR advisories of Example Company."
+///                      ],
+///                      "type": "string",
+///                      "minLength": 1
+///                    },
+///                    "tlp_label": {
+///                      "title": "TLP label",
+///                      "description": "Provides the TLP label for the feed.",
+///                      "type": "string",
+///                      "enum": [
+///                        "UNLABELED",
+///                        "CLEAR",
+///                        "GREEN",
+///                        "AMBER",
+///                        "AMBER+STRICT",
+///                        "RED"
+///                      ]
+///                    },
+///                    "url": {
+///                      "title": "URL of the feed",
+///                      "description": "Contains the URL of the feed.",
+///                      "$ref": "#/$defs/json_url_t"
+///                    }
+///                  }
+///                },
+///                "minItems": 1,
+///                "uniqueItems": true
+///              },
+///              "services": {
+///                "title": "List of ROLIE service document URLs",
+///                "description": "Contains a list of URLs which contain ROLIE service documents.",
+///                "type": "array",
+///                "items": {
+///                  "title": "ROLIE service document URL",
+///                  "description": "Contains a URL of a ROLIE service document.",
+///                  "$ref": "#/$defs/json_url_t"
+///                },
+///                "minItems": 1,
+///                "uniqueItems": true
+///              }
+///            }
+///          }
+///        }
+///      },
+///      "minItems": 1,
+///      "uniqueItems": true
+///    },
+///    "last_updated": {
+///      "title": "Last updated",
+///      "description": "Holds the date and time when the document was last updated.",
+///      "type": "string"
+///    },
+///    "list_on_CSAF_aggregators": {
+///      "title": "List on CSAF aggregators",
+///      "description": "Decides whether this file should be linked in the list of a CSAF aggregator.",
+///      "default": true,
+///      "type": "boolean"
+///    },
+///    "metadata_version": {
+///      "title": "CSAF provider metadata version",
+///      "description": "Gives the version of the CSAF provider metadata specification which the document was generated for.",
+///      "type": "string",
+///      "enum": [
+///        "2.1"
+///      ]
+///    },
+///    "mirror_on_CSAF_aggregators": {
+///      "title": "Mirror on CSAF aggregators",
+///      "description": "Decides whether the CSAF documents can be mirrored and provided by a CSAF aggregator.",
+///      "default": true,
+///      "type": "boolean"
+///    },
+///    "public_openpgp_keys": {
+///      "title": "List of public OpenPGP keys",
+///      "description": "Contains a list of OpenPGP keys used to sign CSAF documents.",
+///      "type": "array",
+///      "items": {
+///        "title": "PGP keys",
+///        "description": "Contains all information about an OpenPGP key used to sign CSAF documents.",
+///        "type": "object",
+///        "required": [
+///          "url"
+///        ],
+///        "properties": {
+///          "fingerprint": {
+///            "type": "string"
+///          },
+///          "url": {
+///            "title": "URL of the key",
+///            "description": "Contains the URL where the key can be retrieved.",
+///            "$ref": "#/$defs/url_t"
+///          }
+///        }
+///      }
+///    },
+///    "publisher": {
+///      "title": "Publisher",
+///      "description": "Provides information about the publisher of the document.",
+///      "type": "object",
+///      "required": [
+///        "category",
+///        "name",
+///        "namespace"
+///      ],
+///      "properties": {
+///        "category": {
+///          "title": "Category of publisher",
+///          "description": "Provides information about the category of publisher releasing the document.",
+///          "type": "string",
+///          "enum": [
+///            "coordinator",
+///            "discoverer",
+///            "multiplier",
+///            "other",
+///            "translator",
+///            "user",
+///            "vendor"
+///          ]
+///        },
+///        "contact_details": {
+///          "title": "Contact details",
+///          "description": "Information on how to contact the publisher, possibly including details such as web sites, email addresses, phone numbers, and postal mail addresses.",
+///          "examples": [
+///            "Example Company can be reached at contact_us@example.com, or via our website at https://www.example.com/contact."
+///          ],
+///          "type": "string",
+///          "minLength": 1
+///        },
+///        "issuing_authority": {
+///          "title": "Issuing authority",
+///          "description": "Provides information about the authority of the issuing party to release the document, in particular, the party's constituency and responsibilities or other obligations.",
+///          "type": "string",
+///          "minLength": 1
+///        },
+///        "name": {
+///          "title": "Name of publisher",
+///          "description": "Contains the name of the issuing party.",
+///          "examples": [
+///            "BSI",
+///            "Cisco PSIRT",
+///            "Siemens ProductCERT"
+///          ],
+///          "type": "string",
+///          "minLength": 1
+///        },
+///        "namespace": {
+///          "title": "Namespace of publisher",
+///          "description": "Contains a URL which is under control of the issuing party and can be used as a globally unique identifier for that issuing party.",
+///          "examples": [
+///            "https://csaf.io",
+///            "https://www.example.com"
+///          ],
+///          "type": "string"
+///        }
+///      },
+///      "additionalProperties": false
+///    },
+///    "role": {
+///      "title": "Role of the issuing party",
+///      "description": "Contains the role of the issuing party according to section 7 in the CSAF standard.",
+///      "default": "csaf_provider",
+///      "type": "string",
+///      "enum": [
+///        "csaf_publisher",
+///        "csaf_provider",
+///        "csaf_trusted_provider"
+///      ]
+///    }
+///  }
+///}
+/// ```
+/// </details>
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct CsafProviderMetadata {
+    pub canonical_url: ::std::string::String,
+    ///Contains a list of used distribution mechanisms.
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub distributions: ::std::option::Option<Vec<Distribution>>,
+    ///Holds the date and time when the document was last updated.
+    pub last_updated: ::std::string::String,
+    ///Decides whether this file should be linked in the list of a CSAF aggregator.
+    #[serde(rename = "list_on_CSAF_aggregators")]
+    pub list_on_csaf_aggregators: bool,
+    ///Gives the version of the CSAF provider metadata specification which the document was generated for.
+    pub metadata_version: CsafProviderMetadataVersion,
+    ///Decides whether the CSAF documents can be mirrored and provided by a CSAF aggregator.
+    #[serde(rename = "mirror_on_CSAF_aggregators")]
+    pub mirror_on_csaf_aggregators: bool,
+    ///Contains a list of OpenPGP keys used to sign CSAF documents.
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub public_openpgp_keys: ::std::vec::Vec<PgpKeys>,
+    pub publisher: Publisher,
+    ///Contains the role of the issuing party according to section 7 in the CSAF standard.
+    pub role: RoleOfTheIssuingParty,
+}
+impl CsafProviderMetadata {
+    pub fn builder() -> builder::CsafProviderMetadata {
+        Default::default()
+    }
+}
+///Gives the version of the CSAF provider metadata specification which the document was generated for.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "CSAF provider metadata version",
+///  "description": "Gives the version of the CSAF provider metadata specification which the document was generated for.",
+///  "type": "string",
+///  "enum": [
+///    "2.1"
+///  ]
+///}
+/// ```
+/// </details>
+#[derive(
+    ::serde::Deserialize,
+    ::serde::Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd
+)]
+pub enum CsafProviderMetadataVersion {
+    #[serde(rename = "2.1")]
+    X21,
+}
+impl ::std::fmt::Display for CsafProviderMetadataVersion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::X21 => f.write_str("2.1"),
+        }
+    }
+}
+impl ::std::str::FromStr for CsafProviderMetadataVersion {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "2.1" => Ok(Self::X21),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for CsafProviderMetadataVersion {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for CsafProviderMetadataVersion {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for CsafProviderMetadataVersion {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+///Contains the information of a used distribution mechanism.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Distribution",
+///  "description": "Contains the information of a used distribution mechanism.",
+///  "type": "object",
+///  "minProperties": 1,
+///  "properties": {
+///    "directory_url": {
+///      "type": "string"
+///    },
+///    "rolie": {
+///      "title": "ROLIE",
+///      "description": "Contains all information for ROLIE distribution.",
+///      "type": "object",
+///      "required": [
+///        "feeds"
+///      ],
+///      "properties": {
+///        "categories": {
+///          "title": "List of ROLIE category document URLs",
+///          "description": "Contains a list of URLs which contain ROLIE category documents.",
+///          "type": "array",
+///          "items": {
+///            "title": "ROLIE category document URL",
+///            "description": "Contains a URL of a ROLIE category document.",
+///            "$ref": "#/$defs/json_url_t"
+///          },
+///          "minItems": 1,
+///          "uniqueItems": true
+///        },
+///        "feeds": {
+///          "title": "List of ROLIE feeds",
+///          "description": "Contains a list of information about ROLIE feeds.",
+///          "type": "array",
+///          "items": {
+///            "title": "ROLIE feed",
+///            "description": "Contains information about the ROLIE feed.",
+///            "type": "object",
+///            "required": [
+///              "tlp_label",
+///              "url"
+///            ],
+///            "properties": {
+///              "summary": {
+///                "title": "Summary of the feed",
+///                "description": "Contains a summary of the feed.",
+///                "examples": [
+///                  "All TLP:CLEAR advisories of Example Company."
+///                ],
+///                "type": "string",
+///                "minLength": 1
+///              },
+///              "tlp_label": {
+///                "title": "TLP label",
+///                "description": "Provides the TLP label for the feed.",
+///                "type": "string",
+///                "enum": [
+///                  "UNLABELED",
+///                  "CLEAR",
+///                  "GREEN",
+///                  "AMBER",
+///                  "AMBER+STRICT",
+///                  "RED"
+///                ]
+///              },
+///              "url": {
+///                "title": "URL of the feed",
+///                "description": "Contains the URL of the feed.",
+///                "$ref": "#/$defs/json_url_t"
+///              }
+///            }
+///          },
+///          "minItems": 1,
+///          "uniqueItems": true
+///        },
+///        "services": {
+///          "title": "List of ROLIE service document URLs",
+///          "description": "Contains a list of URLs which contain ROLIE service documents.",
+///          "type": "array",
+///          "items": {
+///            "title": "ROLIE service document URL",
+///            "description": "Contains a URL of a ROLIE service document.",
+///            "$ref": "#/$defs/json_url_t"
+///          },
+///          "minItems": 1,
+///          "uniqueItems": true
+///        }
+///      }
+///    }
+///  }
+///}
+/// ```
+/// </details>
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct Distribution {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub directory_url: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub rolie: ::std::option::Option<Rolie>,
+}
+impl ::std::default::Default for Distribution {
+    fn default() -> Self {
+        Self {
+            directory_url: Default::default(),
+            rolie: Default::default(),
+        }
+    }
+}
+impl Distribution {
+    pub fn builder() -> builder::Distribution {
+        Default::default()
+    }
+}
+///Provides information about the authority of the issuing party to release the document, in particular, the party's constituency and responsibilities or other obligations.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Issuing authority",
+///  "description": "Provides information about the authority of the issuing party to release the document, in particular, the party's constituency and responsibilities or other obligations.",
+///  "type": "string",
+///  "minLength": 1
+///}
+/// ```
+/// </details>
+#[derive(::serde::Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct IssuingAuthority(::std::string::String);
+impl ::std::ops::Deref for IssuingAuthority {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<IssuingAuthority> for ::std::string::String {
+    fn from(value: IssuingAuthority) -> Self {
+        value.0
+    }
+}
+impl ::std::str::FromStr for IssuingAuthority {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        if value.chars().count() < 1usize {
+            return Err("shorter than 1 characters".into());
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::convert::TryFrom<&str> for IssuingAuthority {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for IssuingAuthority {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for IssuingAuthority {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for IssuingAuthority {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        ::std::string::String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| {
+                <D::Error as ::serde::de::Error>::custom(e.to_string())
+            })
+    }
+}
+///Contains a URL of a JSON file.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "JSON URL type",
+///  "description": "Contains a URL of a JSON file.",
+///  "type": "string",
+///  "pattern": "\\.json$"
+///}
+/// ```
+/// </details>
+#[derive(::serde::Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct JsonUrlT(::std::string::String);
+impl ::std::ops::Deref for JsonUrlT {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<JsonUrlT> for ::std::string::String {
+    fn from(value: JsonUrlT) -> Self {
+        value.0
+    }
+}
+impl ::std::str::FromStr for JsonUrlT {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        static PATTERN: ::std::sync::LazyLock<::regress::Regex> = ::std::sync::LazyLock::new(||
+        { ::regress::Regex::new("\\.json$").unwrap() });
+        if PATTERN.find(value).is_none() {
+            return Err("doesn't match pattern \"\\.json$\"".into());
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::convert::TryFrom<&str> for JsonUrlT {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for JsonUrlT {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for JsonUrlT {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for JsonUrlT {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        ::std::string::String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| {
+                <D::Error as ::serde::de::Error>::custom(e.to_string())
+            })
+    }
+}
+///Contains the name of the issuing party.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Name of publisher",
+///  "description": "Contains the name of the issuing party.",
+///  "examples": [
+///    "BSI",
+///    "Cisco PSIRT",
+///    "Siemens ProductCERT"
+///  ],
+///  "type": "string",
+///  "minLength": 1
+///}
+/// ```
+/// </details>
+#[derive(::serde::Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct NameOfPublisher(::std::string::String);
+impl ::std::ops::Deref for NameOfPublisher {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<NameOfPublisher> for ::std::string::String {
+    fn from(value: NameOfPublisher) -> Self {
+        value.0
+    }
+}
+impl ::std::str::FromStr for NameOfPublisher {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        if value.chars().count() < 1usize {
+            return Err("shorter than 1 characters".into());
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::convert::TryFrom<&str> for NameOfPublisher {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for NameOfPublisher {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for NameOfPublisher {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for NameOfPublisher {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        ::std::string::String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| {
+                <D::Error as ::serde::de::Error>::custom(e.to_string())
+            })
+    }
+}
+///Contains all information about an OpenPGP key used to sign CSAF documents.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "PGP keys",
+///  "description": "Contains all information about an OpenPGP key used to sign CSAF documents.",
+///  "type": "object",
+///  "required": [
+///    "url"
+///  ],
+///  "properties": {
+///    "fingerprint": {
+///      "type": "string"
+///    },
+///    "url": {
+///      "title": "URL of the key",
+///      "description": "Contains the URL where the key can be retrieved.",
+///      "$ref": "#/$defs/url_t"
+///    }
+///  }
+///}
+/// ```
+/// </details>
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct PgpKeys {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub fingerprint: ::std::option::Option<::std::string::String>,
+    ///Contains the URL where the key can be retrieved.
+    pub url: UrlT,
+}
+impl PgpKeys {
+    pub fn builder() -> builder::PgpKeys {
+        Default::default()
+    }
+}
+///Provides information about the publisher of the document.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Publisher",
+///  "description": "Provides information about the publisher of the document.",
+///  "type": "object",
+///  "required": [
+///    "category",
+///    "name",
+///    "namespace"
+///  ],
+///  "properties": {
+///    "category": {
+///      "title": "Category of publisher",
+///      "description": "Provides information about the category of publisher releasing the document.",
+///      "type": "string",
+///      "enum": [
+///        "coordinator",
+///        "discoverer",
+///        "multiplier",
+///        "other",
+///        "translator",
+///        "user",
+///        "vendor"
+///      ]
+///    },
+///    "contact_details": {
+///      "title": "Contact details",
+///      "description": "Information on how to contact the publisher, possibly including details such as web sites, email addresses, phone numbers, and postal mail addresses.",
+///      "examples": [
+///        "Example Company can be reached at contact_us@example.com, or via our website at https://www.example.com/contact."
+///      ],
+///      "type": "string",
+///      "minLength": 1
+///    },
+///    "issuing_authority": {
+///      "title": "Issuing authority",
+///      "description": "Provides information about the authority of the issuing party to release the document, in particular, the party's constituency and responsibilities or other obligations.",
+///      "type": "string",
+///      "minLength": 1
+///    },
+///    "name": {
+///      "title": "Name of publisher",
+///      "description": "Contains the name of the issuing party.",
+///      "examples": [
+///        "BSI",
+///        "Cisco PSIRT",
+///        "Siemens ProductCERT"
+///      ],
+///      "type": "string",
+///      "minLength": 1
+///    },
+///    "namespace": {
+///      "title": "Namespace of publisher",
+///      "description": "Contains a URL which is under control of the issuing party and can be used as a globally unique identifier for that issuing party.",
+///      "examples": [
+///        "https://csaf.io",
+///        "https://www.example.com"
+///      ],
+///      "type": "string"
+///    }
+///  },
+///  "additionalProperties": false
+///}
+/// ```
+/// </details>
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Publisher {
+    ///Provides information about the category of publisher releasing the document.
+    pub category: CategoryOfPublisher,
+    ///Information on how to contact the publisher, possibly including details such as web sites, email addresses, phone numbers, and postal mail addresses.
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub contact_details: ::std::option::Option<ContactDetails>,
+    ///Provides information about the authority of the issuing party to release the document, in particular, the party's constituency and responsibilities or other obligations.
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub issuing_authority: ::std::option::Option<IssuingAuthority>,
+    ///Contains the name of the issuing party.
+    pub name: NameOfPublisher,
+    ///Contains a URL which is under control of the issuing party and can be used as a globally unique identifier for that issuing party.
+    pub namespace: ::std::string::String,
+}
+impl Publisher {
+    pub fn builder() -> builder::Publisher {
+        Default::default()
+    }
+}
+///Contains the role of the issuing party according to section 7 in the CSAF standard.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Role of the issuing party",
+///  "description": "Contains the role of the issuing party according to section 7 in the CSAF standard.",
+///  "default": "csaf_provider",
+///  "type": "string",
+///  "enum": [
+///    "csaf_publisher",
+///    "csaf_provider",
+///    "csaf_trusted_provider"
+///  ]
+///}
+/// ```
+/// </details>
+#[derive(
+    ::serde::Deserialize,
+    ::serde::Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd
+)]
+pub enum RoleOfTheIssuingParty {
+    #[serde(rename = "csaf_publisher")]
+    CsafPublisher,
+    #[serde(rename = "csaf_provider")]
+    CsafProvider,
+    #[serde(rename = "csaf_trusted_provider")]
+    CsafTrustedProvider,
+}
+impl ::std::fmt::Display for RoleOfTheIssuingParty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::CsafPublisher => f.write_str("csaf_publisher"),
+            Self::CsafProvider => f.write_str("csaf_provider"),
+            Self::CsafTrustedProvider => f.write_str("csaf_trusted_provider"),
+        }
+    }
+}
+impl ::std::str::FromStr for RoleOfTheIssuingParty {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "csaf_publisher" => Ok(Self::CsafPublisher),
+            "csaf_provider" => Ok(Self::CsafProvider),
+            "csaf_trusted_provider" => Ok(Self::CsafTrustedProvider),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for RoleOfTheIssuingParty {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for RoleOfTheIssuingParty {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for RoleOfTheIssuingParty {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::default::Default for RoleOfTheIssuingParty {
+    fn default() -> Self {
+        RoleOfTheIssuingParty::CsafProvider
+    }
+}
+///Contains all information for ROLIE distribution.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "ROLIE",
+///  "description": "Contains all information for ROLIE distribution.",
+///  "type": "object",
+///  "required": [
+///    "feeds"
+///  ],
+///  "properties": {
+///    "categories": {
+///      "title": "List of ROLIE category document URLs",
+///      "description": "Contains a list of URLs which contain ROLIE category documents.",
+///      "type": "array",
+///      "items": {
+///        "title": "ROLIE category document URL",
+///        "description": "Contains a URL of a ROLIE category document.",
+///        "$ref": "#/$defs/json_url_t"
+///      },
+///      "minItems": 1,
+///      "uniqueItems": true
+///    },
+///    "feeds": {
+///      "title": "List of ROLIE feeds",
+///      "description": "Contains a list of information about ROLIE feeds.",
+///      "type": "array",
+///      "items": {
+///        "title": "ROLIE feed",
+///        "description": "Contains information about the ROLIE feed.",
+///        "type": "object",
+///        "required": [
+///          "tlp_label",
+///          "url"
+///        ],
+///        "properties": {
+///          "summary": {
+///            "title": "Summary of the feed",
+///            "description": "Contains a summary of the feed.",
+///            "examples": [
+///              "All TLP:CLEAR advisories of Example Company."
+///            ],
+///            "type": "string",
+///            "minLength": 1
+///          },
+///          "tlp_label": {
+///            "title": "TLP label",
+///            "description": "Provides the TLP label for the feed.",
+///            "type": "string",
+///            "enum": [
+///              "UNLABELED",
+///              "CLEAR",
+///              "GREEN",
+///              "AMBER",
+///              "AMBER+STRICT",
+///              "RED"
+///            ]
+///          },
+///          "url": {
+///            "title": "URL of the feed",
+///            "description": "Contains the URL of the feed.",
+///            "$ref": "#/$defs/json_url_t"
+///          }
+///        }
+///      },
+///      "minItems": 1,
+///      "uniqueItems": true
+///    },
+///    "services": {
+///      "title": "List of ROLIE service document URLs",
+///      "description": "Contains a list of URLs which contain ROLIE service documents.",
+///      "type": "array",
+///      "items": {
+///        "title": "ROLIE service document URL",
+///        "description": "Contains a URL of a ROLIE service document.",
+///        "$ref": "#/$defs/json_url_t"
+///      },
+///      "minItems": 1,
+///      "uniqueItems": true
+///    }
+///  }
+///}
+/// ```
+/// </details>
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct Rolie {
+    ///Contains a list of URLs which contain ROLIE category documents.
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub categories: ::std::option::Option<Vec<JsonUrlT>>,
+    ///Contains a list of information about ROLIE feeds.
+    pub feeds: Vec<RolieFeed>,
+    ///Contains a list of URLs which contain ROLIE service documents.
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub services: ::std::option::Option<Vec<JsonUrlT>>,
+}
+impl Rolie {
+    pub fn builder() -> builder::Rolie {
+        Default::default()
+    }
+}
+///Contains information about the ROLIE feed.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "ROLIE feed",
+///  "description": "Contains information about the ROLIE feed.",
+///  "type": "object",
+///  "required": [
+///    "tlp_label",
+///    "url"
+///  ],
+///  "properties": {
+///    "summary": {
+///      "title": "Summary of the feed",
+///      "description": "Contains a summary of the feed.",
+///      "examples": [
+///        "All TLP:CLEAR advisories of Example Company."
+///      ],
+///      "type": "string",
+///      "minLength": 1
+///    },
+///    "tlp_label": {
+///      "title": "TLP label",
+///      "description": "Provides the TLP label for the feed.",
+///      "type": "string",
+///      "enum": [
+///        "UNLABELED",
+///        "CLEAR",
+///        "GREEN",
+///        "AMBER",
+///        "AMBER+STRICT",
+///        "RED"
+///      ]
+///    },
+///    "url": {
+///      "title": "URL of the feed",
+///      "description": "Contains the URL of the feed.",
+///      "$ref": "#/$defs/json_url_t"
+///    }
+///  }
+///}
+/// ```
+/// </details>
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct RolieFeed {
+    ///Contains a summary of the feed.
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub summary: ::std::option::Option<SummaryOfTheFeed>,
+    ///Provides the TLP label for the feed.
+    pub tlp_label: TlpLabel,
+    ///Contains the URL of the feed.
+    pub url: JsonUrlT,
+}
+impl RolieFeed {
+    pub fn builder() -> builder::RolieFeed {
+        Default::default()
+    }
+}
+///Contains a summary of the feed.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "Summary of the feed",
+///  "description": "Contains a summary of the feed.",
+///  "examples": [
+///    "All TLP:CLEAR advisories of Example Company."
+///  ],
+///  "type": "string",
+///  "minLength": 1
+///}
+/// ```
+/// </details>
+#[derive(::serde::Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct SummaryOfTheFeed(::std::string::String);
+impl ::std::ops::Deref for SummaryOfTheFeed {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<SummaryOfTheFeed> for ::std::string::String {
+    fn from(value: SummaryOfTheFeed) -> Self {
+        value.0
+    }
+}
+impl ::std::str::FromStr for SummaryOfTheFeed {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        if value.chars().count() < 1usize {
+            return Err("shorter than 1 characters".into());
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::convert::TryFrom<&str> for SummaryOfTheFeed {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for SummaryOfTheFeed {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for SummaryOfTheFeed {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for SummaryOfTheFeed {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        ::std::string::String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| {
+                <D::Error as ::serde::de::Error>::custom(e.to_string())
+            })
+    }
+}
+///Provides the TLP label for the feed.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "TLP label",
+///  "description": "Provides the TLP label for the feed.",
+///  "type": "string",
+///  "enum": [
+///    "UNLABELED",
+///    "CLEAR",
+///    "GREEN",
+///    "AMBER",
+///    "AMBER+STRICT",
+///    "RED"
+///  ]
+///}
+/// ```
+/// </details>
+#[derive(
+    ::serde::Deserialize,
+    ::serde::Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd
+)]
+pub enum TlpLabel {
+    #[serde(rename = "UNLABELED")]
+    Unlabeled,
+    #[serde(rename = "CLEAR")]
+    Clear,
+    #[serde(rename = "GREEN")]
+    Green,
+    #[serde(rename = "AMBER")]
+    Amber,
+    #[serde(rename = "AMBER+STRICT")]
+    AmberStrict,
+    #[serde(rename = "RED")]
+    Red,
+}
+impl ::std::fmt::Display for TlpLabel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Unlabeled => f.write_str("UNLABELED"),
+            Self::Clear => f.write_str("CLEAR"),
+            Self::Green => f.write_str("GREEN"),
+            Self::Amber => f.write_str("AMBER"),
+            Self::AmberStrict => f.write_str("AMBER+STRICT"),
+            Self::Red => f.write_str("RED"),
+        }
+    }
+}
+impl ::std::str::FromStr for TlpLabel {
+    type Err = self::error::ConversionError;
+    fn from_str(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "UNLABELED" => Ok(Self::Unlabeled),
+            "CLEAR" => Ok(Self::Clear),
+            "GREEN" => Ok(Self::Green),
+            "AMBER" => Ok(Self::Amber),
+            "AMBER+STRICT" => Ok(Self::AmberStrict),
+            "RED" => Ok(Self::Red),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for TlpLabel {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &str,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for TlpLabel {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for TlpLabel {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+///Contains a URL.
+///
+/// <details><summary>JSON schema</summary>
+///
+/// ```json
+///{
+///  "title": "URL type",
+///  "description": "Contains a URL.",
+///  "type": "string"
+///}
+/// ```
+/// </details>
+#[derive(
+    ::serde::Deserialize,
+    ::serde::Serialize,
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd
+)]
+#[serde(transparent)]
+pub struct UrlT(pub ::std::string::String);
+impl ::std::ops::Deref for UrlT {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<UrlT> for ::std::string::String {
+    fn from(value: UrlT) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<::std::string::String> for UrlT {
+    fn from(value: ::std::string::String) -> Self {
+        Self(value)
+    }
+}
+impl ::std::str::FromStr for UrlT {
+    type Err = ::std::convert::Infallible;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::fmt::Display for UrlT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+/// Types for composing complex structures.
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct CsafProviderMetadata {
+        canonical_url: ::std::result::Result<
+            ::std::string::String,
+            ::std::string::String,
+        >,
+        distributions: ::std::result::Result<
+            ::std::option::Option<Vec<super::Distribution>>,
+            ::std::string::String,
+        >,
+        last_updated: ::std::result::Result<
+            ::std::string::String,
+            ::std::string::String,
+        >,
+        list_on_csaf_aggregators: ::std::result::Result<bool, ::std::string::String>,
+        metadata_version: ::std::result::Result<
+            super::CsafProviderMetadataVersion,
+            ::std::string::String,
+        >,
+        mirror_on_csaf_aggregators: ::std::result::Result<bool, ::std::string::String>,
+        public_openpgp_keys: ::std::result::Result<
+            ::std::vec::Vec<super::PgpKeys>,
+            ::std::string::String,
+        >,
+        publisher: ::std::result::Result<super::Publisher, ::std::string::String>,
+        role: ::std::result::Result<super::RoleOfTheIssuingParty, ::std::string::String>,
+    }
+    impl ::std::default::Default for CsafProviderMetadata {
+        fn default() -> Self {
+            Self {
+                canonical_url: Err("no value supplied for canonical_url".to_string()),
+                distributions: Ok(Default::default()),
+                last_updated: Err("no value supplied for last_updated".to_string()),
+                list_on_csaf_aggregators: Err(
+                    "no value supplied for list_on_csaf_aggregators".to_string(),
+                ),
+                metadata_version: Err(
+                    "no value supplied for metadata_version".to_string(),
+                ),
+                mirror_on_csaf_aggregators: Err(
+                    "no value supplied for mirror_on_csaf_aggregators".to_string(),
+                ),
+                public_openpgp_keys: Ok(Default::default()),
+                publisher: Err("no value supplied for publisher".to_string()),
+                role: Err("no value supplied for role".to_string()),
+            }
+        }
+    }
+    impl CsafProviderMetadata {
+        pub fn canonical_url<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.canonical_url = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for canonical_url: {e}")
+                });
+            self
+        }
+        pub fn distributions<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<Vec<super::Distribution>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.distributions = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for distributions: {e}")
+                });
+            self
+        }
+        pub fn last_updated<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.last_updated = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for last_updated: {e}")
+                });
+            self
+        }
+        pub fn list_on_csaf_aggregators<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<bool>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.list_on_csaf_aggregators = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for list_on_csaf_aggregators: {e}"
+                    )
+                });
+            self
+        }
+        pub fn metadata_version<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::CsafProviderMetadataVersion>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.metadata_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for metadata_version: {e}")
+                });
+            self
+        }
+        pub fn mirror_on_csaf_aggregators<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<bool>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.mirror_on_csaf_aggregators = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for mirror_on_csaf_aggregators: {e}"
+                    )
+                });
+            self
+        }
+        pub fn public_openpgp_keys<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::PgpKeys>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.public_openpgp_keys = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for public_openpgp_keys: {e}"
+                    )
+                });
+            self
+        }
+        pub fn publisher<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Publisher>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.publisher = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for publisher: {e}")
+                });
+            self
+        }
+        pub fn role<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::RoleOfTheIssuingParty>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.role = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for role: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<CsafProviderMetadata> for super::CsafProviderMetadata {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: CsafProviderMetadata,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                canonical_url: value.canonical_url?,
+                distributions: value.distributions?,
+                last_updated: value.last_updated?,
+                list_on_csaf_aggregators: value.list_on_csaf_aggregators?,
+                metadata_version: value.metadata_version?,
+                mirror_on_csaf_aggregators: value.mirror_on_csaf_aggregators?,
+                public_openpgp_keys: value.public_openpgp_keys?,
+                publisher: value.publisher?,
+                role: value.role?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::CsafProviderMetadata> for CsafProviderMetadata {
+        fn from(value: super::CsafProviderMetadata) -> Self {
+            Self {
+                canonical_url: Ok(value.canonical_url),
+                distributions: Ok(value.distributions),
+                last_updated: Ok(value.last_updated),
+                list_on_csaf_aggregators: Ok(value.list_on_csaf_aggregators),
+                metadata_version: Ok(value.metadata_version),
+                mirror_on_csaf_aggregators: Ok(value.mirror_on_csaf_aggregators),
+                public_openpgp_keys: Ok(value.public_openpgp_keys),
+                publisher: Ok(value.publisher),
+                role: Ok(value.role),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Distribution {
+        directory_url: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        rolie: ::std::result::Result<
+            ::std::option::Option<super::Rolie>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Distribution {
+        fn default() -> Self {
+            Self {
+                directory_url: Ok(Default::default()),
+                rolie: Ok(Default::default()),
+            }
+        }
+    }
+    impl Distribution {
+        pub fn directory_url<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.directory_url = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for directory_url: {e}")
+                });
+            self
+        }
+        pub fn rolie<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Rolie>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.rolie = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for rolie: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Distribution> for super::Distribution {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: Distribution,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                directory_url: value.directory_url?,
+                rolie: value.rolie?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Distribution> for Distribution {
+        fn from(value: super::Distribution) -> Self {
+            Self {
+                directory_url: Ok(value.directory_url),
+                rolie: Ok(value.rolie),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PgpKeys {
+        fingerprint: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        url: ::std::result::Result<super::UrlT, ::std::string::String>,
+    }
+    impl ::std::default::Default for PgpKeys {
+        fn default() -> Self {
+            Self {
+                fingerprint: Ok(Default::default()),
+                url: Err("no value supplied for url".to_string()),
+            }
+        }
+    }
+    impl PgpKeys {
+        pub fn fingerprint<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.fingerprint = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for fingerprint: {e}")
+                });
+            self
+        }
+        pub fn url<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::UrlT>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.url = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for url: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<PgpKeys> for super::PgpKeys {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: PgpKeys,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                fingerprint: value.fingerprint?,
+                url: value.url?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::PgpKeys> for PgpKeys {
+        fn from(value: super::PgpKeys) -> Self {
+            Self {
+                fingerprint: Ok(value.fingerprint),
+                url: Ok(value.url),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Publisher {
+        category: ::std::result::Result<
+            super::CategoryOfPublisher,
+            ::std::string::String,
+        >,
+        contact_details: ::std::result::Result<
+            ::std::option::Option<super::ContactDetails>,
+            ::std::string::String,
+        >,
+        issuing_authority: ::std::result::Result<
+            ::std::option::Option<super::IssuingAuthority>,
+            ::std::string::String,
+        >,
+        name: ::std::result::Result<super::NameOfPublisher, ::std::string::String>,
+        namespace: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Publisher {
+        fn default() -> Self {
+            Self {
+                category: Err("no value supplied for category".to_string()),
+                contact_details: Ok(Default::default()),
+                issuing_authority: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                namespace: Err("no value supplied for namespace".to_string()),
+            }
+        }
+    }
+    impl Publisher {
+        pub fn category<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::CategoryOfPublisher>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.category = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for category: {e}")
+                });
+            self
+        }
+        pub fn contact_details<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::ContactDetails>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.contact_details = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for contact_details: {e}")
+                });
+            self
+        }
+        pub fn issuing_authority<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::IssuingAuthority>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.issuing_authority = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for issuing_authority: {e}")
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::NameOfPublisher>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn namespace<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.namespace = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for namespace: {e}")
+                });
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Publisher> for super::Publisher {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: Publisher,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                category: value.category?,
+                contact_details: value.contact_details?,
+                issuing_authority: value.issuing_authority?,
+                name: value.name?,
+                namespace: value.namespace?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Publisher> for Publisher {
+        fn from(value: super::Publisher) -> Self {
+            Self {
+                category: Ok(value.category),
+                contact_details: Ok(value.contact_details),
+                issuing_authority: Ok(value.issuing_authority),
+                name: Ok(value.name),
+                namespace: Ok(value.namespace),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Rolie {
+        categories: ::std::result::Result<
+            ::std::option::Option<Vec<super::JsonUrlT>>,
+            ::std::string::String,
+        >,
+        feeds: ::std::result::Result<Vec<super::RolieFeed>, ::std::string::String>,
+        services: ::std::result::Result<
+            ::std::option::Option<Vec<super::JsonUrlT>>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Rolie {
+        fn default() -> Self {
+            Self {
+                categories: Ok(Default::default()),
+                feeds: Err("no value supplied for feeds".to_string()),
+                services: Ok(Default::default()),
+            }
+        }
+    }
+    impl Rolie {
+        pub fn categories<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<Vec<super::JsonUrlT>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.categories = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for categories: {e}")
+                });
+            self
+        }
+        pub fn feeds<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<Vec<super::RolieFeed>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.feeds = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for feeds: {e}"));
+            self
+        }
+        pub fn services<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<Vec<super::JsonUrlT>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.services = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for services: {e}")
+                });
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Rolie> for super::Rolie {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: Rolie,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                categories: value.categories?,
+                feeds: value.feeds?,
+                services: value.services?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Rolie> for Rolie {
+        fn from(value: super::Rolie) -> Self {
+            Self {
+                categories: Ok(value.categories),
+                feeds: Ok(value.feeds),
+                services: Ok(value.services),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct RolieFeed {
+        summary: ::std::result::Result<
+            ::std::option::Option<super::SummaryOfTheFeed>,
+            ::std::string::String,
+        >,
+        tlp_label: ::std::result::Result<super::TlpLabel, ::std::string::String>,
+        url: ::std::result::Result<super::JsonUrlT, ::std::string::String>,
+    }
+    impl ::std::default::Default for RolieFeed {
+        fn default() -> Self {
+            Self {
+                summary: Ok(Default::default()),
+                tlp_label: Err("no value supplied for tlp_label".to_string()),
+                url: Err("no value supplied for url".to_string()),
+            }
+        }
+    }
+    impl RolieFeed {
+        pub fn summary<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::SummaryOfTheFeed>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.summary = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for summary: {e}")
+                });
+            self
+        }
+        pub fn tlp_label<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::TlpLabel>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tlp_label = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for tlp_label: {e}")
+                });
+            self
+        }
+        pub fn url<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::JsonUrlT>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.url = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for url: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<RolieFeed> for super::RolieFeed {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: RolieFeed,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                summary: value.summary?,
+                tlp_label: value.tlp_label?,
+                url: value.url?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::RolieFeed> for RolieFeed {
+        fn from(value: super::RolieFeed) -> Self {
+            Self {
+                summary: Ok(value.summary),
+                tlp_label: Ok(value.tlp_label),
+                url: Ok(value.url),
+            }
+        }
+    }
+}
+crate::macros::impl_string_newtype_ergonomics!(ContactDetails);
+crate::macros::impl_string_newtype_ergonomics!(IssuingAuthority);
+crate::macros::impl_string_newtype_ergonomics!(JsonUrlT);
+crate::macros::impl_string_newtype_ergonomics!(NameOfPublisher);
+crate::macros::impl_string_newtype_ergonomics!(SummaryOfTheFeed);
+crate::macros::impl_string_newtype_ergonomics!(UrlT);
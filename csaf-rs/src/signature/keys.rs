@@ -0,0 +1,135 @@
+//! Validity of OpenPGP keys.
+//!
+//! The `pgp` crate parses keys and checks single signatures, but leaves it to its users to decide
+//! which keys are valid. As GnuPG does, a primary key needs a valid self-signature and a subkey a
+//! valid binding signature (with a back signature, if it can sign). The newest of them sets the
+//! key flags and the expiration time of the key.
+
+use super::PublicKey;
+use chrono::{DateTime, Utc};
+use pgp::composed::SignedPublicKey;
+use pgp::packet::{Signature, SignatureType, SubpacketData};
+use pgp::types::{KeyDetails, Tag, Timestamp};
+use std::sync::Arc;
+
+/// Formats a fingerprint or key ID as uppercase hexadecimal string.
+pub(super) fn hex(bytes: impl AsRef<[u8]>) -> String {
+    bytes.as_ref().iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+pub(super) fn date_time(timestamp: Timestamp) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp.as_secs().into(), 0)
+}
+
+/// Whether the signature is expired at the given time.
+pub(super) fn signature_expired(signature: &Signature, now: DateTime<Utc>) -> bool {
+    expiration(
+        signature.created(),
+        signature.signature_expiration_time().map(|d| d.as_secs()),
+    )
+    .is_some_and(|expires| expires <= now)
+}
+
+/// The primary key and the subkeys of the certificate which have a valid self-signature.
+///
+/// A certificate without valid self-signature on its primary key has no valid keys at all.
+pub(super) fn valid_keys(certificate: SignedPublicKey) -> Vec<PublicKey> {
+    let certificate = Arc::new(certificate);
+    let primary = &certificate.primary_key;
+    let direct_signatures = certificate
+        .details
+        .direct_signatures
+        .iter()
+        .filter(|signature| signature.verify_key(primary).is_ok());
+    let certifications = certificate.details.users.iter().flat_map(|user| {
+        user.signatures.iter().filter(|signature| {
+            signature.typ() != Some(SignatureType::CertRevocation)
+                && signature.verify_certification(primary, Tag::UserId, &user.id).is_ok()
+        })
+    });
+    let Some(self_signature) = newest(direct_signatures.chain(certifications)) else {
+        return Vec::new();
+    };
+    let revoked = certificate
+        .details
+        .revocation_signatures
+        .iter()
+        .any(|signature| signature.verify_key(primary).is_ok());
+    let primary_fingerprint = hex(primary.fingerprint());
+    let primary_key = PublicKey {
+        fingerprint: primary_fingerprint.clone(),
+        primary_fingerprint: primary_fingerprint.clone(),
+        key_id: hex(primary.legacy_key_id()),
+        created: date_time(primary.created_at()),
+        expires: key_expiration(primary, self_signature),
+        revoked,
+        // Keys without key flags predate them, their primary key is meant to sign
+        can_sign: !has_key_flags(self_signature) || self_signature.key_flags().sign(),
+        certificate: certificate.clone(),
+        subkey: None,
+    };
+
+    let mut keys = Vec::new();
+    for (index, subkey) in certificate.public_subkeys.iter().enumerate() {
+        let bindings = subkey.signatures.iter().filter(|signature| {
+            signature.typ() == Some(SignatureType::SubkeyBinding)
+                && signature.verify_subkey_binding(primary, &subkey.key).is_ok()
+                && (!signature.key_flags().sign()
+                    || signature
+                        .embedded_signature()
+                        .is_some_and(|back| back.verify_primary_key_binding(&subkey.key, primary).is_ok()))
+        });
+        let Some(binding) = newest(bindings) else {
+            continue;
+        };
+        let subkey_revoked = subkey.signatures.iter().any(|signature| {
+            signature.typ() == Some(SignatureType::SubkeyRevocation)
+                && signature.verify_subkey_binding(primary, &subkey.key).is_ok()
+        });
+        // A subkey expires with its primary key at the latest
+        let expires = match (key_expiration(&subkey.key, binding), primary_key.expires) {
+            (Some(subkey_expires), Some(primary_expires)) => Some(subkey_expires.min(primary_expires)),
+            (subkey_expires, primary_expires) => subkey_expires.or(primary_expires),
+        };
+        keys.push(PublicKey {
+            fingerprint: hex(subkey.key.fingerprint()),
+            primary_fingerprint: primary_fingerprint.clone(),
+            key_id: hex(subkey.key.legacy_key_id()),
+            created: date_time(subkey.key.created_at()),
+            expires,
+            revoked: revoked || subkey_revoked,
+            can_sign: binding.key_flags().sign(),
+            certificate: certificate.clone(),
+            subkey: Some(index),
+        });
+    }
+    keys.insert(0, primary_key);
+    keys
+}
+
+/// The newest of the signatures.
+fn newest<'a>(signatures: impl Iterator<Item = &'a Signature>) -> Option<&'a Signature> {
+    signatures.max_by_key(|signature| signature.created().map(Timestamp::as_secs))
+}
+
+fn has_key_flags(signature: &Signature) -> bool {
+    signature.config().is_some_and(|config| {
+        config
+            .hashed_subpackets()
+            .any(|subpacket| matches!(subpacket.data, SubpacketData::KeyFlags(_)))
+    })
+}
+
+/// The expiration time of a key set by its self-signature.
+fn key_expiration(key: &impl KeyDetails, self_signature: &Signature) -> Option<DateTime<Utc>> {
+    expiration(
+        Some(key.created_at()),
+        self_signature.key_expiration_time().map(|d| d.as_secs()),
+    )
+}
+
+/// The time a validity period starting at `start` ends, where a period of 0 never ends.
+fn expiration(start: Option<Timestamp>, seconds: Option<u32>) -> Option<DateTime<Utc>> {
+    let seconds = seconds.filter(|seconds| *seconds > 0)?;
+    DateTime::from_timestamp(i64::from(start?.as_secs()) + i64::from(seconds), 0)
+}
@@ -0,0 +1,542 @@
+//! OpenPGP signatures which accompany distributed CSAF documents (requirement 7.1.19).
+//!
+//! A document `esa-2024-001.json` is distributed with the detached, ASCII-armored signature
+//! `esa-2024-001.json.asc`. [`verify_file`] checks it against a [`Keyring`] of the publisher's
+//! public keys, e.g. those listed in `provider-metadata.json`, and [`sign_file`] writes it with a
+//! [`SecretKey`].
+//!
+//! ```no_run
+//! use csaf::signature::{Keyring, SecretKey, sign_file, verify_file};
+//! use std::path::Path;
+//!
+//! let document = Path::new("white/2024/esa-2024-001.json");
+//! let secret_key = SecretKey::load(Path::new("secret.asc"), Some("passphrase")).unwrap();
+//! sign_file(document, &secret_key).unwrap();
+//!
+//! let keyring = Keyring::load(Path::new("keys.asc")).unwrap();
+//! let verified = verify_file(document, &keyring).unwrap();
+//! println!("Signed by {}", verified.fingerprint);
+//! ```
+//!
+//! The OpenPGP operations are implemented in Rust by the [`pgp`] crate, so no external tools are
+//! needed. No trust model applies: the keys of a keyring are trusted as given, but subkeys without
+//! a valid binding signature are dropped, and signatures of expired or revoked keys are rejected.
+
+mod keys;
+
+use chrono::{DateTime, Utc};
+use keys::{date_time, hex, signature_expired};
+use pgp::composed::{
+    ArmorOptions, Deserializable, DetachedSignature, PublicOrSecret, SignedPublicKey, SignedSecretKey,
+};
+use pgp::crypto::hash::HashAlgorithm;
+use pgp::packet::Signature;
+use pgp::types::{KeyDetails, Password};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Error while loading keys, signing or verifying a signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// A file could not be read or written
+    Io(PathBuf, String),
+    /// The signature file does not exist
+    Missing(PathBuf),
+    /// An OpenPGP operation failed with this message
+    OpenPgp(String),
+    /// The data contains no signature
+    NoSignature,
+    /// The data contains no usable key
+    NoKey,
+    /// The passphrase of the secret key is missing or wrong
+    Passphrase,
+    /// No key of the keyring has the issuer's key ID
+    UnknownKey(String),
+    /// The signature of the key with this fingerprint does not match the document
+    BadSignature(String),
+    /// The signature of the key with this fingerprint is expired
+    ExpiredSignature(String),
+    /// The key with this fingerprint which made the signature is expired
+    ExpiredKey(String),
+    /// The key with this fingerprint which made the signature is revoked
+    RevokedKey(String),
+}
+
+impl SignatureError {
+    /// The file the error refers to, if any.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            SignatureError::Io(path, _) | SignatureError::Missing(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// The messages do not contain the [path](SignatureError::path) of the file.
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SignatureError::Io(_, err) => write!(f, "Could not access the file: {err}"),
+            SignatureError::Missing(_) => write!(f, "The signature file is missing"),
+            SignatureError::OpenPgp(err) => write!(f, "OpenPGP operation failed: {err}"),
+            SignatureError::NoSignature => write!(f, "The file contains no signature"),
+            SignatureError::NoKey => write!(f, "The file contains no usable key"),
+            SignatureError::Passphrase => write!(f, "The passphrase of the secret key is missing or wrong"),
+            SignatureError::UnknownKey(key_id) => {
+                write!(f, "The signing key {key_id} is not in the keyring")
+            },
+            SignatureError::BadSignature(fingerprint) => {
+                write!(f, "The signature of the key {fingerprint} does not match the document")
+            },
+            SignatureError::ExpiredSignature(fingerprint) => {
+                write!(f, "The signature of the key {fingerprint} is expired")
+            },
+            SignatureError::ExpiredKey(fingerprint) => write!(f, "The signing key {fingerprint} is expired"),
+            SignatureError::RevokedKey(fingerprint) => write!(f, "The signing key {fingerprint} is revoked"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+impl From<pgp::errors::Error> for SignatureError {
+    fn from(err: pgp::errors::Error) -> Self {
+        SignatureError::OpenPgp(err.to_string())
+    }
+}
+
+/// A public key or subkey of a [`Keyring`].
+#[derive(Debug, Clone)]
+pub struct PublicKey {
+    fingerprint: String,
+    primary_fingerprint: String,
+    key_id: String,
+    created: Option<DateTime<Utc>>,
+    /// The expiration time, which is the one of the primary key at the latest
+    expires: Option<DateTime<Utc>>,
+    /// Whether the key or its primary key is revoked
+    revoked: bool,
+    can_sign: bool,
+    certificate: Arc<SignedPublicKey>,
+    /// The index of the subkey in the certificate, `None` for the primary key
+    subkey: Option<usize>,
+}
+
+impl PublicKey {
+    /// The fingerprint as uppercase hexadecimal string, as listed in `provider-metadata.json`.
+    pub fn fingerprint(&self) -> String {
+        self.fingerprint.clone()
+    }
+
+    /// The fingerprint of the primary key, which is the key's own one for primary keys.
+    pub fn primary_fingerprint(&self) -> String {
+        self.primary_fingerprint.clone()
+    }
+
+    /// The key ID, the last 8 bytes of the fingerprint as uppercase hexadecimal string.
+    pub fn key_id(&self) -> String {
+        self.key_id.clone()
+    }
+
+    /// The creation time of the key.
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.created
+    }
+
+    /// Whether the key is neither revoked nor expired at the given time.
+    fn valid_at(&self, time: DateTime<Utc>) -> bool {
+        !self.revoked && self.expires.is_none_or(|expires| expires > time)
+    }
+
+    /// Checks that the signature was made by this key and matches the document.
+    fn verify(&self, signature: &Signature, document: &[u8]) -> Result<(), pgp::errors::Error> {
+        match self.subkey {
+            Some(index) => signature.verify(&self.certificate.public_subkeys[index].key, document),
+            None => signature.verify(&self.certificate.primary_key, document),
+        }
+    }
+
+    /// Whether the signature names this key as its issuer.
+    fn issued(&self, signature: &Signature) -> bool {
+        signature
+            .issuer_fingerprint()
+            .iter()
+            .any(|fingerprint| hex(fingerprint) == self.fingerprint)
+            || signature
+                .issuer_key_id()
+                .iter()
+                .any(|key_id| hex(key_id) == self.key_id)
+    }
+}
+
+/// Public keys to verify signatures with.
+#[derive(Debug, Clone, Default)]
+pub struct Keyring {
+    keys: Vec<PublicKey>,
+}
+
+impl Keyring {
+    /// Reads ASCII-armored or binary public keys.
+    ///
+    /// Secret keys are accepted as well, only their public part is used.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, SignatureError> {
+        let keys: Vec<_> = read_keys(data)
+            .into_iter()
+            .flat_map(|key| match key {
+                PublicOrSecret::Public(public) => keys::valid_keys(public),
+                PublicOrSecret::Secret(secret) => keys::valid_keys(secret.to_public_key()),
+            })
+            .collect();
+        if keys.is_empty() {
+            return Err(SignatureError::NoKey);
+        }
+        Ok(Keyring { keys })
+    }
+
+    /// Loads the keys of a file, see [`Keyring::from_bytes`].
+    pub fn load(path: &Path) -> Result<Self, SignatureError> {
+        Self::from_bytes(&read(path)?)
+    }
+
+    /// Adds the keys of another keyring.
+    pub fn extend(&mut self, other: Keyring) {
+        self.keys.extend(other.keys);
+    }
+
+    /// The keys and subkeys of the keyring.
+    pub fn keys(&self) -> &[PublicKey] {
+        &self.keys
+    }
+}
+
+/// A secret key to sign documents with.
+///
+/// The first valid key or subkey of a transferable secret key which can sign is used.
+#[derive(Clone)]
+pub struct SecretKey {
+    key: Arc<SignedSecretKey>,
+    /// The index of the signing subkey in the secret subkeys, `None` for the primary key
+    subkey: Option<usize>,
+    passphrase: Option<String>,
+    public: PublicKey,
+}
+
+impl SecretKey {
+    /// Reads an ASCII-armored or binary secret key and checks that the passphrase unlocks it.
+    pub fn from_bytes(data: &[u8], passphrase: Option<&str>) -> Result<Self, SignatureError> {
+        let now = Utc::now();
+        let key = read_keys(data)
+            .into_iter()
+            .filter_map(|key| match key {
+                PublicOrSecret::Secret(secret) => Some(secret),
+                PublicOrSecret::Public(_) => None,
+            })
+            .find_map(|secret| {
+                let public = keys::valid_keys(secret.to_public_key())
+                    .into_iter()
+                    .filter(|public| public.can_sign && public.valid_at(now))
+                    .find_map(|public| {
+                        // Subkeys of which only the public part is given cannot sign
+                        let subkey = match public.subkey {
+                            Some(_) => Some(
+                                secret
+                                    .secret_subkeys
+                                    .iter()
+                                    .position(|subkey| hex(subkey.key.fingerprint()) == public.fingerprint)?,
+                            ),
+                            None => None,
+                        };
+                        Some((subkey, public))
+                    })?;
+                Some((secret, public))
+            });
+        let (key, (subkey, public)) = key.ok_or(SignatureError::NoKey)?;
+        let key = SecretKey {
+            key: Arc::new(key),
+            subkey,
+            passphrase: passphrase.map(str::to_string),
+            public,
+        };
+        key.unlock()?;
+        Ok(key)
+    }
+
+    /// Loads the secret key of a file, see [`SecretKey::from_bytes`].
+    pub fn load(path: &Path, passphrase: Option<&str>) -> Result<Self, SignatureError> {
+        Self::from_bytes(&read(path)?, passphrase)
+    }
+
+    /// The public part of the key.
+    pub fn public(&self) -> &PublicKey {
+        &self.public
+    }
+
+    fn password(&self) -> Password {
+        self.passphrase.as_deref().map_or_else(Password::empty, Password::from)
+    }
+
+    /// Checks that the passphrase unlocks the key.
+    fn unlock(&self) -> Result<(), SignatureError> {
+        let password = self.password();
+        let unlocked = match self.subkey {
+            Some(index) => self.key.secret_subkeys[index].key.unlock(&password, |_, _| Ok(())),
+            None => self.key.primary_key.unlock(&password, |_, _| Ok(())),
+        };
+        Ok(unlocked.map_err(|_| SignatureError::Passphrase)??)
+    }
+
+    /// Creates a detached ASCII-armored SHA-512 signature.
+    fn sign(&self, document: &[u8]) -> Result<String, SignatureError> {
+        let (rng, password) = (rand::thread_rng(), self.password());
+        let signature = match self.subkey {
+            Some(index) => DetachedSignature::sign_binary_data(
+                rng,
+                &self.key.secret_subkeys[index].key,
+                &password,
+                HashAlgorithm::Sha512,
+                document,
+            ),
+            None => DetachedSignature::sign_binary_data(
+                rng,
+                &self.key.primary_key,
+                &password,
+                HashAlgorithm::Sha512,
+                document,
+            ),
+        }?;
+        Ok(signature.to_armored_string(ArmorOptions::default())?)
+    }
+}
+
+/// A signature which matches the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedSignature {
+    /// The fingerprint of the key which made the signature
+    pub fingerprint: String,
+    /// The fingerprint of its primary key, which differs if a subkey made the signature
+    pub primary_fingerprint: String,
+    /// The creation time of the signature
+    pub created: Option<DateTime<Utc>>,
+}
+
+/// The path of the signature file of the document, e.g. `esa-2024-001.json.asc`.
+pub fn signature_path(document: &Path) -> PathBuf {
+    let mut file_name = document.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".asc");
+    document.with_file_name(file_name)
+}
+
+/// Verify a detached ASCII-armored or binary signature of the document.
+///
+/// Succeeds if any of the signatures was made by a valid key of the keyring and matches the
+/// document.
+pub fn verify_detached(
+    document: &[u8],
+    signature: &[u8],
+    keyring: &Keyring,
+) -> Result<VerifiedSignature, SignatureError> {
+    let signatures: Vec<_> = DetachedSignature::from_reader_many(signature)
+        .map(|(signatures, _)| signatures.filter_map(Result::ok).collect())
+        .unwrap_or_default();
+    let mut error = None;
+    for DetachedSignature { signature } in &signatures {
+        match verify_signature(document, signature, keyring) {
+            Ok(verified) => return Ok(verified),
+            Err(err) => error = Some(err),
+        }
+    }
+    Err(error.unwrap_or(SignatureError::NoSignature))
+}
+
+/// Verify a single signature with the key of the keyring which made it.
+fn verify_signature(
+    document: &[u8],
+    signature: &Signature,
+    keyring: &Keyring,
+) -> Result<VerifiedSignature, SignatureError> {
+    let issuers: Vec<_> = keyring.keys.iter().filter(|key| key.issued(signature)).collect();
+    let Some(key) = issuers.iter().find(|key| key.verify(signature, document).is_ok()) else {
+        return Err(match issuers.first() {
+            Some(key) => SignatureError::BadSignature(key.fingerprint()),
+            None => SignatureError::UnknownKey(
+                signature
+                    .issuer_fingerprint()
+                    .first()
+                    .map(hex)
+                    .or_else(|| signature.issuer_key_id().first().map(hex))
+                    .unwrap_or_default(),
+            ),
+        });
+    };
+    let now = Utc::now();
+    if key.revoked {
+        return Err(SignatureError::RevokedKey(key.fingerprint()));
+    }
+    if !key.valid_at(now) {
+        return Err(SignatureError::ExpiredKey(key.fingerprint()));
+    }
+    if signature_expired(signature, now) {
+        return Err(SignatureError::ExpiredSignature(key.fingerprint()));
+    }
+    Ok(VerifiedSignature {
+        fingerprint: key.fingerprint(),
+        primary_fingerprint: key.primary_fingerprint(),
+        created: signature.created().and_then(date_time),
+    })
+}
+
+/// Verify the [signature file](signature_path) of the document.
+pub fn verify_file(document: &Path, keyring: &Keyring) -> Result<VerifiedSignature, SignatureError> {
+    let signature = signature_path(document);
+    if !signature.is_file() {
+        return Err(SignatureError::Missing(signature));
+    }
+    verify_detached(&read(document)?, &read(&signature)?, keyring)
+}
+
+/// Create a detached ASCII-armored SHA-512 signature of the document.
+pub fn sign_detached(document: &[u8], key: &SecretKey) -> Result<String, SignatureError> {
+    key.sign(document)
+}
+
+/// Sign the document and write the [signature file](signature_path).
+///
+/// Returns the path of the written file.
+pub fn sign_file(document: &Path, key: &SecretKey) -> Result<PathBuf, SignatureError> {
+    let path = signature_path(document);
+    let signature = sign_detached(&read(document)?, key)?;
+    fs::write(&path, signature).map_err(|err| SignatureError::Io(path.clone(), err.to_string()))?;
+    Ok(path)
+}
+
+/// The public and secret keys of ASCII-armored or binary data, skipping those which cannot be
+/// parsed.
+fn read_keys(data: &[u8]) -> Vec<PublicOrSecret> {
+    PublicOrSecret::from_reader_many(data)
+        .map(|(keys, _)| keys.filter_map(Result::ok).collect())
+        .unwrap_or_default()
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, SignatureError> {
+    fs::read(path).map_err(|err| SignatureError::Io(path.to_path_buf(), err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/openpgp");
+    const ED25519_FINGERPRINT: &str = "0AA16741332EEF4FF9DB38DBB54F73EDA1BE7F3E";
+    const RSA_FINGERPRINT: &str = "B9980D2C5979434CB20BDA161ADF0ECE4D52F2B8";
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(FIXTURES).join(name)
+    }
+
+    fn keyring() -> Keyring {
+        let mut keyring = Keyring::load(&fixture("ed25519-public.asc")).unwrap();
+        keyring.extend(Keyring::load(&fixture("rsa-public.asc")).unwrap());
+        keyring
+    }
+
+    #[test]
+    fn test_keyring() {
+        let keyring = keyring();
+        let primary_keys: Vec<_> = keyring
+            .keys()
+            .iter()
+            .filter(|key| key.fingerprint() == key.primary_fingerprint())
+            .map(PublicKey::fingerprint)
+            .collect();
+        assert_eq!(primary_keys, [ED25519_FINGERPRINT, RSA_FINGERPRINT]);
+        assert_eq!(keyring.keys()[0].key_id(), "B54F73EDA1BE7F3E");
+        assert_eq!(Keyring::from_bytes(b"no key").unwrap_err(), SignatureError::NoKey);
+    }
+
+    #[test]
+    fn test_verify_signatures_of_gnupg() {
+        let document = fixture("csaf-rs_csaf-csaf_2_1-template.json");
+        let verified = verify_file(&document, &keyring()).unwrap();
+        assert_eq!(verified.fingerprint, ED25519_FINGERPRINT);
+        assert!(verified.created.is_some());
+
+        let content = fs::read(&document).unwrap();
+        let rsa_signature = fs::read(fixture("csaf-rs_csaf-csaf_2_1-template.json.rsa.asc")).unwrap();
+        let verified = verify_detached(&content, &rsa_signature, &keyring()).unwrap();
+        assert_eq!(verified.primary_fingerprint, RSA_FINGERPRINT);
+
+        // The signing key must be in the keyring
+        let ed25519_only = Keyring::load(&fixture("ed25519-public.asc")).unwrap();
+        assert!(matches!(
+            verify_detached(&content, &rsa_signature, &ed25519_only),
+            Err(SignatureError::UnknownKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_reject_tampered_document() {
+        let mut content = fs::read(fixture("csaf-rs_csaf-csaf_2_1-template.json")).unwrap();
+        let signature = fs::read(fixture("csaf-rs_csaf-csaf_2_1-template.json.asc")).unwrap();
+        let position = content.windows(5).position(|window| window == b"CLEAR").unwrap();
+        content[position..position + 5].copy_from_slice(b"GREEN");
+        assert_eq!(
+            verify_detached(&content, &signature, &keyring()),
+            Err(SignatureError::BadSignature(ED25519_FINGERPRINT.to_string()))
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let document = dir.path().join("esa-2024-001.json");
+        fs::write(&document, &content).unwrap();
+        assert_eq!(
+            verify_file(&document, &keyring()),
+            Err(SignatureError::Missing(dir.path().join("esa-2024-001.json.asc")))
+        );
+    }
+
+    #[test]
+    fn test_reject_expired_and_revoked_keys() {
+        let content = fs::read(fixture("csaf-rs_csaf-csaf_2_1-template.json")).unwrap();
+        for (key, signature, expected) in [
+            (
+                "expired-public.asc",
+                "csaf-rs_csaf-csaf_2_1-template.json.expired.asc",
+                SignatureError::ExpiredKey("1E21470851F4364E2E7C52CCC7A978B42ED53B58".to_string()),
+            ),
+            (
+                "revoked-public.asc",
+                "csaf-rs_csaf-csaf_2_1-template.json.revoked.asc",
+                SignatureError::RevokedKey("9ABD2E23843D51E01F38A81DC3D0B005FCF450EE".to_string()),
+            ),
+        ] {
+            let keyring = Keyring::load(&fixture(key)).unwrap();
+            let signature = fs::read(fixture(signature)).unwrap();
+            assert_eq!(verify_detached(&content, &signature, &keyring), Err(expected));
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let document = dir.path().join("esa-2024-001.json");
+        fs::write(&document, "{}").unwrap();
+
+        for (secret_key, fingerprint) in [
+            ("ed25519-secret.asc", ED25519_FINGERPRINT),
+            ("rsa-secret.asc", RSA_FINGERPRINT),
+        ] {
+            let secret_key = fixture(secret_key);
+            assert_eq!(
+                SecretKey::load(&secret_key, Some("wrong")).err(),
+                Some(SignatureError::Passphrase)
+            );
+            let secret_key = SecretKey::load(&secret_key, Some("csaf")).unwrap();
+            assert_eq!(secret_key.public().fingerprint(), fingerprint);
+
+            let path = sign_file(&document, &secret_key).unwrap();
+            assert_eq!(path, dir.path().join("esa-2024-001.json.asc"));
+            assert_eq!(verify_file(&document, &keyring()).unwrap().fingerprint, fingerprint);
+        }
+    }
+}
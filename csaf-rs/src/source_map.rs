@@ -0,0 +1,296 @@
+//! Mapping of JSON pointers to their location in the source text
+//!
+//! Parsing into [`serde_json::Value`] drops all information about where a value was located in
+//! the source. If a document is loaded from text, [`SourceMap::parse`] can be used on the same
+//! text to resolve the `instance_path` of findings back to line and column ranges.
+//!
+//! ```rust
+//! # use csaf::source_map::SourceMap;
+//! let text = "{\n  \"document\": {\n    \"title\": \"Example\"\n  }\n}";
+//! let source_map = SourceMap::parse(text).expect("valid JSON");
+//!
+//! let span = source_map.resolve("/document/title").expect("title exists");
+//! assert_eq!(span.start.to_string(), "3:14");
+//!
+//! // paths that do not exist resolve to the closest existing parent
+//! assert_eq!(source_map.resolve("/document/notes"), source_map.resolve("/document"));
+//! ```
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Error, ErrorKind};
+
+/// A position in the source text.
+///
+/// Both line and column start at 1, columns count characters (not bytes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// The range a JSON value occupies in the source text, the end is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// Locations of all values in a JSON document, indexed by their JSON pointer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    spans: HashMap<String, Span>,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl SourceMap {
+    /// Records the location of every value in the given JSON text.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut scanner = Scanner {
+            text,
+            offset: 0,
+            position: Position { line: 1, column: 1 },
+            spans: HashMap::new(),
+        };
+        scanner.value(String::new())?;
+        scanner.skip_whitespace();
+        if scanner.offset < text.len() {
+            return Err(scanner.error("trailing characters"));
+        }
+        Ok(SourceMap { spans: scanner.spans })
+    }
+
+    /// Resolves a JSON pointer to the location of its value.
+    ///
+    /// Findings often point to values that are missing, so if the pointer does not exist, the
+    /// location of the closest existing parent is returned instead.
+    pub fn resolve(&self, instance_path: &str) -> Option<Span> {
+        let mut path = instance_path.trim_end_matches('/');
+        loop {
+            if let Some(span) = self.spans.get(path) {
+                return Some(*span);
+            }
+            path = &path[..path.rfind('/')?];
+        }
+    }
+}
+
+/// A minimal JSON scanner, which only checks the structure as far as needed to track locations.
+///
+/// Validating the JSON itself is left to `serde_json`.
+struct Scanner<'a> {
+    text: &'a str,
+    offset: usize,
+    position: Position,
+    spans: HashMap<String, Span>,
+}
+
+impl Scanner<'_> {
+    fn value(&mut self, path: String) -> Result<(), Error> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek() {
+            Some(b'{') => self.object(&path)?,
+            Some(b'[') => self.array(&path)?,
+            Some(b'"') => {
+                self.string()?;
+            },
+            Some(b'-' | b'0'..=b'9') => self.skip_while(|b| b.is_ascii_digit() || b"+-.eE".contains(&b)),
+            Some(b't' | b'f' | b'n') => self.skip_while(|b| b.is_ascii_alphabetic()),
+            Some(_) => return Err(self.error("expected a value")),
+            None => return Err(self.error("unexpected end of input")),
+        }
+        self.spans.insert(
+            path,
+            Span {
+                start,
+                end: self.position,
+            },
+        );
+        Ok(())
+    }
+
+    fn object(&mut self, path: &str) -> Result<(), Error> {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.advance();
+            return Ok(());
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            self.value(format!("{path}/{}", key.replace('~', "~0").replace('/', "~1")))?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b'}') => {
+                    self.advance();
+                    return Ok(());
+                },
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self, path: &str) -> Result<(), Error> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.advance();
+            return Ok(());
+        }
+        for index in 0.. {
+            self.value(format!("{path}/{index}"))?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b']') => {
+                    self.advance();
+                    break;
+                },
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Scans a string and returns its unescaped content.
+    fn string(&mut self) -> Result<String, Error> {
+        let start = self.offset;
+        self.expect(b'"')?;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    self.advance();
+                    self.advance();
+                },
+                Some(_) => self.advance(),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+        self.advance();
+        serde_json::from_str(&self.text[start..self.offset]).map_err(|err| self.error(&err.to_string()))
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), Error> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("expected '{}'", expected as char)));
+        }
+        self.advance();
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.skip_while(|b| b.is_ascii_whitespace());
+    }
+
+    fn skip_while(&mut self, predicate: impl Fn(u8) -> bool) {
+        while self.peek().is_some_and(&predicate) {
+            self.advance();
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.offset).copied()
+    }
+
+    fn advance(&mut self) {
+        let Some(byte) = self.peek() else {
+            return;
+        };
+        self.offset += 1;
+        if byte == b'\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else if byte & 0xC0 != 0x80 {
+            // continuation bytes of multibyte characters do not start a new column
+            self.position.column += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::new(ErrorKind::InvalidData, format!("{message} at {}", self.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    #[test]
+    fn test_spans() {
+        let text = r#"{
+  "document": {
+    "title": "Übersicht",
+    "notes": [
+      { "category": "summary" },
+      { "a/b~c": 1.5e3 }
+    ]
+  },
+  "vulnerabilities": [],
+  "flag": true
+}"#;
+        let source_map = SourceMap::parse(text).unwrap();
+
+        assert_eq!(
+            source_map.resolve(""),
+            Some(Span {
+                start: position(1, 1),
+                end: position(11, 2)
+            })
+        );
+        assert_eq!(
+            source_map.resolve("/document/title"),
+            Some(Span {
+                start: position(3, 14),
+                end: position(3, 25)
+            })
+        );
+        assert_eq!(
+            source_map.resolve("/document/notes/0/category").map(|s| s.start),
+            Some(position(5, 21))
+        );
+        assert_eq!(
+            source_map.resolve("/document/notes/1/a~1b~0c"),
+            Some(Span {
+                start: position(6, 18),
+                end: position(6, 23)
+            })
+        );
+        assert_eq!(
+            source_map.resolve("/vulnerabilities").map(|s| s.start),
+            Some(position(9, 22))
+        );
+        assert_eq!(source_map.resolve("/flag").map(|s| s.end), Some(position(10, 15)));
+    }
+
+    #[test]
+    fn test_missing_paths_resolve_to_parent() {
+        let source_map = SourceMap::parse(r#"{"document": {"notes": []}}"#).unwrap();
+        assert_eq!(
+            source_map.resolve("/document/notes/3/text"),
+            source_map.resolve("/document/notes")
+        );
+        assert_eq!(source_map.resolve("/product_tree/"), source_map.resolve(""));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        assert!(SourceMap::parse(r#"{"document": }"#).is_err());
+        assert!(SourceMap::parse(r#"{"document": "#).is_err());
+        assert!(SourceMap::parse(r#"{"document": "unterminated}"#).is_err());
+        assert!(SourceMap::parse(r#"{} {}"#).is_err());
+    }
+}
@@ -0,0 +1,825 @@
+//! Dictionaries and allow-lists for the spell check of test 6.3.8.
+//!
+//! The spell check needs a dictionary for the language of the document, so the test is not run by
+//! default: without a [SpellChecker], it is skipped. To run it, validate a document with a spell
+//! checker in its [ValidationContext](crate::validation_context::ValidationContext). Dictionaries
+//! are looked up by a [DictionaryProvider], e.g.
+//! [HunspellDirectory] for the Hunspell dictionaries shipped with most Linux distributions
+//! (`/usr/share/hunspell`). An [AllowList] adds words that are correct but not in the dictionary,
+//! like product names and vulnerability jargon, for all or only for specific publishers.
+//!
+//! ```
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::spell_check::{AllowList, Dictionary, SpellChecker};
+//! use csaf::validation::validate_by_tests;
+//! use csaf::validation_context::{ValidationContext, WithContext};
+//!
+//! let dictionary = Dictionary::from_hunspell("2\nbuffer\noverflow/S\n", "SFX S Y 1\nSFX S 0 s .\n").unwrap();
+//! assert!(dictionary.contains("overflows"));
+//!
+//! let spell_checker = SpellChecker::new(dictionary).with_allow_list(AllowList::new(["ExampleOS"]));
+//! let document = load_document(r#"{"document": {"csaf_version": "2.1", "lang": "en"}}"#).unwrap();
+//! let with_spell_checker = WithContext::new(&document, ValidationContext::new().with_spell_checker(&spell_checker));
+//! let result = validate_by_tests(&with_spell_checker, "2.1", &["6.3.8"]);
+//! assert_eq!(result.test_results.len(), 1);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// A set of correctly spelled words, optionally with the affix and compound rules of a Hunspell
+/// dictionary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    /// The flags of each stem, one set per homonym
+    words: HashMap<String, Vec<Flags>>,
+    affixes: AffixFile,
+}
+
+/// Error while parsing a Hunspell dictionary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HunspellError {
+    /// An affix rule in the `.aff` file is malformed, with its line number.
+    InvalidAffixRule(usize),
+    /// The `.aff` file uses a `FLAG` type other than `char`, `UTF-8`, `long` or `num`.
+    UnsupportedFlagType(String),
+    /// The `.aff` file declares a `SET` encoding other than `UTF-8` or `ISO8859-1`.
+    UnsupportedEncoding(String),
+    /// A dictionary or affix file is not valid in the declared encoding.
+    InvalidEncoding(String),
+    /// A dictionary or affix file could not be read.
+    Io(String, String),
+}
+
+impl Display for HunspellError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HunspellError::InvalidAffixRule(line) => write!(f, "invalid affix rule in line {line}"),
+            HunspellError::UnsupportedFlagType(flag_type) => write!(f, "unsupported flag type {flag_type}"),
+            HunspellError::UnsupportedEncoding(encoding) => write!(f, "unsupported encoding {encoding}"),
+            HunspellError::InvalidEncoding(encoding) => write!(f, "the dictionary is not valid {encoding}"),
+            HunspellError::Io(path, error) => write!(f, "could not read {path}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for HunspellError {}
+
+/// The flags of a dictionary entry, or the continuation flags of an affix rule.
+type Flags = HashSet<String>;
+
+/// How flags are encoded in a Hunspell dictionary, see the `FLAG` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagType {
+    /// One character per flag (the default)
+    Char,
+    /// Two characters per flag
+    Long,
+    /// Comma-separated numbers
+    Num,
+}
+
+impl FlagType {
+    fn parse(self, flags: &str) -> Vec<String> {
+        match self {
+            FlagType::Char => flags.chars().map(String::from).collect(),
+            FlagType::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+                chars.chunks(2).map(|chunk| chunk.iter().collect()).collect()
+            },
+            FlagType::Num => flags.split(',').map(|flag| flag.trim().to_string()).collect(),
+        }
+    }
+}
+
+/// A single prefix or suffix rule of a Hunspell affix file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AffixRule {
+    /// The flag of the affix class, which stems (or other affix rules) need to allow the rule
+    flag: String,
+    cross_product: bool,
+    strip: String,
+    add: String,
+    condition: Vec<ConditionPart>,
+    /// Flags of the affixed word, e.g. for a second suffix
+    continuation: Flags,
+}
+
+/// One character of an affix condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConditionPart {
+    Any,
+    Char(char),
+    OneOf(Vec<char>),
+    NoneOf(Vec<char>),
+}
+
+impl ConditionPart {
+    fn matches(&self, char: char) -> bool {
+        match self {
+            ConditionPart::Any => true,
+            ConditionPart::Char(expected) => char == *expected,
+            ConditionPart::OneOf(chars) => chars.contains(&char),
+            ConditionPart::NoneOf(chars) => !chars.contains(&char),
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Option<Vec<ConditionPart>> {
+    let mut parts = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(char) = chars.next() {
+        parts.push(match char {
+            '.' => ConditionPart::Any,
+            '[' => {
+                let class: String = chars.by_ref().take_while(|c| *c != ']').collect();
+                match class.strip_prefix('^') {
+                    Some(negated) => ConditionPart::NoneOf(negated.chars().collect()),
+                    None => ConditionPart::OneOf(class.chars().collect()),
+                }
+            },
+            ']' => return None,
+            char => ConditionPart::Char(char),
+        });
+    }
+    Some(parts)
+}
+
+impl AffixRule {
+    /// Returns the stem this prefix rule derives the word from, if the rule applies.
+    fn strip_prefix(&self, word: &str) -> Option<String> {
+        let rest = word.strip_prefix(self.add.as_str()).filter(|rest| !rest.is_empty())?;
+        let stem = format!("{}{rest}", self.strip);
+        let chars: Vec<char> = stem.chars().collect();
+        let matches =
+            chars.len() >= self.condition.len() && self.condition.iter().zip(&chars).all(|(part, c)| part.matches(*c));
+        matches.then_some(stem)
+    }
+
+    /// Returns the stem this suffix rule derives the word from, if the rule applies.
+    fn strip_suffix(&self, word: &str) -> Option<String> {
+        let rest = word.strip_suffix(self.add.as_str()).filter(|rest| !rest.is_empty())?;
+        let stem = format!("{rest}{}", self.strip);
+        let chars: Vec<char> = stem.chars().collect();
+        let len = self.condition.len();
+        let matches = chars.len() >= len
+            && (self.condition.iter())
+                .zip(&chars[chars.len() - len..])
+                .all(|(part, c)| part.matches(*c));
+        matches.then_some(stem)
+    }
+}
+
+/// The affix rules and options of a Hunspell `.aff` file, which are applied when looking up a word.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct AffixFile {
+    prefixes: Vec<AffixRule>,
+    suffixes: Vec<AffixRule>,
+    /// Stems with this flag (`NEEDAFFIX`) are only words with an affix
+    need_affix: Option<String>,
+    /// Stems with this flag (`FORBIDDENWORD`) are not words
+    forbidden: Option<String>,
+    /// Stems with this flag (`ONLYINCOMPOUND`) are only words as part of a compound
+    only_in_compound: Option<String>,
+    /// Stems with this flag (`COMPOUNDFLAG`) can be in any position of a compound
+    compound: Option<String>,
+    /// Stems with these flags (`COMPOUNDBEGIN`, `COMPOUNDMIDDLE` and `COMPOUNDEND`) can be at this
+    /// position of a compound
+    compound_begin: Option<String>,
+    compound_middle: Option<String>,
+    compound_end: Option<String>,
+    /// The minimal length of the parts of a compound (`COMPOUNDMIN`)
+    compound_min: usize,
+}
+
+/// Where a word is looked up: on its own or as part of a compound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Word,
+    Begin,
+    Middle,
+    End,
+}
+
+impl AffixFile {
+    fn has(flags: &Flags, flag: &Option<String>) -> bool {
+        flag.as_ref().is_some_and(|flag| flags.contains(flag))
+    }
+
+    /// Whether a stem with these flags can be used at the position.
+    fn allows(&self, flags: &Flags, position: Position) -> bool {
+        if Self::has(flags, &self.forbidden) {
+            return false;
+        }
+        let position_flag = match position {
+            Position::Word => return !Self::has(flags, &self.only_in_compound),
+            Position::Begin => &self.compound_begin,
+            Position::Middle => &self.compound_middle,
+            Position::End => &self.compound_end,
+        };
+        Self::has(flags, &self.compound) || Self::has(flags, position_flag)
+    }
+
+    fn has_compounds(&self) -> bool {
+        self.compound.is_some() || self.compound_begin.is_some()
+    }
+}
+
+/// Decodes the content of a `.dic` or `.aff` file in the encoding declared by the `SET` option.
+fn decode(content: &[u8], encoding: &str) -> Result<String, HunspellError> {
+    match encoding {
+        "UTF-8" => {
+            String::from_utf8(content.to_vec()).map_err(|_| HunspellError::InvalidEncoding(encoding.to_string()))
+        },
+        // ISO 8859-1 maps each byte to the code point with the same value
+        "ISO8859-1" => Ok(content.iter().map(|byte| char::from(*byte)).collect()),
+        other => Err(HunspellError::UnsupportedEncoding(other.to_string())),
+    }
+}
+
+impl Dictionary {
+    /// Create a dictionary from a list of words.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Self {
+        Dictionary {
+            words: words
+                .into_iter()
+                .map(|word| (word.into(), vec![Flags::new()]))
+                .collect(),
+            affixes: AffixFile::default(),
+        }
+    }
+
+    /// Parse a dictionary in Hunspell format, given the raw content of its `.dic` and `.aff` files
+    /// in the encoding declared by the `SET` option of the `.aff` file (`UTF-8` or `ISO8859-1`).
+    ///
+    /// Without a `SET` option, the files are read as UTF-8 if they are valid UTF-8, otherwise as
+    /// ISO8859-1 like Hunspell does.
+    pub fn from_hunspell_bytes(dic: &[u8], aff: &[u8]) -> Result<Self, HunspellError> {
+        // the option names are ASCII, so the encoding can be read before decoding
+        let declared = (aff.split(|byte| *byte == b'\n'))
+            .map(|line| String::from_utf8_lossy(line).trim().to_string())
+            .find_map(|line| line.strip_prefix("SET ").map(|encoding| encoding.trim().to_string()));
+        let encoding = match declared {
+            Some(encoding) => match encoding.to_ascii_uppercase().as_str() {
+                "UTF8" | "UTF-8" => "UTF-8".to_string(),
+                "ISO-8859-1" | "ISO8859-1" => "ISO8859-1".to_string(),
+                _ => encoding,
+            },
+            None if std::str::from_utf8(dic).is_ok() && std::str::from_utf8(aff).is_ok() => "UTF-8".to_string(),
+            None => "ISO8859-1".to_string(),
+        };
+        Self::from_hunspell(&decode(dic, &encoding)?, &decode(aff, &encoding)?)
+    }
+
+    /// Parse a dictionary in Hunspell format, given the content of its `.dic` and `.aff` files.
+    ///
+    /// The prefix and suffix rules (`PFX` and `SFX`) are applied when looking up a word, including
+    /// continuation classes for two suffixes and the cross product of a prefix and suffix. Words
+    /// are split into compounds according to `COMPOUNDFLAG`, `COMPOUNDBEGIN`, `COMPOUNDMIDDLE`,
+    /// `COMPOUNDEND` and `COMPOUNDMIN`; `COMPOUNDRULE` and the `CHECKCOMPOUND*` options are not
+    /// supported. The content is already decoded, so the `SET` option is ignored, see
+    /// [Self::from_hunspell_bytes].
+    pub fn from_hunspell(dic: &str, aff: &str) -> Result<Self, HunspellError> {
+        let mut flag_type = FlagType::Char;
+        for line in aff.lines() {
+            match line.split_whitespace().collect::<Vec<_>>()[..] {
+                ["FLAG", "long"] => flag_type = FlagType::Long,
+                ["FLAG", "num"] => flag_type = FlagType::Num,
+                ["FLAG", "UTF-8"] => flag_type = FlagType::Char,
+                ["FLAG", other] => return Err(HunspellError::UnsupportedFlagType(other.to_string())),
+                _ => {},
+            }
+        }
+
+        // flag aliases (`AF`), referred to by their number starting at 1
+        let mut aliases: Vec<Flags> = Vec::new();
+        let parse_flags = |aliases: &[Flags], flags: &str| -> Flags {
+            match flags.parse::<usize>() {
+                Ok(alias) if !aliases.is_empty() => aliases.get(alias.wrapping_sub(1)).cloned().unwrap_or_default(),
+                _ => flag_type.parse(flags).into_iter().collect(),
+            }
+        };
+        let single_flag = |flag: &str| flag_type.parse(flag).into_iter().next();
+
+        let mut affixes = AffixFile {
+            compound_min: 3,
+            ..AffixFile::default()
+        };
+        // whether the affix class of a flag allows the cross product of prefixes and suffixes
+        let mut cross_products: HashMap<(bool, String), bool> = HashMap::new();
+        for (index, line) in aff.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                ["AF", flags] if flags.parse::<usize>().is_err() => {
+                    aliases.push(flag_type.parse(flags).into_iter().collect());
+                },
+                ["NEEDAFFIX" | "PSEUDOROOT", flag] => affixes.need_affix = single_flag(flag),
+                ["FORBIDDENWORD", flag] => affixes.forbidden = single_flag(flag),
+                ["ONLYINCOMPOUND", flag] => affixes.only_in_compound = single_flag(flag),
+                ["COMPOUNDFLAG", flag] => affixes.compound = single_flag(flag),
+                ["COMPOUNDBEGIN", flag] => affixes.compound_begin = single_flag(flag),
+                ["COMPOUNDMIDDLE", flag] => affixes.compound_middle = single_flag(flag),
+                ["COMPOUNDEND", flag] => affixes.compound_end = single_flag(flag),
+                ["COMPOUNDMIN", min] => {
+                    affixes.compound_min = min.parse().map_err(|_| HunspellError::InvalidAffixRule(index + 1))?
+                },
+                [kind @ ("PFX" | "SFX"), flag, cross_product, count] if count.parse::<usize>().is_ok() => {
+                    cross_products.insert((kind == "PFX", flag.to_string()), cross_product == "Y");
+                },
+                [kind @ ("PFX" | "SFX"), flag, strip, add, ref rest @ ..] => {
+                    let invalid = || HunspellError::InvalidAffixRule(index + 1);
+                    let prefix = kind == "PFX";
+                    let cross_product = *cross_products.get(&(prefix, flag.to_string())).ok_or_else(invalid)?;
+                    let condition = rest.first().copied().unwrap_or(".");
+                    let (add, continuation) = add.split_once('/').unwrap_or((add, ""));
+                    let rule = AffixRule {
+                        flag: flag.to_string(),
+                        cross_product,
+                        strip: if strip == "0" { String::new() } else { strip.to_string() },
+                        add: if add == "0" { String::new() } else { add.to_string() },
+                        condition: parse_condition(condition).ok_or_else(invalid)?,
+                        continuation: if continuation.is_empty() {
+                            Flags::new()
+                        } else {
+                            parse_flags(&aliases, continuation)
+                        },
+                    };
+                    if prefix {
+                        affixes.prefixes.push(rule);
+                    } else {
+                        affixes.suffixes.push(rule);
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        let mut words: HashMap<String, Vec<Flags>> = HashMap::new();
+        // the first line is the (approximate) number of words
+        for line in dic.lines().skip(1) {
+            // morphological fields are separated by whitespace
+            let Some(entry) = line.split(['\t', ' ']).next().filter(|entry| !entry.is_empty()) else {
+                continue;
+            };
+            let (word, flags) = entry.split_once('/').unwrap_or((entry, ""));
+            let flags = if flags.is_empty() {
+                Flags::new()
+            } else {
+                parse_flags(&aliases, flags)
+            };
+            words.entry(word.to_string()).or_default().push(flags);
+        }
+        Ok(Dictionary { words, affixes })
+    }
+
+    /// Returns whether the dictionary contains the word, as is or in lowercase (e.g. at the start
+    /// of a sentence).
+    pub fn contains(&self, word: &str) -> bool {
+        self.check(word) || self.check(&word.to_lowercase())
+    }
+
+    /// Returns the number of words in the dictionary, without the forms created by affix rules.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns whether the dictionary does not contain any words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    fn check(&self, word: &str) -> bool {
+        self.check_at(word, Position::Word)
+            || (self.affixes.has_compounds() && self.check_compound(word, Position::Begin))
+    }
+
+    /// Whether the word is a compound of words allowed at their positions, starting with a word at
+    /// `position`.
+    fn check_compound(&self, word: &str, position: Position) -> bool {
+        let min = self.affixes.compound_min.max(1);
+        word.char_indices().map(|(index, _)| index).skip(min).any(|split| {
+            let (first, rest) = word.split_at(split);
+            rest.chars().count() >= min
+                && self.check_at(first, position)
+                && (self.check_at(rest, Position::End) || self.check_compound(rest, Position::Middle))
+        })
+    }
+
+    /// Whether a stem of the word has flags for which `accept` returns true.
+    fn has_stem(&self, stem: &str, accept: impl Fn(&Flags) -> bool) -> bool {
+        self.words.get(stem).is_some_and(|homonyms| homonyms.iter().any(accept))
+    }
+
+    /// Whether the word is a stem or a stem with affixes allowed at the position.
+    fn check_at(&self, word: &str, position: Position) -> bool {
+        let affixes = &self.affixes;
+        let allowed = |flags: &Flags| affixes.allows(flags, position);
+        if self.has_stem(word, |flags| {
+            allowed(flags) && !AffixFile::has(flags, &affixes.need_affix)
+        }) {
+            return true;
+        }
+        let complete = |rule: &AffixRule| !AffixFile::has(&rule.continuation, &affixes.need_affix);
+
+        for suffix in &affixes.suffixes {
+            let Some(stem) = suffix.strip_suffix(word) else {
+                continue;
+            };
+            if complete(suffix) && self.has_stem(&stem, |flags| allowed(flags) && flags.contains(&suffix.flag)) {
+                return true;
+            }
+            // a second suffix, whose continuation class allows this one
+            let twofold = (affixes.suffixes.iter())
+                .filter(|inner| inner.continuation.contains(&suffix.flag))
+                .filter_map(|inner| Some((inner, inner.strip_suffix(&stem)?)))
+                .any(|(inner, stem)| self.has_stem(&stem, |flags| allowed(flags) && flags.contains(&inner.flag)));
+            if twofold {
+                return true;
+            }
+        }
+
+        for prefix in &affixes.prefixes {
+            let Some(stem) = prefix.strip_prefix(word) else {
+                continue;
+            };
+            if complete(prefix) && self.has_stem(&stem, |flags| allowed(flags) && flags.contains(&prefix.flag)) {
+                return true;
+            }
+            // a suffix as well, which is allowed with this prefix by the cross product or its
+            // continuation class
+            let with_suffix = (affixes.suffixes.iter())
+                .filter_map(|suffix| Some((suffix, suffix.strip_suffix(&stem)?)))
+                .any(|(suffix, stem)| {
+                    self.has_stem(&stem, |flags| {
+                        let prefix_allowed =
+                            (prefix.cross_product && suffix.cross_product && flags.contains(&prefix.flag))
+                                || suffix.continuation.contains(&prefix.flag);
+                        allowed(flags) && flags.contains(&suffix.flag) && prefix_allowed
+                    })
+                });
+            if with_suffix {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Provides the dictionary for a language.
+pub trait DictionaryProvider: Send + Sync {
+    /// Returns the dictionary for a BCP 47 language tag (e.g. `en-US`), or why there is none.
+    fn dictionary(&self, language: &str) -> Result<Arc<Dictionary>, String>;
+}
+
+/// A single dictionary is used for all languages.
+impl DictionaryProvider for Dictionary {
+    fn dictionary(&self, _language: &str) -> Result<Arc<Dictionary>, String> {
+        Ok(Arc::new(self.clone()))
+    }
+}
+
+/// Loads Hunspell dictionaries (`.dic` and `.aff` files) from a directory.
+///
+/// For a language tag like `en-US`, the files `en_US.dic` and `en_US.aff` are used, or
+/// `en.dic` and `en.aff` if they do not exist. Loaded dictionaries are cached.
+pub struct HunspellDirectory {
+    directory: PathBuf,
+    cache: Mutex<HashMap<String, Result<Arc<Dictionary>, String>>>,
+}
+
+impl HunspellDirectory {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        HunspellDirectory {
+            directory: directory.into(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn load(&self, language: &str) -> Result<Arc<Dictionary>, String> {
+        let full = language.replace('-', "_");
+        let primary = language.split('-').next().unwrap_or(language);
+        for stem in [full.as_str(), primary] {
+            let dic_path = self.directory.join(format!("{stem}.dic"));
+            if !dic_path.is_file() {
+                continue;
+            }
+            let dic = read(&dic_path)?;
+            let aff_path = dic_path.with_extension("aff");
+            let aff = if aff_path.is_file() {
+                read(&aff_path)?
+            } else {
+                Vec::new()
+            };
+            return Dictionary::from_hunspell_bytes(&dic, &aff)
+                .map(Arc::new)
+                .map_err(|e| e.to_string());
+        }
+        Err(format!(
+            "no dictionary for language {language} in {}",
+            self.directory.display()
+        ))
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| HunspellError::Io(path.display().to_string(), e.to_string()).to_string())
+}
+
+impl DictionaryProvider for HunspellDirectory {
+    fn dictionary(&self, language: &str) -> Result<Arc<Dictionary>, String> {
+        let mut cache = self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache
+            .entry(language.to_string())
+            .or_insert_with(|| self.load(language))
+            .clone()
+    }
+}
+
+impl Debug for HunspellDirectory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HunspellDirectory")
+            .field("directory", &self.directory)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Words that are spelled correctly even though they are not in the dictionary, e.g. product
+/// names and vulnerability jargon. Words are compared case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowList {
+    global: HashSet<String>,
+    /// Words only allowed in documents of the publisher with the given namespace
+    by_publisher: HashMap<String, HashSet<String>>,
+}
+
+impl AllowList {
+    /// Create an allow-list with words allowed for all publishers.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        let mut allow_list = AllowList::default();
+        allow_list.allow(words);
+        allow_list
+    }
+
+    /// Allow words for all publishers.
+    pub fn allow<S: AsRef<str>>(&mut self, words: impl IntoIterator<Item = S>) {
+        self.global
+            .extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+    }
+
+    /// Allow words in documents of the publisher with the given namespace
+    /// (`/document/publisher/namespace`).
+    pub fn allow_for_publisher<S: AsRef<str>>(&mut self, namespace: &str, words: impl IntoIterator<Item = S>) {
+        self.by_publisher
+            .entry(namespace.trim_end_matches('/').to_string())
+            .or_default()
+            .extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+    }
+
+    /// Parse an allow-list with one word per line. Words after a `[namespace]` line are only allowed
+    /// for the publisher with that namespace, empty lines and lines starting with `#` are ignored.
+    ///
+    /// ```text
+    /// # allowed for all publishers
+    /// CVSS
+    /// [https://example.com]
+    /// ExampleOS
+    /// ```
+    pub fn parse(content: &str) -> Self {
+        let mut allow_list = AllowList::default();
+        let mut namespace: Option<&str> = None;
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                namespace = Some(section.trim());
+                continue;
+            }
+            match namespace {
+                Some(namespace) => allow_list.allow_for_publisher(namespace, [line]),
+                None => allow_list.allow([line]),
+            }
+        }
+        allow_list
+    }
+
+    /// Returns whether the word is allowed in documents of the publisher with the given namespace.
+    pub fn allows(&self, publisher_namespace: &str, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.global.contains(&word)
+            || self
+                .by_publisher
+                .get(publisher_namespace.trim_end_matches('/'))
+                .is_some_and(|words| words.contains(&word))
+    }
+}
+
+/// Checks the spelling of texts with the dictionaries of a [DictionaryProvider] and an
+/// [AllowList].
+pub struct SpellChecker {
+    provider: Box<dyn DictionaryProvider>,
+    allow_list: AllowList,
+}
+
+impl SpellChecker {
+    pub fn new(provider: impl DictionaryProvider + 'static) -> Self {
+        SpellChecker {
+            provider: Box::new(provider),
+            allow_list: AllowList::default(),
+        }
+    }
+
+    pub fn with_allow_list(mut self, allow_list: AllowList) -> Self {
+        self.allow_list = allow_list;
+        self
+    }
+
+    /// Returns the dictionary for a BCP 47 language tag, or why there is none.
+    pub fn dictionary(&self, language: &str) -> Result<Arc<Dictionary>, String> {
+        self.provider.dictionary(language)
+    }
+
+    /// Returns the distinct words of a text which are neither in the dictionary nor allowed for
+    /// the publisher, in the order of their first occurrence.
+    ///
+    /// URLs, e-mail addresses, words containing digits (like identifiers and versions) and words
+    /// in all caps (like acronyms) are not checked.
+    pub fn misspelled_words<'t>(
+        &self,
+        dictionary: &Dictionary,
+        publisher_namespace: &str,
+        text: &'t str,
+    ) -> Vec<&'t str> {
+        let mut misspelled = Vec::new();
+        for chunk in text.split_whitespace() {
+            if chunk.contains("://") || chunk.contains('@') || chunk.starts_with("www.") {
+                continue;
+            }
+            let words = chunk
+                .split(|c: char| !c.is_alphanumeric() && c != '\'' && c != '’')
+                .map(|word| word.trim_matches(['\'', '’']));
+            for word in words {
+                let skip = word.chars().count() < 2
+                    || word.chars().any(|c| c.is_numeric())
+                    || word.chars().all(|c| !c.is_lowercase());
+                if skip || misspelled.contains(&word) {
+                    continue;
+                }
+                let normalized = word.replace('’', "'");
+                if !dictionary.contains(&normalized) && !self.allow_list.allows(publisher_namespace, word) {
+                    misspelled.push(word);
+                }
+            }
+        }
+        misspelled
+    }
+}
+
+impl Debug for SpellChecker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpellChecker")
+            .field("allow_list", &self.allow_list)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIC: &str = "4\nadvisory/S\nupdate/DSU\nhurry/D\nflow/BS\n";
+    const AFF: &str = "\
+SET UTF-8
+PFX U Y 1
+PFX U 0 un .
+PFX B Y 1
+PFX B 0 over .
+SFX S Y 3
+SFX S y ies [^aeiou]y
+SFX S 0 s [aeiou]y
+SFX S 0 s [^y]
+SFX D Y 3
+SFX D 0 d e
+SFX D y ied [^aeiou]y
+SFX D 0 ed [^ey]
+";
+
+    #[test]
+    fn test_from_hunspell() {
+        let dictionary = Dictionary::from_hunspell(DIC, AFF).unwrap();
+        for word in [
+            "advisory",
+            "advisories",
+            "update",
+            "updated",
+            "unupdated",
+            "hurried",
+            "flow",
+            "overflow",
+            "overflows",
+        ] {
+            assert!(dictionary.contains(word), "{word} should be in the dictionary");
+        }
+        assert!(dictionary.contains("Advisory"));
+        for word in ["advisorys", "updateed", "hurryed", "unflow", "hurrys"] {
+            assert!(!dictionary.contains(word), "{word} should not be in the dictionary");
+        }
+
+        let long = Dictionary::from_hunspell("1\nflow/AaBb\n", "FLAG long\nSFX Bb Y 1\nSFX Bb 0 ing .\n").unwrap();
+        assert!(long.contains("flowing"));
+        let aliases = Dictionary::from_hunspell("1\nflow/1\n", "AF 1\nAF AB\nSFX B Y 1\nSFX B 0 ing .\n").unwrap();
+        assert!(aliases.contains("flowing"));
+        assert_eq!(
+            Dictionary::from_hunspell("", "FLAG unknown"),
+            Err(HunspellError::UnsupportedFlagType("unknown".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_hunspell_continuation_and_compounds() {
+        // `ize` continues with the suffixes of class D, `V` only exists with a suffix
+        let aff = "\
+NEEDAFFIX X
+COMPOUNDFLAG C
+ONLYINCOMPOUND O
+COMPOUNDMIN 3
+FORBIDDENWORD F
+SFX Z Y 1
+SFX Z 0 ize/D .
+SFX D Y 1
+SFX D 0 d e
+SFX V Y 1
+SFX V 0 ing .
+";
+        let dictionary =
+            Dictionary::from_hunspell("6\nnormal/Z\nsecur/XV\nfirm/C\nware/C\nfugen/OC\nwarez/F\n", aff).unwrap();
+        for word in [
+            "normalize",
+            "normalized",
+            "securing",
+            "firmware",
+            "warefirm",
+            "firmfugenware",
+        ] {
+            assert!(dictionary.contains(word), "{word} should be in the dictionary");
+        }
+        for word in ["normald", "secur", "fugen", "warez", "firmwar", "fiware"] {
+            assert!(!dictionary.contains(word), "{word} should not be in the dictionary");
+        }
+    }
+
+    #[test]
+    fn test_hunspell_encodings() {
+        let latin1 = Dictionary::from_hunspell_bytes(b"1\nna\xefve\n", b"SET ISO8859-1\n").unwrap();
+        assert!(latin1.contains("naïve"));
+        let utf8 = Dictionary::from_hunspell_bytes("1\nnaïve\n".as_bytes(), b"").unwrap();
+        assert!(utf8.contains("naïve"));
+        assert_eq!(
+            Dictionary::from_hunspell_bytes(b"1\n\xe4\n", b"SET KOI8-R\n"),
+            Err(HunspellError::UnsupportedEncoding("KOI8-R".to_string()))
+        );
+        assert_eq!(
+            Dictionary::from_hunspell_bytes(b"1\nna\xefve\n", b"SET UTF-8\n"),
+            Err(HunspellError::InvalidEncoding("UTF-8".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_misspelled_words() {
+        let dictionary = Dictionary::from_hunspell(DIC, AFF).unwrap();
+        let mut allow_list = AllowList::parse("# jargon\nRCE\n[https://example.com/]\nExampleOS\n");
+        allow_list.allow(["the", "in", "an", "of", "and", "fixes", "see"]);
+        let spell_checker = SpellChecker::new(Dictionary::default()).with_allow_list(allow_list);
+
+        let text = "The advisory updates fixes an overflow in ExampleOS 1.2.3 and CVE-2024-1234, see \
+                    https://example.com/advisry. Teh advisoy, teh RCE!";
+        assert_eq!(
+            spell_checker.misspelled_words(&dictionary, "https://example.com", text),
+            vec!["Teh", "advisoy", "teh"]
+        );
+        assert_eq!(
+            spell_checker.misspelled_words(&dictionary, "https://other.example", "ExampleOS"),
+            vec!["ExampleOS"]
+        );
+    }
+
+    #[test]
+    fn test_hunspell_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::write(directory.path().join("en_US.dic"), DIC).unwrap();
+        std::fs::write(directory.path().join("en_US.aff"), AFF).unwrap();
+        std::fs::write(directory.path().join("de.dic"), "1\nSicherheitshinweis\n").unwrap();
+
+        let provider = HunspellDirectory::new(directory.path());
+        assert!(provider.dictionary("en-US").unwrap().contains("advisories"));
+        assert!(provider.dictionary("de-DE").unwrap().contains("Sicherheitshinweis"));
+        assert!(
+            provider
+                .dictionary("fr")
+                .unwrap_err()
+                .starts_with("no dictionary for language fr")
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Information about the tests defined in section 6 of the CSAF standard, such as their titles
+//! and whether they are mandatory, recommended or informative.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Category of a test, which determines how a failure affects the validity of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestCategory {
+    /// Tests in section 6.1 (and the JSON schema), a failure makes the document invalid.
+    Mandatory,
+    /// Tests in section 6.2, a failure indicates a likely problem with the document.
+    Recommended,
+    /// Tests in section 6.3, a failure is a hint to improve the document.
+    Informative,
+}
+
+impl TestCategory {
+    /// Returns the category of the given test ID, derived from the section it is defined in.
+    ///
+    /// The categories of [registered rules](crate::custom_rules) are returned by
+    /// [RuleRegistry::test_category](crate::custom_rules::RuleRegistry::test_category).
+    pub fn of(test_id: &str) -> Option<Self> {
+        match test_id {
+            "schema" => Some(TestCategory::Mandatory),
+            id if id.starts_with("6.1.") => Some(TestCategory::Mandatory),
+            id if id.starts_with("6.2.") => Some(TestCategory::Recommended),
+            id if id.starts_with("6.3.") => Some(TestCategory::Informative),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TestCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            TestCategory::Mandatory => write!(f, "mandatory"),
+            TestCategory::Recommended => write!(f, "recommended"),
+            TestCategory::Informative => write!(f, "informative"),
+        }
+    }
+}
+
+/// State of the implementation of a test in this library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImplementationState {
+    /// The test is executed on the deserialized document.
+    Implemented,
+    /// The test operates on the raw JSON, so it is only executed on a
+    /// [RawDocument](crate::csaf::raw::RawDocument) and skipped otherwise.
+    RawDocumentOnly,
+    /// The test is defined in the standard, but not implemented yet. It is not part of any preset
+    /// and results in `NotFound` if requested explicitly.
+    NotImplemented,
+}
+
+impl ImplementationState {
+    /// Returns whether the test can be executed, at least on a raw document.
+    pub fn is_implemented(&self) -> bool {
+        *self != ImplementationState::NotImplemented
+    }
+}
+
+impl Display for ImplementationState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ImplementationState::Implemented => write!(f, "implemented"),
+            ImplementationState::RawDocumentOnly => write!(f, "raw document only"),
+            ImplementationState::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+/// Information about a test of a CSAF version, and whether this library implements it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestCapability {
+    /// The test ID as used in presets and test results, e.g. `6.1.27.3` or `schema`
+    pub test_id: &'static str,
+    /// The section of the standard defining the test. The JSON schema is part of section 3.
+    pub section: &'static str,
+    /// The title of the test in the standard
+    pub title: Option<&'static str>,
+    /// Whether the test is mandatory, recommended or informative
+    pub category: TestCategory,
+    /// Whether the test is implemented
+    pub state: ImplementationState,
+}
+
+impl TestCapability {
+    /// Returns the information about a test with the given implementation state.
+    ///
+    /// Returns `None` if the test ID does not belong to a section of the standard.
+    pub fn new(version: &str, test_id: &'static str, state: ImplementationState) -> Option<Self> {
+        Some(TestCapability {
+            test_id,
+            section: if test_id == "schema" { "3" } else { test_id },
+            title: test_title(version, test_id),
+            category: TestCategory::of(test_id)?,
+            state,
+        })
+    }
+}
+
+/// Returns all tests defined in the given CSAF version, in the order of the standard, with the
+/// state of their implementation.
+///
+/// The presets of a version only contain implemented tests of this list, see
+/// [Validatable::tests_in_preset](crate::validation::Validatable::tests_in_preset).
+///
+/// Returns `None` for unknown versions.
+pub fn test_capabilities(version: &str) -> Option<Vec<TestCapability>> {
+    use crate::validation::Validatable;
+    match version {
+        "2.0" => Some(crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework::test_capabilities()),
+        "2.1" => Some(crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework::test_capabilities()),
+        _ => None,
+    }
+}
+
+/// Returns the title of a test as given in the CSAF standard of the given version.
+///
+/// Returns `None` for unknown versions or tests.
+pub fn test_title(version: &str, test_id: &str) -> Option<&'static str> {
+    match version {
+        "2.0" => title_2_0(test_id),
+        "2.1" => title_2_1(test_id),
+        _ => None,
+    }
+}
+
+/// Titles that changed in CSAF 2.1 or do not exist in CSAF 2.0, everything else is shared.
+fn title_2_0(test_id: &str) -> Option<&'static str> {
+    match test_id {
+        "6.1.7" => Some("Multiple Scores with same Version per Product"),
+        "6.2.3" => Some("Missing Score"),
+        "6.3.6" => Some("Use of non-self referencing URLs Failing to Resolve"),
+        "6.3.7" => Some("Use of self referencing URLs Failing to Resolve"),
+        "6.3.8" => Some("Spell check"),
+        id => title_2_1(id).filter(|_| in_csaf_2_0(id)),
+    }
+}
+
+/// Returns whether the test is defined in CSAF 2.0, which ends with 6.1.33, 6.1.27.11, 6.2.20 and
+/// 6.3.11.
+fn in_csaf_2_0(test_id: &str) -> bool {
+    let Some(numbers) = test_id
+        .split('.')
+        .map(|n| n.parse::<u8>().ok())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return test_id == "schema";
+    };
+    match numbers[..] {
+        [6, 1, 27, n] => n <= 11,
+        [6, 1, n] => n <= 33,
+        [6, 2, n] => n <= 20,
+        [6, 3, n] => n <= 11,
+        _ => false,
+    }
+}
+
+fn title_2_1(test_id: &str) -> Option<&'static str> {
+    Some(match test_id {
+        "schema" => "JSON Schema",
+        // mandatory tests
+        "6.1.1" => "Missing Definition of Product ID",
+        "6.1.2" => "Multiple Definition of Product ID",
+        "6.1.3" => "Circular Definition of Product ID",
+        "6.1.4" => "Missing Definition of Product Group ID",
+        "6.1.5" => "Multiple Definition of Product Group ID",
+        "6.1.6" => "Contradicting Product Status",
+        "6.1.7" => "Multiple Scores with Same Version per Product",
+        "6.1.8" => "Invalid CVSS",
+        "6.1.9" => "Invalid CVSS computation",
+        "6.1.10" => "Inconsistent CVSS",
+        "6.1.11" => "CWE",
+        "6.1.12" => "Language",
+        "6.1.13" => "PURL",
+        "6.1.14" => "Sorted Revision History",
+        "6.1.15" => "Translator",
+        "6.1.16" => "Latest Document Version",
+        "6.1.17" => "Document Status Draft",
+        "6.1.18" => "Released Revision History",
+        "6.1.19" => "Revision History Entries for Pre-release Versions",
+        "6.1.20" => "Non-draft Document Version",
+        "6.1.21" => "Missing Item in Revision History",
+        "6.1.22" => "Multiple Definition in Revision History",
+        "6.1.23" => "Multiple Use of Same CVE",
+        "6.1.24" => "Multiple Definition in Involvements",
+        "6.1.25" => "Multiple Use of Same Hash Algorithm",
+        "6.1.26" => "Prohibited Document Category Name",
+        "6.1.27.1" => "Document Notes",
+        "6.1.27.2" => "Document References",
+        "6.1.27.3" => "Vulnerabilities",
+        "6.1.27.4" => "Product Tree",
+        "6.1.27.5" => "Vulnerability Notes",
+        "6.1.27.6" => "Product Status",
+        "6.1.27.7" => "VEX Product Status",
+        "6.1.27.8" => "Vulnerability ID",
+        "6.1.27.9" => "Impact Statement",
+        "6.1.27.10" => "Action Statement",
+        "6.1.27.11" => "Vulnerabilities",
+        "6.1.27.12" => "Affected Products",
+        "6.1.27.13" => "Corresponding Affected Products",
+        "6.1.27.14" => "Document Notes",
+        "6.1.27.15" => "Product Tree",
+        "6.1.27.16" => "Revision History",
+        "6.1.27.17" => "Reasoning for Withdrawal",
+        "6.1.27.18" => "Reasoning for Supersession",
+        "6.1.27.19" => "Reference to Superseding Document",
+        "6.1.28" => "Translation",
+        "6.1.29" => "Remediation without Product Reference",
+        "6.1.30" => "Mixed Integer and Semantic Versioning",
+        "6.1.31" => "Version Range in Product Version",
+        "6.1.32" => "Flag without Product Reference",
+        "6.1.33" => "Multiple Flags with VEX Justification Codes per Product",
+        "6.1.34" => "Branches Recursion Depth",
+        "6.1.35" => "Contradicting Remediations",
+        "6.1.36" => "Contradicting Product Status Remediation Combination",
+        "6.1.37" => "Date and Time",
+        "6.1.38" => "Non-Public Sharing Group with Max UUID",
+        "6.1.39" => "Public Sharing Group with no Max UUID",
+        "6.1.40" => "Invalid Sharing Group Name",
+        "6.1.41" => "Missing Sharing Group Name",
+        "6.1.42" => "PURL Consistency",
+        "6.1.43" => "Multiple Stars in Model Number",
+        "6.1.44" => "Multiple Stars in Serial Number",
+        "6.1.45" => "Inconsistent Disclosure Date",
+        "6.1.46" => "Invalid SSVC",
+        "6.1.47" => "Inconsistent SSVC Target IDs",
+        "6.1.48" => "SSVC Decision Points",
+        "6.1.49" => "Inconsistent SSVC Timestamp",
+        "6.1.50" => "Product Version Range Rules",
+        "6.1.51" => "Inconsistent EPSS Timestamp",
+        "6.1.52" => "Inconsistent First Known Exploitation Dates",
+        "6.1.53" => "Inconsistent Exploitation Date",
+        "6.1.54" => "License Expression",
+        "6.1.55" => "License Text",
+        "6.1.56" => "Use of CVSS and Qualitative Severity Rating",
+        "6.1.57" => "Stacked Branch Categories",
+        "6.1.58" => "Use of product_version in one Path with product_version_range",
+        "6.1.59" => "Contradicting Product Status and Flags",
+        "6.1.60.1" => "Inconsistent Flag Date",
+        "6.1.60.2" => "Inconsistent Involvement Date",
+        "6.1.60.3" => "Inconsistent Threat Date",
+        "6.1.61" => "Multiple Stars in Stock Keeping Unit",
+        // recommended tests
+        "6.2.1" => "Unused Definition of Product ID",
+        "6.2.2" => "Missing Remediation",
+        "6.2.3" => "Missing Metric",
+        "6.2.4" => "Build Metadata in Revision History",
+        "6.2.5" => "Older Initial Release Date than Revision History",
+        "6.2.6" => "Older Current Release Date than Revision History",
+        "6.2.7" => "Missing Date in Involvements",
+        "6.2.8" => "Use of MD5 as the only Hash Algorithm",
+        "6.2.9" => "Use of SHA-1 as the only Hash Algorithm",
+        "6.2.10" => "Missing TLP label",
+        "6.2.11" => "Missing Canonical URL",
+        "6.2.12" => "Missing Document Language",
+        "6.2.13" => "Sorting",
+        "6.2.14" => "Use of Private Language",
+        "6.2.15" => "Use of Default Language",
+        "6.2.16" => "Missing Product Identification Helper",
+        "6.2.17" => "CVE in field IDs",
+        "6.2.18" => "Product Version Range without vers",
+        "6.2.19" => "CVSS for Fixed Products",
+        "6.2.20" => "Additional Properties",
+        "6.2.21" => "Same Timestamps in Revision History",
+        "6.2.22" => "Document Tracking ID in Title",
+        "6.2.23" => "Usage of Deprecated CWE",
+        "6.2.24" => "Usage of Non-Latest CWE Version",
+        "6.2.25" => "Usage of CWE Not Allowed for Vulnerability Mapping",
+        "6.2.26" => "Usage of CWE Allowed with Review for Vulnerability Mapping",
+        "6.2.27" => "Discouraged Product Status Remediation Combination",
+        "6.2.28" => "Usage of Max UUID",
+        "6.2.29" => "Usage of Nil UUID",
+        "6.2.30" => "Usage of Sharing Group on TLP:CLEAR",
+        "6.2.31" => "Hardware and Software Mix",
+        "6.2.32" => "Use of Same Product Identification Helper for Different Products",
+        "6.2.33" => "Disclosure Date Newer than Revision History",
+        "6.2.34" => "Usage of Unknown SSVC Decision Point Base Namespace",
+        "6.2.35" => "Usage of Non-Latest SSVC Decision Point Version",
+        "6.2.36" => "Usage of SSVC Decision Point Namespace with Extension in TLP:CLEAR Document",
+        "6.2.37" => "Usage of Example SSVC Decision Point Namespace",
+        "6.2.38" => "Usage of Deprecated Profile",
+        "6.2.39.1" => "Language Specific License Text",
+        "6.2.39.2" => "Language Specific Reasoning for Withdrawal",
+        "6.2.39.3" => "Language Specific Reasoning for Supersession",
+        "6.2.39.4" => "Language Specific Reference to Superseding Document",
+        "6.2.41" => "Old EPSS Timestamp",
+        "6.2.47" => "Use of Qualitative Severity Rating by Issuing Party",
+        "6.2.48" => "Misuse at Vendor Name",
+        "6.2.52" => "Unknown Hash Algorithm",
+        "6.2.53" => "Matching Text for Registered ID System",
+        // informative tests
+        "6.3.1" => "Use of CVSS v2 as the only Scoring System",
+        "6.3.2" => "Use of CVSS v3.0",
+        "6.3.3" => "Missing CVE",
+        "6.3.4" => "Missing CWE",
+        "6.3.5" => "Use of Short Hash",
+        "6.3.6" => "Use of Non-Self Referencing URLs Failing to Resolve",
+        "6.3.7" => "Use of Self Referencing URLs Failing to Resolve",
+        "6.3.8" => "Spell Check",
+        "6.3.9" => "Branch Categories",
+        "6.3.10" => "Usage of Product Version Range",
+        "6.3.11" => "Usage of V as Version Indicator",
+        "6.3.12" => "Missing CVSS v4.0",
+        "6.3.13" => "Missing SSVC Decision Point Resources",
+        "6.3.14" => "Multiple Selections of Same SSVC Decision Point",
+        "6.3.15" => "Usage of SSVC Decision Point Namespace with Extension in Non-TLP:CLEAR Document",
+        "6.3.18" => "Use of Qualitative Severity Rating",
+        "6.3.20" => "Use of Unregistered ID System",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category() {
+        assert_eq!(TestCategory::of("schema"), Some(TestCategory::Mandatory));
+        assert_eq!(TestCategory::of("6.1.27.3"), Some(TestCategory::Mandatory));
+        assert_eq!(TestCategory::of("6.2.39.2"), Some(TestCategory::Recommended));
+        assert_eq!(TestCategory::of("6.3.1"), Some(TestCategory::Informative));
+        assert_eq!(TestCategory::of("6.4.1"), None);
+    }
+
+    #[test]
+    fn test_titles() {
+        assert_eq!(test_title("2.1", "6.1.27.3"), Some("Vulnerabilities"));
+        assert_eq!(test_title("2.0", "6.2.3"), Some("Missing Score"));
+        assert_eq!(test_title("2.1", "6.2.3"), Some("Missing Metric"));
+        assert_eq!(test_title("2.0", "6.1.33"), test_title("2.1", "6.1.33"));
+        // tests introduced with CSAF 2.1
+        assert_eq!(test_title("2.0", "6.1.34"), None);
+        assert_eq!(test_title("2.0", "6.1.27.12"), None);
+        assert_eq!(test_title("2.0", "6.2.21"), None);
+        assert_eq!(test_title("2.0", "6.3.12"), None);
+        assert_eq!(test_title("1.2", "6.1.1"), None);
+    }
+}
@@ -4,6 +4,7 @@
 //! expected results, with support for comparing validation errors while ignoring order.
 
 use crate::validation::TestFinding;
+use crate::validation_result::TestResult;
 
 /// Compare actual and expected test results.
 ///
@@ -11,14 +12,25 @@ use crate::validation::TestFinding;
 ///
 /// When both actual and expected are errors, this function compares the error lists
 /// ignoring the order of errors, but ensuring all expected errors are present.
+///
+/// An expected `Ok(())` matches every passed or skipped result, the reason is not compared.
 pub fn compare_test_results(
-    actual: &Result<(), Vec<TestFinding>>,
+    actual: &TestResult,
     expected: &Result<(), Vec<TestFinding>>,
     csaf_version: &str,
     test_id: &str,
     case_num: &str,
 ) -> Result<(), String> {
-    match (actual, expected) {
+    let actual = match actual {
+        TestResult::Failed(findings) => Err(findings.to_owned()),
+        TestResult::NotFound => {
+            return Err(format!(
+                "CSAF {csaf_version}: Test {test_id} case {case_num}: Test was not found."
+            ));
+        },
+        TestResult::Passed(_) | TestResult::Skipped(_) => Ok(()),
+    };
+    match (&actual, expected) {
         (Ok(()), Ok(())) => {
             // Both pass - good
             Ok(())
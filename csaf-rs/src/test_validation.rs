@@ -1,10 +1,17 @@
-use crate::validation::TestFinding;
+use crate::csaf::raw::RawDocument;
+use crate::test_info::{ImplementationState, TestCapability, TestCategory};
+use crate::validation::TestResultStatus;
+use crate::validation_result::TestResult;
 
 /// Trait for test validation logic.
 ///
 /// Implement this trait to provide validation logic for specific test cases.
 /// The trait is generic over the document type to allow validators to work
 /// with different CSAF versions.
+///
+/// Validation functions may either return a plain `Result<(), Vec<TestFinding>>` or a
+/// [TestResult] if they need to report why they passed or were skipped; the macros
+/// below convert both into a [TestResult].
 pub trait TestValidator<Doc> {
     /// Validate a CSAF document according to this test's requirements.
     ///
@@ -12,9 +19,10 @@ pub trait TestValidator<Doc> {
     /// * `doc` - The CSAF document to validate
     ///
     /// # Returns
-    /// * `Ok(())` if validation passes
-    /// * `Err(Vec<TestFinding>)` if validation fails
-    fn validate(&self, doc: &Doc) -> Result<(), Vec<TestFinding>>;
+    /// * `TestResult::Passed` with the reason if validation passes
+    /// * `TestResult::Failed` with the findings if validation fails
+    /// * `TestResult::Skipped` with the reason if the test could not be executed
+    fn validate(&self, doc: &Doc) -> TestResult;
 }
 
 /// Macro to generate the boilerplate `TestValidator<CommonSecurityAdvisoryFramework>` impl blocks
@@ -48,8 +56,8 @@ macro_rules! impl_validator {
             fn validate(
                 &self,
                 doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
-            ) -> Result<(), Vec<crate::validation::TestFinding>> {
-                $validate_fn(doc)
+            ) -> crate::validation_result::TestResult {
+                $validate_fn(doc).into()
             }
         }
     };
@@ -60,8 +68,8 @@ macro_rules! impl_validator {
             fn validate(
                 &self,
                 doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
-            ) -> Result<(), Vec<crate::validation::TestFinding>> {
-                $validate_fn(doc)
+            ) -> crate::validation_result::TestResult {
+                $validate_fn(doc).into()
             }
         }
     };
@@ -104,8 +112,8 @@ macro_rules! impl_raw_json_validator {
                 document: &crate::csaf::raw::RawDocument<
                     crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
                 >,
-            ) -> Result<(), Vec<crate::validation::TestFinding>> {
-                $validate_fn(document.get_json())
+            ) -> crate::validation_result::TestResult {
+                $validate_fn(document.get_json()).into()
             }
         }
     };
@@ -120,11 +128,136 @@ macro_rules! impl_raw_json_validator {
                 document: &crate::csaf::raw::RawDocument<
                     crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
                 >,
-            ) -> Result<(), Vec<crate::validation::TestFinding>> {
-                $validate_fn(document.get_json())
+            ) -> crate::validation_result::TestResult {
+                $validate_fn(document.get_json()).into()
             }
         }
     };
 }
 
 pub(crate) use impl_raw_json_validator;
+
+/// Implementation of a test in the dispatch table of a CSAF version, see [TestTable].
+pub(crate) enum TestRunner<Doc: 'static> {
+    /// The test operates on the deserialized document.
+    Parsed(fn(&Doc) -> TestResult),
+    /// The test operates on the raw JSON and can only be executed on a [RawDocument].
+    Raw(fn(&RawDocument<Doc>) -> TestResult),
+}
+
+/// Dispatch table of a CSAF version, listing the implementation of each implemented test by its
+/// ID. It is the single source for running tests, for the implementation state reported by
+/// [capabilities_from_table] and therefore for the presets.
+pub(crate) type TestTable<Doc> = [(&'static str, TestRunner<Doc>)];
+
+fn find_runner<Doc>(table: &'static TestTable<Doc>, test_id: &str) -> Option<&'static TestRunner<Doc>> {
+    table.iter().find(|(id, _)| *id == test_id).map(|(_, runner)| runner)
+}
+
+/// Converts the result of a test into the flat representation of a validation run, `None` means
+/// the test was not found.
+pub(crate) fn to_test_result(test_id: &str, result: Option<TestResult>) -> crate::validation::TestResult {
+    crate::validation::TestResult {
+        test_id: test_id.to_string(),
+        status: result.map_or(TestResultStatus::NotFound, |result| result.with_test_id(test_id).into()),
+    }
+}
+
+/// Runs a test of the table on a deserialized document.
+///
+/// Tests operating on the raw JSON are skipped, as they can only be executed on a [RawDocument].
+pub(crate) fn run_parsed_test<Doc>(
+    table: &'static TestTable<Doc>,
+    document: &Doc,
+    test_id: &str,
+) -> crate::validation::TestResult {
+    let result = match find_runner(table, test_id) {
+        Some(TestRunner::Parsed(run)) => Some(run(document)),
+        Some(TestRunner::Raw(_)) => Some(TestResult::precondition_failed(format!(
+            "Test {test_id} operates on the raw JSON document and can only be executed on a RawDocument."
+        ))),
+        None => None,
+    };
+    to_test_result(test_id, result)
+}
+
+/// Runs a test of the table which operates on the raw JSON, all other tests are `NotFound`.
+pub(crate) fn run_raw_test<Doc>(
+    table: &'static TestTable<Doc>,
+    document: &RawDocument<Doc>,
+    test_id: &str,
+) -> crate::validation::TestResult {
+    let result = match find_runner(table, test_id) {
+        Some(TestRunner::Raw(run)) => Some(run(document)),
+        Some(TestRunner::Parsed(_)) | None => None,
+    };
+    to_test_result(test_id, result)
+}
+
+/// Lists the given tests of the standard with the implementation state according to the table.
+pub(crate) fn capabilities_from_table<Doc>(
+    table: &'static TestTable<Doc>,
+    version: &str,
+    spec_tests: impl IntoIterator<Item = &'static str>,
+) -> Vec<TestCapability> {
+    spec_tests
+        .into_iter()
+        .filter_map(|test_id| {
+            let state = match find_runner(table, test_id) {
+                Some(TestRunner::Parsed(_)) => ImplementationState::Implemented,
+                Some(TestRunner::Raw(_)) => ImplementationState::RawDocumentOnly,
+                None => ImplementationState::NotImplemented,
+            };
+            TestCapability::new(version, test_id, state)
+        })
+        .collect()
+}
+
+/// Returns those of the given tests which are implemented, in the given order.
+pub(crate) fn implemented_tests(capabilities: &[TestCapability], test_ids: &[&'static str]) -> Vec<&'static str> {
+    test_ids
+        .iter()
+        .copied()
+        .filter(|test_id| {
+            capabilities
+                .iter()
+                .any(|capability| capability.test_id == *test_id && capability.state.is_implemented())
+        })
+        .collect()
+}
+
+/// Returns the presets shared by all CSAF versions, built from the implemented tests:
+/// `schema`, `mandatory`, `recommended`, `informative`, `basic`, `extended` and `full`.
+pub(crate) fn common_preset(capabilities: &[TestCapability], preset: &str) -> Option<Vec<&'static str>> {
+    if preset == "schema" {
+        return Some(implemented_tests(capabilities, &["schema"]));
+    }
+    let categories = preset_categories(preset)?;
+    // only the presets combining several categories include the schema
+    let with_schema = !matches!(preset, "mandatory" | "recommended" | "informative");
+    Some(
+        capabilities
+            .iter()
+            .filter(|capability| capability.state.is_implemented() && categories.contains(&capability.category))
+            .filter(|capability| with_schema || capability.test_id != "schema")
+            .map(|capability| capability.test_id)
+            .collect(),
+    )
+}
+
+/// Returns the categories of the tests in the presets which are built from categories, e.g. all
+/// of them for `full`.
+pub(crate) fn preset_categories(preset: &str) -> Option<&'static [TestCategory]> {
+    match preset {
+        "mandatory" | "basic" => Some(&[TestCategory::Mandatory]),
+        "recommended" => Some(&[TestCategory::Recommended]),
+        "informative" => Some(&[TestCategory::Informative]),
+        "extended" => Some(&[TestCategory::Mandatory, TestCategory::Recommended]),
+        "full" => Some(&[
+            TestCategory::Mandatory,
+            TestCategory::Recommended,
+            TestCategory::Informative,
+        ]),
+        _ => None,
+    }
+}
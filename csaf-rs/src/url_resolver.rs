@@ -0,0 +1,99 @@
+//! Resolution of URLs for the tests 6.3.6 and 6.3.7, which check that the URLs of references
+//! resolve.
+//!
+//! These are the only tests that need network access, so they are not run by default: without a
+//! resolver, they are skipped. To run them, validate a document with a [UrlResolver] in its
+//! [ValidationContext](crate::validation_context::ValidationContext), e.g. the `HttpUrlResolver`
+//! (with the `http-resolver` feature) or an [InMemoryUrlResolver] for tests and offline use.
+//!
+//! ```
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::url_resolver::InMemoryUrlResolver;
+//! use csaf::validation::validate_by_tests;
+//! use csaf::validation_context::{ValidationContext, WithContext};
+//!
+//! let document = load_document(r#"{"document": {"csaf_version": "2.1"}}"#).unwrap();
+//! let resolver = InMemoryUrlResolver::new(["https://example.com/advisory.json"]);
+//! let with_resolver = WithContext::new(&document, ValidationContext::new().with_url_resolver(&resolver));
+//! let result = validate_by_tests(&with_resolver, "2.1", &["6.3.6", "6.3.7"]);
+//! assert_eq!(result.test_results.len(), 2);
+//! ```
+
+use std::collections::HashSet;
+
+/// Resolves URLs, e.g. by requesting them via HTTP.
+pub trait UrlResolver: Send + Sync {
+    /// Returns `Ok(())` if the URL resolves, or the reason why it does not.
+    fn resolve(&self, url: &str) -> Result<(), String>;
+}
+
+/// A resolver with a fixed set of URLs that resolve, all others fail to resolve.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryUrlResolver {
+    resolvable: HashSet<String>,
+}
+
+impl InMemoryUrlResolver {
+    /// Create a resolver for which exactly the given URLs resolve.
+    pub fn new<S: Into<String>>(resolvable: impl IntoIterator<Item = S>) -> Self {
+        InMemoryUrlResolver {
+            resolvable: resolvable.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl UrlResolver for InMemoryUrlResolver {
+    fn resolve(&self, url: &str) -> Result<(), String> {
+        if self.resolvable.contains(url) {
+            Ok(())
+        } else {
+            Err("not found".to_string())
+        }
+    }
+}
+
+/// A resolver that sends HTTP `HEAD` requests (or `GET`, if the server does not support `HEAD`).
+///
+/// A URL resolves if the response has a status code from the 2xx (Successful) or 3xx (Redirection)
+/// class. Redirects are not followed.
+#[cfg(feature = "http-resolver")]
+#[derive(Debug, Clone)]
+pub struct HttpUrlResolver {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "http-resolver")]
+impl HttpUrlResolver {
+    /// Create a resolver which gives up on a URL after the given timeout.
+    pub fn new(timeout: std::time::Duration) -> Self {
+        let config = ureq::Agent::config_builder()
+            .max_redirects(0)
+            .http_status_as_error(false)
+            .timeout_global(Some(timeout))
+            .user_agent(concat!("csaf-rs/", env!("CARGO_PKG_VERSION")))
+            .build();
+        HttpUrlResolver { agent: config.into() }
+    }
+}
+
+#[cfg(feature = "http-resolver")]
+impl Default for HttpUrlResolver {
+    fn default() -> Self {
+        HttpUrlResolver::new(std::time::Duration::from_secs(10))
+    }
+}
+
+#[cfg(feature = "http-resolver")]
+impl UrlResolver for HttpUrlResolver {
+    fn resolve(&self, url: &str) -> Result<(), String> {
+        let mut status = self.agent.head(url).call().map_err(|e| e.to_string())?.status();
+        if status == 405 || status == 501 {
+            status = self.agent.get(url).call().map_err(|e| e.to_string())?.status();
+        }
+        if status.is_success() || status.is_redirection() {
+            Ok(())
+        } else {
+            Err(format!("HTTP status {status}"))
+        }
+    }
+}
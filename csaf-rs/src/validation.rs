@@ -1,8 +1,9 @@
 use std::fmt::Display;
 
-use TestResultStatus::*;
 use serde::{Deserialize, Serialize};
 
+pub use crate::validation_result::{Passed, Skipped};
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TestFindingData {
@@ -45,6 +46,15 @@ impl From<&TestFinding> for ValidationError {
     }
 }
 
+impl From<&ValidationError> for TestFindingData {
+    fn from(error: &ValidationError) -> Self {
+        TestFindingData {
+            message: error.message.clone(),
+            instance_path: error.instance_path.clone(),
+        }
+    }
+}
+
 impl std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "ValidationError: {} at {}", self.message, self.instance_path)
@@ -69,21 +79,55 @@ pub struct TestResult {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum TestResultStatus {
-    Success,
+    /// The test passed, see [Passed] for whether anything was actually checked.
+    Passed(Passed),
     Failure {
         errors: Vec<ValidationError>,
         warnings: Vec<ValidationError>,
         infos: Vec<ValidationError>,
     },
     NotFound,
-    Skipped,
+    /// The test could not be executed, see [Skipped] for why.
+    Skipped(Skipped),
+}
+
+impl From<crate::validation_result::TestResult> for TestResultStatus {
+    fn from(result: crate::validation_result::TestResult) -> Self {
+        use crate::validation_result::TestResult;
+        match result {
+            TestResult::Passed(passed) => TestResultStatus::Passed(passed),
+            TestResult::Failed(findings) => TestResultStatus::Failure {
+                errors: findings
+                    .iter()
+                    .filter(|f| matches!(f, TestFinding::Error(_)))
+                    .map(ValidationError::from)
+                    .collect(),
+                warnings: findings
+                    .iter()
+                    .filter(|f| matches!(f, TestFinding::Warning(_)))
+                    .map(ValidationError::from)
+                    .collect(),
+                infos: findings
+                    .iter()
+                    .filter(|f| matches!(f, TestFinding::Information(_)))
+                    .map(ValidationError::from)
+                    .collect(),
+            },
+            TestResult::Skipped(skipped) => TestResultStatus::Skipped(skipped),
+            TestResult::NotFound => TestResultStatus::NotFound,
+        }
+    }
 }
 
 impl Display for TestResultStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Success => write!(f, "Success"),
-            Failure {
+            TestResultStatus::Passed(Passed::Success) => write!(f, "Success"),
+            TestResultStatus::Passed(Passed::NoData { message }) => write!(f, "Passed (no data: {message})"),
+            TestResultStatus::Passed(Passed::NotApplicable { message }) => {
+                write!(f, "Passed (not applicable: {message})")
+            },
+            TestResultStatus::Failure {
                 errors,
                 warnings,
                 infos,
@@ -94,8 +138,13 @@ impl Display for TestResultStatus {
                 warnings.len(),
                 infos.len()
             ),
-            NotFound => write!(f, "Test not found"),
-            Skipped => write!(f, "Test skipped"),
+            TestResultStatus::NotFound => write!(f, "Test not found"),
+            TestResultStatus::Skipped(Skipped::DeserializationFailed { message }) => {
+                write!(f, "Test skipped (deserialization failed: {message})")
+            },
+            TestResultStatus::Skipped(Skipped::PreconditionFailed { message, .. }) => {
+                write!(f, "Test skipped (precondition failed: {message})")
+            },
         }
     }
 }
@@ -137,9 +186,19 @@ pub trait Validate {
 ///
 /// It can then be used to validate documents with [validate_by_preset] or [validate_by_tests].
 pub trait Validatable {
-    /// Returns the test IDs belonging to a preset
+    /// Returns all tests of the CSAF version with the state of their implementation, see
+    /// [test_capabilities](crate::test_info::test_capabilities).
+    fn test_capabilities() -> Vec<crate::test_info::TestCapability>;
+
+    /// Returns the implemented test IDs belonging to a preset
     fn tests_in_preset(preset: &str) -> Option<Vec<&'static str>>;
 
+    /// Returns the test IDs belonging to a preset when validating this target, which includes the
+    /// rules of a [WithContext](crate::validation_context::WithContext) wrapper.
+    fn preset_tests(&self, preset: &str) -> Option<Vec<&str>> {
+        Self::tests_in_preset(preset)
+    }
+
     /// Runs a test by test ID
     fn run_test(&self, test_id: &str) -> TestResult;
 }
@@ -156,17 +215,23 @@ pub fn validate_by_test(target: &impl Validatable, test_id: &str) -> TestResult
 
 /// Validate document with specific tests and return detailed results.
 pub fn validate_by_tests(target: &impl Validatable, version: &str, test_ids: &[&str]) -> ValidationResult {
-    let mut test_results = Vec::new();
+    let test_results = test_ids
+        .iter()
+        .map(|test_id| validate_by_test(target, test_id))
+        .collect();
+    aggregate_test_results(version, test_results)
+}
+
+/// Gather the numbers of errors, warnings, infos and tests not found from the test results.
+pub(crate) fn aggregate_test_results(version: &str, test_results: Vec<TestResult>) -> ValidationResult {
     let mut num_errors: usize = 0;
     let mut num_warnings: usize = 0;
     let mut num_infos: usize = 0;
     let mut num_not_found: usize = 0;
 
-    // Loop through tests and gather all results and errors
-    for test_id in test_ids {
-        let test_result = validate_by_test(target, test_id);
+    for test_result in &test_results {
         match &test_result.status {
-            Failure {
+            TestResultStatus::Failure {
                 errors,
                 warnings,
                 infos,
@@ -175,12 +240,11 @@ pub fn validate_by_tests(target: &impl Validatable, version: &str, test_ids: &[&
                 num_warnings += warnings.len();
                 num_infos += infos.len();
             },
-            NotFound => {
+            TestResultStatus::NotFound => {
                 num_not_found += 1;
             },
             _ => {},
         }
-        test_results.push(test_result);
     }
 
     ValidationResult {
@@ -197,8 +261,142 @@ pub fn validate_by_tests(target: &impl Validatable, version: &str, test_ids: &[&
 /// Validate document with a preset and return detailed results.
 pub fn validate_by_preset<V: Validatable>(target: &V, version: &str, preset: &str) -> ValidationResult {
     // Retrieve the test IDs for the given preset
-    let test_ids: Vec<&str> = V::tests_in_preset(preset).unwrap_or(vec![]);
+    let test_ids: Vec<&str> = target.preset_tests(preset).unwrap_or(vec![]);
 
     // Forward them to validate_by_tests
     validate_by_tests(target, version, &test_ids)
 }
+
+/// Options for validating many documents at once, see [validate_batch_by_tests].
+#[cfg(feature = "parallel")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// Number of threads to use, `0` uses one thread per available CPU
+    pub jobs: usize,
+    /// Also run the tests of each document in parallel, which helps if there are fewer (or much
+    /// larger) documents than threads
+    pub parallel_tests: bool,
+}
+
+/// Apply `f` to all items on a thread pool configured by `options` and return the results in
+/// the order of the items.
+///
+/// This is the building block of [validate_batch_by_tests], and can be used to parallelize
+/// loading and validation of documents together.
+#[cfg(feature = "parallel")]
+pub fn run_batch<I, R, F>(items: &[I], options: &BatchOptions, f: F) -> Result<Vec<R>, rayon::ThreadPoolBuildError>
+where
+    I: Sync,
+    R: Send,
+    F: Fn(&I) -> R + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(options.jobs).build()?;
+    Ok(pool.install(|| items.par_iter().map(f).collect()))
+}
+
+/// Validate many documents with specific tests in parallel and return detailed results, in the
+/// order of the documents.
+///
+/// Fails only if the thread pool could not be created.
+#[cfg(feature = "parallel")]
+pub fn validate_batch_by_tests<V>(
+    documents: &[V],
+    version: &str,
+    test_ids: &[&str],
+    options: &BatchOptions,
+) -> Result<Vec<ValidationResult>, rayon::ThreadPoolBuildError>
+where
+    V: Validatable + Sync,
+{
+    run_batch(documents, options, |document| {
+        validate_in_batch(document, version, test_ids, options)
+    })
+}
+
+/// Validate one document of a batch, running its tests in parallel if requested.
+#[cfg(feature = "parallel")]
+fn validate_in_batch(
+    document: &(impl Validatable + Sync),
+    version: &str,
+    test_ids: &[&str],
+    options: &BatchOptions,
+) -> ValidationResult {
+    use rayon::prelude::*;
+
+    if options.parallel_tests {
+        let test_results = test_ids
+            .par_iter()
+            .map(|test_id| validate_by_test(document, test_id))
+            .collect();
+        aggregate_test_results(version, test_results)
+    } else {
+        validate_by_tests(document, version, test_ids)
+    }
+}
+
+/// Validate many documents with a preset in parallel and return detailed results, in the order
+/// of the documents.
+///
+/// Fails only if the thread pool could not be created.
+#[cfg(feature = "parallel")]
+pub fn validate_batch_by_preset<V>(
+    documents: &[V],
+    version: &str,
+    preset: &str,
+    options: &BatchOptions,
+) -> Result<Vec<ValidationResult>, rayon::ThreadPoolBuildError>
+where
+    V: Validatable + Sync,
+{
+    run_batch(documents, options, |document| {
+        let test_ids: Vec<&str> = document.preset_tests(preset).unwrap_or(vec![]);
+        validate_in_batch(document, version, &test_ids, options)
+    })
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::loader::load_document;
+
+    /// Some tests report their findings in hash map order, so only compare the test IDs and counts
+    fn summarize(result: &ValidationResult) -> (Vec<String>, usize, usize, usize) {
+        let test_ids = result.test_results.iter().map(|r| r.test_id.clone()).collect();
+        (test_ids, result.num_errors, result.num_warnings, result.num_infos)
+    }
+
+    #[test]
+    fn test_validate_batch_by_preset() {
+        let dir = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../type-generator/assets/tests/csaf_2.1/mandatory"
+        );
+        let mut paths: Vec<_> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+        let documents: Vec<_> = paths
+            .iter()
+            .take(20)
+            .filter_map(|path| load_document(path.as_path()).ok())
+            .collect();
+        assert!(documents.len() > 1);
+
+        let sequential: Vec<_> = documents
+            .iter()
+            .map(|document| summarize(&validate_by_preset(document, "2.1", "mandatory")))
+            .collect();
+        for parallel_tests in [false, true] {
+            let options = BatchOptions {
+                jobs: 4,
+                parallel_tests,
+            };
+            let batch = validate_batch_by_preset(&documents, "2.1", "mandatory", &options).unwrap();
+            assert_eq!(batch.iter().map(summarize).collect::<Vec<_>>(), sequential);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Everything beyond the document that tests and rules may need: the [UrlResolver] for the tests
+//! 6.3.6 and 6.3.7, the [SpellChecker] for the test 6.3.8 and the [RuleRegistry] with
+//! [organization-specific rules](crate::custom_rules).
+//!
+//! All of them are optional. A [ValidationContext] collects those that are available, and a
+//! document wrapped with [WithContext] is validated with them: the tests needing a missing
+//! resolver or spell checker are skipped as usual, and without a registry, only the tests of the
+//! standard are known.
+//!
+//! ```
+//! use csaf::csaf2_1::loader::load_document;
+//! use csaf::custom_rules::RuleRegistry;
+//! use csaf::spell_check::{Dictionary, SpellChecker};
+//! use csaf::url_resolver::InMemoryUrlResolver;
+//! use csaf::validation::validate_by_preset;
+//! use csaf::validation_context::{ValidationContext, WithContext};
+//!
+//! let resolver = InMemoryUrlResolver::new(["https://example.com/advisory.json"]);
+//! let spell_checker = SpellChecker::new(Dictionary::new(["advisory"]));
+//! let rules = RuleRegistry::new();
+//! let context = ValidationContext::new()
+//!     .with_url_resolver(&resolver)
+//!     .with_spell_checker(&spell_checker)
+//!     .with_rules(&rules);
+//!
+//! let document = load_document(r#"{"document": {"csaf_version": "2.1", "lang": "en"}}"#).unwrap();
+//! let result = validate_by_preset(&WithContext::new(&document, context), "2.1", "full");
+//! assert!(result.test_results.iter().any(|r| r.test_id == "6.3.8"));
+//! ```
+
+use crate::csaf::raw::HasParsed;
+use crate::csaf_traits::CsafTrait;
+use crate::custom_rules::{RuleRegistry, RuleTarget};
+use crate::spell_check::SpellChecker;
+use crate::test_validation::to_test_result;
+use crate::url_resolver::UrlResolver;
+use crate::validation::{TestResult, Validatable};
+use crate::validation_result;
+use crate::validations::test_6_3_06::test_6_3_6_non_self_referencing_urls_failing_to_resolve;
+use crate::validations::test_6_3_07::test_6_3_7_self_referencing_urls_failing_to_resolve;
+use crate::validations::test_6_3_08::test_6_3_8_spell_check;
+
+/// The optional resolver, spell checker and rules used when validating a document, see
+/// [WithContext].
+#[derive(Clone, Copy, Default)]
+pub struct ValidationContext<'a> {
+    url_resolver: Option<&'a dyn UrlResolver>,
+    spell_checker: Option<&'a SpellChecker>,
+    rules: Option<&'a RuleRegistry>,
+}
+
+impl<'a> ValidationContext<'a> {
+    /// Create a context without resolver, spell checker and rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the resolver for the tests 6.3.6 and 6.3.7.
+    pub fn with_url_resolver(mut self, url_resolver: &'a dyn UrlResolver) -> Self {
+        self.url_resolver = Some(url_resolver);
+        self
+    }
+
+    /// Use the spell checker for the test 6.3.8.
+    pub fn with_spell_checker(mut self, spell_checker: &'a SpellChecker) -> Self {
+        self.spell_checker = Some(spell_checker);
+        self
+    }
+
+    /// Run the rules of the registry alongside the tests of the standard.
+    pub fn with_rules(mut self, rules: &'a RuleRegistry) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// The resolver for the tests 6.3.6 and 6.3.7, if any.
+    pub fn url_resolver(&self) -> Option<&'a dyn UrlResolver> {
+        self.url_resolver
+    }
+
+    /// The spell checker for the test 6.3.8, if any.
+    pub fn spell_checker(&self) -> Option<&'a SpellChecker> {
+        self.spell_checker
+    }
+
+    /// The registry of the rules run alongside the tests of the standard, if any.
+    pub fn rules(&self) -> Option<&'a RuleRegistry> {
+        self.rules
+    }
+}
+
+/// A document along with the [ValidationContext] it is validated with.
+///
+/// The document is usually a [RawDocument](crate::csaf::raw::RawDocument): rules on the raw JSON
+/// can only be executed on it.
+pub struct WithContext<'a, D> {
+    document: &'a D,
+    context: ValidationContext<'a>,
+}
+
+impl<'a, D> WithContext<'a, D> {
+    pub fn new(document: &'a D, context: ValidationContext<'a>) -> Self {
+        WithContext { document, context }
+    }
+
+    /// Runs a test on the deserialized document, which is skipped if deserialization failed.
+    fn run_parsed(
+        &self,
+        test: impl FnOnce(&D::Parsed) -> validation_result::TestResult,
+    ) -> validation_result::TestResult
+    where
+        D: HasParsed,
+    {
+        match self.document.get_parsed() {
+            Ok(parsed) => test(parsed),
+            Err(message) => validation_result::TestResult::deserialization_failed(message.as_str()),
+        }
+    }
+}
+
+impl<D: HasParsed> HasParsed for WithContext<'_, D> {
+    type Parsed = D::Parsed;
+
+    fn get_parsed(&self) -> &Result<Self::Parsed, String> {
+        self.document.get_parsed()
+    }
+
+    fn get_raw_json(&self) -> Option<&serde_json::Value> {
+        self.document.get_raw_json()
+    }
+}
+
+impl<D> Validatable for WithContext<'_, D>
+where
+    D: Validatable + HasParsed,
+    D::Parsed: CsafTrait + RuleTarget,
+{
+    fn test_capabilities() -> Vec<crate::test_info::TestCapability> {
+        D::test_capabilities()
+    }
+
+    fn tests_in_preset(preset: &str) -> Option<Vec<&'static str>> {
+        D::tests_in_preset(preset)
+    }
+
+    fn preset_tests(&self, preset: &str) -> Option<Vec<&str>> {
+        let tests = self.document.preset_tests(preset)?;
+        Some(match self.context.rules {
+            Some(rules) => rules.extend_preset(preset, tests),
+            None => tests,
+        })
+    }
+
+    fn run_test(&self, test_id: &str) -> TestResult {
+        if let Some(rule) = self.context.rules.and_then(|rules| rules.get(test_id)) {
+            return to_test_result(test_id, Some(rule.run(self.document)));
+        }
+        let result = match (test_id, self.context.url_resolver, self.context.spell_checker) {
+            ("6.3.6", Some(resolver), _) => {
+                self.run_parsed(|doc| test_6_3_6_non_self_referencing_urls_failing_to_resolve(doc, resolver))
+            },
+            ("6.3.7", Some(resolver), _) => {
+                self.run_parsed(|doc| test_6_3_7_self_referencing_urls_failing_to_resolve(doc, resolver))
+            },
+            ("6.3.8", _, Some(spell_checker)) => self.run_parsed(|doc| test_6_3_8_spell_check(doc, spell_checker)),
+            _ => return self.document.run_test(test_id),
+        };
+        to_test_result(test_id, Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_rules::CsafRule;
+    use crate::spell_check::Dictionary;
+    use crate::test_info::TestCategory;
+    use crate::url_resolver::InMemoryUrlResolver;
+    use crate::validation::{Passed, Skipped, TestResultStatus, validate_by_preset, validate_by_tests};
+    use crate::validations::utils::url_references::document_with_references;
+
+    struct AlwaysPasses;
+
+    impl CsafRule for AlwaysPasses {
+        fn validate<Doc: CsafTrait>(&self, _doc: &Doc) -> validation_result::TestResult {
+            Ok(()).into()
+        }
+    }
+
+    #[test]
+    fn test_with_context() {
+        let document = document_with_references(
+            &[("self", "https://example.com/.well-known/csaf/white/2024/advisory.json")],
+            &[("external", "https://example.com/blog/advisory")],
+        );
+        let resolver = InMemoryUrlResolver::new(["https://example.com/.well-known/csaf/white/2024/advisory.json"]);
+        let spell_checker = SpellChecker::new(Dictionary::new(["advisory"]));
+        let mut rules = RuleRegistry::new();
+        rules
+            .register_rule("test.pass", "Always Passes", TestCategory::Informative, AlwaysPasses)
+            .unwrap();
+        let tests = ["6.3.6", "6.3.7", "6.3.8", "test.pass"];
+
+        let context = ValidationContext::new()
+            .with_url_resolver(&resolver)
+            .with_spell_checker(&spell_checker)
+            .with_rules(&rules);
+        let with_context = WithContext::new(&document, context);
+        let result = validate_by_tests(&with_context, "2.1", &tests);
+        assert_eq!(result.num_infos, 1);
+        assert_eq!(result.test_results[1].status, TestResultStatus::Passed(Passed::Success));
+        // the spell checker is used, but the document has no language
+        assert_eq!(
+            result.test_results[2].status,
+            TestResultStatus::Skipped(Skipped::PreconditionFailed {
+                test_id: "6.3.8".to_string(),
+                message: "The document language is not given.".to_string(),
+            })
+        );
+        assert_eq!(result.test_results[3].status, TestResultStatus::Passed(Passed::Success));
+        assert!(with_context.preset_tests("full").unwrap().contains(&"test.pass"));
+        assert!(
+            validate_by_preset(&with_context, "2.1", "informative")
+                .test_results
+                .iter()
+                .any(|r| r.test_id == "test.pass")
+        );
+
+        // without a resolver and spell checker, their tests are skipped, and without the
+        // registry, the rule is unknown
+        let result = validate_by_tests(&WithContext::new(&document, ValidationContext::new()), "2.1", &tests);
+        assert!(
+            result.test_results[..3]
+                .iter()
+                .all(|r| matches!(r.status, TestResultStatus::Skipped(Skipped::PreconditionFailed { .. })))
+        );
+        assert_eq!(result.test_results[3].status, TestResultStatus::NotFound);
+    }
+}
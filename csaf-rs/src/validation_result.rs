@@ -1,7 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+use crate::validation::TestFinding;
 
 /// This represents the result of a test run for a single document.
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
     /// overall result, either valid, invalid, or undetermined (e.g. if no tests were executed)
     pub document_status: DocumentStatus,
@@ -14,7 +16,7 @@ pub struct ValidationResult {
 }
 
 /// The final status of the document after running all tests. This is determined by the individual test results and their severity.
-#[derive(Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DocumentStatus {
     /// Cannot determine validity (e.g. no tests executed)
     Undetermined,
@@ -25,7 +27,7 @@ pub enum DocumentStatus {
 }
 
 /// Summary of the test results, including counts of total tests, passed, failed, skipped, and not found.
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ValidationSummary {
     /// Total number of tests along with counts of errors, warnings, and info findings
     pub total: Statistic,
@@ -40,7 +42,7 @@ pub struct ValidationSummary {
 }
 
 /// Statistic for a group of tests, including the count of tests and counts of errors, warnings, and info findings.
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Statistic {
     /// Total number of tests
     pub test_count: usize,
@@ -53,7 +55,7 @@ pub struct Statistic {
 }
 
 /// Result of a single test run, including the test ID and the result.
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TestRunResult {
     /// The ID of the test that was run.
     pub test_id: String,
@@ -62,7 +64,10 @@ pub struct TestRunResult {
 }
 
 /// The result of a single test, which can be passed, failed, skipped, or not found. Failed tests include a list of findings with their severity and details.
-#[derive(Serialize, PartialEq)]
+///
+/// This is also what every [`TestValidator`](crate::test_validation::TestValidator) returns, so each
+/// test can report *why* it passed or was skipped.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TestResult {
     /// The test passed successfully.
     Passed(Passed),
@@ -74,32 +79,21 @@ pub enum TestResult {
     NotFound,
 }
 
-/// Classification of a test finding.
-#[derive(Serialize, PartialEq)]
-#[serde(tag = "severity")]
-pub enum TestFinding {
-    /// An information indicates a failure in an informative test, which does not necessarily mean the document is invalid,
-    /// but may provide insights into common mistakes or bad practices.
-    Infomation(TestFindingData),
-    /// A warning indicates a failure in a recommended test, which does not necessarily mean the document is invalid.
-    /// However, it may indicate potential issues or areas for improvement in the document.
-    Warning(TestFindingData),
-    /// An error indicates a failure in a mandatory test, which means the document is invalid.
-    Error(TestFindingData),
-}
-
 /// The reason why a test was skipped.
-#[derive(Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(tag = "reason")]
 pub enum Skipped {
     /// The test was skipped because the document could not be deserialized, which is a prerequisite for running the test.
     DeserializationFailed { message: String },
     /// The test was skipped because a precondition for the test was not met, such as missing required data or an unsupported feature in the document.
+    ///
+    /// `test_id` is the ID of the skipped test, so the reason can be reported on its own. Validators
+    /// don't know their test ID, it is filled in by the test runner.
     PreconditionFailed { test_id: String, message: String },
 }
 
 /// The reason why a test passed.
-#[derive(Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(tag = "reason")]
 pub enum Passed {
     /// The test passed successfully without any issues.
@@ -110,11 +104,86 @@ pub enum Passed {
     NotApplicable { message: String },
 }
 
-/// The details of a test finding, including the message and the instance path where the finding occurred.
-#[derive(Serialize, PartialEq)]
-pub struct TestFindingData {
-    /// A message describing the finding, which can provide details about the issue or information related to the test result.
-    pub message: String,
-    /// The instance path in the document where the finding occurred, which can help identify the specific location of the issue or information in the document structure.
-    pub instance_path: String,
+impl TestResult {
+    /// Shorthand for a [`Passed::NoData`] result.
+    pub fn no_data(message: impl Into<String>) -> Self {
+        TestResult::Passed(Passed::NoData {
+            message: message.into(),
+        })
+    }
+
+    /// Shorthand for a [`Passed::NotApplicable`] result.
+    pub fn not_applicable(message: impl Into<String>) -> Self {
+        TestResult::Passed(Passed::NotApplicable {
+            message: message.into(),
+        })
+    }
+
+    /// Shorthand for a [`Skipped::PreconditionFailed`] result.
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        TestResult::Skipped(Skipped::PreconditionFailed {
+            test_id: String::new(),
+            message: message.into(),
+        })
+    }
+
+    /// Sets the ID of the test this result belongs to where the result refers to it.
+    pub(crate) fn with_test_id(mut self, id: &str) -> Self {
+        if let TestResult::Skipped(Skipped::PreconditionFailed { test_id, .. }) = &mut self {
+            *test_id = id.to_string();
+        }
+        self
+    }
+
+    /// Shorthand for a [`Skipped::DeserializationFailed`] result.
+    pub fn deserialization_failed(message: impl Into<String>) -> Self {
+        TestResult::Skipped(Skipped::DeserializationFailed {
+            message: message.into(),
+        })
+    }
+}
+
+/// Validation functions that only distinguish between "no findings" and "findings" still return a plain
+/// `Result`, which maps to [`Passed::Success`] and [`TestResult::Failed`] respectively.
+impl From<Result<(), Vec<TestFinding>>> for TestResult {
+    fn from(result: Result<(), Vec<TestFinding>>) -> Self {
+        match result {
+            Ok(()) => TestResult::Passed(Passed::Success),
+            Err(findings) => TestResult::Failed(findings),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::TestFindingData;
+
+    #[test]
+    fn plain_results_map_to_success_and_failed() {
+        let finding = TestFinding::Error(TestFindingData {
+            message: "bad".to_string(),
+            instance_path: "/document".to_string(),
+        });
+        assert_eq!(TestResult::from(Ok(())), TestResult::Passed(Passed::Success));
+        assert_eq!(
+            TestResult::from(Err(vec![finding.clone()])),
+            TestResult::Failed(vec![finding])
+        );
+    }
+
+    #[test]
+    fn runner_sets_test_id_of_failed_precondition() {
+        assert_eq!(
+            TestResult::precondition_failed("The revision history is empty.").with_test_id("6.1.16"),
+            TestResult::Skipped(Skipped::PreconditionFailed {
+                test_id: "6.1.16".to_string(),
+                message: "The revision history is empty.".to_string(),
+            })
+        );
+        assert_eq!(
+            TestResult::no_data("nothing").with_test_id("6.1.16"),
+            TestResult::no_data("nothing")
+        );
+    }
 }
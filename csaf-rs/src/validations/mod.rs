@@ -39,6 +39,7 @@ pub mod test_6_1_27_09;
 pub mod test_6_1_27_10;
 pub mod test_6_1_27_11;
 pub mod test_6_1_27_12;
+pub mod test_6_1_27_13;
 pub mod test_6_1_27_14;
 pub mod test_6_1_27_15;
 pub mod test_6_1_27_16;
@@ -68,6 +69,7 @@ pub mod test_6_1_46;
 pub mod test_6_1_47;
 pub mod test_6_1_48;
 pub mod test_6_1_49;
+pub mod test_6_1_50;
 pub mod test_6_1_51;
 pub mod test_6_1_52;
 pub mod test_6_1_53;
@@ -76,6 +78,10 @@ pub mod test_6_1_55;
 pub mod test_6_1_56;
 pub mod test_6_1_57;
 pub mod test_6_1_58;
+pub mod test_6_1_59;
+pub mod test_6_1_60_1;
+pub mod test_6_1_60_2;
+pub mod test_6_1_60_3;
 pub mod test_6_1_61;
 
 pub mod test_6_2_01;
@@ -96,7 +102,7 @@ pub mod test_6_2_15;
 pub mod test_6_2_16;
 pub mod test_6_2_17;
 pub mod test_6_2_18;
-// pub mod test_6_2_19;
+pub mod test_6_2_19;
 pub mod test_6_2_20;
 pub mod test_6_2_21;
 pub mod test_6_2_22;
@@ -104,6 +110,7 @@ pub mod test_6_2_23;
 pub mod test_6_2_24;
 pub mod test_6_2_25;
 pub mod test_6_2_26;
+pub mod test_6_2_27;
 pub mod test_6_2_28;
 pub mod test_6_2_29;
 pub mod test_6_2_30;
@@ -111,10 +118,14 @@ pub mod test_6_2_31;
 pub mod test_6_2_32;
 pub mod test_6_2_33;
 pub mod test_6_2_34;
+pub mod test_6_2_35;
 pub mod test_6_2_36;
+pub mod test_6_2_37;
 pub mod test_6_2_38;
+pub mod test_6_2_39_1;
 pub mod test_6_2_39_2;
 pub mod test_6_2_39_3;
+pub mod test_6_2_39_4;
 pub mod test_6_2_41;
 pub mod test_6_2_47;
 pub mod test_6_2_48;
@@ -126,13 +137,15 @@ pub mod test_6_3_02;
 pub mod test_6_3_03;
 pub mod test_6_3_04;
 pub mod test_6_3_05;
-// pub mod test_6_3_06;
-// pub mod test_6_3_07;
-// pub mod test_6_3_08;
+pub mod test_6_3_06;
+pub mod test_6_3_07;
+pub mod test_6_3_08;
 pub mod test_6_3_09;
 pub mod test_6_3_10;
 pub mod test_6_3_11;
 pub mod test_6_3_12;
+pub mod test_6_3_13;
+pub mod test_6_3_14;
 pub mod test_6_3_15;
 pub mod test_6_3_18;
 pub mod test_6_3_20;
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 fn generate_multiple_group_id_definition_error(group_id: &str, path: &str) -> TestFinding {
@@ -11,19 +12,15 @@ fn generate_multiple_group_id_definition_error(group_id: &str, path: &str) -> Te
 
 /// 6.1.5 Multiple Definition of Product Group ID
 /// Checks that all product group IDs defined in the document are unique.
-pub fn test_6_1_05_multiple_definition_of_product_group_id(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
-    // Check if there is a product tree, if there isn't, this test can be skipped
+pub fn test_6_1_05_multiple_definition_of_product_group_id(doc: &impl CsafTrait) -> TestResult {
     let Some(tree) = doc.get_product_tree() else {
-        // This will be WasSkipped in the future
-        return Ok(());
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     let product_groups = tree.get_product_groups();
 
-    // Check if there are any product groups, if there aren't, this test can be skipped
     if product_groups.is_empty() {
-        // This will be WasSkipped in the future
-        return Ok(());
+        return TestResult::no_data("The document does not contain any product groups.");
     }
 
     // Create a map of product group IDs to the JSON paths where they are defined
@@ -47,7 +44,7 @@ pub fn test_6_1_05_multiple_definition_of_product_group_id(doc: &impl CsafTrait)
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
@@ -102,8 +102,8 @@ impl crate::test_validation::TestValidator<crate::schema::csaf2_0::schema::Commo
     fn validate(
         &self,
         doc: &crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework,
-    ) -> Result<(), Vec<TestFinding>> {
-        test_6_1_11_cwe(doc, false)
+    ) -> crate::validation_result::TestResult {
+        test_6_1_11_cwe(doc, false).into()
     }
 }
 
@@ -113,8 +113,8 @@ impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::Commo
     fn validate(
         &self,
         doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
-    ) -> Result<(), Vec<TestFinding>> {
-        test_6_1_11_cwe(doc, true)
+    ) -> crate::validation_result::TestResult {
+        test_6_1_11_cwe(doc, true).into()
     }
 }
 
@@ -1,19 +1,20 @@
 use crate::csaf::types::language::CsafLanguage;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{IntoTestFindingError, TestFinding};
+use crate::validation_result::{Passed, TestResult};
 
-pub fn test_6_1_12_language(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_12_language(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
 
     if document.get_lang().is_none() && document.get_source_lang().is_none() {
-        return Ok(()); // This should be a wasSkipped later (see #409)
+        return TestResult::no_data("The document contains neither a language nor a source language.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
     validate_language(document.get_lang(), "/document/lang", &mut errors);
     validate_language(document.get_source_lang(), "/document/source_lang", &mut errors);
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 /// Validate a language code and append the validation error to the errors vector.
@@ -1,6 +1,7 @@
 use crate::csaf_traits::{CsafTrait, DocumentTrait, PublisherTrait};
 use crate::schema::csaf2_1::schema::CategoryOfPublisher;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::sync::LazyLock;
 
 static MISSING_SOURCE_LANG_ERROR: LazyLock<TestFinding> = LazyLock::new(|| {
@@ -13,19 +14,18 @@ static MISSING_SOURCE_LANG_ERROR: LazyLock<TestFinding> = LazyLock::new(|| {
 /// 6.1.15 Translator
 ///
 /// If the `/document/publisher/category` is "translator", then the `/document/source_lang` must be present and set.
-pub fn test_6_1_15_translator(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_15_translator(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
 
     // This test only applies if the publisher category is "translator"
     if CategoryOfPublisher::Translator != document.get_publisher().get_category() {
-        // This should be a wasSkipped later (see #409)
-        return Ok(());
+        return TestResult::not_applicable("The test only applies to documents with publisher category translator.");
     }
 
     // Check if source_lang is present
     match document.get_source_lang() {
-        None => Err(vec![MISSING_SOURCE_LANG_ERROR.clone()]),
-        _ => Ok(()), // We do not care if the language tag is valid or invalid
+        None => TestResult::Failed(vec![MISSING_SOURCE_LANG_ERROR.clone()]),
+        _ => TestResult::Passed(Passed::Success), // We do not care if the language tag is valid or invalid
     }
 }
 
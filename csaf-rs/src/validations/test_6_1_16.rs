@@ -2,56 +2,57 @@ use crate::csaf::types::version_number::CsafVersionNumber;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 /// 6.1.16 Latest Document Version
 ///
 /// `/document/tracking/version` must be equal to the last `/document/tracking/revision_history[]/number` when
 /// sorting the revision history ascending by `date`. Build metadata is ignored. Pre-release parts are ignored
 /// if `/document/status` is "draft".
-pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> TestResult {
     let tracking = doc.get_document().get_tracking();
 
     let mut revision_history = tracking.aggregate_revision_history();
     revision_history.inplace_sort_by_date_then_number();
 
-    // TODO: Technically, this should never be None, as Revision History has minItems: 1 (#409)
-    if let Some(latest_revision_history_item) = revision_history.last() {
-        let latest_number = latest_revision_history_item.number.clone();
-        let doc_status = tracking.get_status();
-        let doc_version = tracking.get_version();
-        // As there are additional criteria to the equality check, we cant just use the Eq impl
-        match (&latest_number, &doc_version) {
-            (CsafVersionNumber::IntVer(last_number), CsafVersionNumber::IntVer(doc_version))
-                if doc_version == last_number =>
-            {
-                // For integer version numbers, the eq compares the u64 ints
-                return Ok(());
-            },
-            (CsafVersionNumber::SemVer(last_number), CsafVersionNumber::SemVer(doc_version)) => {
-                // Manually compare the semver instances according to test requirements
-                let mut equal = true;
-                equal &= equal && doc_version.get_major() == last_number.get_major();
-                equal &= equal && doc_version.get_minor() == last_number.get_minor();
-                equal &= equal && doc_version.get_patch() == last_number.get_patch();
-                if doc_status != DocumentStatus::Draft {
-                    equal &= equal && doc_version.get_prerelease() == last_number.get_prerelease();
-                }
-                if equal {
-                    return Ok(());
-                }
-            },
-            // Mixed version number types cannot be equal
-            _ => {},
-        };
-        // versions are unequal, return error
-        return Err(vec![test_6_1_16_err_generator(
-            &doc_version,
-            &latest_number,
-            &doc_status,
-        )]);
-    }
+    // Technically, this should never be None, as Revision History has minItems: 1
+    let Some(latest_revision_history_item) = revision_history.last() else {
+        return TestResult::precondition_failed("The revision history is empty.");
+    };
 
-    Ok(())
+    let latest_number = latest_revision_history_item.number.clone();
+    let doc_status = tracking.get_status();
+    let doc_version = tracking.get_version();
+    // As there are additional criteria to the equality check, we cant just use the Eq impl
+    match (&latest_number, &doc_version) {
+        (CsafVersionNumber::IntVer(last_number), CsafVersionNumber::IntVer(doc_version))
+            if doc_version == last_number =>
+        {
+            // For integer version numbers, the eq compares the u64 ints
+            return TestResult::Passed(Passed::Success);
+        },
+        (CsafVersionNumber::SemVer(last_number), CsafVersionNumber::SemVer(doc_version)) => {
+            // Manually compare the semver instances according to test requirements
+            let mut equal = true;
+            equal &= equal && doc_version.get_major() == last_number.get_major();
+            equal &= equal && doc_version.get_minor() == last_number.get_minor();
+            equal &= equal && doc_version.get_patch() == last_number.get_patch();
+            if doc_status != DocumentStatus::Draft {
+                equal &= equal && doc_version.get_prerelease() == last_number.get_prerelease();
+            }
+            if equal {
+                return TestResult::Passed(Passed::Success);
+            }
+        },
+        // Mixed version number types cannot be equal
+        _ => {},
+    };
+    // versions are unequal, return error
+    TestResult::Failed(vec![test_6_1_16_err_generator(
+        &doc_version,
+        &latest_number,
+        &doc_status,
+    )])
 }
 
 fn test_6_1_16_err_generator(
@@ -2,6 +2,7 @@ use crate::csaf::types::version_number::CsafVersionNumber;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use strum::{AsRefStr, Display};
 
 #[derive(Display, AsRefStr)]
@@ -37,27 +38,27 @@ fn generate_status_version_error(
 /// and this test can only pass, so we can return early.
 /// If not, we know the secondary criteria is not fulfilled, and we can check if `/document/version`
 /// meets one of the failing criteria and generate the corresponding error(s).
-pub fn test_6_1_17_document_status_draft(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_17_document_status_draft(doc: &impl CsafTrait) -> TestResult {
     let tracking = doc.get_document().get_tracking();
 
     // This is explicitly **NOT** a wasSkipped. The test prose does not mention skipping,
     // we just check the two relevant conditions in inverted order and return early here.
     let doc_status = tracking.get_status();
     if DocumentStatus::Draft == doc_status {
-        return Ok(());
+        return TestResult::Passed(Passed::Success);
     }
 
     let doc_version = tracking.get_version();
     match &doc_version {
         CsafVersionNumber::IntVer(intver) => {
             if intver.get() == 0 {
-                Err(vec![generate_status_version_error(
+                TestResult::Failed(vec![generate_status_version_error(
                     &doc_version,
                     &doc_status,
                     &DocumentStatusDraftErrorReason::IntVerZero,
                 )])
             } else {
-                Ok(())
+                TestResult::Passed(Passed::Success)
             }
         },
         CsafVersionNumber::SemVer(semver) => {
@@ -76,9 +77,12 @@ pub fn test_6_1_17_document_status_draft(doc: &impl CsafTrait) -> Result<(), Vec
                     &DocumentStatusDraftErrorReason::SemVerHasPre,
                 ))
             }
-            errors.map_or(Ok(()), Err)
+            errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
+        },
+        // invalid version numbers are caught by the schema test
+        CsafVersionNumber::Invalid(version) => {
+            TestResult::precondition_failed(format!("The document version '{version}' is invalid."))
         },
-        CsafVersionNumber::Invalid(_) => Ok(()), // #409 this may be skipped, as the version is invalid and will be caught by schema test
     }
 }
 
@@ -3,6 +3,7 @@ use crate::csaf::types::version_number::CsafVersionNumber;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
 
 fn create_revision_history_error(status: &DocumentStatus, number: &CsafVersionNumber, index: usize) -> TestFinding {
     let reason = match number {
@@ -22,7 +23,7 @@ fn create_revision_history_error(status: &DocumentStatus, number: &CsafVersionNu
 ///
 /// For documents with `/document/status` "final" or "interim", no item in `/document/tracking/revision_history[]`
 /// may have the version 0 or 0.y.z.
-pub fn test_6_1_18_released_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_18_released_revision_history(doc: &impl CsafTrait) -> TestResult {
     let tracking = doc.get_document().get_tracking();
 
     let status = tracking.get_status();
@@ -33,17 +34,20 @@ pub fn test_6_1_18_released_revision_history(doc: &impl CsafTrait) -> Result<(),
     let revision_history = tracking.get_revision_history();
     for (revision_index, revision) in revision_history.iter().enumerate() {
         let number = revision.get_number();
-        // ToDo #409 maybe return a skipped here or return a warning if the version number is invalid, but for now we just ignore it
-        if let Ok(major) = number.get_major()
-            && major == 0
-        {
+        // invalid version numbers are caught by the schema test
+        let Ok(major) = number.get_major() else {
+            return TestResult::precondition_failed(format!(
+                "The revision history contains the invalid version number '{number}'."
+            ));
+        };
+        if major == 0 {
             errors
                 .get_or_insert_default()
                 .push(create_revision_history_error(&status, &number, revision_index));
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(Ok(()), Err).into()
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_18, test_6_1_18_released_revision_history);
@@ -3,6 +3,7 @@ use crate::csaf::types::version_number::{CsafVersionNumber, SemVerVersion};
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_status_version_error(status: &DocumentStatus, version: &SemVerVersion) -> TestFinding {
     TestFinding::Error(TestFindingData {
@@ -17,7 +18,7 @@ fn create_status_version_error(status: &DocumentStatus, version: &SemVerVersion)
 ///
 /// For documents with status "final" or "interim", the `/document/tracking/version` field must not contain
 /// a pre-release part (e.g. "1.0.0-alpha").
-pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTrait) -> TestResult {
     let tracking = doc.get_document().get_tracking();
 
     let status = tracking.get_status();
@@ -25,16 +26,21 @@ pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTrait) -> Result<()
 
     match tracking.get_version() {
         // If version is integer versioning, this test does not apply
-        CsafVersionNumber::IntVer(_) => {}, // ToDo maybe generate skipped/not applicable
+        CsafVersionNumber::IntVer(_) => {
+            TestResult::not_applicable("The document uses integer versioning, which has no pre-release part.")
+        },
         CsafVersionNumber::SemVer(semver) => {
             if semver.has_prerelease() {
-                return Err(vec![create_status_version_error(&status, &semver)]);
+                TestResult::Failed(vec![create_status_version_error(&status, &semver)])
+            } else {
+                TestResult::Passed(Passed::Success)
             }
         },
-        CsafVersionNumber::Invalid(_) => {}, // ignore invalid version numbers
+        // invalid version numbers are caught by the schema test
+        CsafVersionNumber::Invalid(version) => {
+            TestResult::precondition_failed(format!("The document version '{version}' is invalid."))
+        },
     }
-
-    Ok(())
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_20, test_6_1_20_non_draft_document_version);
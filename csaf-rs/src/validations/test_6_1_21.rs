@@ -5,6 +5,7 @@ use crate::csaf::types::csaf_datetime::CsafDateTime;
 use crate::csaf::types::version_number::{CsafVersionNumber, CsafVersionNumberError};
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 /// 6.1.21 Missing Item in Revision History
 ///
@@ -12,7 +13,7 @@ use crate::validation::{TestFinding, TestFindingData};
 /// In the case of semantic versioning, this applies only to the Major version.
 /// It MUST also be tested that the first item in such a sorted list has either the version number 0 or 1 in
 /// the case of integer versioning or a Major version of 0 or 1 in the case of semantic versioning.
-pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
 
     struct MissingVersionMetadata {
@@ -25,7 +26,7 @@ pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> Res
     rev_history_tuples.inplace_sort_by_date_then_number();
 
     if rev_history_tuples.is_empty() {
-        return Ok(()); // ToDo #409 this should be Skipped: Precondition failed
+        return TestResult::precondition_failed("The revision history is empty.");
     };
     let mut missing_versions: HashMap<CsafVersionNumber, MissingVersionMetadata> = HashMap::new();
     rev_history_tuples
@@ -142,7 +143,7 @@ pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> Res
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_21, test_6_1_21_missing_item_in_revision_history);
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{CsafTrait, VulnerabilityTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 fn generate_duplicate_cve_error(cve: &str, path: usize) -> TestFinding {
@@ -12,13 +13,11 @@ fn generate_duplicate_cve_error(cve: &str, path: usize) -> TestFinding {
 /// Test 6.1.23: Multiple Use of Same CVE
 ///
 /// Vulnerability items must not contain the same string in the `/vulnerabilities[]/cve` field.
-pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> TestResult {
     let vulnerabilities = doc.get_vulnerabilities();
 
-    // Check if there are any vulnerabilities, if there aren't, this test can be skipped
     if vulnerabilities.is_empty() {
-        // This will be WasSkipped later
-        return Ok(());
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     // Map occurrence paths indexes to CVE identifiers
@@ -31,10 +30,8 @@ pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> Result<(),
         }
     }
 
-    // Check if there are any CVE identifiers, if there aren't, this test can be skipped
     if cve_paths.is_empty() {
-        // This will be WasSkipped later
-        return Ok(());
+        return TestResult::no_data("The document does not contain any CVE identifiers.");
     }
 
     // Generate errors for CVE identifiers with multiple occurrence paths indexes
@@ -47,7 +44,7 @@ pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> Result<(),
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_23, test_6_1_23_multiple_use_of_same_cve);
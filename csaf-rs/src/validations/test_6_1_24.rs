@@ -3,6 +3,7 @@ use crate::csaf::types::csaf_datetime::ValidCsafDateTime;
 use crate::csaf_traits::{CsafTrait, InvolvementTrait, VulnerabilityTrait, WithOptionalDate};
 use crate::schema::csaf2_1::schema::PartyCategory;
 use crate::validation::{IntoTestFindingError, TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 fn generate_duplicate_involvement_error(
@@ -24,13 +25,11 @@ fn generate_duplicate_involvement_error(
 ///
 /// Vulnerability items must not contain the same tuples of the `/vulnerabilities[]/involvements[]/date`
 /// and `/vulnerabilities[]/involvements[]/party` fields.
-pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) -> TestResult {
     let vulnerabilities = doc.get_vulnerabilities();
 
-    // Check if there are any vulnerabilities, if there aren't, this test can be skipped
     if vulnerabilities.is_empty() {
-        // This will be WasSkipped later (#409)
-        return Ok(());
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -85,7 +84,7 @@ pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) ->
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_24, test_6_1_24_multiple_definition_in_involvements);
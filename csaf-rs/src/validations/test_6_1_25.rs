@@ -3,6 +3,7 @@ use crate::csaf_traits::{
     CsafTrait, FileHashTrait, HashTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait,
 };
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 /// Test 6.1.25: Multiple Use of the Same Hash Algorithm
@@ -14,10 +15,9 @@ use std::collections::HashMap;
 /// `/product_tree/branches[](/branches[])*/product/product_identification_helper/hashes[]/file_hashes[]`
 /// `/product_tree/full_product_names[]/product_identification_helper/hashes[]/file_hashes[]`
 /// `/product_tree/relationships[]/full_product_name/product_identification_helper/hashes[]/file_hashes[]`
-pub fn test_6_1_25_multiple_use_of_same_hash_algorithm(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
-    // TODO: This can be a wasSkipped later
+pub fn test_6_1_25_multiple_use_of_same_hash_algorithm(doc: &impl CsafTrait) -> TestResult {
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(());
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -58,7 +58,7 @@ pub fn test_6_1_25_multiple_use_of_same_hash_algorithm(doc: &impl CsafTrait) ->
             }
         }
     });
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 fn test_6_1_25_err_generator(
@@ -2,6 +2,7 @@ use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait};
 use crate::schema::csaf2_1::schema::NoteCategory;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_missing_note_error(doc_category: CsafDocumentCategory) -> TestFinding {
@@ -25,11 +26,13 @@ const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConf
 ///
 /// Documents with these categories must have at least one entry in `/document/notes` with `category` values
 /// of `description`, `details`, `general` or `summary`.
-pub fn test_6_1_27_01_document_notes(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_01_document_notes(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     // check if there is a document note with the required category
@@ -50,10 +53,10 @@ pub fn test_6_1_27_01_document_notes(doc: &impl CsafTrait) -> Result<(), Vec<Tes
 
     // if there isn't a note with the required category, return an error
     if !found_valid_note {
-        return Err(vec![create_missing_note_error(doc_category)]);
+        return TestResult::Failed(vec![create_missing_note_error(doc_category)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_27_1, test_6_1_27_01_document_notes);
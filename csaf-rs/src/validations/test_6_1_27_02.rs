@@ -2,6 +2,7 @@ use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, ReferenceTrait};
 use crate::schema::csaf2_1::schema::CategoryOfReference;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_missing_external_reference_error(doc_category: &CsafDocumentCategory) -> TestFinding {
@@ -24,11 +25,13 @@ const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConf
 /// or `csaf_security_incident_response`.
 ///
 /// Documents with these categories must have at least one entry in `/document/references` with an external reference.
-pub fn test_6_1_27_02_document_references(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_02_document_references(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     // check if there is a document reference with category 'external'
@@ -44,10 +47,10 @@ pub fn test_6_1_27_02_document_references(doc: &impl CsafTrait) -> Result<(), Ve
 
     // if there isn't a reference with category 'external', return an error
     if !found_external_reference {
-        return Err(vec![create_missing_external_reference_error(&doc_category)]);
+        return TestResult::Failed(vec![create_missing_external_reference_error(&doc_category)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_27_2, test_6_1_27_02_document_references);
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_must_not_have_vuln_element_error(doc_category: &CsafDocumentCategory) -> TestFinding {
@@ -24,19 +25,21 @@ const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConf
 /// value `csaf_withdrawn` and `csaf_superseded` for `/document/csaf_version` `2.1`.
 ///
 /// Documents with this category must not have a `/vulnerabilities` element.
-pub fn test_6_1_27_03_vulnerability(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_03_vulnerability(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
     // check if document has a relevant category for this test
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     // return error if there are elements in /vulnerabilities
     if !doc.get_vulnerabilities().is_empty() {
-        return Err(vec![create_must_not_have_vuln_element_error(&doc_category)]);
+        return TestResult::Failed(vec![create_must_not_have_vuln_element_error(&doc_category)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_27_3, test_6_1_27_03_vulnerability);
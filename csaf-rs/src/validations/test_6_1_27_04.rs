@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 /// 6.1.27.4 Product Tree
@@ -10,19 +11,21 @@ use crate::validations::utils::document_category_test_config::DocumentCategoryTe
 /// value `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
 ///
 /// Documents with this category must have a `/product_tree` element.
-pub fn test_6_1_27_04_product_tree(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_04_product_tree(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     // return error if there are there isn't a product tree
     if doc.get_product_tree().is_none() {
-        return Err(vec![test_6_1_27_04_err_generator(doc_category)]);
+        return TestResult::Failed(vec![test_6_1_27_04_err_generator(doc_category)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 /// 6.1.27.7 VEX Product Status
@@ -9,11 +10,11 @@ use crate::validations::utils::document_category_test_config::DocumentCategoryTe
 ///
 /// In documents with this category each `/vulnerabilities[]/product_status` must have at least one
 /// of the elements: `fixed`, `known_affected`, `known_not_affected` or `under_investigation`
-pub fn test_6_1_27_07_vex_product_status(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_07_vex_product_status(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category(&doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) = PROFILE_TEST_CONFIG.not_applicable(&doc_category) {
+        return not_applicable;
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -31,7 +32,7 @@ pub fn test_6_1_27_07_vex_product_status(doc: &impl CsafTrait) -> Result<(), Vec
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
@@ -6,6 +6,7 @@ use crate::csaf_traits::{
 };
 use crate::schema::csaf2_1::schema::CategoryOfTheThreat;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 use std::collections::{HashMap, HashSet};
 
@@ -16,14 +17,17 @@ use std::collections::{HashMap, HashSet};
 /// Each item in `/vulnerabilities[]/product_status/known_not_affected` must have a corresponding
 /// impact statement in `/vulnerabilities[]/flags` or `/vulnerabilities[]/threats`. For impact statements under
 /// `threats`, the category must be `impact`.
-pub fn test_6_1_27_09_impact_statement(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_09_impact_statement(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
     let vulnerabilities = doc.get_vulnerabilities();
 
     // Only execute this test for documents with category 'csaf_vex'
     // and if there are any vulnerabilities present
-    if !PROFILE_TEST_CONFIG.matches_category(&doc_category) || vulnerabilities.is_empty() {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) = PROFILE_TEST_CONFIG.not_applicable(&doc_category) {
+        return not_applicable;
+    }
+    if vulnerabilities.is_empty() {
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -89,7 +93,7 @@ pub fn test_6_1_27_09_impact_statement(doc: &impl CsafTrait) -> Result<(), Vec<T
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
@@ -2,6 +2,7 @@ use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::resolve_product_groups;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 use std::collections::{HashMap, HashSet};
 
@@ -12,14 +13,17 @@ use std::collections::{HashMap, HashSet};
 /// Each item in `/vulnerabilities[]/product_status/known_affected` must have a corresponding
 /// action statement in `/vulnerabilities[]/remediations`
 ///
-pub fn test_6_1_27_10_action_statement(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_10_action_statement(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
     let vulnerabilities = doc.get_vulnerabilities();
 
     // Only execute this test for documents with category 'csaf_vex'
     // and if there are any vulnerabilities present
-    if !PROFILE_TEST_CONFIG.matches_category(&doc_category) || vulnerabilities.is_empty() {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) = PROFILE_TEST_CONFIG.not_applicable(&doc_category) {
+        return not_applicable;
+    }
+    if vulnerabilities.is_empty() {
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -71,7 +75,7 @@ pub fn test_6_1_27_10_action_statement(doc: &impl CsafTrait) -> Result<(), Vec<T
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
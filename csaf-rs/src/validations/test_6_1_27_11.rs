@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_missing_vulnerabilities_error(document_category: &CsafDocumentCategory) -> TestFinding {
@@ -17,18 +18,20 @@ fn create_missing_vulnerabilities_error(document_category: &CsafDocumentCategory
 /// value `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
 ///
 /// In documents with this category a `/vulnerabilities[]` element must exist.
-pub fn test_6_1_27_11_vulnerabilities(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_11_vulnerabilities(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     if doc.get_vulnerabilities().is_empty() {
-        return Err(vec![create_missing_vulnerabilities_error(&doc_category)]);
+        return TestResult::Failed(vec![create_missing_vulnerabilities_error(&doc_category)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
@@ -0,0 +1,99 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
+use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
+
+fn create_missing_corresponding_affected_products_error(
+    document_category: &CsafDocumentCategory,
+    vulnerability_index: usize,
+) -> TestFinding {
+    TestFinding::Error(TestFindingData {
+        message: format!(
+            "Document with category '{document_category}' lists fixed products, but no corresponding first_affected, known_affected or last_affected products"
+        ),
+        instance_path: format!("/vulnerabilities/{vulnerability_index}/product_status"),
+    })
+}
+
+/// 6.1.27.13 Corresponding Affected Products
+///
+/// This test only applies to documents with `/document/category` with value `csaf_vex`.
+///
+/// For each item in `/vulnerabilities[]` that has a `product_status/fixed` or `product_status/first_fixed`
+/// element, it MUST be tested that at least one of the elements `product_status/first_affected`,
+/// `product_status/known_affected` or `product_status/last_affected` exists, as a product can only be
+/// fixed if it was affected before.
+pub fn test_6_1_27_13_corresponding_affected_products(doc: &impl CsafTrait) -> TestResult {
+    let doc_category = doc.get_document().get_category();
+
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
+    }
+
+    let mut errors: Option<Vec<TestFinding>> = None;
+    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(product_status) = vulnerability.get_product_status()
+            && (product_status.get_fixed().is_some() || product_status.get_first_fixed().is_some())
+            && product_status.get_first_affected().is_none()
+            && product_status.get_known_affected().is_none()
+            && product_status.get_last_affected().is_none()
+        {
+            errors
+                .get_or_insert_default()
+                .push(create_missing_corresponding_affected_products_error(&doc_category, v_i));
+        }
+    }
+
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
+    DocumentCategoryTestConfig::new().shared(&[CsafDocumentCategory::CsafVex]);
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_1_27_13,
+    test_6_1_27_13_corresponding_affected_products
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_1_27_13 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_27_13() {
+        let missing_affected = |vulnerability_index| {
+            Err(vec![create_missing_corresponding_affected_products_error(
+                &CsafDocumentCategory::CsafVex,
+                vulnerability_index,
+            )])
+        };
+
+        // Case 11 to 16: fixed products with corresponding affected products
+        // Case S11: fixed products without affected products in a document that is not a VEX
+        // Case S12: fixed products with each of the affected product status groups
+        TESTS_2_1.test_6_1_27_13.expect(ExpectedResults {
+            case_01: missing_affected(0),
+            case_02: missing_affected(0),
+            case_03: missing_affected(0),
+            case_04: missing_affected(0),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_14: Ok(()),
+            case_15: Ok(()),
+            case_16: Ok(()),
+            case_s01: Err(vec![
+                create_missing_corresponding_affected_products_error(&CsafDocumentCategory::CsafVex, 1),
+                create_missing_corresponding_affected_products_error(&CsafDocumentCategory::CsafVex, 2),
+            ]),
+            case_s11: Ok(()),
+            case_s12: Ok(()),
+        });
+    }
+}
@@ -2,6 +2,7 @@ use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, NoteTrait};
 use crate::schema::csaf2_1::schema::NoteCategory;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_missing_description_note(document_category: &CsafDocumentCategory) -> TestFinding {
@@ -18,11 +19,13 @@ fn create_missing_description_note(document_category: &CsafDocumentCategory) ->
 /// This test only applies to documents with `/document/category` with value `csaf_withdrawn` or `csaf_superseded`.
 ///
 /// There must be at least one item in `/document/notes[]` with category `description`.
-pub fn test_6_1_27_14_document_notes_with_description(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_14_document_notes_with_description(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(());
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     let has_description = doc.get_document().get_notes().is_some_and(|notes| {
@@ -32,9 +35,9 @@ pub fn test_6_1_27_14_document_notes_with_description(doc: &impl CsafTrait) -> R
     });
 
     if has_description {
-        Ok(())
+        TestResult::Passed(Passed::Success)
     } else {
-        Err(vec![create_missing_description_note(&doc_category)])
+        TestResult::Failed(vec![create_missing_description_note(&doc_category)])
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_revision_history_only_one_entry_error(document_category: &CsafDocumentCategory) -> TestFinding {
@@ -17,17 +18,19 @@ fn create_revision_history_only_one_entry_error(document_category: &CsafDocument
 /// This test only applies to documents with `/document/category` with value `csaf_withdrawn` or `csaf_superseded`.
 ///
 /// The revision history shall not contain only one entry.
-pub fn test_6_1_27_16_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_16_revision_history(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // TODO This will be a wasSkipped later ([#409](https://github.com/csaf-rs/csaf/issues/409))
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
     if doc.get_document().get_tracking().get_revision_history().len() == 1 {
-        return Err(vec![create_revision_history_only_one_entry_error(&doc_category)]);
+        return TestResult::Failed(vec![create_revision_history_only_one_entry_error(&doc_category)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new().csaf21(&[
@@ -3,6 +3,7 @@ use crate::csaf::types::language::CsafLanguage;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::schema::csaf2_1::schema::NoteCategory;
 use crate::validation::TestFinding;
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 use crate::validations::utils::document_notes_with_title_and_category::check_notes_with_title_and_category;
 
@@ -12,15 +13,23 @@ use crate::validations::utils::document_notes_with_title_and_category::check_not
 ///
 /// If the document language is English or unspecified, it MUST be tested that exactly one item in document notes exists that has the title Reasoning for Withdrawal.
 /// The category of this item MUST be description.
-pub fn test_6_1_27_17_document_notes_for_withdrawal(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_17_document_notes_for_withdrawal(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
     match doc.get_document().get_lang() {
-        Some(CsafLanguage::Invalid(_, _)) => return Ok(()), // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
-        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_default() || !valid_lang.is_english() => return Ok(()), // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+        Some(CsafLanguage::Invalid(language, _)) => {
+            return TestResult::precondition_failed(format!("The document language '{language}' is invalid."));
+        },
+        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_default() || !valid_lang.is_english() => {
+            return TestResult::not_applicable(format!(
+                "The test only applies to documents in English or without a language, but the document language is '{valid_lang}'."
+            ));
+        },
         Some(_) => {}, // this is english
         None => {},    // no language set
     }
@@ -32,7 +41,7 @@ pub fn test_6_1_27_17_document_notes_for_withdrawal(doc: &impl CsafTrait) -> Res
         &doc_category,
     )
     .map(|v| v.into_iter().map(TestFinding::Error).collect())
-    .map_or(Ok(()), Err)
+    .map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
@@ -3,6 +3,7 @@ use crate::csaf::types::language::CsafLanguage;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, ReferenceTrait};
 use crate::schema::csaf2_1::schema::CategoryOfReference;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_missing_reference_error(document_category: &CsafDocumentCategory) -> TestFinding {
@@ -27,15 +28,23 @@ fn create_incorrect_category_error(reference_index: usize) -> TestFinding {
 ///
 /// It MUST be tested that at least one item in document references exists that has a summary starting with "Superseding Document".
 /// The category of this item MUST be external.
-pub fn test_6_1_27_19_reference_to_superseding_document(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_27_19_reference_to_superseding_document(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(()); // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
     match doc.get_document().get_lang() {
-        Some(CsafLanguage::Invalid(_, _)) => return Ok(()), // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
-        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_default() || !valid_lang.is_english() => return Ok(()), // ToDo generate skipped https://github.com/csaf-rs/csaf/issues/409
+        Some(CsafLanguage::Invalid(language, _)) => {
+            return TestResult::precondition_failed(format!("The document language '{language}' is invalid."));
+        },
+        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_default() || !valid_lang.is_english() => {
+            return TestResult::not_applicable(format!(
+                "The test only applies to documents in English or without a language, but the document language is '{valid_lang}'."
+            ));
+        },
         Some(_) => {}, // this is english
         None => {},    // no language set
     }
@@ -62,16 +71,16 @@ pub fn test_6_1_27_19_reference_to_superseding_document(doc: &impl CsafTrait) ->
     // So the incorrect category has precedence over the missing reference. This way the error message is more specific and hints more
     // directly to the wrong instance.
     if let Some(errs) = errors {
-        return Err(errs);
+        return TestResult::Failed(errs);
     }
 
     // completely missing reference with correct summary and category has second precedence
     if !has_external_reference_with_correct_summary {
-        return Err(vec![create_missing_reference_error(
+        return TestResult::Failed(vec![create_missing_reference_error(
             &CsafDocumentCategory::CsafSuperseded,
         )]);
     }
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
@@ -1,6 +1,7 @@
 use crate::csaf::types::language::CsafLanguage;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_same_language_error(lang: &CsafLanguage) -> TestFinding {
     TestFinding::Error(TestFindingData {
@@ -12,20 +13,24 @@ fn create_same_language_error(lang: &CsafLanguage) -> TestFinding {
 /// 6.1.28 Translation
 ///
 /// `/document/lang` and `/document/source_lang` must have different values
-pub fn test_6_1_28_translation(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_28_translation(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
 
     // Check if both lang and source_lang are present
     let (lang, source_lang) = match (document.get_lang(), document.get_source_lang()) {
         (Some(lang), Some(source_lang)) => (lang, source_lang),
-        (_, _) => return Ok(()), // This should be a wasSkipped later (see #409)
+        (_, _) => {
+            return TestResult::not_applicable(
+                "The test only applies to documents with both a language and a source language.",
+            );
+        },
     };
 
     if lang == source_lang {
-        return Err(vec![create_same_language_error(&lang)]);
+        return TestResult::Failed(vec![create_same_language_error(&lang)]);
     }
 
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_28, test_6_1_28_translation);
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{CsafTrait, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_missing_product_reference_error(vulnerability_index: usize, remediation_index: usize) -> TestFinding {
     TestFinding::Error(TestFindingData {
@@ -11,13 +12,11 @@ fn create_missing_product_reference_error(vulnerability_index: usize, remediatio
 /// 6.1.29 Remediation without Product Reference
 ///
 /// Each item in `/vulnerabilities[]/remediations[]` must have at least one of the elements group_ids or product_ids.
-pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTrait) -> TestResult {
     let vulnerabilities = doc.get_vulnerabilities();
 
-    // Check if there are vulnerability, if not, this test can be skipped
     if vulnerabilities.is_empty() {
-        // This will be a WasSkipped later
-        return Ok(());
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -35,7 +34,7 @@ pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTrait) -
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
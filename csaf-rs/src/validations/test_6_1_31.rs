@@ -1,5 +1,6 @@
 use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashSet;
 
 fn create_forbidden_strings_in_version_error(
@@ -79,9 +80,9 @@ fn check_branch_name_for_forbidden_substrings(branch_name: &str) -> Option<Vec<&
 /// tokenized by whitespace in their branch `name`.
 /// `<=` and `>=` are prioritized before `<` and `>` respectively.
 /// The error contains all unique offending operators and keywords.
-pub fn test_6_1_31_version_range_in_product_version_branch_name(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_31_version_range_in_product_version_branch_name(doc: &impl CsafTrait) -> TestResult {
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(());
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -101,7 +102,7 @@ pub fn test_6_1_31_version_range_in_product_version_branch_name(doc: &impl CsafT
         }
     });
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{CsafTrait, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_flag_without_product_reference_error(vulnerability_index: usize, flag_index: usize) -> TestFinding {
     TestFinding::Error(TestFindingData {
@@ -11,13 +12,11 @@ fn create_flag_without_product_reference_error(vulnerability_index: usize, flag_
 /// 6.1.32 Flag without Product Reference
 ///
 /// Each `/vulnerabilities[]/flags[]` must have at least one of the elements group_ids or product_ids.
-pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTrait) -> TestResult {
     let vulnerabilities = doc.get_vulnerabilities();
 
-    // Check if there are any vulnerabilities, if there aren't, this test can be skipped
     if vulnerabilities.is_empty() {
-        // This will be WasSkipped later
-        return Ok(());
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -36,7 +35,7 @@ pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTrait) -> Resul
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_1_32, test_6_1_32_flag_without_product_reference);
@@ -2,7 +2,7 @@ use crate::csaf_traits::resolve_product_groups;
 use crate::csaf_traits::{CsafTrait, FlagTrait, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds};
 use crate::schema::csaf2_1::schema::LabelOfTheFlag;
 use crate::validation::{TestFinding, TestFindingData};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 type VexJustificationInfo = (LabelOfTheFlag, usize, Option<String>);
 /// 6.1.33 Multiple Flags with VEX Justification Codes per Product
@@ -11,8 +11,8 @@ pub fn test_6_1_33_multiple_flags_with_vex_codes_per_product(doc: &impl CsafTrai
 
     // Check each flag in each vulnerability
     for (vuln_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
-        // Generate a hashmap of product IDs to flag labels, flag indices, and group ids
-        let mut product_id_to_flags_map: HashMap<String, Vec<VexJustificationInfo>> = HashMap::new();
+        // Generate a map of product IDs to flag labels, flag indices, and group ids
+        let mut product_id_to_flags_map: BTreeMap<String, Vec<VexJustificationInfo>> = BTreeMap::new();
         // if there are flags for the vuln, iterate over them
         if let Some(flags) = vulnerability.get_flags() {
             for (flag_i, flag) in flags.iter().enumerate() {
@@ -46,10 +46,12 @@ pub fn test_6_1_33_multiple_flags_with_vex_codes_per_product(doc: &impl CsafTrai
             }
         }
 
-        // Collect errors for products that appear in multiple flag labels
+        // Collect errors for products that appear in multiple flags
         for (product_id, flag_flag_i_group_ids_arr) in product_id_to_flags_map {
-            // Extract unique labels from the (label, flag_i, group_ids) tuples
-            if flag_flag_i_group_ids_arr.len() > 1 {
+            // A product listed twice in the same flag (e.g. directly and via a group) is a member of only one flag
+            let flag_indices: BTreeSet<usize> =
+                flag_flag_i_group_ids_arr.iter().map(|(_, flag_i, _)| *flag_i).collect();
+            if flag_indices.len() > 1 {
                 // extract all labels
                 let labels: Vec<LabelOfTheFlag> =
                     flag_flag_i_group_ids_arr.iter().map(|(label, _, _)| *label).collect();
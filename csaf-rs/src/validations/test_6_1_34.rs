@@ -1,5 +1,6 @@
 use crate::csaf_traits::{BranchTrait, CsafTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 static MAX_DEPTH: u32 = 30;
 
@@ -10,10 +11,9 @@ fn create_excessive_branch_depth_error(branch_index: usize, path: &str) -> TestF
     })
 }
 
-pub fn test_6_1_34_branches_recursion_depth(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
-    // TODO This can be wasSkipped in the future
+pub fn test_6_1_34_branches_recursion_depth(doc: &impl CsafTrait) -> TestResult {
     let Some(branches) = doc.get_product_tree().and_then(|t| t.get_branches()) else {
-        return Ok(());
+        return TestResult::no_data("The document does not contain any branches.");
     };
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -24,7 +24,7 @@ pub fn test_6_1_34_branches_recursion_depth(doc: &impl CsafTrait) -> Result<(),
                 .push(create_excessive_branch_depth_error(i, &path));
         }
     }
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_34, test_6_1_34_branches_recursion_depth);
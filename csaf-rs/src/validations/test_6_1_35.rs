@@ -1,6 +1,7 @@
 use crate::csaf_traits::{CsafTrait, RemediationTrait, VulnerabilityTrait};
 use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::BTreeMap;
 use strum::{AsRefStr, Display};
 
@@ -165,16 +166,16 @@ impl ProductIdRemediationCategoriesMap {
 /// This takes indirect relations through product groups into account.
 ///
 /// For more details on how the checks work, see `ProductIdRemediationCategoriesMap`.
-pub fn test_6_1_35_contradicting_remediations(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_35_contradicting_remediations(doc: &impl CsafTrait) -> TestResult {
     let vulnerabilities = doc.get_vulnerabilities();
     if vulnerabilities.is_empty() {
-        return Ok(()); // TODO #409 this will be noData after refactor
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
     let mut errors: Option<Vec<TestFinding>> = None;
     for (v_i, v) in vulnerabilities.iter().enumerate() {
         ProductIdRemediationCategoriesMap::aggregate(doc, v).check_category_contradiction(v_i, &mut errors);
     }
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_35, test_6_1_35_contradicting_remediations);
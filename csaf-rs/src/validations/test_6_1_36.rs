@@ -60,9 +60,15 @@ fn create_fixed_conflict_error(
     })
 }
 
+/// 6.1.36 Status Group Contradicting Remediation Categories
+///
+/// For each item in `/vulnerabilities`, it must be tested that a product is not member of a
+/// product status group and a remediation category which contradict each other. Products are
+/// also taken into account if a remediation references them through a product group.
 pub fn test_6_1_36_status_group_contradicting_remediation_categories(
     doc: &impl CsafTrait,
 ) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(product_status) = v.get_product_status() {
             let status_map = ProductStatusGroupMap::from(product_status);
@@ -77,22 +83,28 @@ pub fn test_6_1_36_status_group_contradicting_remediation_categories(
                         if status_map.contains(&ProductStatusGroup::Affected, &p)
                             && cat == CategoryOfTheRemediation::OptionalPatch
                         {
-                            return Err(vec![create_affected_conflict_error(&p, &cat, v_i, r_i)]);
+                            errors
+                                .get_or_insert_default()
+                                .push(create_affected_conflict_error(&p, &cat, v_i, r_i));
                         }
                         if status_map.contains(&ProductStatusGroup::NotAffected, &p)
                             && NOT_AFFECTED_CONFLICTS.contains(&cat)
                         {
-                            return Err(vec![create_not_affected_conflict_error(&p, &cat, v_i, r_i)]);
+                            errors
+                                .get_or_insert_default()
+                                .push(create_not_affected_conflict_error(&p, &cat, v_i, r_i));
                         }
                         if status_map.contains(&ProductStatusGroup::Fixed, &p) && FIXED_CONFLICTS.contains(&cat) {
-                            return Err(vec![create_fixed_conflict_error(&p, &cat, v_i, r_i)]);
+                            errors
+                                .get_or_insert_default()
+                                .push(create_fixed_conflict_error(&p, &cat, v_i, r_i));
                         }
                     }
                 }
             }
         }
     }
-    Ok(())
+    errors.map_or(Ok(()), Err)
 }
 
 crate::test_validation::impl_validator!(
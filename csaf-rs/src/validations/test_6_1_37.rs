@@ -1,8 +1,8 @@
 use crate::csaf::types::csaf_datetime::CsafDateTime;
 use crate::csaf::types::csaf_datetime::CsafDateTime::{Invalid, Valid};
 use crate::csaf_traits::{
-    CsafTrait, DocumentTrait, FirstKnownExploitationDatesTrait, TrackingTrait, VulnerabilityTrait, WithDate,
-    WithOptionalDate,
+    ContentTrait, CsafTrait, DocumentTrait, EpssTrait, FirstKnownExploitationDatesTrait, MetricTrait, TrackingTrait,
+    VulnerabilityTrait, WithDate, WithOptionalDate,
 };
 use crate::validation::{TestFinding, TestFindingData};
 use regex::Regex;
@@ -16,54 +16,73 @@ static CSAF_RFC3339_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 /// (ISO 8601 format with time zone or UTC).
 ///
 /// This function checks all date/time fields in the document, including tracking dates,
-/// vulnerability disclosure/discovery dates, remediation dates, threat dates, etc.
+/// vulnerability disclosure/discovery dates, remediation dates, threat dates, EPSS timestamps, etc.
 pub fn test_6_1_37_date_and_time(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+    let mut check = |date_time: &CsafDateTime, instance_path: &str| {
+        if let Err(error) = check_datetime(date_time, instance_path) {
+            errors.get_or_insert_default().extend(error);
+        }
+    };
     let tracking = doc.get_document().get_tracking();
 
     // Check the initial release date
-    check_datetime(
+    check(
         &tracking.get_initial_release_date(),
         "/document/tracking/initial_release_date",
-    )?;
+    );
 
     // Check the current release date
-    check_datetime(
+    check(
         &tracking.get_current_release_date(),
         "/document/tracking/current_release_date",
-    )?;
+    );
 
     // Check the generator date if present
     if let Some(generator) = tracking.get_generator()
         && let Some(date) = generator.get_date()
     {
-        check_datetime(&date, "/document/tracking/generator/date")?;
+        check(&date, "/document/tracking/generator/date");
     }
 
     // Check revision history dates if present
     for (i_r, revision) in tracking.get_revision_history().iter().enumerate() {
-        check_datetime(
+        check(
             &revision.get_date(),
             &format!("/document/tracking/revision_history/{i_r}/date"),
-        )?;
+        );
     }
 
     // Check vulnerability-related dates
     for (i_v, vuln) in doc.get_vulnerabilities().iter().enumerate() {
         // Check disclosure date if present
         if let Some(date) = vuln.get_disclosure_date() {
-            check_datetime(&date, &format!("/vulnerabilities/{i_v}/disclosure_date"))?;
+            check(&date, &format!("/vulnerabilities/{i_v}/disclosure_date"));
         }
 
         // Check the discovery date if present
         if let Some(date) = vuln.get_discovery_date() {
-            check_datetime(&date, &format!("/vulnerabilities/{i_v}/discovery_date"))?;
+            check(&date, &format!("/vulnerabilities/{i_v}/discovery_date"));
+        }
+
+        if let Some(first_known_exploitation_dates) = vuln.get_first_known_exploitation_dates() {
+            for (i_d, date) in first_known_exploitation_dates.iter().enumerate() {
+                check(
+                    &date.get_date(),
+                    &format!("/vulnerabilities/{i_v}/first_known_exploitation_dates/{i_d}/date"),
+                );
+                check(
+                    &date.get_exploitation_date(),
+                    &format!("/vulnerabilities/{i_v}/first_known_exploitation_dates/{i_d}/exploitation_date"),
+                );
+            }
         }
 
         // Check flags dates if present
         if let Some(flags) = vuln.get_flags() {
             for (i_f, flag) in flags.iter().enumerate() {
                 if let Some(date) = flag.get_date() {
-                    check_datetime(&date, &format!("/vulnerabilities/{i_v}/flags/{i_f}/date"))?;
+                    check(&date, &format!("/vulnerabilities/{i_v}/flags/{i_f}/date"));
                 }
             }
         }
@@ -72,7 +91,19 @@ pub fn test_6_1_37_date_and_time(doc: &impl CsafTrait) -> Result<(), Vec<TestFin
         if let Some(involvements) = vuln.get_involvements() {
             for (i_i, involvement) in involvements.iter().enumerate() {
                 if let Some(date) = involvement.get_date() {
-                    check_datetime(&date, &format!("/vulnerabilities/{i_v}/involvements/{i_i}/date"))?;
+                    check(&date, &format!("/vulnerabilities/{i_v}/involvements/{i_i}/date"));
+                }
+            }
+        }
+
+        // Check EPSS timestamps if present
+        if let Some(metrics) = vuln.get_metrics() {
+            for (i_m, metric) in metrics.iter().enumerate() {
+                if let Some(epss) = metric.get_content().get_epss() {
+                    check(
+                        &epss.get_timestamp(),
+                        &format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/epss/timestamp"),
+                    );
                 }
             }
         }
@@ -80,32 +111,19 @@ pub fn test_6_1_37_date_and_time(doc: &impl CsafTrait) -> Result<(), Vec<TestFin
         // Check remediation dates if present
         for (i_r, remediation) in vuln.get_remediations().iter().enumerate() {
             if let Some(date) = remediation.get_date() {
-                check_datetime(&date, &format!("/vulnerabilities/{i_v}/remediations/{i_r}/date"))?;
+                check(&date, &format!("/vulnerabilities/{i_v}/remediations/{i_r}/date"));
             }
         }
 
         // Check threat dates if present
         for (i_t, threat) in vuln.get_threats().iter().enumerate() {
             if let Some(date) = threat.get_date() {
-                check_datetime(&date, &format!("/vulnerabilities/{i_v}/threats/{i_t}/date"))?;
-            }
-        }
-
-        if let Some(first_known_exploitation_dates) = vuln.get_first_known_exploitation_dates() {
-            for (i_d, date) in first_known_exploitation_dates.iter().enumerate() {
-                check_datetime(
-                    &date.get_date(),
-                    &format!("/vulnerabilities/{i_v}/first_known_exploitation_dates/{i_d}/date"),
-                )?;
-                check_datetime(
-                    &date.get_exploitation_date(),
-                    &format!("/vulnerabilities/{i_v}/first_known_exploitation_dates/{i_d}/exploitation_date"),
-                )?;
+                check(&date, &format!("/vulnerabilities/{i_v}/threats/{i_t}/date"));
             }
         }
     }
 
-    Ok(())
+    errors.map_or(Ok(()), Err)
 }
 
 fn create_invalid_format_error(date_time: &str, instance_path: &str) -> TestFinding {
@@ -200,6 +218,13 @@ mod tests {
             case_14: Ok(()),
             case_15: Ok(()),
             case_16: Ok(()),
+            case_s01: Err(vec![
+                create_invalid_format_error("2024-01-24 10:00:00Z", "/document/tracking/current_release_date"),
+                create_invalid_format_error(
+                    "2024-01-24T10:00:00",
+                    "/vulnerabilities/0/metrics/0/content/epss/timestamp",
+                ),
+            ]),
         });
     }
 }
@@ -1,6 +1,7 @@
 use crate::csaf::types::purl::csaf_purl::CsafPurl::{Invalid, Valid};
 use crate::csaf_traits::{CsafTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 fn create_purl_consistency_error(path: &str, index: usize) -> TestFinding {
@@ -12,8 +13,10 @@ fn create_purl_consistency_error(path: &str, index: usize) -> TestFinding {
 
 /// 6.1.42 PURL Consistency
 /// Checks the consistency of PURLs within the same product_identification_helper. PURLs must only differ in qualifiers.
-pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
+    let mut checked_helpers = 0;
+    let mut invalid_purls = 0;
 
     if let Some(product_tree) = doc.get_product_tree() {
         product_tree.visit_all_products(&mut |product, path| {
@@ -41,15 +44,14 @@ pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> Result<(), Vec<Test
                                 // push path index into vec
                                 .push(i);
                         },
-                        Invalid(_) => {
-                            // ToDo #409 create precondition failed warning
-                            continue;
-                        },
+                        // invalid PURLs are caught by the schema test
+                        Invalid(_) => invalid_purls += 1,
                     };
                 }
 
                 // if there were any valid purls
                 if let Some(bases) = bases_map {
+                    checked_helpers += 1;
                     // Collect values and sort by length descending
                     let mut sorted_values: Vec<Vec<usize>> = bases.into_values().collect();
                     // Sort by group size descending, then by first index ascending for determinism
@@ -69,7 +71,16 @@ pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> Result<(), Vec<Test
         });
     }
 
-    errors.map_or(Ok(()), Err)
+    match errors {
+        Some(errors) => TestResult::Failed(errors),
+        None if checked_helpers == 0 && invalid_purls > 0 => {
+            TestResult::precondition_failed("All PURLs which have to be checked for consistency are invalid.")
+        },
+        None if checked_helpers == 0 => {
+            TestResult::no_data("The document does not contain any product identification helper with multiple PURLs.")
+        },
+        None => TestResult::Passed(Passed::Success),
+    }
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_42, test_6_1_42_purl_consistency);
@@ -3,6 +3,7 @@ use crate::csaf::types::csaf_datetime::{CsafDateTime, ValidCsafDateTime};
 use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, TlpTrait, TrackingTrait, VulnerabilityTrait};
 use crate::schema::csaf2_1::schema::{DocumentStatus, LabelOfTlp};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
 
 fn create_disclosure_date_too_late_error(
     doc_status: &DocumentStatus,
@@ -23,7 +24,7 @@ fn create_disclosure_date_too_late_error(
 /// For each vulnerability, it is tested that the `disclosure_date` is earlier or equal to the `date`
 /// of the newest item in the `revision_history` (taking timezones into consideration)
 /// if the document is TLP:CLEAR and the document status is `final` or `interim`.
-pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
     let tracking = document.get_tracking();
     let status = tracking.get_status();
@@ -40,7 +41,7 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
     };
 
     if !is_tlp_clear {
-        return Ok(());
+        return TestResult::not_applicable("The test only applies to TLP:CLEAR documents.");
     }
 
     // Get sorted revision history and find the newest entry
@@ -51,9 +52,11 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
     let newest_revision_date = match revision_history.last() {
         Some(rev) => match &rev.date {
             CsafDateTime::Valid(date) => date,
-            CsafDateTime::Invalid(_) => return Ok(()), // TODO: This will be a Precondition failed #409
+            CsafDateTime::Invalid(_) => {
+                return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
+            },
         },
-        None => return Ok(()), // TODO this should be a #409 precondition failed #409
+        None => return TestResult::precondition_failed("The revision history is empty."),
     };
 
     // Check each vulnerability's disclosure date
@@ -80,7 +83,7 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(Ok(()), Err).into()
 }
 
 crate::test_validation::impl_validator!(
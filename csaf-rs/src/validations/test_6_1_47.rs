@@ -2,6 +2,7 @@ use crate::csaf_traits::{
     ContentTrait, CsafTrait, DocumentTrait, MetricTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait,
 };
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_document_id_multiple_vulnerabilities_error(
     document_id: &str,
@@ -26,21 +27,16 @@ fn create_target_id_mismatch_error(target_id: &str, i_v: usize, i_m: usize, i_t:
     })
 }
 
-fn create_invalid_ssvc_error(error: impl std::fmt::Display, i_v: usize, i_m: usize) -> TestFinding {
-    TestFinding::Error(TestFindingData {
-        message: format!("Invalid SSVC object: {error}"),
-        instance_path: format!("/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2"),
-    })
-}
-
 /// 6.1.47 Inconsistent SSVC Target IDs
 ///
 /// For each ssvc_v2 object it MUST be tested that each item in target_ids is either
 /// the CVE of the vulnerability given in cve or the text of an item in the ids array of the vulnerability.
 /// The test MUST fail, if the target ID equals the /document/tracking/id and the CSAF document
 /// contains more than one vulnerability.
-pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
+    let mut checked_ssvc = 0;
+    let mut invalid_ssvc = 0;
 
     let vulnerabilities = doc.get_vulnerabilities();
 
@@ -54,6 +50,7 @@ pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> Result<(), Vec<
                     match content.get_ssvc_v2() {
                         // parsing succeeded
                         Ok(ssvc) => {
+                            checked_ssvc += 1;
                             // get the SSVC target_ids if they exist
                             if let Some(target_ids) = &ssvc.target_ids {
                                 let document_id = doc.get_document().get_tracking().get_id();
@@ -98,20 +95,22 @@ pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> Result<(), Vec<
                                 }
                             }
                         },
-                        // parsing failed
-                        Err(err) => {
-                            // TODO #409 this will nondeterminable later
-                            errors
-                                .get_or_insert_default()
-                                .push(create_invalid_ssvc_error(err, i_v, i_m));
-                        },
+                        // invalid SSVC objects are caught by 6.1.46
+                        Err(_) => invalid_ssvc += 1,
                     }
                 }
             }
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    match errors {
+        Some(errors) => TestResult::Failed(errors),
+        None if checked_ssvc == 0 && invalid_ssvc > 0 => {
+            TestResult::precondition_failed("All SSVC objects are invalid.")
+        },
+        None if checked_ssvc == 0 => TestResult::no_data("The document does not contain any SSVC objects."),
+        None => TestResult::Passed(Passed::Success),
+    }
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_47, test_6_1_47_inconsistent_ssvc_id);
@@ -8,7 +8,11 @@ fn create_invalid_ssvc_error(error: impl std::fmt::Display, i_v: usize, i_m: usi
     })
 }
 
-/// Test function for invocation by users, does not permit usage of the "test" namespace.
+/// 6.1.48 SSVC Decision Points
+///
+/// For each SSVC object, it is tested that all decision points are registered (or valid private
+/// ones) and their values are valid and in the correct order. The reserved `test` namespace is
+/// not allowed.
 pub fn test_6_1_48_ssvc_decision_points(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
     test_6_1_48_ssvc_decision_points_internal(doc, false)
 }
@@ -19,9 +23,9 @@ fn test_6_1_48_ssvc_decision_points_internal(
     doc: &impl CsafTrait,
     allow_test_namespaces: bool,
 ) -> Result<(), Vec<TestFinding>> {
-    let vulnerabilities = doc.get_vulnerabilities();
+    let mut errors: Option<Vec<TestFinding>> = None;
 
-    for (i_v, v) in vulnerabilities.iter().enumerate() {
+    for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(metrics) = v.get_metrics() {
             for (i_m, m) in metrics.iter().enumerate() {
                 let content = m.get_content();
@@ -29,25 +33,22 @@ fn test_6_1_48_ssvc_decision_points_internal(
                     match content.get_ssvc_v2() {
                         Ok(ssvc) => {
                             let result = ssvc::validate_selection_list(&ssvc, allow_test_namespaces);
-                            if !result.success {
-                                let validation_errors: Vec<TestFinding> = result
-                                    .errors
-                                    .into_iter()
-                                    .map(|ssvc_error| {
-                                        let path_suffix = ssvc_error.instance_path.join("/");
-                                        TestFinding::Error(TestFindingData {
-                                            message: ssvc_error.message,
-                                            instance_path: format!(
-                                                "/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2/{path_suffix}"
-                                            ),
-                                        })
+                            errors
+                                .get_or_insert_default()
+                                .extend(result.errors.into_iter().map(|ssvc_error| {
+                                    let path_suffix = ssvc_error.instance_path.join("/");
+                                    TestFinding::Error(TestFindingData {
+                                        message: ssvc_error.message,
+                                        instance_path: format!(
+                                            "/vulnerabilities/{i_v}/metrics/{i_m}/content/ssvc_v2/{path_suffix}"
+                                        ),
                                     })
-                                    .collect();
-                                return Err(validation_errors);
-                            }
+                                }));
                         },
                         Err(err) => {
-                            return Err(vec![create_invalid_ssvc_error(err, i_v, i_m)]);
+                            errors
+                                .get_or_insert_default()
+                                .push(create_invalid_ssvc_error(err, i_v, i_m));
                         },
                     }
                 }
@@ -55,26 +56,27 @@ fn test_6_1_48_ssvc_decision_points_internal(
         }
     }
 
-    Ok(())
+    errors.filter(|errors| !errors.is_empty()).map_or(Ok(()), Err)
 }
 
-impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
-    for crate::csaf2_1::testcases::ValidatorForTest6_1_48
-{
-    fn validate(
-        &self,
-        doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
-    ) -> Result<(), Vec<TestFinding>> {
-        // Use the internal validation function allowing usage of the "test" namespace.
-        test_6_1_48_ssvc_decision_points_internal(doc, true)
-    }
-}
+crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_48, test_6_1_48_ssvc_decision_points);
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::csaf2_1::testcases::ExpectedResults_6_1_48 as ExpectedResults;
-    use crate::csaf2_1::testcases::TESTS_2_1;
-    use crate::validation::{TestFinding, TestFindingData};
+    use crate::csaf2_1::testcases::Test6_1_48;
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+
+    /// The test fixtures use the reserved "test" namespace, which is only allowed here.
+    #[derive(Debug, Default)]
+    struct ValidatorAllowingTestNamespaces;
+
+    impl crate::test_validation::TestValidator<CommonSecurityAdvisoryFramework> for ValidatorAllowingTestNamespaces {
+        fn validate(&self, doc: &CommonSecurityAdvisoryFramework) -> crate::validation_result::TestResult {
+            test_6_1_48_ssvc_decision_points_internal(doc, true).into()
+        }
+    }
 
     #[test]
     fn test_test_6_1_48() {
@@ -138,13 +140,23 @@ mod tests {
             instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0/namespace".to_string(),
         })]);
         let case_16 = case_06.clone();
+        let case_s01 = Err(vec![
+            TestFinding::Error(TestFindingData {
+                message: "Unknown SSVC decision point 'ssvc::SIs' with version '2.0.0'".to_string(),
+                instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0".to_string(),
+            }),
+            TestFinding::Error(TestFindingData {
+                message: "Unknown SSVC decision point 'ssvc::SIs' with version '2.0.0'".to_string(),
+                instance_path: "/vulnerabilities/1/metrics/0/content/ssvc_v2/selections/0".to_string(),
+            }),
+        ]);
         let case_19 = Err(vec![TestFinding::Error ( TestFindingData {
             message: "The values for SSVC decision point 'ssvc//.example.test$de-DE::Safety Impact' (version 2.0.0) are not in correct order".to_string(),
             instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0/values/2".to_string(),
         })]);
 
         // Only CSAF 2.1 has this test, with 20 test cases (6 error cases, 14 success cases)
-        TESTS_2_1.test_6_1_48.expect(ExpectedResults {
+        Test6_1_48::<ValidatorAllowingTestNamespaces>::new().expect(ExpectedResults {
             case_01,
             case_02,
             case_03,
@@ -165,6 +177,7 @@ mod tests {
             case_18: Ok(()),
             case_19, // wrong order of translated keys "R" and "C"
             case_31: Ok(()),
+            case_s01, // errors of all vulnerabilities are reported
         });
     }
 }
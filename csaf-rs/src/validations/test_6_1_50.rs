@@ -0,0 +1,77 @@
+use crate::csaf::types::vers::csaf_vers_range::CsafVersRange;
+use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait};
+use crate::validation::{IntoTestFindingError, TestFinding};
+
+/// 6.1.50 Product Version Range Rules
+///
+/// For each element of type `/$defs/branches_t` with `category` of `product_version_range`
+/// whose `name` starts with `vers:`, it MUST be tested that the `name` is a valid vers according
+/// to the version range specification. This includes the syntax of the constraints as well as the
+/// rules that versions must be unique, `*` must be used alone and the constraints must describe
+/// non-overlapping ranges.
+///
+/// Version ranges not using vers are reported by 6.2.18.
+pub fn test_6_1_50_product_version_range_rules(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            if branch.get_category() == CategoryOfTheBranch::ProductVersionRange
+                && branch.get_name().starts_with("vers:")
+                && let CsafVersRange::Invalid(error) = CsafVersRange::from(branch.get_name())
+            {
+                errors
+                    .get_or_insert_default()
+                    .push(error.into_test_finding_error(&format!("{path}/name")));
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_50, test_6_1_50_product_version_range_rules);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_1_50 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    fn invalid_vers_error(vers: &str, instance_path: &str) -> TestFinding {
+        match CsafVersRange::from(vers) {
+            CsafVersRange::Invalid(error) => error.into_test_finding_error(instance_path),
+            CsafVersRange::Valid(_) => panic!("{vers} should be an invalid vers"),
+        }
+    }
+
+    #[test]
+    fn test_test_6_1_50() {
+        let range_path = "/product_tree/branches/0/branches/0/branches/0/name";
+
+        // Case 11 to 16: valid vers and version ranges not using vers
+        // Case S11: valid vers and a version range not using vers
+        TESTS_2_1.test_6_1_50.expect(ExpectedResults {
+            case_01: Err(vec![invalid_vers_error("vers:semver/>=1.0.0|>=2.0.0", range_path)]),
+            case_02: Err(vec![invalid_vers_error("vers:semver/*|1.0.0", range_path)]),
+            case_03: Err(vec![invalid_vers_error("vers:semver/1.0.0|1.0.0", range_path)]),
+            case_04: Err(vec![invalid_vers_error("vers:semver/>=", range_path)]),
+            case_05: Err(vec![invalid_vers_error("vers:semver/>=1.0", range_path)]),
+            case_06: Err(vec![invalid_vers_error("vers:semver/=>1.0.0", range_path)]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_14: Ok(()),
+            case_15: Ok(()),
+            case_16: Ok(()),
+            case_s01: Err(vec![
+                invalid_vers_error(
+                    "vers:semver/>=1.0.0|>=2.0.0",
+                    "/product_tree/branches/0/branches/1/name",
+                ),
+                invalid_vers_error("vers:npm/*|1.0.0", "/product_tree/branches/0/branches/2/name"),
+            ]),
+            case_s11: Ok(()),
+        });
+    }
+}
@@ -5,6 +5,7 @@ use crate::csaf_traits::{
 };
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_epss_timestamp_too_new_error(
     doc_status: &DocumentStatus,
@@ -25,7 +26,7 @@ fn create_epss_timestamp_too_new_error(
 /// For each vulnerability, it is tested that the EPSS `timestamp` is earlier or equal to the `date`
 /// of the newest item in the `revision_history` (taking timezones into consideration)
 /// if the document status is `final` or `interim`.
-pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
     let tracking = document.get_tracking();
     let status = tracking.get_status();
@@ -40,13 +41,17 @@ pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> Result<(
     let newest_revision_date = match revision_history.last() {
         Some(rev) => match &rev.date {
             CsafDateTime::Valid(date) => date,
-            CsafDateTime::Invalid(_) => return Ok(()), // TODO: This will be a Precondition failed #409
+            CsafDateTime::Invalid(_) => {
+                return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
+            },
         },
-        None => return Ok(()), // TODO this should be a #409 precondition failed #409
+        None => return TestResult::precondition_failed("The revision history is empty."),
     };
 
     // Check each vulnerability's EPSS timestamp
     let mut errors: Option<Vec<TestFinding>> = None;
+    let mut checked_timestamps = 0;
+    let mut invalid_timestamps = 0;
     for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(metrics) = vulnerability.get_metrics() {
             for (i_m, metric) in metrics.iter().enumerate() {
@@ -54,6 +59,7 @@ pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> Result<(
                 if let Some(epss) = content.get_epss() {
                     match epss.get_timestamp() {
                         CsafDateTime::Valid(valid_timestamp) => {
+                            checked_timestamps += 1;
                             // TODO fix this after #503
                             if &valid_timestamp > newest_revision_date {
                                 let content_json_path = content.get_content_json_path(i_v, i_m);
@@ -65,16 +71,22 @@ pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> Result<(
                                 ));
                             }
                         },
-                        CsafDateTime::Invalid(_) => {
-                            // TODO: This will be a NonDeterminable (#409) later
-                        },
+                        // invalid timestamps are caught by 6.1.37
+                        CsafDateTime::Invalid(_) => invalid_timestamps += 1,
                     }
                 }
             }
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    match errors {
+        Some(errors) => TestResult::Failed(errors),
+        None if checked_timestamps == 0 && invalid_timestamps > 0 => {
+            TestResult::precondition_failed("All EPSS timestamps are invalid.")
+        },
+        None if checked_timestamps == 0 => TestResult::no_data("The document does not contain any EPSS timestamps."),
+        None => TestResult::Passed(Passed::Success),
+    }
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_51, test_6_1_51_inconsistent_epss_timestamp);
@@ -6,6 +6,7 @@ use crate::csaf_traits::{
 };
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
 use strum::{AsRefStr, Display};
 
 #[derive(Display, AsRefStr)]
@@ -38,7 +39,7 @@ fn create_date_too_new_error(
 /// `exploitation_date` properties are both earlier than or equal to the `date` of the newest item
 /// of the `revision_history` (taking timezones into consideration) if the document
 /// status is `final` or `interim`.
-pub fn test_6_1_52_inconsistent_first_known_exploitation_dates(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_52_inconsistent_first_known_exploitation_dates(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
     let tracking = document.get_tracking();
     let status = tracking.get_status();
@@ -52,15 +53,21 @@ pub fn test_6_1_52_inconsistent_first_known_exploitation_dates(doc: &impl CsafTr
     let newest_revision_date = match revision_history.last() {
         Some(rev) => match &rev.date {
             CsafDateTime::Valid(date) => date,
-            CsafDateTime::Invalid(_) => return Ok(()), // TODO: This will be a Precondition failed #409
+            CsafDateTime::Invalid(_) => {
+                return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
+            },
         },
-        None => return Ok(()), // TODO this should be a #409 precondition failed #409
+        None => return TestResult::precondition_failed("The revision history is empty."),
     };
 
+    let vulnerabilities = doc.get_vulnerabilities();
+    if vulnerabilities.is_empty() {
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
+    }
+
     // Check each vulnerability's first known exploitation dates
     let mut errors: Option<Vec<TestFinding>> = None;
-    // TODO: #409 no data
-    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+    for (v_i, vulnerability) in vulnerabilities.iter().enumerate() {
         if let Some(first_known_exploitation_dates) = vulnerability.get_first_known_exploitation_dates() {
             for (f_i, first_known_exploitation_date) in first_known_exploitation_dates.iter().enumerate() {
                 if let Valid(valid_first_known_explot_date) = first_known_exploitation_date.get_date() {
@@ -96,7 +103,7 @@ pub fn test_6_1_52_inconsistent_first_known_exploitation_dates(doc: &impl CsafTr
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(Ok(()), Err).into()
 }
 
 crate::test_validation::impl_validator!(
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_datetime::CsafDateTime::Valid;
 use crate::csaf_traits::{CsafTrait, FirstKnownExploitationDatesTrait, VulnerabilityTrait, WithDate};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_inconsistent_exploitation_date_error(
     exploitation_date: &str,
@@ -20,14 +21,17 @@ fn create_inconsistent_exploitation_date_error(
 ///
 /// For each `/vulnerabilities[]/first_known_exploitation_dates[]`, it is tested that `exploitation_date`
 /// is earlier or equal to the `date`.
-pub fn test_6_1_53_inconsistent_exploitation_date(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_53_inconsistent_exploitation_date(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
+    let mut checked_dates = 0;
+    let mut invalid_dates = 0;
     for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(first_known_exploitation_dates) = vulnerability.get_first_known_exploitation_dates() {
             for (f_i, first_known_exploitation_date) in first_known_exploitation_dates.iter().enumerate() {
                 if let Valid(exploitation_date) = first_known_exploitation_date.get_exploitation_date()
                     && let Valid(date) = first_known_exploitation_date.get_date()
                 {
+                    checked_dates += 1;
                     if exploitation_date > date {
                         errors
                             .get_or_insert_default()
@@ -39,13 +43,23 @@ pub fn test_6_1_53_inconsistent_exploitation_date(doc: &impl CsafTrait) -> Resul
                             ));
                     }
                 } else {
-                    // TODO: This will be a NonDeterminable (#409) later
+                    // invalid dates are caught by 6.1.37
+                    invalid_dates += 1;
                 }
             }
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    match errors {
+        Some(errors) => TestResult::Failed(errors),
+        None if checked_dates == 0 && invalid_dates > 0 => {
+            TestResult::precondition_failed("All first known exploitation dates contain invalid dates.")
+        },
+        None if checked_dates == 0 => {
+            TestResult::no_data("The document does not contain any first known exploitation dates.")
+        },
+        None => TestResult::Passed(Passed::Success),
+    }
 }
 
 crate::test_validation::impl_validator!(
@@ -3,6 +3,7 @@ use spdx::Expression;
 use crate::csaf_traits::CsafTrait;
 use crate::schema::csaf2_1::schema::LicenseExpression;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_invalid_license_expression_error(license_expression: &str, error: &str) -> TestFinding {
     TestFinding::Error(TestFindingData {
@@ -60,20 +61,18 @@ fn parse_license_as_allowed_in_csaf(license: &LicenseExpression) -> Result<Expre
 /// on the DocumentRef part given in 3.2.2.7.
 pub fn test_6_1_54_invalid_license_expression(
     doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
-) -> Result<(), Vec<TestFinding>> {
-    let document = doc.get_document();
+) -> TestResult {
+    let Some(license) = doc.get_document().license_expression.as_ref() else {
+        return TestResult::no_data("The document does not contain a license expression.");
+    };
 
-    document
-        .license_expression
-        .as_ref()
-        .map(|license| match parse_license_as_allowed_in_csaf(license) {
-            Ok(_) => Ok(()),
-            Err(error) => Err(vec![create_invalid_license_expression_error(
-                license.as_str(),
-                format!("Error at position {}: {}", error.span.start, error.reason).as_str(),
-            )]),
-        })
-        .unwrap_or(Ok(())) // TODO: this may become passed - not data #409
+    match parse_license_as_allowed_in_csaf(license) {
+        Ok(_) => TestResult::Passed(Passed::Success),
+        Err(error) => TestResult::Failed(vec![create_invalid_license_expression_error(
+            license.as_str(),
+            format!("Error at position {}: {}", error.span.start, error.reason).as_str(),
+        )]),
+    }
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_54, test_6_1_54_invalid_license_expression);
@@ -29,7 +29,7 @@ fn create_incorrect_license_text_category_error(license_expression_path: &str, c
     })
 }
 
-fn license_listed_in_spdx_licensedb(license: &LicenseExpression) -> bool {
+pub(crate) fn license_listed_in_spdx_licensedb(license: &LicenseExpression) -> bool {
     match Expression::parse(license.as_str()) {
         Ok(parsed) => parsed.requirements().all(|requirement| match &requirement.req.license {
             spdx::LicenseItem::Other(license_ref) => {
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait, build_leaf_instance_path};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 fn create_stacked_categories_error(
@@ -28,11 +29,11 @@ fn create_stacked_categories_error(
 ///
 /// In the product tree, the path from root to any FPN, all branch categories (except 'product_family')
 /// are only allowed to occur once.
-pub fn test_6_1_57_stacked_branch_categories(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_57_stacked_branch_categories(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
 
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(()); // this will be a Passed::NoData later (#409)
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     // get all paths from root to leaves in the product tree
@@ -70,7 +71,7 @@ pub fn test_6_1_57_stacked_branch_categories(doc: &impl CsafTrait) -> Result<(),
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_1_57, test_6_1_57_stacked_branch_categories);
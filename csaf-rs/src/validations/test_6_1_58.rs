@@ -1,5 +1,6 @@
 use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait, build_leaf_instance_path};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_both_product_version_version_range_error(instance_path: String) -> TestFinding {
     TestFinding::Error(TestFindingData {
@@ -12,11 +13,9 @@ fn create_both_product_version_version_range_error(instance_path: String) -> Tes
 ///
 /// In the product tree, the path from root to any FPN, the branches with category 'product_version'
 /// and 'product_version_range' are not allowed to occur together.
-pub fn test_6_1_58_product_version_and_product_version_range_in_one_path(
-    doc: &impl CsafTrait,
-) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_1_58_product_version_and_product_version_range_in_one_path(doc: &impl CsafTrait) -> TestResult {
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(()); // this will be a Passed::NoData later (#409)
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -44,7 +43,7 @@ pub fn test_6_1_58_product_version_and_product_version_range_in_one_path(
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
@@ -0,0 +1,131 @@
+use crate::csaf_traits::{
+    CsafTrait, FlagTrait, ProductStatusGroup, ProductStatusGroupMap, VulnerabilityTrait, WithOptionalGroupIds,
+    WithOptionalProductIds, resolve_product_groups,
+};
+use crate::schema::csaf2_1::schema::LabelOfTheFlag;
+use crate::validation::{TestFinding, TestFindingData};
+use std::collections::BTreeSet;
+
+/// Product status groups that contradict a flag, as all flag labels are justifications for a
+/// product not being affected.
+const CONTRADICTING_GROUPS: &[ProductStatusGroup] =
+    &[ProductStatusGroup::Affected, ProductStatusGroup::UnderInvestigation];
+
+fn create_contradicting_flag_error(
+    product_id: &str,
+    group: &ProductStatusGroup,
+    label: &LabelOfTheFlag,
+    v_i: usize,
+    f_i: usize,
+) -> TestFinding {
+    TestFinding::Error(TestFindingData {
+        message: format!("Product {product_id} is listed as {group} but has the contradicting flag {label}"),
+        instance_path: format!("/vulnerabilities/{v_i}/flags/{f_i}"),
+    })
+}
+
+/// 6.1.59 Contradicting Product Status and Flags
+///
+/// For each item in `/vulnerabilities`, it must be tested that no product which is referenced by
+/// a flag is member of the product status groups "affected" or "under investigation", as the
+/// labels of flags justify why a product is not affected. Products are also taken into account if
+/// a flag references them through a product group.
+pub fn test_6_1_59_contradicting_product_status_and_flags(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let (Some(product_status), Some(flags)) = (vulnerability.get_product_status(), vulnerability.get_flags())
+        else {
+            continue;
+        };
+        let status_map = ProductStatusGroupMap::from(product_status);
+        for (f_i, flag) in flags.iter().enumerate() {
+            let mut product_ids: BTreeSet<String> = flag
+                .get_product_ids()
+                .into_iter()
+                .flatten()
+                .map(|id| id.to_owned())
+                .collect();
+            if let Some(group_ids) = flag.get_group_ids()
+                && let Some(resolved_product_ids) = resolve_product_groups(doc, group_ids)
+            {
+                product_ids.extend(resolved_product_ids);
+            }
+            for product_id in &product_ids {
+                for group in CONTRADICTING_GROUPS {
+                    if status_map.contains(group, product_id) {
+                        errors.get_or_insert_default().push(create_contradicting_flag_error(
+                            product_id,
+                            group,
+                            &flag.get_label(),
+                            v_i,
+                            f_i,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_1_59,
+    test_6_1_59_contradicting_product_status_and_flags
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_1_59 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_1_59() {
+        // Case 11 to 13: flags only reference products that are not affected
+        // Case S11: flag referencing not affected products through a product group
+        TESTS_2_1.test_6_1_59.expect(ExpectedResults {
+            case_01: Err(vec![create_contradicting_flag_error(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::Affected,
+                &LabelOfTheFlag::ComponentNotPresent,
+                0,
+                0,
+            )]),
+            case_02: Err(vec![create_contradicting_flag_error(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::UnderInvestigation,
+                &LabelOfTheFlag::ComponentNotPresent,
+                0,
+                0,
+            )]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_s01: Err(vec![create_contradicting_flag_error(
+                "CSAFPID-9080701",
+                &ProductStatusGroup::Affected,
+                &LabelOfTheFlag::VulnerableCodeNotPresent,
+                0,
+                1,
+            )]),
+            case_s02: Err(vec![
+                create_contradicting_flag_error(
+                    "CSAFPID-9080700",
+                    &ProductStatusGroup::UnderInvestigation,
+                    &LabelOfTheFlag::ComponentNotPresent,
+                    0,
+                    0,
+                ),
+                create_contradicting_flag_error(
+                    "CSAFPID-9080700",
+                    &ProductStatusGroup::UnderInvestigation,
+                    &LabelOfTheFlag::VulnerableCodeNotPresent,
+                    0,
+                    1,
+                ),
+            ]),
+            case_s11: Ok(()),
+        });
+    }
+}
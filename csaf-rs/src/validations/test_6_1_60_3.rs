@@ -0,0 +1,79 @@
+use crate::csaf_traits::{CsafTrait, VulnerabilityTrait, WithOptionalDate};
+use crate::validation_result::TestResult;
+use crate::validations::utils::dates_after_revision_history::test_dates_not_newer_than_revision_history;
+
+/// 6.1.60.3 Inconsistent Threat Date
+///
+/// For each item in `/vulnerabilities[]/threats`, it is tested that the `date` is earlier than or
+/// equal to the `date` of the newest item of the `revision_history` (taking timezones into
+/// consideration) if the document status is `final` or `interim`.
+pub fn test_6_1_60_3_inconsistent_threat_date(doc: &impl CsafTrait) -> TestResult {
+    let dates = doc
+        .get_vulnerabilities()
+        .iter()
+        .enumerate()
+        .flat_map(|(v_i, vulnerability)| {
+            vulnerability
+                .get_threats()
+                .iter()
+                .enumerate()
+                .filter_map(move |(t_i, threat)| {
+                    threat
+                        .get_date()
+                        .map(|date| (date, format!("/vulnerabilities/{v_i}/threats/{t_i}/date")))
+                })
+        });
+    test_dates_not_newer_than_revision_history(doc, "threat", dates)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_1_60_3,
+    test_6_1_60_3_inconsistent_threat_date
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::types::csaf_datetime::ValidCsafDateTime;
+    use crate::csaf2_1::testcases::ExpectedResults_6_1_60_3 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::schema::csaf2_1::schema::DocumentStatus;
+    use crate::validations::utils::dates_after_revision_history::create_date_too_new_error;
+    use std::str::FromStr;
+
+    fn too_new(date: &str, newest_revision_date: &str, instance_path: &str) -> crate::validation::TestFinding {
+        create_date_too_new_error(
+            "threat",
+            &DocumentStatus::Final,
+            &ValidCsafDateTime::from_str(date).unwrap(),
+            &ValidCsafDateTime::from_str(newest_revision_date).unwrap(),
+            instance_path,
+        )
+    }
+
+    #[test]
+    fn test_test_6_1_60_3() {
+        // Case 11 and 12: threat dates not newer than the newest revision date
+        // Case S11: newer threat date on a draft document
+        TESTS_2_1.test_6_1_60_3.expect(ExpectedResults {
+            case_01: Err(vec![too_new(
+                "2024-07-13T10:00:00.000Z",
+                "2024-01-24T10:00:00.000Z",
+                "/vulnerabilities/0/threats/0/date",
+            )]),
+            case_02: Err(vec![too_new(
+                "2024-02-29T14:30:00.000+04:00",
+                "2024-02-29T10:00:00.000Z",
+                "/vulnerabilities/0/threats/0/date",
+            )]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_s01: Err(vec![too_new(
+                "2024-01-24T12:00:00.000Z",
+                "2024-01-24T10:00:00.000Z",
+                "/vulnerabilities/0/threats/2/date",
+            )]),
+            case_s11: Ok(()),
+        });
+    }
+}
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, ProductTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 
 fn create_unused_product_id_error(product_id: &str, path: &str) -> TestFinding {
@@ -16,12 +17,15 @@ const SKIP_TEST_CONFIG: DocumentCategoryTestConfig =
 /// 6.2.1 Unused Definition of Product ID
 ///
 /// All defined product IDs need to be referenced at least once in the document.
-pub fn test_6_2_01_unused_definition_of_product_id(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_01_unused_definition_of_product_id(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
 
     // Skips the test for profile "Informational Advisory"
-    if SKIP_TEST_CONFIG.matches_category(&doc.get_document().get_category()) {
-        return Ok(());
+    let doc_category = doc.get_document().get_category();
+    if SKIP_TEST_CONFIG.matches_category(&doc_category) {
+        return TestResult::not_applicable(format!(
+            "The test does not apply to documents with category '{doc_category}'."
+        ));
     }
 
     // Get all references to product IDs in the document
@@ -38,7 +42,7 @@ pub fn test_6_2_01_unused_definition_of_product_id(doc: &impl CsafTrait) -> Resu
         });
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_2_1, test_6_2_01_unused_definition_of_product_id);
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_datetime::CsafDateTime::Valid;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_older_initial_release_date_error(
     initial_release_date: impl std::fmt::Display,
@@ -16,7 +17,7 @@ fn create_older_initial_release_date_error(
 
 /// 6.2.5 Older Initial Release Date than Revision History
 ///
-pub fn test_6_2_05_older_init_release_than_rev_history(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_05_older_init_release_than_rev_history(doc: &impl CsafTrait) -> TestResult {
     let initial_release_date = doc.get_document().get_tracking().get_initial_release_date();
     // TODO: Check for invalid dates here, will be done after revision history refactor, which will introduce
     // generic parsing error handling
@@ -25,22 +26,22 @@ pub fn test_6_2_05_older_init_release_than_rev_history(doc: &impl CsafTrait) ->
     rev_history.inplace_sort_by_date_then_number();
     // We can safely unwrap here because empty revision histories would not parse schema validation
     let earliest_rev_history_item_date = match rev_history.first() {
-        None => return Ok(()), // TODO #409 return a precondition failed here,
+        None => return TestResult::precondition_failed("The revision history is empty."),
         Some(x) => x,
     };
     let Valid(initial_release_date) = initial_release_date else {
-        return Ok(()); // TODO #409 return a precondition failed here,
+        return TestResult::precondition_failed("The initial release date is invalid.");
     };
     let Valid(earliest_rev_history_item_date) = &earliest_rev_history_item_date.date else {
-        return Ok(()); // TODO #409 return a precondition failed here,
+        return TestResult::precondition_failed("The date of the oldest revision history item is invalid.");
     };
     if initial_release_date.get_as_utc() < earliest_rev_history_item_date.get_as_utc() {
-        return Err(vec![create_older_initial_release_date_error(
+        return TestResult::Failed(vec![create_older_initial_release_date_error(
             initial_release_date.get_raw_string(),
             earliest_rev_history_item_date.get_raw_string(),
         )]);
     }
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_2_5, test_6_2_05_older_init_release_than_rev_history);
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_datetime::CsafDateTime::Valid;
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_older_current_release_date_error(
     current_release_date: impl std::fmt::Display,
@@ -16,7 +17,7 @@ fn create_older_current_release_date_error(
 
 /// 6.2.6 Older Current Release Date than Revision History
 ///
-pub fn test_6_2_06_older_current_release_than_rev_history(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_06_older_current_release_than_rev_history(doc: &impl CsafTrait) -> TestResult {
     let current_release_date = doc.get_document().get_tracking().get_current_release_date();
     // TODO: Check for invalid dates here, will be done after revision history refactor, which will introduce
     // generic parsing error handling
@@ -25,22 +26,22 @@ pub fn test_6_2_06_older_current_release_than_rev_history(doc: &impl CsafTrait)
     rev_history.inplace_sort_by_date_then_number();
     // We can safely unwrap here because empty revision histories would not parse schema validation
     let newest_rev_history_item_date = match rev_history.last() {
-        None => return Ok(()), // TODO #409 return a precondition failed here,
+        None => return TestResult::precondition_failed("The revision history is empty."),
         Some(x) => x,
     };
     let Valid(current_release_date) = current_release_date else {
-        return Ok(()); // TODO #409 return a precondition failed here
+        return TestResult::precondition_failed("The current release date is invalid.");
     };
     let Valid(newest_rev_history_item_date) = &newest_rev_history_item_date.date else {
-        return Ok(()); // TODO #409 return a precondition failed here
+        return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
     };
     if current_release_date.get_as_utc() < newest_rev_history_item_date.get_as_utc() {
-        return Err(vec![create_older_current_release_date_error(
+        return TestResult::Failed(vec![create_older_current_release_date_error(
             current_release_date.get_raw_string(),
             newest_rev_history_item_date.get_raw_string(),
         )]);
     }
-    Ok(())
+    TestResult::Passed(Passed::Success)
 }
 
 crate::test_validation::impl_validator!(
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{CsafTrait, CsafVersion, DistributionTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::sync::LazyLock;
 
 /// 6.2.10 Missing TLP label
@@ -9,11 +10,11 @@ use std::sync::LazyLock;
 /// This test is obsolete in CSAF 2.1, as `distribution/tlp` is now required by the schema.
 /// The test harness for CSAF 2.1 does not include the test.
 /// If the test function was to be called programmatically on a CSAF 2.1 doc, we are returning
-/// Ok(()). (later wasSkipped TODO)
-pub fn test_6_2_10_missing_tlp_label(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+/// not applicable.
+pub fn test_6_2_10_missing_tlp_label(doc: &impl CsafTrait) -> TestResult {
     // In CSAF 2.1 this field is mandatory and validated via the schema, so we skip this test
     if doc.get_document().get_csaf_version() == CsafVersion::X21 {
-        return Ok(()); // TODO #409 wasSkipped
+        return TestResult::not_applicable("The TLP label is mandatory in CSAF 2.1 and checked by the schema test.");
     }
     // We just need to consider get_distribution_20 / get_tlp_20 here. If either is missing, return an error
     if doc
@@ -22,9 +23,9 @@ pub fn test_6_2_10_missing_tlp_label(doc: &impl CsafTrait) -> Result<(), Vec<Tes
         .and_then(|d| d.get_tlp_20())
         .is_none()
     {
-        Err(vec![MISSING_TLP_LABEL_ERROR.clone()])
+        TestResult::Failed(vec![MISSING_TLP_LABEL_ERROR.clone()])
     } else {
-        Ok(())
+        TestResult::Passed(Passed::Success)
     }
 }
 
@@ -89,10 +90,10 @@ mod tests {
             }))
             .expect("Failed to parse CSAF 2.1 document");
 
-        let result = test_6_2_10_missing_tlp_label(&minimal_csaf_21);
-        assert!(
-            result.is_ok(),
-            "Test 6.2.10 should be skipped (Ok) for CSAF 2.1 documents"
+        assert_eq!(
+            test_6_2_10_missing_tlp_label(&minimal_csaf_21),
+            TestResult::not_applicable("The TLP label is mandatory in CSAF 2.1 and checked by the schema test."),
+            "Test 6.2.10 should not apply to CSAF 2.1 documents"
         );
     }
 }
@@ -2,16 +2,17 @@ use crate::csaf::types::language::CsafLanguage;
 use crate::csaf::types::language::valid_language::PrivateUseReason;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 /// 6.2.14 Use of Private Language
 ///
 /// For each element of type `/$defs/lang_t` it MUST be tested that the language code does not
 /// contain subtags reserved for private use.
-pub fn test_6_2_14_use_of_private_language(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_14_use_of_private_language(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
 
     if document.get_lang().is_none() && document.get_source_lang().is_none() {
-        return Ok(()); // This should be a wasSkipped later (see #409)
+        return TestResult::no_data("The document contains neither a language nor a source language.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -19,7 +20,7 @@ pub fn test_6_2_14_use_of_private_language(doc: &impl CsafTrait) -> Result<(), V
     validate_private_language(document.get_lang(), "/document/lang", &mut errors);
     validate_private_language(document.get_source_lang(), "/document/source_lang", &mut errors);
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 /// Helper function to validate a `lang` tag and check if it contains subtags reserved for private use.
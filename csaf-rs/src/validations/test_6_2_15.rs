@@ -1,15 +1,16 @@
 use crate::csaf::types::language::CsafLanguage;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 /// 6.2.15 Use of Default Language
 ///
 /// The language tag in `/document/lang` and `/document/source_lang` must not contain the default language code `i-default`.
-pub fn test_6_2_15_use_of_default_language(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_15_use_of_default_language(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
 
     if document.get_lang().is_none() && document.get_source_lang().is_none() {
-        return Ok(()); // This should be a wasSkipped later (see #409)
+        return TestResult::no_data("The document contains neither a language nor a source language.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -17,7 +18,7 @@ pub fn test_6_2_15_use_of_default_language(doc: &impl CsafTrait) -> Result<(), V
     validate_default_language(document.get_lang(), "/document/lang", &mut errors);
     validate_default_language(document.get_source_lang(), "/document/source_lang", &mut errors);
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 /// Helper function to validate a `lang` tag and check if it is the default language.
@@ -0,0 +1,161 @@
+use crate::csaf::types::csaf_vuln_metric::CsafVulnerabilityMetric;
+use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, ProductStatusTrait, VulnerabilityTrait};
+use crate::validation::{TestFinding, TestFindingData};
+use std::collections::BTreeSet;
+
+fn create_cvss_for_fixed_product_warning(
+    product_id: &str,
+    metric: &CsafVulnerabilityMetric,
+    instance_prefix: &str,
+) -> TestFinding {
+    TestFinding::Warning(TestFindingData {
+        message: format!(
+            "Product {product_id} is listed as fixed, but the {metric} applying to it does not have an environmental score of 0"
+        ),
+        instance_path: format!("{instance_prefix}/{}", metric.get_metric_prop_name()),
+    })
+}
+
+/// 6.2.19 CVSS for Fixed Products
+///
+/// For each item in the fixed products group (`first_fixed` and `fixed`) it MUST be tested that a
+/// CVSS applying to this product has an environmental score of 0. The test passes if none of the
+/// fixed products is covered by a CVSS object.
+pub fn test_6_2_19_cvss_for_fixed_products(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+
+    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let (Some(product_status), Some(metrics)) = (vulnerability.get_product_status(), vulnerability.get_metrics())
+        else {
+            continue;
+        };
+        let fixed_products: BTreeSet<&str> = product_status
+            .get_first_fixed()
+            .into_iter()
+            .flatten()
+            .chain(product_status.get_fixed().into_iter().flatten())
+            .collect();
+        if fixed_products.is_empty() {
+            continue;
+        }
+
+        for (m_i, metric) in metrics.iter().enumerate() {
+            let content = metric.get_content();
+            let instance_prefix = content.get_content_json_path(v_i, m_i);
+            for metric_type in content.get_cvss_metric_types() {
+                let cvss = match metric_type {
+                    CsafVulnerabilityMetric::CvssV2(_) => content.get_cvss_v2(),
+                    CsafVulnerabilityMetric::CvssV3(_) => content.get_cvss_v3(),
+                    CsafVulnerabilityMetric::CvssV4(_) => content.get_cvss_v4(),
+                    _ => None,
+                };
+                let has_zero_environmental_score = cvss
+                    .and_then(|cvss| cvss.get("environmentalScore"))
+                    .and_then(|score| score.as_f64())
+                    .is_some_and(|score| score == 0.0);
+                if has_zero_environmental_score {
+                    continue;
+                }
+                for product_id in metric.get_products().filter(|p| fixed_products.contains(p)) {
+                    errors
+                        .get_or_insert_default()
+                        .push(create_cvss_for_fixed_product_warning(
+                            product_id,
+                            &metric_type,
+                            &instance_prefix,
+                        ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(ValidatorForTest6_2_19, test_6_2_19_cvss_for_fixed_products);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_0::testcases::ExpectedResults_6_2_19 as ExpectedResults_2_0;
+    use crate::csaf2_0::testcases::TESTS_2_0;
+    use crate::csaf2_1::testcases::ExpectedResults_6_2_19 as ExpectedResults_2_1;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_19() {
+        let cvss_v2 = CsafVulnerabilityMetric::CvssV2("2.0".to_string());
+        let cvss_v3_0 = CsafVulnerabilityMetric::CvssV3("3.0".to_string());
+        let cvss_v3_1 = CsafVulnerabilityMetric::CvssV3("3.1".to_string());
+        let cvss_v4 = CsafVulnerabilityMetric::CvssV4("4.0".to_string());
+        let fixed_2_0 = |metric: &CsafVulnerabilityMetric| {
+            Err(vec![create_cvss_for_fixed_product_warning(
+                "CSAFPID-9080700",
+                metric,
+                "/vulnerabilities/0/scores/0",
+            )])
+        };
+        let fixed_2_1 = |metric: &CsafVulnerabilityMetric| {
+            Err(vec![create_cvss_for_fixed_product_warning(
+                "CSAFPID-9080700",
+                metric,
+                "/vulnerabilities/0/metrics/0/content",
+            )])
+        };
+
+        // Case 11 ff.: CVSS of fixed products with an environmental score of 0 or CVSS only for
+        // products that are not fixed
+        TESTS_2_0.test_6_2_19.expect(ExpectedResults_2_0 {
+            case_01: fixed_2_0(&cvss_v3_1),
+            case_02: fixed_2_0(&cvss_v3_1),
+            case_03: fixed_2_0(&cvss_v3_0),
+            case_04: fixed_2_0(&cvss_v3_0),
+            case_05: fixed_2_0(&cvss_v2),
+            case_06: fixed_2_0(&cvss_v2),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_14: Ok(()),
+            case_15: Ok(()),
+            case_16: Ok(()),
+            case_17: Ok(()),
+        });
+
+        // Case S01 and S02: first fixed and fixed products without and with a non-zero
+        // environmental score
+        let missing_zero_score = Err(vec![
+            create_cvss_for_fixed_product_warning(
+                "CSAFPID-9080700",
+                &cvss_v3_1,
+                "/vulnerabilities/0/metrics/0/content",
+            ),
+            create_cvss_for_fixed_product_warning(
+                "CSAFPID-9080701",
+                &cvss_v3_1,
+                "/vulnerabilities/0/metrics/0/content",
+            ),
+        ]);
+        TESTS_2_1.test_6_2_19.expect(ExpectedResults_2_1 {
+            case_01: fixed_2_1(&cvss_v3_1),
+            case_02: fixed_2_1(&cvss_v3_1),
+            case_03: fixed_2_1(&cvss_v3_0),
+            case_04: fixed_2_1(&cvss_v3_0),
+            case_05: fixed_2_1(&cvss_v2),
+            case_06: fixed_2_1(&cvss_v2),
+            case_07: fixed_2_1(&cvss_v4),
+            case_08: fixed_2_1(&cvss_v4),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_14: Ok(()),
+            case_15: Ok(()),
+            case_16: Ok(()),
+            case_17: Ok(()),
+            case_18: Ok(()),
+            case_19: Ok(()),
+            case_s01: missing_zero_score.clone(),
+            case_s02: missing_zero_score,
+            case_s11: Ok(()),
+        });
+    }
+}
@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_datetime::{CsafDateTime, ValidCsafDateTime};
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashMap;
 
 fn create_same_timestamp_error(index: usize, date: &ValidCsafDateTime, conflicting_indices: &[usize]) -> TestFinding {
@@ -24,7 +25,7 @@ fn create_same_timestamp_error(index: usize, date: &ValidCsafDateTime, conflicti
 ///
 /// It MUST be tested that the timestamps of all items in the revision history are pairwise disjoint,
 /// taking timezones into account.
-pub fn test_6_2_21_same_timestamps_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_21_same_timestamps_in_revision_history(doc: &impl CsafTrait) -> TestResult {
     let revision_history = doc.get_document().get_tracking().aggregate_revision_history();
 
     // lookup of ValidCsafDateTime (hash function uses normalized utc)
@@ -48,8 +49,9 @@ pub fn test_6_2_21_same_timestamps_in_revision_history(doc: &impl CsafTrait) ->
                     },
                 }
             },
+            // invalid dates are caught by 6.1.37 / the schema test
             CsafDateTime::Invalid(_) => {
-                return Ok(()); // TODO #409 this will be nondeterminable later
+                return TestResult::precondition_failed("The revision history contains an invalid date.");
             },
         }
     }
@@ -64,7 +66,7 @@ pub fn test_6_2_21_same_timestamps_in_revision_history(doc: &impl CsafTrait) ->
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
@@ -0,0 +1,131 @@
+use crate::csaf_traits::{CsafTrait, ProductStatusGroup, ProductStatusGroupMap, RemediationTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
+use crate::validation::{TestFinding, TestFindingData};
+
+/// Combinations of product status groups and remediation categories which are discouraged, but
+/// not contradicting (contradicting combinations are reported by 6.1.36).
+const DISCOURAGED_COMBINATIONS: &[(ProductStatusGroup, &[CategoryOfTheRemediation])] = &[
+    (
+        ProductStatusGroup::NotAffected,
+        &[
+            CategoryOfTheRemediation::FixPlanned,
+            CategoryOfTheRemediation::NoFixPlanned,
+        ],
+    ),
+    (
+        ProductStatusGroup::UnderInvestigation,
+        &[CategoryOfTheRemediation::OptionalPatch],
+    ),
+];
+
+fn create_discouraged_combination_warning(
+    product_id: &str,
+    group: &ProductStatusGroup,
+    category: &CategoryOfTheRemediation,
+    v_i: usize,
+    r_i: usize,
+) -> TestFinding {
+    TestFinding::Warning(TestFindingData {
+        message: format!(
+            "Product {product_id} is listed as {group} and has the discouraged remediation category {category}"
+        ),
+        instance_path: format!("/vulnerabilities/{v_i}/remediations/{r_i}"),
+    })
+}
+
+/// 6.2.27 Discouraged Product Status Remediation Combination
+///
+/// For each item in `/vulnerabilities`, it must be tested that a product is not member of a
+/// product status group and a remediation category whose combination is discouraged:
+/// "not affected" products with `fix_planned` or `no_fix_planned` remediations, and products
+/// "under investigation" with `optional_patch` remediations. Products are also taken into account
+/// if a remediation references them through a product group.
+pub fn test_6_2_27_discouraged_product_status_remediation_combination(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = vulnerability.get_product_status() else {
+            continue;
+        };
+        let status_map = ProductStatusGroupMap::from(product_status);
+        for (r_i, remediation) in vulnerability.get_remediations().iter().enumerate() {
+            let Some(product_ids) = remediation.get_all_product_ids(doc) else {
+                continue;
+            };
+            let category = remediation.get_category();
+            for product_id in &product_ids {
+                for (group, categories) in DISCOURAGED_COMBINATIONS {
+                    if categories.contains(&category) && status_map.contains(group, product_id) {
+                        errors
+                            .get_or_insert_default()
+                            .push(create_discouraged_combination_warning(
+                                product_id, group, &category, v_i, r_i,
+                            ));
+                    }
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_2_27,
+    test_6_2_27_discouraged_product_status_remediation_combination
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_2_27 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_27() {
+        // Case 11 to 13: remediation categories that are not discouraged for the product status
+        // Case S11: workaround for products referenced through a product group
+        TESTS_2_1.test_6_2_27.expect(ExpectedResults {
+            case_01: Err(vec![create_discouraged_combination_warning(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::NotAffected,
+                &CategoryOfTheRemediation::FixPlanned,
+                0,
+                0,
+            )]),
+            case_02: Err(vec![create_discouraged_combination_warning(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::NotAffected,
+                &CategoryOfTheRemediation::NoFixPlanned,
+                0,
+                0,
+            )]),
+            case_03: Err(vec![create_discouraged_combination_warning(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::UnderInvestigation,
+                &CategoryOfTheRemediation::OptionalPatch,
+                0,
+                0,
+            )]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_s01: Err(vec![create_discouraged_combination_warning(
+                "CSAFPID-9080700",
+                &ProductStatusGroup::NotAffected,
+                &CategoryOfTheRemediation::FixPlanned,
+                0,
+                0,
+            )]),
+            case_s02: Err(vec![create_discouraged_combination_warning(
+                "CSAFPID-9080701",
+                &ProductStatusGroup::UnderInvestigation,
+                &CategoryOfTheRemediation::OptionalPatch,
+                0,
+                0,
+            )]),
+            case_s11: Ok(()),
+        });
+    }
+}
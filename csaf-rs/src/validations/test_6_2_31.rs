@@ -1,6 +1,7 @@
 use crate::csaf::traits::vulnerabilities::product_ident_helper_trait::ProductIdentificationHelperTrait;
 use crate::csaf_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashSet;
 
 fn generate_hardware_software_mix_error(product_id: &str, base_path: &str) -> TestFinding {
@@ -13,9 +14,9 @@ fn generate_hardware_software_mix_error(product_id: &str, base_path: &str) -> Te
 }
 
 /// Test 6.2.31: Hardware and Software Mix
-pub fn test_6_2_31_hardware_software_mix(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_31_hardware_software_mix(doc: &impl CsafTrait) -> TestResult {
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(());
+        return TestResult::no_data("The document does not contain a product tree.");
     };
     let mut errors: Vec<TestFinding> = vec![];
 
@@ -38,7 +39,11 @@ pub fn test_6_2_31_hardware_software_mix(doc: &impl CsafTrait) -> Result<(), Vec
         }
     });
 
-    if errors.is_empty() { Ok(()) } else { Err(errors) }
+    if errors.is_empty() {
+        TestResult::Passed(Passed::Success)
+    } else {
+        TestResult::Failed(errors)
+    }
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_2_31, test_6_2_31_hardware_software_mix);
@@ -5,6 +5,7 @@ use crate::csaf::traits::vulnerabilities::{
 use crate::csaf::types::purl::csaf_purl::CsafPurl;
 use crate::csaf_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::{HashMap, HashSet};
 
 fn generate_duplicate_helper_error(category: &str, value: &str, product_id: &str, base_path: &str) -> TestFinding {
@@ -36,9 +37,9 @@ fn process_violations(
 }
 
 /// Test 6.2.32: Use of Same Product Identification Helper for Different Products
-pub fn test_6_2_32_duplicate_product_identification_helpers(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_32_duplicate_product_identification_helpers(doc: &impl CsafTrait) -> TestResult {
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(());
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     let mut errors: HashSet<TestFinding> = HashSet::new();
@@ -162,7 +163,11 @@ pub fn test_6_2_32_duplicate_product_identification_helpers(doc: &impl CsafTrait
     // Convert error HashSet back to Vec at the very end
     let error_vec: Vec<TestFinding> = errors.into_iter().collect();
 
-    if error_vec.is_empty() { Ok(()) } else { Err(error_vec) }
+    if error_vec.is_empty() {
+        TestResult::Passed(Passed::Success)
+    } else {
+        TestResult::Failed(error_vec)
+    }
 }
 
 crate::test_validation::impl_validator!(
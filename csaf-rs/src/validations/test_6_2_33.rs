@@ -1,6 +1,7 @@
 use crate::csaf::types::csaf_datetime::{CsafDateTime, ValidCsafDateTime};
 use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait, VulnerabilityTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use chrono::Utc;
 
 fn create_disclosure_date_newer_than_revision_error(
@@ -29,7 +30,7 @@ fn create_disclosure_date_newer_than_revision_error(
 ///
 /// Unlike test 6.1.45, this test applies regardless of TLP/status, and additionally takes the datetime of test
 /// execution into consideration.
-pub fn test_6_2_33_disclosure_date_newer_than_revision(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_33_disclosure_date_newer_than_revision(doc: &impl CsafTrait) -> TestResult {
     // Get sorted revision history and find the newest entry
     let mut revision_history = doc.get_document().get_tracking().aggregate_revision_history();
     revision_history.inplace_sort_by_date_then_number();
@@ -37,9 +38,11 @@ pub fn test_6_2_33_disclosure_date_newer_than_revision(doc: &impl CsafTrait) ->
     let newest_revision_date = match revision_history.last() {
         Some(rev) => match &rev.date {
             CsafDateTime::Valid(date) => date,
-            CsafDateTime::Invalid(_) => return Ok(()), // TODO: This will be a Precondition failed #409
+            CsafDateTime::Invalid(_) => {
+                return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
+            },
         },
-        None => return Ok(()), // TODO this should be a #409 precondition failed #409
+        None => return TestResult::precondition_failed("The revision history is empty."),
     };
 
     let now = Utc::now();
@@ -72,7 +75,7 @@ pub fn test_6_2_33_disclosure_date_newer_than_revision(doc: &impl CsafTrait) ->
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
@@ -69,8 +69,8 @@ impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::Commo
     fn validate(
         &self,
         doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
-    ) -> Result<(), Vec<TestFinding>> {
-        test_6_2_34_usage_of_unknown_ssvc_decision_point_base_namespace(doc, false)
+    ) -> crate::validation_result::TestResult {
+        test_6_2_34_usage_of_unknown_ssvc_decision_point_base_namespace(doc, false).into()
     }
 }
 
@@ -0,0 +1,154 @@
+use crate::csaf_traits::CsafTrait;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validations::utils::ssvc::{
+    SsvcSelectionListAndIndices, iter_ssvc_selection_lists, latest_decision_point_version,
+    parse_decision_point_version, ssvc_selection_path,
+};
+
+fn create_non_latest_version_warning(
+    namespace: &str,
+    key: &str,
+    version: &str,
+    latest_version: &str,
+    instance_path: &str,
+) -> TestFinding {
+    TestFinding::Warning(TestFindingData {
+        message: format!(
+            "Usage of SSVC decision point `{namespace}:{key}` in version {version}, but the latest version is {latest_version}"
+        ),
+        instance_path: format!("{instance_path}/version"),
+    })
+}
+
+/// 6.2.35 Usage of Non-Latest SSVC Decision Point Version
+///
+/// For each SSVC decision point given under `selections` with a registered namespace, it MUST be
+/// tested that the latest decision point version was used.
+///
+/// The latest versions are the ones known to the `ssvc` crate. Unknown versions are reported by
+/// test 6.1.48.
+pub fn test_6_2_35_usage_of_non_latest_ssvc_decision_point_version(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+
+    for SsvcSelectionListAndIndices {
+        vuln_index,
+        metric_index,
+        selection_list,
+    } in iter_ssvc_selection_lists(doc)
+    {
+        for (s_i, selection) in selection_list.selections.iter().enumerate() {
+            // invalid namespaces are reported by 6.1.48
+            let Ok(namespace) = ssvc::validate_namespace(selection.namespace.as_str(), true) else {
+                continue;
+            };
+            if !namespace.is_registered() {
+                continue;
+            }
+            let Some(latest_version) = latest_decision_point_version(namespace.base_name(), selection.key.as_str())
+            else {
+                continue;
+            };
+            if let (Some(version), Some(latest)) = (
+                parse_decision_point_version(selection.version.as_str()),
+                parse_decision_point_version(latest_version),
+            ) && version < latest
+            {
+                errors.get_or_insert_default().push(create_non_latest_version_warning(
+                    selection.namespace.as_str(),
+                    selection.key.as_str(),
+                    selection.version.as_str(),
+                    latest_version,
+                    &ssvc_selection_path(vuln_index, metric_index, s_i),
+                ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_2_35,
+    test_6_2_35_usage_of_non_latest_ssvc_decision_point_version
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::raw::HasParsed;
+    use crate::csaf2_1::testcases::ExpectedResults_6_2_35 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::validations::utils::csaf_2_1_template;
+
+    #[test]
+    fn test_test_6_2_35() {
+        // Case 11 and 12: latest decision point versions
+        // Case S11: latest decision point versions and a decision point from a private namespace
+        TESTS_2_1.test_6_2_35.expect(ExpectedResults {
+            case_01: Err(vec![create_non_latest_version_warning(
+                "ssvc",
+                "E",
+                "1.0.0",
+                "1.1.0",
+                "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0",
+            )]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_s01: Err(vec![
+                create_non_latest_version_warning(
+                    "ssvc",
+                    "E",
+                    "1.0.0",
+                    "1.1.0",
+                    "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0",
+                ),
+                create_non_latest_version_warning(
+                    "ssvc",
+                    "SI",
+                    "2.0.0",
+                    "2.0.1",
+                    "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/2",
+                ),
+            ]),
+            case_s11: Ok(()),
+        });
+    }
+
+    #[test]
+    fn test_test_6_2_35_latest_versions_of_ssvc_crate() {
+        // Older versions of decision points bundled with the ssvc crate are flagged with the latest
+        // version of the crate, the latest version itself is not
+        for (key, older_version, value) in [("E", "1.0.0", "A"), ("SI", "2.0.0", "N")] {
+            let latest_version = latest_decision_point_version("ssvc", key).unwrap();
+            let document = csaf_2_1_template(|json| {
+                json["vulnerabilities"] = serde_json::json!([{
+                    "metrics": [{
+                        "content": {"ssvc_v2": {
+                            "schemaVersion": "2.0.0",
+                            "timestamp": "2024-01-24T10:00:00.000Z",
+                            "selections": [
+                                {"namespace": "ssvc", "key": key, "version": latest_version, "values": [{"key": value}]},
+                                {"namespace": "ssvc", "key": key, "version": older_version, "values": [{"key": value}]}
+                            ]
+                        }},
+                        "products": ["CSAFPID-0001"]
+                    }]
+                }]);
+            });
+
+            assert_eq!(
+                test_6_2_35_usage_of_non_latest_ssvc_decision_point_version(document.get_parsed().as_ref().unwrap()),
+                Err(vec![create_non_latest_version_warning(
+                    "ssvc",
+                    key,
+                    older_version,
+                    latest_version,
+                    "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/1",
+                )])
+            );
+        }
+    }
+}
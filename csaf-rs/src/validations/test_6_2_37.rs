@@ -0,0 +1,80 @@
+use crate::csaf_traits::CsafTrait;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validations::utils::ssvc::{SsvcNamespaceResultAndPath, iter_ssvc_namespaces};
+
+fn create_example_namespace_warning(namespace: &str, instance_path: &str) -> TestFinding {
+    TestFinding::Warning(TestFindingData {
+        message: format!("Usage of SSVC decision point namespace reserved for documentation purposes: `{namespace}`"),
+        instance_path: instance_path.to_owned(),
+    })
+}
+
+/// 6.2.37 Usage of Example SSVC Decision Point Namespace
+///
+/// For each SSVC decision point given under `selections`, it MUST be tested that the base
+/// namespace is not `example`, which is reserved for documentation purposes.
+///
+/// Invalid namespaces are reported by test 6.1.48.
+pub fn test_6_2_37_usage_of_example_ssvc_decision_point_namespace(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+
+    for SsvcNamespaceResultAndPath { instance_path, result } in iter_ssvc_namespaces(doc, true) {
+        if let Ok(parsed_namespace) = result
+            && parsed_namespace.is_example()
+        {
+            errors.get_or_insert_default().push(create_example_namespace_warning(
+                &parsed_namespace.to_string(),
+                &instance_path,
+            ));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_2_37,
+    test_6_2_37_usage_of_example_ssvc_decision_point_namespace
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_2_37 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::validations::utils::ssvc::ssvc_selection_namespace_path;
+
+    #[test]
+    fn test_test_6_2_37() {
+        // Case 11 to 13: no example namespace
+        // Case S11: private namespace of example.com
+        TESTS_2_1.test_6_2_37.expect(ExpectedResults {
+            case_01: Err(vec![create_example_namespace_warning(
+                "example",
+                &ssvc_selection_namespace_path(0, 0, 0),
+            )]),
+            case_02: Err(vec![create_example_namespace_warning(
+                "example",
+                &ssvc_selection_namespace_path(0, 0, 1),
+            )]),
+            case_03: Err(vec![create_example_namespace_warning(
+                "example//.com.example#refinement",
+                &ssvc_selection_namespace_path(0, 0, 0),
+            )]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_s01: Err(vec![
+                create_example_namespace_warning("example", &ssvc_selection_namespace_path(0, 0, 1)),
+                create_example_namespace_warning(
+                    "example//.com.example#refinement",
+                    &ssvc_selection_namespace_path(0, 0, 3),
+                ),
+            ]),
+            case_s11: Ok(()),
+        });
+    }
+}
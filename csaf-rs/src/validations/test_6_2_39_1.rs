@@ -0,0 +1,106 @@
+use crate::csaf::types::language::CsafLanguage;
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::TestFinding;
+use crate::validation_result::{Passed, TestResult};
+use crate::validations::test_6_1_55::license_listed_in_spdx_licensedb;
+use crate::validations::utils::document_notes_with_title_and_category::check_notes_with_title_and_category;
+use crate::validations::utils::language_specific_translations::{
+    create_no_translation_known_info, get_translation_for_term_license,
+};
+
+/// 6.2.39.1 Language Specific License Text
+///
+/// This test only applies if the document language is specified but not English, and the
+/// `license_expression` contains license identifiers or exceptions that are not listed in the
+/// SPDX license list or AboutCode's "ScanCode LicenseDB".
+///
+/// It MUST be tested that exactly one item in document notes exists that has the language specific
+/// translation of the term `License` as title. The category of this item MUST be
+/// `legal_disclaimer`. If no language specific translation has been recorded, the test MUST be
+/// skipped and output an information to the user that no such translation is known.
+pub fn test_6_2_39_1_language_specific_license_text(
+    doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
+) -> TestResult {
+    let document = doc.get_document();
+
+    let Some(license) = document.license_expression.as_ref() else {
+        return TestResult::no_data("The document does not contain a license expression.");
+    };
+    if license_listed_in_spdx_licensedb(license) {
+        return TestResult::not_applicable(
+            "The license expression only contains licenses listed in the SPDX license list or the ScanCode LicenseDB.",
+        );
+    }
+
+    let primary_lang = match document.get_lang() {
+        // language unspecified, test 6.1.55 covers this
+        None => {
+            return TestResult::not_applicable(
+                "The test only applies to documents with a language other than English, but the document has no language.",
+            );
+        },
+        Some(CsafLanguage::Invalid(language, _)) => {
+            return TestResult::precondition_failed(format!("The document language '{language}' is invalid."));
+        },
+        // english is covered by 6.1.55
+        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_english() => {
+            return TestResult::not_applicable(format!(
+                "The test only applies to documents with a language other than English, but the document language is '{valid_lang}'."
+            ));
+        },
+        Some(CsafLanguage::Valid(valid_lang)) => valid_lang.primary_language().to_string(),
+    };
+
+    // get language-specific translation
+    let Some(translated_title) = get_translation_for_term_license(&primary_lang) else {
+        return TestResult::Failed(vec![create_no_translation_known_info("License", &primary_lang)]);
+    };
+
+    check_notes_with_title_and_category(
+        document.get_notes().map(Vec::as_slice),
+        translated_title,
+        &NoteCategory::LegalDisclaimer,
+        &document.get_category(),
+    )
+    .map(|v| v.into_iter().map(TestFinding::Warning).collect())
+    .map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_2_39_1,
+    test_6_2_39_1_language_specific_license_text
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+    use crate::csaf2_1::testcases::ExpectedResults_6_2_39_1 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+    use crate::validations::utils::document_notes_with_title_and_category::create_incorrect_category_data;
+
+    #[test]
+    fn test_test_6_2_39_1() {
+        let de_title = get_translation_for_term_license("de").unwrap();
+        let wrong_category = |category: &NoteCategory| {
+            Err(vec![TestFinding::Warning(create_incorrect_category_data(
+                de_title,
+                category,
+                &NoteCategory::LegalDisclaimer,
+                &CsafDocumentCategory::CsafBase,
+                0,
+            ))])
+        };
+
+        TESTS_2_1.test_6_2_39_1.expect(ExpectedResults {
+            case_01: wrong_category(&NoteCategory::Other),
+            case_02: wrong_category(&NoteCategory::General),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_13: Ok(()),
+            case_s11: Err(vec![create_no_translation_known_info("License", "eo")]),
+        });
+    }
+}
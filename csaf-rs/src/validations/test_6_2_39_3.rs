@@ -3,6 +3,7 @@ use crate::csaf::types::language::CsafLanguage;
 use crate::csaf_traits::{CsafTrait, DocumentTrait};
 use crate::schema::csaf2_1::schema::NoteCategory;
 use crate::validation::TestFinding;
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
 use crate::validations::utils::document_notes_with_title_and_category::check_notes_with_title_and_category;
 use crate::validations::utils::language_specific_translations::{
@@ -18,25 +19,37 @@ use crate::validations::utils::language_specific_translations::{
 /// translation of the term `Reasoning for Supersession` as title. The category of this item MUST
 /// be `description`. If no language specific translation has been recorded, the test MUST be
 /// skipped and output an information to the user that no such translation is known.
-pub fn test_6_2_39_3_language_specific_reasoning_for_supersession(
-    doc: &impl CsafTrait,
-) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_39_3_language_specific_reasoning_for_supersession(doc: &impl CsafTrait) -> TestResult {
     let doc_category = doc.get_document().get_category();
 
-    if !PROFILE_TEST_CONFIG.matches_category_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category) {
-        return Ok(());
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
     }
 
     let primary_lang = match doc.get_document().get_lang() {
-        None => return Ok(()), // language unspecified, test 6.1.27.18 covers this
-        Some(CsafLanguage::Invalid(_, _)) => return Ok(()), // wasSkipped in #407
-        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_english() => return Ok(()), // english is covered by 6.1.27.18
+        // language unspecified, test 6.1.27.18 covers this
+        None => {
+            return TestResult::not_applicable(
+                "The test only applies to documents with a language other than English, but the document has no language.",
+            );
+        },
+        Some(CsafLanguage::Invalid(language, _)) => {
+            return TestResult::precondition_failed(format!("The document language '{language}' is invalid."));
+        },
+        // english is covered by 6.1.27.18
+        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_english() => {
+            return TestResult::not_applicable(format!(
+                "The test only applies to documents with a language other than English, but the document language is '{valid_lang}'."
+            ));
+        },
         Some(CsafLanguage::Valid(valid_lang)) => valid_lang.primary_language().to_string(),
     };
 
     // get language-specific translation
     let Some(translated_title) = get_translation_for_term_reasoning_for_supersession(&primary_lang) else {
-        return Err(vec![create_no_translation_known_info(
+        return TestResult::Failed(vec![create_no_translation_known_info(
             "Reasoning for Supersession",
             &primary_lang,
         )]);
@@ -49,7 +62,7 @@ pub fn test_6_2_39_3_language_specific_reasoning_for_supersession(
         &doc_category,
     )
     .map(|v| v.into_iter().map(TestFinding::Warning).collect())
-    .map_or(Ok(()), Err)
+    .map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
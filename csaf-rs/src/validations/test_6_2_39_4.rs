@@ -0,0 +1,137 @@
+use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
+use crate::csaf::types::language::CsafLanguage;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, ReferenceTrait};
+use crate::schema::csaf2_1::schema::CategoryOfReference;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
+use crate::validations::utils::document_category_test_config::DocumentCategoryTestConfig;
+use crate::validations::utils::language_specific_translations::{
+    create_no_translation_known_info, get_translation_for_term_superseding_document,
+};
+
+fn create_missing_reference_warning(translated_summary: &str, document_category: &CsafDocumentCategory) -> TestFinding {
+    TestFinding::Warning(TestFindingData {
+        message: format!(
+            "Document with category `{document_category}` should have at least one reference whose summary starts with `{translated_summary}` and has the category `external`"
+        ),
+        instance_path: "/document/references".to_string(),
+    })
+}
+
+fn create_incorrect_category_warning(translated_summary: &str, reference_index: usize) -> TestFinding {
+    TestFinding::Warning(TestFindingData {
+        message: format!(
+            "The reference summary starts with the correct string `{translated_summary}`. However it uses the wrong category."
+        ),
+        instance_path: format!("/document/references/{reference_index}"),
+    })
+}
+
+/// 6.2.39.4 Language Specific Reference to Superseding Document
+///
+/// This test only applies to documents with `/document/category` with value `csaf_superseded` and
+/// only if the document language is specified but not English.
+///
+/// It MUST be tested that at least one item in document references exists that has a summary
+/// starting with the language specific translation of the term `Superseding Document`. The category
+/// of this item MUST be `external`. If no language specific translation has been recorded, the test
+/// MUST be skipped and output an information to the user that no such translation is known.
+pub fn test_6_2_39_4_language_specific_reference_to_superseding_document(doc: &impl CsafTrait) -> TestResult {
+    let doc_category = doc.get_document().get_category();
+
+    if let Some(not_applicable) =
+        PROFILE_TEST_CONFIG.not_applicable_with_csaf_version(doc.get_document().get_csaf_version(), &doc_category)
+    {
+        return not_applicable;
+    }
+
+    let primary_lang = match doc.get_document().get_lang() {
+        // language unspecified, test 6.1.27.19 covers this
+        None => {
+            return TestResult::not_applicable(
+                "The test only applies to documents with a language other than English, but the document has no language.",
+            );
+        },
+        Some(CsafLanguage::Invalid(language, _)) => {
+            return TestResult::precondition_failed(format!("The document language '{language}' is invalid."));
+        },
+        // english is covered by 6.1.27.19
+        Some(CsafLanguage::Valid(valid_lang)) if valid_lang.is_english() => {
+            return TestResult::not_applicable(format!(
+                "The test only applies to documents with a language other than English, but the document language is '{valid_lang}'."
+            ));
+        },
+        Some(CsafLanguage::Valid(valid_lang)) => valid_lang.primary_language().to_string(),
+    };
+
+    // get language-specific translation
+    let Some(translated_summary) = get_translation_for_term_superseding_document(&primary_lang) else {
+        return TestResult::Failed(vec![create_no_translation_known_info(
+            "Superseding Document",
+            &primary_lang,
+        )]);
+    };
+
+    let mut has_external_reference_with_correct_summary = false;
+    let mut errors: Option<Vec<TestFinding>> = None;
+    if let Some(references) = doc.get_document().get_references() {
+        for (r_i, reference) in references.iter().enumerate() {
+            if reference.get_summary().starts_with(translated_summary) {
+                if reference.get_category() != CategoryOfReference::External {
+                    errors
+                        .get_or_insert_default()
+                        .push(create_incorrect_category_warning(translated_summary, r_i));
+                } else {
+                    has_external_reference_with_correct_summary = true;
+                }
+            }
+        }
+    }
+
+    // Same precedence as in 6.1.27.19: a matching reference with the wrong category is the more
+    // specific finding.
+    if let Some(errs) = errors {
+        return TestResult::Failed(errs);
+    }
+    if !has_external_reference_with_correct_summary {
+        return TestResult::Failed(vec![create_missing_reference_warning(
+            translated_summary,
+            &doc_category,
+        )]);
+    }
+    TestResult::Passed(Passed::Success)
+}
+
+const PROFILE_TEST_CONFIG: DocumentCategoryTestConfig =
+    DocumentCategoryTestConfig::new().csaf21(&[CsafDocumentCategory::CsafSuperseded]);
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_2_39_4,
+    test_6_2_39_4_language_specific_reference_to_superseding_document
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_2_39_4 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_2_39_4() {
+        let de_summary = get_translation_for_term_superseding_document("de").unwrap();
+        let wrong_category = Err(vec![create_incorrect_category_warning(de_summary, 0)]);
+
+        TESTS_2_1.test_6_2_39_4.expect(ExpectedResults {
+            case_01: wrong_category.clone(),
+            case_s01: wrong_category,
+            case_s02: Err(vec![create_missing_reference_warning(
+                de_summary,
+                &CsafDocumentCategory::CsafSuperseded,
+            )]),
+            case_11: Ok(()),
+            case_12: Ok(()),
+            case_s11: Err(vec![create_no_translation_known_info("Superseding Document", "eo")]),
+        });
+    }
+}
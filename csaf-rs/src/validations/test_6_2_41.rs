@@ -4,6 +4,7 @@ use crate::csaf_traits::{
     ContentTrait, CsafTrait, DocumentTrait, EpssTrait, MetricTrait, TrackingTrait, VulnerabilityTrait,
 };
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use chrono::TimeDelta;
 
 fn create_old_epss_timestamp_error(
@@ -24,7 +25,7 @@ fn create_old_epss_timestamp_error(
 /// For each vulnerability, it MUST be tested that the youngest EPSS timestamp is not more than
 /// 15 days older than the date of the newest item of the revision_history (taking timezones into account),
 /// if the document status is `final` or `interim`.
-pub fn test_6_2_41_old_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_41_old_epss_timestamp(doc: &impl CsafTrait) -> TestResult {
     let document = doc.get_document();
     let tracking = document.get_tracking();
 
@@ -37,16 +38,19 @@ pub fn test_6_2_41_old_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<Te
     let newest_revision_date = match revision_history.last() {
         Some(rev) => match &rev.date {
             CsafDateTime::Valid(date) => date,
-            CsafDateTime::Invalid(_) => return Ok(()), // TODO: This will be a Precondition failed #409
+            CsafDateTime::Invalid(_) => {
+                return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
+            },
         },
-        None => return Ok(()), // TODO this should be a #409 precondition failed #409
+        None => return TestResult::precondition_failed("The revision history is empty."),
     };
     let vulnerabilities = doc.get_vulnerabilities();
     if vulnerabilities.is_empty() {
-        return Ok(());
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
+    let mut found_epss = false;
     let fifteen_days = TimeDelta::days(15);
 
     for (i_v, vulnerability) in vulnerabilities.iter().enumerate() {
@@ -68,15 +72,15 @@ pub fn test_6_2_41_old_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<Te
                                 newest_epss = Some((valid_timestamp, i_m));
                             }
                         },
-                        CsafDateTime::Invalid(_) => {
-                            // TODO: This will be a NonDeterminable (#409) later
-                        },
+                        // invalid timestamps are caught by 6.1.37
+                        CsafDateTime::Invalid(_) => {},
                     }
                 }
             }
 
             // if there was an epss metric
             if let Some((newest_epss_timestamp, path_metric_idx)) = newest_epss {
+                found_epss = true;
                 // if it is 15 days older than the newest revision date, add an error
                 let diff = newest_revision_date.get_as_utc() - newest_epss_timestamp.get_as_utc();
                 if diff > fifteen_days {
@@ -92,7 +96,11 @@ pub fn test_6_2_41_old_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<Te
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    match errors {
+        Some(errors) => TestResult::Failed(errors),
+        None if !found_epss => TestResult::no_data("The document does not contain any valid EPSS timestamps."),
+        None => TestResult::Passed(Passed::Success),
+    }
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_2_41, test_6_2_41_old_epss_timestamp);
@@ -1,5 +1,6 @@
 use crate::csaf_traits::{ContentTrait, CsafTrait, DocumentTrait, MetricTrait, VulnerabilityTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use std::collections::HashSet;
 
 fn create_qualitative_severity_rating_error(content_path: &str) -> TestFinding {
@@ -16,13 +17,10 @@ fn create_qualitative_severity_rating_error(content_path: &str) -> TestFinding {
 ///
 /// This covers all items in metrics that do not have a source property and those where the source
 /// is equal to the canonical URL. It does not cover assessments made by third parties.
-pub fn test_6_2_47_use_of_qualitative_severity_rating_by_issuing_party(
-    doc: &impl CsafTrait,
-) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_47_use_of_qualitative_severity_rating_by_issuing_party(doc: &impl CsafTrait) -> TestResult {
     let vulnerabilities = doc.get_vulnerabilities();
     if vulnerabilities.is_empty() {
-        // wasSkipped later (#407)
-        return Ok(());
+        return TestResult::no_data("The document does not contain any vulnerabilities.");
     }
 
     // collect canonical URLs into a HashSet for O(1) lookups
@@ -56,7 +54,7 @@ pub fn test_6_2_47_use_of_qualitative_severity_rating_by_issuing_party(
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(
@@ -1,6 +1,7 @@
 use crate::csaf::consts::chars::is_invisible_char;
 use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn create_misuse_at_vendor_name_error(vendor_name: &str, path: &str) -> TestFinding {
     let message: String  = match vendor_name == "Open Source" {
@@ -29,9 +30,9 @@ fn is_open_source(name: &str) -> bool {
 ///
 /// For each item in branches with category `vendor` it MUST be tested that the name is not
 /// `Open Source` (case-insensitive, white space insensitive).
-pub fn test_6_2_48_misuse_at_vendor_name(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_2_48_misuse_at_vendor_name(doc: &impl CsafTrait) -> TestResult {
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(()); // TODO #409
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -44,7 +45,7 @@ pub fn test_6_2_48_misuse_at_vendor_name(doc: &impl CsafTrait) -> Result<(), Vec
         }
     });
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(csaf2_1, ValidatorForTest6_2_48, test_6_2_48_misuse_at_vendor_name);
@@ -1,7 +1,7 @@
 use crate::csaf::types::csaf_vuln_metric::CsafVulnerabilityMetric;
 use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
 use crate::validation::{TestFinding, TestFindingData};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 fn create_cvss_v2_only_error(instance_path: String) -> TestFinding {
     TestFinding::Information(TestFindingData {
@@ -18,44 +18,43 @@ pub fn test_6_3_1_use_of_cvss_v2_as_only_scoring_system(doc: &impl CsafTrait) ->
 
     // for each vuln
     for (v_i, vuln) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(metrics) = vuln.get_metrics() else {
+            continue;
+        };
         // generate a map of each product to the set of vulnerability metrics used for it
-        let mut product_metrics_map: HashMap<String, HashSet<CsafVulnerabilityMetric>> =
-            HashMap::<String, HashSet<CsafVulnerabilityMetric>>::new();
-        // generate a map of each product to the paths where it was encountered
-        let mut product_path_map: HashMap<String, HashSet<String>> = HashMap::<String, HashSet<String>>::new();
+        let mut product_metrics_map: BTreeMap<&str, HashSet<CsafVulnerabilityMetric>> = BTreeMap::new();
+        // generate a map of each product to the indices of the metrics it is listed in
+        let mut product_metric_indices_map: BTreeMap<&str, BTreeSet<usize>> = BTreeMap::new();
         // for each metric and each product in it
-        if let Some(metrics) = vuln.get_metrics() {
-            for (m_i, metric) in metrics.iter().enumerate() {
-                let content = metric.get_content();
-                for product in metric.get_products() {
-                    // add all vulnerability metrics of this metric to the product -> vulnerability metrics map
-                    for vulnerability_metric in content.get_vulnerability_metric_types() {
-                        product_metrics_map
-                            .entry(product.to_owned())
-                            .or_default()
-                            .insert(vulnerability_metric);
-                    }
-                    // add the path of this metric to the product -> paths map
-                    product_path_map
-                        .entry(product.to_owned())
-                        .or_default()
-                        .insert(content.get_content_json_path(v_i, m_i));
-                }
+        for (m_i, metric) in metrics.iter().enumerate() {
+            let content = metric.get_content();
+            for product in metric.get_products() {
+                // add all vulnerability metrics of this metric to the product -> vulnerability metrics map
+                product_metrics_map
+                    .entry(product)
+                    .or_default()
+                    .extend(content.get_vulnerability_metric_types());
+                // add the index of this metric to the product -> metric indices map
+                product_metric_indices_map.entry(product).or_default().insert(m_i);
             }
         }
-        // for each product that has only CVSS v2 as vulnerability metric,
+        // collect the metrics of all products that have only CVSS v2 as vulnerability metric,
+        // each metric is reported only once, even if it lists several of these products
+        let mut cvss_v2_only_metrics: BTreeSet<usize> = BTreeSet::new();
         for (product, metrics_set) in &product_metrics_map {
-            if metrics_set.len() == 1 && matches!(metrics_set.iter().next(), Some(CsafVulnerabilityMetric::CvssV2(_))) {
-                // create an error for each path it was encountered at
-                if let Some(paths) = product_path_map.get(product) {
-                    for path in paths {
-                        errors
-                            .get_or_insert_default()
-                            .push(create_cvss_v2_only_error(path.clone()));
-                    }
-                }
+            if metrics_set.len() == 1
+                && matches!(metrics_set.iter().next(), Some(CsafVulnerabilityMetric::CvssV2(_)))
+                && let Some(metric_indices) = product_metric_indices_map.get(product)
+            {
+                cvss_v2_only_metrics.extend(metric_indices);
             }
         }
+        // create an error for each of these metrics
+        for m_i in cvss_v2_only_metrics {
+            errors.get_or_insert_default().push(create_cvss_v2_only_error(
+                metrics[m_i].get_content().get_content_json_path(v_i, m_i),
+            ));
+        }
     }
 
     errors.map_or(Ok(()), Err)
@@ -104,6 +103,11 @@ mod tests {
             case_12: Ok(()),
             case_13: Ok(()),
             case_14: Ok(()),
+            // each metric is reported once, even if it lists several products
+            case_s01: Err(vec![
+                create_cvss_v2_only_error("/vulnerabilities/0/metrics/0/content".to_string()),
+                create_cvss_v2_only_error("/vulnerabilities/0/metrics/1/content".to_string()),
+            ]),
         });
     }
 }
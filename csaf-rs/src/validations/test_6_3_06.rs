@@ -0,0 +1,61 @@
+use crate::csaf_traits::CsafTrait;
+use crate::url_resolver::UrlResolver;
+use crate::validation_result::TestResult;
+use crate::validations::utils::url_references::{check_urls, reference_urls, requires_url_resolver};
+
+/// 6.3.6 Use of Non-Self Referencing URLs Failing to Resolve
+///
+/// For each URL of a reference which is not in the category `self`, it must be tested that it
+/// resolves with an HTTP status code from the 2xx (Successful) or 3xx (Redirection) class.
+///
+/// This needs network access, so the test is only run with a [UrlResolver], see
+/// [crate::url_resolver]. Otherwise, it is skipped.
+pub fn test_6_3_6_non_self_referencing_urls_failing_to_resolve(
+    doc: &impl CsafTrait,
+    resolver: &dyn UrlResolver,
+) -> TestResult {
+    check_urls(&reference_urls(doc, false), &[], resolver)
+}
+
+crate::test_validation::impl_validator!(ValidatorForTest6_3_6, requires_url_resolver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::raw::HasParsed;
+    use crate::url_resolver::InMemoryUrlResolver;
+    use crate::validation::TestFinding;
+    use crate::validations::utils::url_references::{create_failed_to_resolve_info, document_with_references};
+
+    #[test]
+    fn test_test_6_3_6() {
+        let document = document_with_references(
+            &[
+                ("self", "https://example.com/.well-known/csaf/white/2024/advisory.json"),
+                ("external", "https://example.com/blog/advisory"),
+            ],
+            &[
+                ("external", "https://example.com/missing"),
+                ("external", "https://example.com/blog/advisory"),
+            ],
+        );
+        let doc = document.get_parsed().as_ref().unwrap();
+        let resolver = InMemoryUrlResolver::new(["https://example.com/blog/advisory"]);
+
+        let expected: Vec<TestFinding> = vec![create_failed_to_resolve_info(
+            "https://example.com/missing",
+            "not found",
+            "/vulnerabilities/0/references/0/url",
+        )];
+        assert_eq!(
+            test_6_3_6_non_self_referencing_urls_failing_to_resolve(doc, &resolver),
+            TestResult::Failed(expected)
+        );
+
+        let resolver = InMemoryUrlResolver::new(["https://example.com/blog/advisory", "https://example.com/missing"]);
+        assert_eq!(
+            test_6_3_6_non_self_referencing_urls_failing_to_resolve(doc, &resolver),
+            Ok(()).into()
+        );
+    }
+}
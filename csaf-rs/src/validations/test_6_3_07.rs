@@ -0,0 +1,75 @@
+use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::url_resolver::UrlResolver;
+use crate::validation_result::TestResult;
+use crate::validations::utils::url_references::{check_urls, reference_urls, requires_url_resolver};
+
+/// 6.3.7 Use of Self Referencing URLs Failing to Resolve
+///
+/// For each URL of a reference in the category `self` (which includes the canonical URL), it must
+/// be tested that it resolves with an HTTP status code from the 2xx (Successful) or 3xx
+/// (Redirection) class.
+///
+/// This needs network access, so the test is only run with a [UrlResolver], see
+/// [crate::url_resolver]. Otherwise, it is skipped.
+pub fn test_6_3_7_self_referencing_urls_failing_to_resolve(
+    doc: &impl CsafTrait,
+    resolver: &dyn UrlResolver,
+) -> TestResult {
+    let canonical_urls = doc.get_document().get_canonical_urls();
+    check_urls(&reference_urls(doc, true), &canonical_urls, resolver)
+}
+
+crate::test_validation::impl_validator!(ValidatorForTest6_3_7, requires_url_resolver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::raw::HasParsed;
+    use crate::url_resolver::InMemoryUrlResolver;
+    use crate::validations::utils::url_references::{
+        create_canonical_url_failed_to_resolve_info, create_failed_to_resolve_info, document_with_references,
+    };
+
+    #[test]
+    fn test_test_6_3_7() {
+        let canonical_url = "https://example.com/.well-known/csaf/white/2024/csaf-rs_csaf-csaf_2_1-template.json";
+        let document = document_with_references(
+            &[
+                ("self", canonical_url),
+                ("external", "https://example.com/blog/advisory"),
+                ("self", "https://example.com/advisories/esa-2024-001"),
+            ],
+            &[],
+        );
+        let doc = document.get_parsed().as_ref().unwrap();
+        assert_eq!(doc.get_document().get_canonical_urls(), vec![canonical_url]);
+
+        assert_eq!(
+            test_6_3_7_self_referencing_urls_failing_to_resolve(doc, &InMemoryUrlResolver::default()),
+            TestResult::Failed(vec![
+                create_canonical_url_failed_to_resolve_info(canonical_url, "not found", "/document/references/0/url"),
+                create_failed_to_resolve_info(
+                    "https://example.com/advisories/esa-2024-001",
+                    "not found",
+                    "/document/references/2/url"
+                ),
+            ])
+        );
+        assert_eq!(
+            test_6_3_7_self_referencing_urls_failing_to_resolve(
+                doc,
+                &InMemoryUrlResolver::new([canonical_url, "https://example.com/advisories/esa-2024-001"])
+            ),
+            Ok(()).into()
+        );
+
+        let without_references = document_with_references(&[], &[]);
+        assert!(matches!(
+            test_6_3_7_self_referencing_urls_failing_to_resolve(
+                without_references.get_parsed().as_ref().unwrap(),
+                &InMemoryUrlResolver::default()
+            ),
+            TestResult::Passed(_)
+        ));
+    }
+}
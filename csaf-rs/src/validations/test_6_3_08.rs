@@ -0,0 +1,163 @@
+use crate::csaf::types::language::CsafLanguage;
+use crate::csaf_traits::{
+    CsafTrait, DocumentTrait, NoteTrait, PublisherTrait, RemediationTrait, ThreatTrait, VulnerabilityTrait,
+};
+use crate::spell_check::{Dictionary, SpellChecker};
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
+
+/// 6.3.8 Spell Check
+///
+/// If the document language is given, it must be tested that there are no spelling mistakes in
+/// free-text fields: the document title, the titles and texts of all notes, the vulnerability
+/// titles and the details of remediations and threats. Acknowledgment names are not checked.
+///
+/// This needs a dictionary for the document language, so the test is only run with a
+/// [SpellChecker], see [crate::spell_check]. Otherwise, it is skipped.
+pub fn test_6_3_8_spell_check(doc: &impl CsafTrait, spell_checker: &SpellChecker) -> TestResult {
+    let document = doc.get_document();
+    let language = match document.get_lang() {
+        Some(CsafLanguage::Valid(language)) => language.to_string(),
+        Some(CsafLanguage::Invalid(language, _)) => {
+            return TestResult::precondition_failed(format!("The document language '{language}' is invalid."));
+        },
+        None => return TestResult::precondition_failed("The document language is not given."),
+    };
+    let dictionary = match spell_checker.dictionary(&language) {
+        Ok(dictionary) => dictionary,
+        Err(reason) => return TestResult::precondition_failed(format!("No dictionary available: {reason}")),
+    };
+
+    let mut texts = vec![(document.get_title(), "/document/title".to_string())];
+    add_notes(&mut texts, document.get_notes(), "/document");
+    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(title) = vulnerability.get_title() {
+            texts.push((title, format!("/vulnerabilities/{v_i}/title")));
+        }
+        add_notes(
+            &mut texts,
+            vulnerability.get_notes(),
+            &format!("/vulnerabilities/{v_i}"),
+        );
+        for (r_i, remediation) in vulnerability.get_remediations().iter().enumerate() {
+            texts.push((
+                remediation.get_details(),
+                format!("/vulnerabilities/{v_i}/remediations/{r_i}/details"),
+            ));
+        }
+        for (t_i, threat) in vulnerability.get_threats().iter().enumerate() {
+            texts.push((
+                threat.get_details(),
+                format!("/vulnerabilities/{v_i}/threats/{t_i}/details"),
+            ));
+        }
+    }
+
+    let findings = check_texts(
+        &texts,
+        spell_checker,
+        &dictionary,
+        document.get_publisher().get_namespace(),
+    );
+    if findings.is_empty() { Ok(()) } else { Err(findings) }.into()
+}
+
+fn add_notes<'a>(texts: &mut Vec<(&'a str, String)>, notes: Option<&'a Vec<impl NoteTrait>>, prefix: &str) {
+    for (index, note) in notes.into_iter().flatten().enumerate() {
+        if let Some(title) = note.get_title() {
+            texts.push((title, format!("{prefix}/notes/{index}/title")));
+        }
+        texts.push((note.get_text(), format!("{prefix}/notes/{index}/text")));
+    }
+}
+
+fn check_texts(
+    texts: &[(&str, String)],
+    spell_checker: &SpellChecker,
+    dictionary: &Dictionary,
+    publisher_namespace: &str,
+) -> Vec<TestFinding> {
+    texts
+        .iter()
+        .flat_map(|(text, instance_path)| {
+            spell_checker
+                .misspelled_words(dictionary, publisher_namespace, text)
+                .into_iter()
+                .map(|word| create_spelling_mistake_info(word, instance_path))
+        })
+        .collect()
+}
+
+/// Result of the test 6.3.8 if no spell checker is given.
+fn requires_spell_checker(_doc: &impl CsafTrait) -> TestResult {
+    TestResult::precondition_failed("Spell checking needs dictionaries, but no spell checker was given.")
+}
+
+fn create_spelling_mistake_info(word: &str, instance_path: &str) -> TestFinding {
+    TestFinding::Information(TestFindingData {
+        message: format!("Possible spelling mistake: '{word}'"),
+        instance_path: instance_path.to_string(),
+    })
+}
+
+crate::test_validation::impl_validator!(ValidatorForTest6_3_8, requires_spell_checker);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf::raw::{HasParsed, RawDocument};
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+    use crate::spell_check::AllowList;
+    use crate::validations::utils::csaf_2_1_template;
+
+    fn document(lang: Option<&str>) -> RawDocument<CommonSecurityAdvisoryFramework> {
+        csaf_2_1_template(|json| {
+            json["document"]["title"] = "Buffer overflow in ExampleOS".into();
+            json["document"]["notes"] = serde_json::json!([
+                {"category": "summary", "title": "Sumary", "text": "A buffer overflow in ExampleOS allows RCE."}
+            ]);
+            json["document"]["acknowledgments"] = serde_json::json!([{"names": ["Jane Doh"]}]);
+            json["vulnerabilities"] = serde_json::json!([{
+                "title": "Buffer overflow",
+                "remediations": [
+                    {"category": "vendor_fix", "details": "Update to version 1.2.3.", "product_ids": ["CSAFPID-0001"]}
+                ],
+            }]);
+            if let Some(lang) = lang {
+                json["document"]["lang"] = lang.into();
+            }
+        })
+    }
+
+    fn spell_checker() -> SpellChecker {
+        let dictionary = Dictionary::new([
+            "a", "allows", "buffer", "in", "overflow", "summary", "to", "update", "version",
+        ]);
+        let mut allow_list = AllowList::default();
+        allow_list.allow_for_publisher(
+            "https://github.com/csaf-rs/csaf/tree/main/type-generator/assets/tests",
+            ["ExampleOS"],
+        );
+        SpellChecker::new(dictionary).with_allow_list(allow_list)
+    }
+
+    #[test]
+    fn test_test_6_3_8() {
+        let spell_checker = spell_checker();
+
+        let document = document(Some("en"));
+        let doc = document.get_parsed().as_ref().unwrap();
+        let expected = vec![create_spelling_mistake_info("Sumary", "/document/notes/0/title")];
+        assert_eq!(
+            test_6_3_8_spell_check(doc, &spell_checker),
+            TestResult::Failed(expected)
+        );
+
+        let document = self::document(None);
+        let doc = document.get_parsed().as_ref().unwrap();
+        assert_eq!(
+            test_6_3_8_spell_check(doc, &spell_checker),
+            TestResult::precondition_failed("The document language is not given.")
+        );
+    }
+}
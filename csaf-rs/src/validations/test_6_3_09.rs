@@ -1,5 +1,6 @@
 use crate::csaf_traits::{BranchTrait, CategoryOfTheBranch, CsafTrait, ProductTreeTrait, build_leaf_instance_path};
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 
 fn format_category_path(categories: &[CategoryOfTheBranch]) -> String {
     categories
@@ -43,11 +44,11 @@ const REQUIRED_CATEGORIES_ORDER: [CategoryOfTheBranch; 3] = [
 ///
 /// Other branch categories can be used before, after or between the aforementioned branch categories
 /// without making the test invalid.
-pub fn test_6_3_9_branch_categories(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+pub fn test_6_3_9_branch_categories(doc: &impl CsafTrait) -> TestResult {
     let mut errors: Option<Vec<TestFinding>> = None;
 
     let Some(product_tree) = doc.get_product_tree() else {
-        return Ok(()); // this will be a Passed::NoData later (#409)
+        return TestResult::no_data("The document does not contain a product tree.");
     };
 
     // get all paths from root to leaves in the product tree
@@ -77,7 +78,7 @@ pub fn test_6_3_9_branch_categories(doc: &impl CsafTrait) -> Result<(), Vec<Test
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 crate::test_validation::impl_validator!(ValidatorForTest6_3_9, test_6_3_9_branch_categories);
@@ -0,0 +1,70 @@
+use crate::csaf_traits::CsafTrait;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validations::utils::ssvc::{SsvcSelectionListAndIndices, iter_ssvc_selection_lists};
+
+fn create_missing_decision_point_resources_info(vuln_index: usize, metric_index: usize) -> TestFinding {
+    TestFinding::Information(TestFindingData {
+        message: "The SSVC selections use decision points from an unregistered namespace or a namespace extension, \
+                  but no decision point resources are given"
+            .to_string(),
+        instance_path: format!("/vulnerabilities/{vuln_index}/metrics/{metric_index}/content/ssvc_v2"),
+    })
+}
+
+/// 6.3.13 Missing SSVC Decision Point Resources
+///
+/// For each SSVC metric, it MUST be tested that `decision_point_resources` is given if any of the
+/// decision points given under `selections` uses an unregistered base namespace or a namespace
+/// with an extension, as these decision points cannot be looked up in the SSVC repository.
+///
+/// Invalid namespaces are reported by test 6.1.48.
+pub fn test_6_3_13_missing_ssvc_decision_point_resources(doc: &impl CsafTrait) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+
+    for SsvcSelectionListAndIndices {
+        vuln_index,
+        metric_index,
+        selection_list,
+    } in iter_ssvc_selection_lists(doc)
+    {
+        let needs_resources = selection_list.selections.iter().any(|selection| {
+            ssvc::validate_namespace(selection.namespace.as_str(), true)
+                .is_ok_and(|namespace| namespace.is_unregistered() || namespace.extensions.is_some())
+        });
+        if needs_resources && selection_list.decision_point_resources.is_empty() {
+            errors
+                .get_or_insert_default()
+                .push(create_missing_decision_point_resources_info(vuln_index, metric_index));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_3_13,
+    test_6_3_13_missing_ssvc_decision_point_resources
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_3_13 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_13() {
+        // Case 11: decision point resources for an unregistered namespace
+        // Case S11: registered namespace without and unregistered namespace with resources
+        TESTS_2_1.test_6_3_13.expect(ExpectedResults {
+            case_01: Err(vec![create_missing_decision_point_resources_info(0, 0)]),
+            case_11: Ok(()),
+            case_s01: Err(vec![
+                create_missing_decision_point_resources_info(0, 1),
+                create_missing_decision_point_resources_info(0, 2),
+            ]),
+            case_s11: Ok(()),
+        });
+    }
+}
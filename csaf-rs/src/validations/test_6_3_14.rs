@@ -0,0 +1,82 @@
+use crate::csaf_traits::CsafTrait;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validations::utils::ssvc::{SsvcSelectionListAndIndices, iter_ssvc_selection_lists, ssvc_selection_path};
+use std::collections::HashMap;
+
+fn create_multiple_selections_info(namespace: &str, key: &str, first_index: usize, instance_path: &str) -> TestFinding {
+    TestFinding::Information(TestFindingData {
+        message: format!(
+            "The SSVC decision point `{namespace}:{key}` was already selected in the selection at index {first_index}"
+        ),
+        instance_path: instance_path.to_owned(),
+    })
+}
+
+/// 6.3.14 Multiple Selections of Same SSVC Decision Point
+///
+/// For each SSVC metric, it MUST be tested that each decision point, identified by its namespace
+/// and key, is given at most once under `selections`, regardless of its version.
+pub fn test_6_3_14_multiple_selections_of_same_ssvc_decision_point(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<TestFinding>> {
+    let mut errors: Option<Vec<TestFinding>> = None;
+
+    for SsvcSelectionListAndIndices {
+        vuln_index,
+        metric_index,
+        selection_list,
+    } in iter_ssvc_selection_lists(doc)
+    {
+        let mut first_selections: HashMap<(&str, &str), usize> = HashMap::new();
+        for (s_i, selection) in selection_list.selections.iter().enumerate() {
+            let namespace = selection.namespace.as_str();
+            let key = selection.key.as_str();
+            let first_index = *first_selections.entry((namespace, key)).or_insert(s_i);
+            if first_index != s_i {
+                errors.get_or_insert_default().push(create_multiple_selections_info(
+                    namespace,
+                    key,
+                    first_index,
+                    &ssvc_selection_path(vuln_index, metric_index, s_i),
+                ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+crate::test_validation::impl_validator!(
+    csaf2_1,
+    ValidatorForTest6_3_14,
+    test_6_3_14_multiple_selections_of_same_ssvc_decision_point
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csaf2_1::testcases::ExpectedResults_6_3_14 as ExpectedResults;
+    use crate::csaf2_1::testcases::TESTS_2_1;
+
+    #[test]
+    fn test_test_6_3_14() {
+        // Case 11: each decision point selected once
+        // Case S11: same key in different namespaces
+        TESTS_2_1.test_6_3_14.expect(ExpectedResults {
+            case_01: Err(vec![create_multiple_selections_info(
+                "ssvc",
+                "E",
+                0,
+                "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/1",
+            )]),
+            case_11: Ok(()),
+            case_s01: Err(vec![create_multiple_selections_info(
+                "ssvc",
+                "E",
+                0,
+                "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/3",
+            )]),
+            case_s11: Ok(()),
+        });
+    }
+}
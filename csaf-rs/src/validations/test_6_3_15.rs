@@ -1,6 +1,7 @@
 use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, TlpTrait};
 use crate::schema::csaf2_1::schema::LabelOfTlp;
 use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::{Passed, TestResult};
 use crate::validations::utils::ssvc::{
     SsvcNamespaceResultAndPath, create_generic_namespace_finding_data, iter_ssvc_namespaces,
 };
@@ -23,11 +24,14 @@ fn create_namespace_extension_in_non_tlp_clear_info(namespace: &str, instance_pa
 pub fn test_6_3_15_usage_of_ssvc_decision_point_namespace_with_extension_in_non_tlp_clear_document(
     doc: &impl CsafTrait,
     allow_test_namespaces: bool,
-) -> Result<(), Vec<TestFinding>> {
+) -> TestResult {
     // This test only applies to non-TLP:CLEAR documents
-    let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
-    if distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() == LabelOfTlp::Clear {
-        return Ok(());
+    let label = match doc.get_document().get_distribution_21().and_then(|d| d.get_tlp_21()) {
+        Ok(tlp) => tlp.get_label(),
+        Err(e) => return TestResult::Failed(vec![e]),
+    };
+    if label == LabelOfTlp::Clear {
+        return TestResult::not_applicable("The test only applies to non-TLP:CLEAR documents.");
     }
 
     let mut errors: Option<Vec<TestFinding>> = None;
@@ -62,7 +66,7 @@ pub fn test_6_3_15_usage_of_ssvc_decision_point_namespace_with_extension_in_non_
         }
     }
 
-    errors.map_or(Ok(()), Err)
+    errors.map_or(TestResult::Passed(Passed::Success), TestResult::Failed)
 }
 
 impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework>
@@ -71,7 +75,7 @@ impl crate::test_validation::TestValidator<crate::schema::csaf2_1::schema::Commo
     fn validate(
         &self,
         doc: &crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework,
-    ) -> Result<(), Vec<TestFinding>> {
+    ) -> crate::validation_result::TestResult {
         test_6_3_15_usage_of_ssvc_decision_point_namespace_with_extension_in_non_tlp_clear_document(doc, false)
     }
 }
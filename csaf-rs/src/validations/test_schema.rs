@@ -123,7 +123,7 @@ mod tests {
             ));
             let actual = validate_schema(&serde_json::from_str(file_content).unwrap(), $validator);
             crate::test_result_comparison::compare_test_results(
-                &actual,
+                &actual.into(),
                 &$expected,
                 concat!("V", $csaf_major, "_", $csaf_minor),
                 "schema",
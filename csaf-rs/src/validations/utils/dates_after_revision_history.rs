@@ -0,0 +1,66 @@
+use crate::csaf::macros::skip_if_document_status_is_not::skip_if_document_status_is_not;
+use crate::csaf::types::csaf_datetime::{CsafDateTime, ValidCsafDateTime};
+use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::schema::csaf2_1::schema::DocumentStatus;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
+
+pub(crate) fn create_date_too_new_error(
+    subject: &str,
+    doc_status: &DocumentStatus,
+    date: &ValidCsafDateTime,
+    newest_revision_date: &ValidCsafDateTime,
+    instance_path: &str,
+) -> TestFinding {
+    TestFinding::Error(TestFindingData {
+        message: format!(
+            "The date '{date}' of the {subject} is newer than the newest revision date ({newest_revision_date}) on a document with status {doc_status}"
+        ),
+        instance_path: instance_path.to_string(),
+    })
+}
+
+/// Shared implementation of the 6.1.60.x tests: each of the given dates (with its instance path)
+/// must be earlier than or equal to the `date` of the newest item of the `revision_history` if the
+/// document status is `final` or `interim`. Invalid dates are ignored, they are reported by 6.1.37.
+///
+/// `subject` names the kind of item the dates belong to in error messages, e.g. `flag`.
+pub(crate) fn test_dates_not_newer_than_revision_history(
+    doc: &impl CsafTrait,
+    subject: &str,
+    dates: impl IntoIterator<Item = (CsafDateTime, String)>,
+) -> TestResult {
+    let tracking = doc.get_document().get_tracking();
+    let status = tracking.get_status();
+
+    skip_if_document_status_is_not!(status, Final, Interim);
+
+    let mut revision_history = tracking.aggregate_revision_history();
+    revision_history.inplace_sort_by_date_then_number();
+
+    let newest_revision_date = match revision_history.last() {
+        Some(rev) => match &rev.date {
+            CsafDateTime::Valid(date) => date,
+            CsafDateTime::Invalid(_) => {
+                return TestResult::precondition_failed("The date of the newest revision history item is invalid.");
+            },
+        },
+        None => return TestResult::precondition_failed("The revision history is empty."),
+    };
+
+    let errors: Vec<TestFinding> = dates
+        .into_iter()
+        .filter_map(|(date, instance_path)| match date {
+            CsafDateTime::Valid(date) if &date > newest_revision_date => Some(create_date_too_new_error(
+                subject,
+                &status,
+                &date,
+                newest_revision_date,
+                &instance_path,
+            )),
+            _ => None,
+        })
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }.into()
+}
@@ -1,5 +1,6 @@
 use crate::csaf::types::csaf_document_category::CsafDocumentCategory;
 use crate::csaf_traits::CsafVersion;
+use crate::validation_result::TestResult;
 
 /// Configuration for tests that need to check document categories per CSAF version.
 ///
@@ -97,6 +98,30 @@ impl DocumentCategoryTestConfig {
             "Test applicability without a specified CSAF doc version was checked on a config that does not specify version-independent categories. (This looks like a dev error.)"
         );
     }
+
+    /// Returns a [Passed::NotApplicable](crate::validation_result::Passed::NotApplicable) result if the
+    /// test does not apply to the document category for the given CSAF version, see
+    /// [Self::matches_category_with_csaf_version].
+    pub fn not_applicable_with_csaf_version(
+        &self,
+        csaf_version: CsafVersion,
+        document_category: &CsafDocumentCategory,
+    ) -> Option<TestResult> {
+        (!self.matches_category_with_csaf_version(csaf_version, document_category))
+            .then(|| not_applicable_to_category(document_category))
+    }
+
+    /// Returns a [Passed::NotApplicable](crate::validation_result::Passed::NotApplicable) result if the
+    /// test does not apply to the document category, see [Self::matches_category].
+    pub fn not_applicable(&self, document_category: &CsafDocumentCategory) -> Option<TestResult> {
+        (!self.matches_category(document_category)).then(|| not_applicable_to_category(document_category))
+    }
+}
+
+fn not_applicable_to_category(document_category: &CsafDocumentCategory) -> TestResult {
+    TestResult::not_applicable(format!(
+        "The test does not apply to documents with category '{document_category}'."
+    ))
 }
 
 #[cfg(test)]
@@ -157,6 +182,23 @@ mod tests {
         assert!(!TEST_CONFIG.matches_category(&CsafDocumentCategory::CsafWithdrawn));
     }
 
+    #[test]
+    fn test_not_applicable() {
+        const TEST_CONFIG: DocumentCategoryTestConfig =
+            DocumentCategoryTestConfig::new().csaf21(&[CsafDocumentCategory::CsafWithdrawn]);
+
+        assert_eq!(
+            TEST_CONFIG.not_applicable_with_csaf_version(CsafVersion::X21, &CsafDocumentCategory::CsafWithdrawn),
+            None
+        );
+        assert_eq!(
+            TEST_CONFIG.not_applicable_with_csaf_version(CsafVersion::X21, &CsafDocumentCategory::CsafVex),
+            Some(TestResult::not_applicable(
+                "The test does not apply to documents with category 'csaf_vex'."
+            ))
+        );
+    }
+
     #[test]
     fn test_config_without_shared_categories_panic_on_is_ignored_for() {
         const TEST_CONFIG: DocumentCategoryTestConfig = DocumentCategoryTestConfig::new()
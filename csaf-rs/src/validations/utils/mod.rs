@@ -1,7 +1,23 @@
+pub(crate) mod dates_after_revision_history;
 pub(crate) mod document_category_test_config;
 pub(crate) mod document_notes_with_title_and_category;
 pub(crate) mod language_specific_translations;
 pub(crate) mod rvisc;
 pub(crate) mod ssvc;
+pub(crate) mod url_references;
 pub(crate) mod validation_schema_urls;
 pub(crate) mod validation_schemas;
+
+/// Load the CSAF 2.1 template of the supplementary test files, modified by `modify`, as document.
+#[cfg(test)]
+pub(crate) fn csaf_2_1_template(
+    modify: impl FnOnce(&mut serde_json::Value),
+) -> crate::csaf::raw::RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework> {
+    let template = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../type-generator/assets/tests/csaf_2.1/csaf-rs_csaf-csaf_2_1-TEMPLATE.json"
+    );
+    let mut json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(template).unwrap()).unwrap();
+    modify(&mut json);
+    crate::csaf::raw::RawDocument::new(json)
+}
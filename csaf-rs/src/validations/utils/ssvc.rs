@@ -1,11 +1,18 @@
 use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
 use crate::validation::TestFindingData;
+use ssvc::selection_list::SelectionList;
 use ssvc::{NamespaceError, ParsedNamespace};
 
+/// Returns the JSON instance path to an SSVC v2 selection.
+pub(crate) fn ssvc_selection_path(vuln_index: usize, metric_index: usize, selection_index: usize) -> String {
+    format!("/vulnerabilities/{vuln_index}/metrics/{metric_index}/content/ssvc_v2/selections/{selection_index}")
+}
+
 /// Returns the JSON instance path to the `namespace` field of an SSVC v2 selection.
 pub(crate) fn ssvc_selection_namespace_path(vuln_index: usize, metric_index: usize, selection_index: usize) -> String {
     format!(
-        "/vulnerabilities/{vuln_index}/metrics/{metric_index}/content/ssvc_v2/selections/{selection_index}/namespace"
+        "{}/namespace",
+        ssvc_selection_path(vuln_index, metric_index, selection_index)
     )
 }
 
@@ -15,14 +22,18 @@ pub(crate) struct SsvcNamespaceResultAndPath {
     pub instance_path: String,
 }
 
-/// Returns an iterator over all SSVC v2 namespace parse results found in the document.
+/// An SSVC v2 selection list with the indices of the vulnerability and metric it was found in.
+pub(crate) struct SsvcSelectionListAndIndices {
+    pub vuln_index: usize,
+    pub metric_index: usize,
+    pub selection_list: SelectionList,
+}
+
+/// Returns an iterator over all SSVC v2 selection lists found in the document.
 ///
 /// The iterator skips SSVC metric objects that fail to deserialize (those are reported by test
-/// 6.1.46). Every remaining selection yields exactly one [`SsvcNamespaceResultAndPath`] item.
-pub(crate) fn iter_ssvc_namespaces<D: CsafTrait>(
-    doc: &D,
-    allow_test_namespaces: bool,
-) -> impl Iterator<Item = SsvcNamespaceResultAndPath> {
+/// 6.1.46).
+pub(crate) fn iter_ssvc_selection_lists<D: CsafTrait>(doc: &D) -> impl Iterator<Item = SsvcSelectionListAndIndices> {
     doc.get_vulnerabilities()
         .iter()
         .enumerate()
@@ -34,17 +45,65 @@ pub(crate) fn iter_ssvc_namespaces<D: CsafTrait>(
                 .map(move |(metric_index, metric)| (vuln_index, metric_index, metric))
         })
         .filter(|(_, _, metric)| metric.get_content().has_ssvc_v2())
-        .flat_map(move |(vuln_index, metric_index, metric)| {
+        .filter_map(|(vuln_index, metric_index, metric)| {
             metric
                 .get_content()
                 .get_ssvc_v2()
+                .ok()
+                .map(|selection_list| SsvcSelectionListAndIndices {
+                    vuln_index,
+                    metric_index,
+                    selection_list,
+                })
+        })
+}
+
+/// Returns an iterator over all SSVC v2 namespace parse results found in the document.
+///
+/// The iterator skips SSVC metric objects that fail to deserialize (those are reported by test
+/// 6.1.46). Every remaining selection yields exactly one [`SsvcNamespaceResultAndPath`] item.
+pub(crate) fn iter_ssvc_namespaces<D: CsafTrait>(
+    doc: &D,
+    allow_test_namespaces: bool,
+) -> impl Iterator<Item = SsvcNamespaceResultAndPath> {
+    iter_ssvc_selection_lists(doc).flat_map(
+        move |SsvcSelectionListAndIndices {
+                  vuln_index,
+                  metric_index,
+                  selection_list,
+              }| {
+            selection_list
+                .selections
                 .into_iter()
-                .flat_map(|selection_list| selection_list.selections.into_iter().enumerate())
+                .enumerate()
                 .map(move |(sl_item_index, sl_item)| SsvcNamespaceResultAndPath {
                     result: ssvc::validate_namespace(sl_item.namespace.as_str(), allow_test_namespaces),
                     instance_path: ssvc_selection_namespace_path(vuln_index, metric_index, sl_item_index),
                 })
-        })
+        },
+    )
+}
+
+// The latest version of each decision point, derived from the decision points of the `ssvc` crate
+// by the build script
+include!(concat!(env!("OUT_DIR"), "/ssvc_decision_points.rs"));
+
+/// Returns the latest known version of the registered SSVC decision point with the given base
+/// namespace and key.
+pub(crate) fn latest_decision_point_version(base_namespace: &str, key: &str) -> Option<&'static str> {
+    LATEST_DECISION_POINT_VERSIONS
+        .iter()
+        .find(|(namespace, dp_key, _)| *namespace == base_namespace && *dp_key == key)
+        .map(|(_, _, version)| *version)
+}
+
+/// Parses an SSVC decision point version (`MAJOR.MINOR.PATCH`) into comparable parts.
+pub(crate) fn parse_decision_point_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => Some((major, minor, patch)),
+        _ => None,
+    }
 }
 
 /// Returns a [`TestFindingData`] for a generic SSVC namespace error, using the given JSON instance path.
@@ -73,6 +132,14 @@ impl NamespaceErrorExt for NamespaceError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_latest_decision_point_version() {
+        assert_eq!(latest_decision_point_version("ssvc", "E"), Some("1.1.0"));
+        assert_eq!(latest_decision_point_version("cvss", "AV"), Some("3.0.1"));
+        assert_eq!(latest_decision_point_version("ssvc", "UNKNOWN"), None);
+        assert_eq!(latest_decision_point_version("example", "E"), None);
+    }
+
     #[test]
     fn test_to_test_finding_data() {
         let err = NamespaceError::ReservedForbiddenNamespace {
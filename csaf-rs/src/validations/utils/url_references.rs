@@ -0,0 +1,91 @@
+//! Reference URLs checked by the tests 6.3.6 and 6.3.7
+
+use crate::csaf::traits::util::extract_references::ExtractReferenceUrls;
+use crate::csaf_traits::{CsafTrait, DocumentTrait, ReferenceTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfReference as CategoryOfReference21;
+use crate::url_resolver::UrlResolver;
+use crate::validation::{TestFinding, TestFindingData};
+use crate::validation_result::TestResult;
+use std::collections::HashMap;
+
+/// Returns the URLs and instance paths of all references in `/document/references` and
+/// `/vulnerabilities[]/references` which are (or are not) in the category `self`.
+pub(crate) fn reference_urls(doc: &impl CsafTrait, self_referencing: bool) -> Vec<(String, String)> {
+    let mut urls = doc
+        .get_document()
+        .get_references()
+        .extract_reference_urls("/document/references", is_self_referencing(self_referencing));
+    for (index, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        urls.extend(vulnerability.get_references().extract_reference_urls(
+            &format!("/vulnerabilities/{index}/references"),
+            is_self_referencing(self_referencing),
+        ));
+    }
+    urls
+}
+
+fn is_self_referencing<R: ReferenceTrait>(self_referencing: bool) -> impl Fn(&R) -> bool {
+    // using CategoryOfReference21 here is fine, CategoryOfReference20 is 1:1 mapped to this
+    move |reference| (reference.get_category() == CategoryOfReference21::Self_) == self_referencing
+}
+
+/// Resolve all URLs, each one only once, and report those that fail to resolve.
+///
+/// Failures of the `canonical_urls` are reported as such.
+pub(crate) fn check_urls(urls: &[(String, String)], canonical_urls: &[&str], resolver: &dyn UrlResolver) -> TestResult {
+    if urls.is_empty() {
+        return TestResult::no_data("The document does not contain any matching references.");
+    }
+    let mut resolved = HashMap::new();
+    let findings: Vec<_> = urls
+        .iter()
+        .filter_map(|(url, instance_path)| {
+            let result = resolved.entry(url.as_str()).or_insert_with(|| resolver.resolve(url));
+            let reason = result.as_ref().err()?;
+            Some(if canonical_urls.contains(&url.as_str()) {
+                create_canonical_url_failed_to_resolve_info(url, reason, instance_path)
+            } else {
+                create_failed_to_resolve_info(url, reason, instance_path)
+            })
+        })
+        .collect();
+    if findings.is_empty() { Ok(()) } else { Err(findings) }.into()
+}
+
+/// Result of the tests 6.3.6 and 6.3.7 if no resolver is given.
+pub(crate) fn requires_url_resolver(_doc: &impl CsafTrait) -> TestResult {
+    TestResult::precondition_failed("Resolving URLs needs network access, but no URL resolver was given.")
+}
+
+pub(crate) fn create_failed_to_resolve_info(url: &str, reason: &str, instance_path: &str) -> TestFinding {
+    TestFinding::Information(TestFindingData {
+        message: format!("URL '{url}' failed to resolve: {reason}"),
+        instance_path: instance_path.to_string(),
+    })
+}
+
+pub(crate) fn create_canonical_url_failed_to_resolve_info(url: &str, reason: &str, instance_path: &str) -> TestFinding {
+    TestFinding::Information(TestFindingData {
+        message: format!("The canonical URL '{url}' failed to resolve: {reason}"),
+        instance_path: instance_path.to_string(),
+    })
+}
+
+/// Build a CSAF 2.1 document from the template with the given `(category, url)` references in
+/// `/document/references` and `/vulnerabilities/0/references`.
+#[cfg(test)]
+pub(crate) fn document_with_references(
+    document_references: &[(&str, &str)],
+    vulnerability_references: &[(&str, &str)],
+) -> crate::csaf::raw::RawDocument<crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework> {
+    let references = |references: &[(&str, &str)]| {
+        references
+            .iter()
+            .map(|(category, url)| serde_json::json!({"category": category, "summary": "Reference", "url": url}))
+            .collect::<Vec<_>>()
+    };
+    super::csaf_2_1_template(|json| {
+        json["document"]["references"] = references(document_references).into();
+        json["vulnerabilities"] = serde_json::json!([{"references": references(vulnerability_references)}]);
+    })
+}
@@ -0,0 +1,179 @@
+//! Expansion of the command line inputs into the list of files to validate
+//!
+//! Every input is either `-` (a document read from stdin), a directory (walked recursively), a
+//! glob pattern (expanded to the matching paths) or a file. Include and exclude patterns are
+//! applied to the files found in directories and by glob patterns, files given explicitly are
+//! always validated.
+
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Path used for a document read from stdin
+pub const STDIN: &str = "-";
+
+/// Files included when walking a directory if no include pattern is given
+const DEFAULT_INCLUDE: &str = "*.json";
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Filter for the files found in directories and by glob patterns.
+#[derive(Debug, Default)]
+pub struct InputFilter {
+    pub include: Vec<Pattern>,
+    pub exclude: Vec<Pattern>,
+}
+
+impl InputFilter {
+    /// Returns whether a file should be validated, `relative` is its path relative to the
+    /// directory that was walked (or the file name for glob patterns).
+    fn includes(&self, relative: &Path) -> bool {
+        let included = if self.include.is_empty() {
+            matches(&Pattern::new(DEFAULT_INCLUDE).expect("valid default pattern"), relative)
+        } else {
+            self.include.iter().any(|pattern| matches(pattern, relative))
+        };
+        included && !self.excludes(relative)
+    }
+
+    fn excludes(&self, relative: &Path) -> bool {
+        self.exclude.iter().any(|pattern| matches(pattern, relative))
+    }
+}
+
+/// Patterns without a `/` match the file name, all others the relative path.
+fn matches(pattern: &Pattern, relative: &Path) -> bool {
+    if pattern.as_str().contains('/') {
+        pattern.matches_path_with(relative, MATCH_OPTIONS)
+    } else {
+        relative
+            .file_name()
+            .is_some_and(|name| pattern.matches_with(&name.to_string_lossy(), MATCH_OPTIONS))
+    }
+}
+
+/// Read a newline-separated list of inputs from a file (or stdin for `-`), skipping empty lines.
+pub fn read_file_list(list: &str) -> Result<Vec<String>> {
+    let content = if list == STDIN {
+        std::io::read_to_string(std::io::stdin()).context("Could not read the file list from stdin")?
+    } else {
+        std::fs::read_to_string(list).with_context(|| format!("Could not read the file list {list}"))?
+    };
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Expand the inputs to the list of files to validate, in a deterministic order.
+///
+/// Inputs that do not exist and do not match anything as glob pattern are kept as they are, so
+/// they are reported as unreadable files.
+pub fn expand_inputs(inputs: &[String], filter: &InputFilter) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        let path = Path::new(input);
+        if input == STDIN || path.is_file() {
+            files.push(path.to_path_buf());
+        } else if path.is_dir() {
+            walk_directory(path, filter, &mut files)?;
+        } else if input.contains(['*', '?', '[']) {
+            let matched = glob::glob_with(input, MATCH_OPTIONS).with_context(|| format!("Invalid pattern {input}"))?;
+            let len = files.len();
+            for entry in matched {
+                let entry = entry?;
+                if entry.is_dir() {
+                    walk_directory(&entry, filter, &mut files)?;
+                } else if filter.includes(Path::new(entry.file_name().unwrap_or_default())) {
+                    files.push(entry);
+                }
+            }
+            if files.len() == len {
+                files.push(path.to_path_buf());
+            }
+        } else {
+            files.push(path.to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+fn walk_directory(directory: &Path, filter: &InputFilter, files: &mut Vec<PathBuf>) -> Result<()> {
+    let relative = |path: &Path| path.strip_prefix(directory).unwrap_or(path).to_path_buf();
+    let walker = WalkDir::new(directory)
+        .sort_by_file_name()
+        .into_iter()
+        // Prune excluded directories, but never the directory itself
+        .filter_entry(|entry| entry.depth() == 0 || !entry.file_type().is_dir() || !filter.excludes(&relative(entry.path())));
+    for entry in walker {
+        let entry = entry?;
+        if entry.file_type().is_file() && filter.includes(&relative(entry.path())) {
+            files.push(entry.into_path());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> InputFilter {
+        let patterns = |patterns: &[&str]| patterns.iter().map(|p| Pattern::new(p).unwrap()).collect();
+        InputFilter {
+            include: patterns(include),
+            exclude: patterns(exclude),
+        }
+    }
+
+    #[test]
+    fn test_filter() {
+        let default = filter(&[], &[]);
+        assert!(default.includes(Path::new("2024/esa-2024-001.json")));
+        assert!(!default.includes(Path::new("2024/esa-2024-001.json.sha512")));
+
+        let filter = filter(&["2024/*.json"], &["provider-metadata.json", "feed-*.json"]);
+        assert!(filter.includes(Path::new("2024/esa-2024-001.json")));
+        assert!(!filter.includes(Path::new("2023/esa-2023-001.json")));
+        assert!(!filter.includes(Path::new("2024/sub/esa-2024-001.json")));
+        assert!(!filter.includes(Path::new("2024/feed-tlp-white.json")));
+    }
+
+    #[test]
+    fn test_expand_inputs() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../type-generator/assets/tests/csaf_2.1");
+        let root = root.to_string_lossy();
+
+        let all = expand_inputs(&[root.to_string()], &InputFilter::default()).unwrap();
+        assert!(all.len() > 10);
+        assert!(all.windows(2).all(|pair| pair[0] != pair[1]));
+
+        let mandatory = expand_inputs(&[format!("{root}/mandatory/*-6-1-01-*.json")], &InputFilter::default()).unwrap();
+        assert!(!mandatory.is_empty());
+        assert!(mandatory.iter().all(|path| path.to_string_lossy().contains("-6-1-01-")));
+
+        let excluded = expand_inputs(&[root.to_string()], &filter(&[], &["mandatory"])).unwrap();
+        assert!(
+            excluded
+                .iter()
+                .all(|path| !path.to_string_lossy().contains("/mandatory/"))
+        );
+        assert!(excluded.len() < all.len());
+
+        let missing = expand_inputs(
+            &["missing/*.json".to_string(), STDIN.to_string()],
+            &InputFilter::default(),
+        );
+        assert_eq!(
+            missing.unwrap(),
+            vec![PathBuf::from("missing/*.json"), PathBuf::from(STDIN)]
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Output of validation results as JSON
+//!
+//! The output is an array with one entry per file, which contains the serialized
+//! [ValidationResult] or, if the file could not be loaded, the error.
+
+use crate::ValidatedFile;
+use csaf::converter::ConversionChange;
+use csaf::validation::ValidationResult;
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileReport<'a> {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a ValidationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    unknown_tests: &'a [String],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    conversion_changes: &'a [ConversionChange],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    hash_errors: &'a [String],
+}
+
+/// Serialize the results of all files as pretty-printed JSON array.
+pub fn to_string(files: &[(PathBuf, anyhow::Result<ValidatedFile>)]) -> serde_json::Result<String> {
+    let reports: Vec<_> = files
+        .iter()
+        .map(|(path, validated)| {
+            let path = path.display().to_string();
+            match validated {
+                Ok(validated) => FileReport {
+                    path,
+                    result: Some(&validated.result),
+                    error: None,
+                    unknown_tests: &validated.unknown_tests,
+                    conversion_changes: &validated.conversion_report.changes,
+                    hash_errors: &validated.hash_errors,
+                },
+                Err(err) => FileReport {
+                    path,
+                    result: None,
+                    error: Some(format!("{err:#}")),
+                    unknown_tests: &[],
+                    conversion_changes: &[],
+                    hash_errors: &[],
+                },
+            }
+        })
+        .collect();
+    serde_json::to_string_pretty(&reports)
+}
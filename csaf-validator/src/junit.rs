@@ -0,0 +1,216 @@
+//! Output of validation results as JUnit XML
+//!
+//! Each file becomes a test suite and each executed test a test case. A test case fails if the test
+//! found errors, while warnings and infos are only reported as its output. Files that could not be
+//! loaded become a test suite with a single erroneous test case.
+
+use crate::ValidatedFile;
+use csaf::custom_rules::RuleRegistry;
+use csaf::validation::{TestResult, TestResultStatus, ValidationError};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Counters of a test suite or the whole report.
+#[derive(Debug, Default, Clone, Copy)]
+struct Counts {
+    tests: usize,
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+}
+
+impl Counts {
+    fn add(&mut self, other: Counts) {
+        self.tests += other.tests;
+        self.failures += other.failures;
+        self.errors += other.errors;
+        self.skipped += other.skipped;
+    }
+
+    fn attributes(&self) -> String {
+        format!(
+            r#"tests="{}" failures="{}" errors="{}" skipped="{}""#,
+            self.tests, self.failures, self.errors, self.skipped
+        )
+    }
+}
+
+/// Serialize the results of all files as JUnit XML report, with the titles of registered rules
+/// looked up in `rules`.
+pub fn to_string(files: &[(PathBuf, anyhow::Result<ValidatedFile>)], rules: &RuleRegistry) -> String {
+    let mut total = Counts::default();
+    let mut suites = String::new();
+    for (path, validated) in files {
+        let name = escape(&path.display().to_string());
+        let mut counts = Counts::default();
+        let mut cases = String::new();
+        match validated {
+            Ok(validated) => {
+                for test_result in &validated.result.test_results {
+                    write_test_case(
+                        &mut cases,
+                        &mut counts,
+                        &name,
+                        &validated.result.version,
+                        test_result,
+                        rules,
+                    );
+                }
+            },
+            Err(err) => {
+                counts.tests += 1;
+                counts.errors += 1;
+                let message = escape(&format!("{err:#}"));
+                let _ = writeln!(
+                    cases,
+                    r#"    <testcase name="load" classname="{name}"><error message="{message}"/></testcase>"#
+                );
+            },
+        }
+        let _ = writeln!(suites, r#"  <testsuite name="{name}" {}>"#, counts.attributes());
+        suites.push_str(&cases);
+        suites.push_str("  </testsuite>\n");
+        total.add(counts);
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"csaf-validator\" {}>\n{suites}</testsuites>",
+        total.attributes()
+    )
+}
+
+fn write_test_case(
+    out: &mut String,
+    counts: &mut Counts,
+    classname: &str,
+    version: &str,
+    test_result: &TestResult,
+    rules: &RuleRegistry,
+) {
+    counts.tests += 1;
+    let test_id = &test_result.test_id;
+    let name = match rules.test_title(version, test_id) {
+        Some(title) => escape(&format!("{test_id} {title}")),
+        None => escape(test_id),
+    };
+    let _ = write!(out, r#"    <testcase name="{name}" classname="{classname}">"#);
+    match &test_result.status {
+        TestResultStatus::Passed(_) => {},
+        TestResultStatus::Failure {
+            errors,
+            warnings,
+            infos,
+        } => {
+            let details = [("Error", errors), ("Warning", warnings), ("Info", infos)]
+                .into_iter()
+                .flat_map(|(severity, findings)| {
+                    findings.iter().map(move |ValidationError { message, instance_path }| {
+                        format!("{instance_path}: {message} [{severity}]\n")
+                    })
+                })
+                .collect::<String>();
+            if errors.is_empty() {
+                let _ = write!(out, "<system-out>{}</system-out>", escape(&details));
+            } else {
+                counts.failures += 1;
+                let message = format!("{} error(s) found", errors.len());
+                let _ = write!(
+                    out,
+                    r#"<failure message="{}" type="error">{}</failure>"#,
+                    escape(&message),
+                    escape(&details)
+                );
+            }
+        },
+        TestResultStatus::NotFound | TestResultStatus::Skipped(_) => {
+            counts.skipped += 1;
+            let message = match &test_result.status {
+                TestResultStatus::NotFound => "Test not found".to_string(),
+                status => status.to_string(),
+            };
+            let _ = write!(out, r#"<skipped message="{}"/>"#, escape(&message));
+        },
+    }
+    out.push_str("</testcase>\n");
+}
+
+/// Escape text for use in XML attributes and content.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\t' | '\r' => escaped.push(char),
+            // other control characters are not allowed in XML 1.0
+            char if char.is_control() => escaped.push('\u{FFFD}'),
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csaf::converter::ConversionReport;
+    use csaf::validation::{Skipped, ValidationResult};
+
+    #[test]
+    fn test_to_string() {
+        let validated = ValidatedFile {
+            result: ValidationResult {
+                success: false,
+                version: "2.1".to_string(),
+                test_results: vec![
+                    TestResult {
+                        test_id: "6.1.1".to_string(),
+                        status: TestResultStatus::Failure {
+                            errors: vec![ValidationError {
+                                message: "Missing definition of product_id: <A&B>".to_string(),
+                                instance_path: "/vulnerabilities/0/product_status/fixed/0".to_string(),
+                            }],
+                            warnings: vec![],
+                            infos: vec![],
+                        },
+                    },
+                    TestResult {
+                        test_id: "6.1.13".to_string(),
+                        status: TestResultStatus::Skipped(Skipped::PreconditionFailed {
+                            test_id: "6.1.13".to_string(),
+                            message: "no PURLs".to_string(),
+                        }),
+                    },
+                ],
+                num_errors: 1,
+                num_warnings: 0,
+                num_infos: 0,
+                num_not_found: 0,
+            },
+            unknown_tests: vec![],
+            source_map: None,
+            conversion_report: ConversionReport::default(),
+            hash_errors: vec![],
+        };
+        let files = [
+            (PathBuf::from("a.json"), Ok(validated)),
+            (PathBuf::from("b.json"), Err(anyhow::anyhow!("not JSON"))),
+        ];
+
+        let xml = to_string(&files, &RuleRegistry::new());
+        assert!(xml.contains(r#"<testsuites name="csaf-validator" tests="3" failures="1" errors="1" skipped="1">"#));
+        assert!(xml.contains(r#"<testcase name="6.1.1 Missing Definition of Product ID" classname="a.json">"#));
+        assert!(xml.contains("Missing definition of product_id: &lt;A&amp;B&gt; [Error]"));
+        assert!(xml.contains(r#"<skipped message="Test skipped (precondition failed: no PURLs)"/>"#));
+        assert!(xml.contains(r#"<error message="not JSON"/>"#));
+        assert_balanced_tags(&xml);
+    }
+
+    /// Check that start and end tags are balanced, without pulling in an XML parser.
+    fn assert_balanced_tags(xml: &str) {
+        assert_eq!(xml.matches("<testsuite ").count(), xml.matches("</testsuite>").count());
+        assert_eq!(xml.matches("<testcase ").count(), xml.matches("</testcase>").count());
+    }
+}
@@ -0,0 +1,124 @@
+//! Output of `--list-tests`: the tests of each CSAF version with their implementation state
+
+use anstream::println;
+use csaf::custom_rules::{CustomRule, RuleRegistry};
+use csaf::test_info::{ImplementationState, TestCapability, TestCategory, test_capabilities};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VersionCapabilities<'a> {
+    version: &'static str,
+    tests: Vec<ListedTest<'a>>,
+}
+
+/// A test of the standard or a registered rule, serialized like a [TestCapability].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListedTest<'a> {
+    test_id: &'a str,
+    section: &'a str,
+    title: Option<&'a str>,
+    category: TestCategory,
+    state: ImplementationState,
+}
+
+impl From<TestCapability> for ListedTest<'_> {
+    fn from(capability: TestCapability) -> Self {
+        ListedTest {
+            test_id: capability.test_id,
+            section: capability.section,
+            title: capability.title,
+            category: capability.category,
+            state: capability.state,
+        }
+    }
+}
+
+impl<'a> From<&'a CustomRule> for ListedTest<'a> {
+    fn from(rule: &'a CustomRule) -> Self {
+        ListedTest {
+            test_id: rule.id(),
+            section: rule.id(),
+            title: Some(rule.title()),
+            category: rule.category(),
+            state: rule.state(),
+        }
+    }
+}
+
+/// Returns the tests of the given CSAF version, or of all versions for `auto`, followed by the
+/// registered rules.
+///
+/// Returns `None` for unknown versions.
+fn capabilities<'a>(csaf_version: &str, rules: &'a RuleRegistry) -> Option<Vec<VersionCapabilities<'a>>> {
+    let versions: &[&'static str] = match csaf_version {
+        "auto" => &["2.0", "2.1"],
+        "2.0" => &["2.0"],
+        "2.1" => &["2.1"],
+        _ => return None,
+    };
+    versions
+        .iter()
+        .map(|version| {
+            Some(VersionCapabilities {
+                version,
+                tests: (test_capabilities(version)?.into_iter().map(ListedTest::from))
+                    .chain(rules.rules().iter().map(ListedTest::from))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Print the tests of the given CSAF version (or all versions for `auto`) as text or JSON.
+pub fn print(csaf_version: &str, json: bool, rules: &RuleRegistry) -> anyhow::Result<()> {
+    let Some(versions) = capabilities(csaf_version, rules) else {
+        anyhow::bail!("Invalid CSAF version: {csaf_version}");
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&versions)?);
+        return Ok(());
+    }
+
+    let dimmed = anstyle::Style::new().dimmed();
+    for VersionCapabilities { version, tests } in versions {
+        println!("CSAF {version}");
+        for test in &tests {
+            let style = match test.state {
+                ImplementationState::NotImplemented => dimmed,
+                _ => anstyle::Style::new(),
+            };
+            println!(
+                "  {style}{:10} {:12} {:18} {}{style:#}",
+                test.test_id,
+                test.category.to_string(),
+                test.state.to_string(),
+                test.title.unwrap_or_default()
+            );
+        }
+        let implemented = tests.iter().filter(|test| test.state.is_implemented()).count();
+        println!("{implemented} of {} tests implemented\n", tests.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_of_versions() {
+        let rules = RuleRegistry::new();
+        let versions: Vec<_> = capabilities("auto", &rules)
+            .unwrap()
+            .iter()
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(versions, ["2.0", "2.1"]);
+        let versions = capabilities("2.1", &rules).unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].tests[0].test_id, "schema");
+        assert!(capabilities("1.2", &rules).is_none());
+    }
+}
@@ -1,26 +1,75 @@
-use anstream::println;
-use anyhow::{Result, bail};
-use clap::{CommandFactory, Parser};
+mod input;
+mod json;
+mod junit;
+mod list_tests;
+mod provider;
+mod sarif;
+mod summary;
+
+use anstream::{eprintln, println};
+use anyhow::{Context, Result, bail};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use csaf::converter::ConversionReport;
+use csaf::converter::cvrf::{CvrfImportOptions, import_cvrf_as_csaf_2_0, import_cvrf_as_csaf_2_1};
 use csaf::csaf::loader::detect_version_with;
+use csaf::csaf::raw::RawDocument;
+use csaf::csaf_traits::CsafTrait;
 use csaf::csaf2_0::loader::load_document as load_document_2_0;
 use csaf::csaf2_1::loader::load_document as load_document_2_1;
-use csaf::json::JsonSource;
+use csaf::custom_rules::{CustomRule, RuleRegistry, RuleTarget};
+use csaf::declarative_rules::RuleFile;
+use csaf::hash_sidecar::verify_sidecars;
+use csaf::json::{JsonSource, Reader};
+use csaf::preset_config::{PresetConfig, PresetConfigError, ResolvedPreset};
+use csaf::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf2_0;
+use csaf::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf2_1;
+use csaf::signature::{Keyring, verify_file};
+use csaf::source_map::SourceMap;
+use csaf::spell_check::{AllowList, HunspellDirectory, SpellChecker};
+use csaf::url_resolver::HttpUrlResolver;
 use csaf::validation::ValidationError;
 use csaf::validation::{
-    TestResult,
-    TestResultStatus::{Failure, NotFound, Skipped, Success},
-    Validatable, ValidationResult, validate_by_tests,
+    BatchOptions, TestResult,
+    TestResultStatus::{Failure, NotFound, Passed, Skipped},
+    Validatable, ValidationResult, run_batch, validate_by_tests,
 };
-use std::path::Path;
+use csaf::validation_context::{ValidationContext, WithContext};
+use glob::Pattern;
+use input::{InputFilter, STDIN, expand_inputs, read_file_list};
+use provider::ProviderOutputFormat;
+use sarif::SarifLog;
+use serde::de::DeserializeOwned;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use summary::Summary;
 
 /// A validator for CSAF documents
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
-    /// Path(s) to CSAF document(s) to validate, specify multiple files as: `PATH`... (e.g. `doc1.json doc2.json`)
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Path(s) to CSAF document(s) to validate, specify multiple files as: `PATH`... (e.g. `doc1.json doc2.json`).
+    /// Directories are searched recursively, glob patterns (e.g. `white/2024/*.json`) are expanded and `-` reads
+    /// a document from stdin
     #[arg(action = clap::ArgAction::Append)]
     path: Vec<String>,
 
+    /// Read additional paths from a file with one path per line (`-` for stdin)
+    #[arg(long)]
+    files_from: Option<String>,
+
+    /// Only validate files found in directories or by glob patterns which match this pattern [default: *.json].
+    /// Patterns without `/` match the file name, others the path relative to the searched directory
+    #[arg(long, value_parser = Pattern::new, action = clap::ArgAction::Append)]
+    include: Vec<Pattern>,
+
+    /// Skip files and directories found in directories or by glob patterns which match this pattern
+    #[arg(long, value_parser = Pattern::new, action = clap::ArgAction::Append)]
+    exclude: Vec<Pattern>,
+
     /// Version of CSAF to use
     #[arg(short = 'C', long, default_value = "auto")]
     csaf_version: String,
@@ -29,89 +78,567 @@ struct Args {
     #[arg(short = 'T', long, default_value = "basic", action = clap::ArgAction::Append)]
     test: Vec<String>,
 
+    /// Load additional presets from a TOML or JSON file, which can be used with `--test`. They extend other presets,
+    /// add or remove tests, override the severity of findings and suppress findings at instance paths
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// Load declarative rules from a TOML or JSON file, which check the values at a JSON pointer or JSONPath. They are
+    /// run in the presets of their category and can be requested by their ID with `--test`; may be specified multiple
+    /// times
+    #[arg(long, value_name = "FILE", action = clap::ArgAction::Append)]
+    rules: Vec<PathBuf>,
+
     #[arg(short = 'v', long)]
     verbose: bool,
+
+    /// Number of files to validate in parallel, `0` uses one thread per CPU. The results are still reported in the
+    /// order of the files, but only once all of them are validated
+    #[arg(short = 'j', long, default_value_t = 1)]
+    jobs: usize,
+
+    /// Run the tests 6.3.6 and 6.3.7, which check that the URLs of references resolve, by requesting them via HTTP.
+    /// Otherwise, they are skipped
+    #[arg(long)]
+    resolve_urls: bool,
+
+    /// Run the spell check (test 6.3.8) with the Hunspell dictionaries (e.g. `en_US.dic` and `en_US.aff`) in this
+    /// directory, e.g. `/usr/share/hunspell`. Otherwise, it is skipped
+    #[arg(long, value_name = "DIR")]
+    dictionaries: Option<PathBuf>,
+
+    /// Words the spell check accepts in addition to the dictionaries, one per line. Words after a `[namespace]` line
+    /// are only accepted in documents of the publisher with that namespace
+    #[arg(long, value_name = "FILE", requires = "dictionaries")]
+    allow_list: Option<PathBuf>,
+
+    /// Verify the hash files (`<file>.sha256` and `<file>.sha512`) of each document, of which at least one has to
+    /// exist. Documents with a missing or mismatching hash file fail validation
+    #[arg(long)]
+    verify_hashes: bool,
+
+    /// Verify the OpenPGP signature (`<file>.asc`) of each document against the keys of `--keyring` before validating
+    /// it. Documents with a missing or invalid signature are rejected without being validated
+    #[arg(long, requires = "keyring")]
+    verify_signature: bool,
+
+    /// Public keys (ASCII-armored or binary) to verify signatures with; may be specified multiple times
+    #[arg(long, value_name = "FILE", requires = "verify_signature", action = clap::ArgAction::Append)]
+    keyring: Vec<PathBuf>,
+
+    /// List the tests of the CSAF version (of all versions for `auto`) with their title, category and implementation
+    /// state, then exit. Presets only contain implemented tests
+    #[arg(long)]
+    list_tests: bool,
+
+    /// Format of the output
+    #[arg(short = 'f', long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Publisher name to use when importing CVRF documents (`.xml`), which do not contain it
+    #[arg(long)]
+    cvrf_publisher_name: Option<String>,
+
+    /// Publisher namespace to use when importing CVRF documents (`.xml`), which do not contain it
+    #[arg(long)]
+    cvrf_publisher_namespace: Option<String>,
+}
+
+/// What the options of [`Args`] load before any file is validated, shared by the validation of all files.
+#[derive(Debug, Default)]
+struct RunContext {
+    /// Rules loaded from the files given with `--rules`
+    rule_registry: RuleRegistry,
+    /// Presets loaded from the file given with `--config`
+    preset_config: PresetConfig,
+    /// Resolver for the URLs of references, set if `--resolve-urls` is given
+    url_resolver: Option<HttpUrlResolver>,
+    /// Spell checker for the test 6.3.8, set if `--dictionaries` is given
+    spell_checker: Option<SpellChecker>,
+    /// Keys loaded from the files given with `--keyring`, set if `--verify-signature` is given
+    signature_keyring: Option<Keyring>,
+}
+
+impl RunContext {
+    /// Load the rules, presets, dictionaries and keys given by the arguments.
+    fn from_args(args: &Args) -> Result<Self> {
+        let mut context = RunContext {
+            url_resolver: args.resolve_urls.then(HttpUrlResolver::default),
+            ..Default::default()
+        };
+        if let Some(directory) = &args.dictionaries {
+            let allow_list = match &args.allow_list {
+                Some(path) => AllowList::parse(
+                    &std::fs::read_to_string(path)
+                        .with_context(|| format!("could not read allow-list {}", path.display()))?,
+                ),
+                None => AllowList::default(),
+            };
+            context.spell_checker =
+                Some(SpellChecker::new(HunspellDirectory::new(directory)).with_allow_list(allow_list));
+        }
+
+        if args.verify_signature {
+            let mut keyring = Keyring::default();
+            for path in &args.keyring {
+                keyring
+                    .extend(Keyring::load(path).with_context(|| format!("could not load keyring {}", path.display()))?);
+            }
+            context.signature_keyring = Some(keyring);
+        }
+
+        for path in &args.rules {
+            RuleFile::load(path)?.register(&mut context.rule_registry)?;
+        }
+        if let Some(path) = &args.config {
+            context.preset_config = PresetConfig::load(path)?;
+            check_presets(args, &context)?;
+        }
+        Ok(context)
+    }
+
+    /// The resolver, spell checker and rules to validate documents with.
+    fn validation_context(&self) -> ValidationContext<'_> {
+        let mut validation_context = ValidationContext::new().with_rules(&self.rule_registry);
+        if let Some(resolver) = &self.url_resolver {
+            validation_context = validation_context.with_url_resolver(resolver);
+        }
+        if let Some(spell_checker) = &self.spell_checker {
+            validation_context = validation_context.with_spell_checker(spell_checker);
+        }
+        validation_context
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Check a local copy of a provider's directory tree against the distribution requirements
+    ///
+    /// Checks the directory served as `/.well-known/csaf/` against the distribution requirements of section 7.1: the
+    /// `<tlp>/<year>/<canonical-filename>.json` layout, hash and signature files, `index.txt`, `changes.csv` and
+    /// `provider-metadata.json`. Exits with 1 if a requirement failed
+    Provider {
+        /// The directory served as `/.well-known/csaf/`
+        dir: PathBuf,
+
+        /// Format of the output
+        #[arg(short = 'f', long, value_enum, default_value_t = ProviderOutputFormat::Text)]
+        format: ProviderOutputFormat,
+    },
+}
+
+/// Format of the validation results printed to stdout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// A SARIF 2.1.0 log with one run covering all files
+    Sarif,
+    /// A JSON array with the validation result of each file
+    Json,
+    /// A JUnit XML report with one test suite per file
+    Junit,
+}
+
+/// Exit code of the validator. If several apply to the given files, the highest one is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Outcome {
+    /// All documents passed validation
+    Valid = 0,
+    /// At least one document failed validation
+    Invalid = 1,
+    /// The arguments are invalid (reported by clap), or an input given by an option, such as the
+    /// configuration, a rules file, a file list or a keyring, could not be loaded
+    Setup = 2,
+    /// At least one file could not be read or loaded as a CSAF document
+    Unreadable = 3,
+    /// At least one of the requested tests does not exist in the CSAF version of a document
+    UnknownTest = 4,
+    /// At least one document was rejected because of a missing or invalid signature
+    BadSignature = 5,
 }
 
-fn main() -> Result<(), anyhow::Error> {
+/// A document rejected by `--verify-signature`, which is not validated.
+#[derive(Debug)]
+struct RejectedSignature(String);
+
+impl Display for RejectedSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Signature verification failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for RejectedSignature {}
+
+/// Result of validating a single file, along with everything needed to report it.
+struct ValidatedFile {
+    result: ValidationResult,
+    /// Requested test IDs that are neither a preset nor a test of the document's CSAF version
+    unknown_tests: Vec<String>,
+    /// Locations of the values in the source file, only available for JSON files
+    source_map: Option<SourceMap>,
+    /// Lossy or heuristic changes made while importing a CVRF document
+    conversion_report: ConversionReport,
+    /// Problems with the hash files of the document, only checked with `--verify-hashes`
+    hash_errors: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    run().unwrap_or_else(|err| {
+        eprintln!("Error: {err:?}");
+        ExitCode::from(Outcome::Setup as u8)
+    })
+}
+
+/// Validates the files of the arguments. An error means that the validator could not be set up or
+/// could not report the results, the problems of single files are reported with them.
+fn run() -> Result<ExitCode> {
     let args = Args::parse();
+    if let Some(Command::Provider { dir, format }) = &args.command {
+        return provider::check(dir, *format);
+    }
+    let context = RunContext::from_args(&args)?;
 
-    if args.path.is_empty() {
+    if args.list_tests {
+        list_tests::print(
+            &args.csaf_version,
+            args.format == OutputFormat::Json,
+            &context.rule_registry,
+        )?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut inputs = args.path.clone();
+    if let Some(list) = &args.files_from {
+        inputs.extend(read_file_list(list)?);
+    }
+    if inputs.is_empty() {
         Args::command().print_help()?;
-        return Ok(());
+        return Ok(ExitCode::SUCCESS);
     }
+    let filter = InputFilter {
+        include: args.include.clone(),
+        exclude: args.exclude.clone(),
+    };
+    let paths = expand_inputs(&inputs, &filter)?;
 
-    if args
-        .path
+    let files: Vec<(PathBuf, Result<ValidatedFile>)> = if args.jobs == 1 {
+        // Report each file as soon as it is validated
+        paths
+            .into_iter()
+            .map(|path| {
+                print_file_header(&path, &args);
+                let validated = validate_file(&path, &args, &context);
+                print_file_result(&path, &validated, &args);
+                (path, validated)
+            })
+            .collect()
+    } else {
+        let options = BatchOptions {
+            jobs: args.jobs,
+            parallel_tests: false,
+        };
+        let results = run_batch(&paths, &options, |path| validate_file(path, &args, &context))?;
+        paths
+            .into_iter()
+            .zip(results)
+            .inspect(|(path, validated)| {
+                print_file_header(path, &args);
+                print_file_result(path, validated, &args);
+            })
+            .collect()
+    };
+
+    match args.format {
+        OutputFormat::Text if files.len() > 1 => Summary::new(&files).print(),
+        OutputFormat::Text => {},
+        OutputFormat::Sarif => {
+            let mut log = SarifLog::default();
+            for (path, validated) in &files {
+                log.add_file(path, validated, &context.rule_registry);
+            }
+            println!("{}", serde_json::to_string_pretty(&log)?);
+        },
+        OutputFormat::Json => println!("{}", json::to_string(&files)?),
+        OutputFormat::Junit => println!("{}", junit::to_string(&files, &context.rule_registry)),
+    }
+
+    let outcome = files
         .iter()
-        .map(|file| validate_file(Path::new(&file), &args))
-        .filter(|result| match result {
-            Ok(result) => !result.success,
-            Err(err) => {
-                println!("{err}\n");
-                true
-            },
+        .map(|(_, validated)| match validated {
+            Err(err) if err.is::<RejectedSignature>() => Outcome::BadSignature,
+            Err(_) => Outcome::Unreadable,
+            Ok(validated) if !validated.unknown_tests.is_empty() => Outcome::UnknownTest,
+            Ok(validated) if !validated.result.success || !validated.hash_errors.is_empty() => Outcome::Invalid,
+            Ok(_) => Outcome::Valid,
         })
-        .count()
-        > 0
-    {
-        bail!("One or more files failed validation");
+        .max()
+        .unwrap_or(Outcome::Valid);
+    match outcome {
+        Outcome::Valid | Outcome::Setup => {},
+        Outcome::Invalid => eprintln!("Error: One or more files failed validation"),
+        Outcome::Unreadable => eprintln!("Error: One or more files could not be loaded"),
+        Outcome::UnknownTest => eprintln!("Error: One or more of the requested tests do not exist"),
+        Outcome::BadSignature => eprintln!("Error: One or more files were rejected because of their signature"),
+    }
+    Ok(ExitCode::from(outcome as u8))
+}
+
+/// Check that the requested user-defined presets can be resolved for the CSAF version(s) to validate.
+///
+/// Unknown tests are only reported for the files they are unknown in, as tests can be specific to a CSAF version.
+fn check_presets(args: &Args, context: &RunContext) -> Result<()> {
+    let (config, rules) = (&context.preset_config, &context.rule_registry);
+    for name in args.test.iter().filter(|name| config.contains(name)) {
+        let results = match args.csaf_version.as_str() {
+            "2.0" => vec![config.resolve_with_rules::<RawDocument<Csaf2_0>>(name, rules)],
+            "2.1" => vec![config.resolve_with_rules::<RawDocument<Csaf2_1>>(name, rules)],
+            _ => vec![
+                config.resolve_with_rules::<RawDocument<Csaf2_0>>(name, rules),
+                config.resolve_with_rules::<RawDocument<Csaf2_1>>(name, rules),
+            ],
+        };
+        for result in results {
+            match result {
+                Ok(_) | Err(PresetConfigError::UnknownTests { .. }) => {},
+                Err(err) => bail!("Invalid preset {name}: {err}"),
+            }
+        }
     }
     Ok(())
 }
 
+/// Print the name of a file before its results, in text mode.
+fn print_file_header(path: &Path, args: &Args) {
+    if args.format == OutputFormat::Text {
+        let file_color = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Cyan.into()));
+        println!("Validating file: {file_color}{}{file_color:#}", path.display());
+    }
+}
+
+/// Print the results of a file (or why it could not be validated), in text mode.
+fn print_file_result(path: &Path, validated: &Result<ValidatedFile>, args: &Args) {
+    if args.format == OutputFormat::Text {
+        match validated {
+            Ok(validated) => print_validated_file(path, validated, args.verbose),
+            Err(err) => println!("{err}\n"),
+        }
+    }
+}
+
 /// Try to validate a file as a CSAF document based on the specified version.
-fn validate_file(path: &Path, args: &Args) -> Result<ValidationResult> {
-    let file_color = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Cyan.into()));
-    println!("Validating file: {file_color}{}{file_color:#}", path.display());
+fn validate_file(path: &Path, args: &Args, context: &RunContext) -> Result<ValidatedFile> {
+    if let Some(keyring) = &context.signature_keyring {
+        verify_signature(path, keyring)?;
+    }
+    let mut validated = if path == Path::new(STDIN) {
+        detect_and_validate(Reader(std::io::stdin().lock()), args, context)?
+    } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("xml")) {
+        import_and_validate_cvrf(path, args, context)?
+    } else {
+        let text = std::fs::read_to_string(path)?;
+        let mut validated = detect_and_validate(text.as_str(), args, context)?;
+        // The text was already loaded successfully, so this only fails in corner cases
+        validated.source_map = SourceMap::parse(&text).ok();
+        validated
+    };
+    if args.verify_hashes {
+        validated.hash_errors = verify_hashes(path);
+    }
+    Ok(validated)
+}
+
+/// Verify the signature file of a document, rejecting it if the signature is missing or invalid.
+fn verify_signature(path: &Path, keyring: &Keyring) -> Result<(), RejectedSignature> {
+    if path == Path::new(STDIN) {
+        return Err(RejectedSignature(
+            "The signature of a document read from stdin cannot be verified".to_string(),
+        ));
+    }
+    verify_file(path, keyring).map(|_| ()).map_err(|err| match err.path() {
+        Some(file) => RejectedSignature(format!("{}: {err}", file.display())),
+        None => RejectedSignature(err.to_string()),
+    })
+}
+
+/// Verify the hash files of a document, returning the problems found.
+fn verify_hashes(path: &Path) -> Vec<String> {
+    if path == Path::new(STDIN) {
+        return vec!["The hash files of a document read from stdin cannot be verified".to_string()];
+    }
+    let results = verify_sidecars(path);
+    if results.is_empty() {
+        return vec!["No hash file (.sha256 or .sha512) exists".to_string()];
+    }
+    results
+        .into_iter()
+        .filter_map(|(_, result)| result.err())
+        .map(|err| match err.path() {
+            Some(sidecar) => format!("{}: {err}", sidecar.display()),
+            None => err.to_string(),
+        })
+        .collect()
+}
+
+/// Load a document from any JSON source, detecting its version unless specified, and validate it.
+fn detect_and_validate<S: JsonSource>(source: S, args: &Args, context: &RunContext) -> Result<ValidatedFile> {
     match args.csaf_version.as_str() {
         "auto" => {
-            let detected = detect_version_with(path)?;
-            load_and_validate(detected.data, &detected.version, args)
+            let detected = detect_version_with(source)?;
+            load_and_validate(detected.data, &detected.version, args, context)
         },
-        other => load_and_validate(path, other, args),
+        other => load_and_validate(source, other, args, context),
     }
 }
 
 /// Load a document of the given version from any JSON source and validate it.
-fn load_and_validate<S: JsonSource>(source: S, version: &str, args: &Args) -> Result<ValidationResult> {
+fn load_and_validate<S: JsonSource>(
+    source: S,
+    version: &str,
+    args: &Args,
+    context: &RunContext,
+) -> Result<ValidatedFile> {
     match version {
         "2.0" => {
             let document = load_document_2_0(source)?;
-            Ok(validate_document(document, "2.0", args))
+            Ok(validate_document(document, "2.0", args, context))
         },
         "2.1" => {
             let document = load_document_2_1(source)?;
-            Ok(validate_document(document, "2.1", args))
+            Ok(validate_document(document, "2.1", args, context))
         },
         _ => bail!("Invalid CSAF version: {version}"),
     }
 }
 
+/// Import a CVRF document as CSAF 2.0 (or 2.1, if requested) and validate the result.
+fn import_and_validate_cvrf(path: &Path, args: &Args, context: &RunContext) -> Result<ValidatedFile> {
+    let xml = std::fs::read_to_string(path)?;
+    let options = CvrfImportOptions {
+        publisher_name: args.cvrf_publisher_name.clone(),
+        publisher_namespace: args.cvrf_publisher_namespace.clone(),
+        ..Default::default()
+    };
+    let (mut validated, conversion_report) = match args.csaf_version.as_str() {
+        "auto" | "2.0" => {
+            let import = import_cvrf_as_csaf_2_0(&xml, &options)?;
+            (
+                validate_document(import.document, "2.0", args, context),
+                import.warnings,
+            )
+        },
+        "2.1" => {
+            let import = import_cvrf_as_csaf_2_1(&xml, &options)?;
+            (
+                validate_document(import.document, "2.1", args, context),
+                import.warnings,
+            )
+        },
+        other => bail!("Invalid CSAF version: {other}"),
+    };
+    validated.conversion_report = conversion_report;
+    Ok(validated)
+}
+
+/// Print the lossy or heuristic changes made while importing a document.
+fn print_conversion_warnings(report: &ConversionReport) {
+    let color = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Yellow.into()));
+    for change in &report.changes {
+        println!(" {color}{change} [Conversion]{color:#}");
+    }
+}
+
 /// Validate a CSAF document of the specified version with the provided arguments.
-///
-/// This prints the results of the tests on stdout.
-fn validate_document<T>(document: T, version: &str, args: &Args) -> ValidationResult
+fn validate_document<T>(document: RawDocument<T>, version: &str, args: &Args, context: &RunContext) -> ValidatedFile
 where
-    T: Validatable,
+    T: CsafTrait + DeserializeOwned + RuleTarget,
+    RawDocument<T>: Validatable,
 {
+    let rules = &context.rule_registry;
+    let known_tests: Vec<_> = RawDocument::<T>::test_capabilities()
+        .iter()
+        .map(|capability| capability.test_id)
+        .chain(rules.rules().iter().map(CustomRule::id))
+        .collect();
+    let mut unknown_tests = Vec::new();
+    let mut user_presets = ResolvedPreset::default();
     let test_ids: Vec<_> = args
         .test
         .iter()
-        .flat_map(|test_or_preset| match T::tests_in_preset(test_or_preset) {
-            Some(test_ids) => test_ids,
-            None => vec![test_or_preset.as_str()],
+        .flat_map(|test_or_preset| {
+            if context.preset_config.contains(test_or_preset) {
+                return match context
+                    .preset_config
+                    .resolve_with_rules::<RawDocument<T>>(test_or_preset, rules)
+                {
+                    Ok(preset) => {
+                        let test_ids = preset.tests.clone();
+                        user_presets.merge(preset);
+                        test_ids
+                    },
+                    Err(PresetConfigError::UnknownTests { tests, .. }) => {
+                        unknown_tests.extend(tests);
+                        vec![]
+                    },
+                    // all other errors were already reported by check_presets
+                    Err(_) => vec![],
+                };
+            }
+            match RawDocument::<T>::tests_in_preset(test_or_preset) {
+                Some(test_ids) => rules.extend_preset(test_or_preset, test_ids),
+                None => {
+                    if !known_tests.contains(&test_or_preset.as_str()) {
+                        unknown_tests.push(test_or_preset.clone());
+                    }
+                    vec![test_or_preset.as_str()]
+                },
+            }
         })
         .collect();
 
-    let result = validate_by_tests(&document, version, &test_ids);
+    let result = validate_by_tests(
+        &WithContext::new(&document, context.validation_context()),
+        version,
+        &test_ids,
+    );
+    let result = user_presets.apply(result);
+    ValidatedFile {
+        result,
+        unknown_tests,
+        source_map: None,
+        conversion_report: ConversionReport::default(),
+        hash_errors: Vec::new(),
+    }
+}
 
-    print_validation_result(&result, args.verbose);
-    result
+/// Print the conversion warnings and validation result of a file to stdout.
+///
+/// If the locations of the values in the source file are known, findings are prefixed with
+/// `file:line:column`.
+fn print_validated_file(path: &Path, validated: &ValidatedFile, verbose: bool) {
+    print_conversion_warnings(&validated.conversion_report);
+    let locations = validated.source_map.as_ref().map(|source_map| (path, source_map));
+    print_validation_result(&validated.result, verbose, locations);
+    if !validated.unknown_tests.is_empty() {
+        let bold = anstyle::Style::new().underline();
+        println!(
+            "{bold}Error:{bold:#} unknown test(s) for CSAF {}: {}\n",
+            validated.result.version,
+            validated.unknown_tests.join(", ")
+        );
+    }
+    if !validated.hash_errors.is_empty() {
+        let bold = anstyle::Style::new().underline();
+        println!("{bold}Error:{bold:#} hash verification failed");
+        for error in &validated.hash_errors {
+            println!("  {error}");
+        }
+        println!();
+    }
 }
 
 /// Print a validation result to stdout (for CLI use)
-pub fn print_validation_result(result: &ValidationResult, verbose: bool) {
+fn print_validation_result(result: &ValidationResult, verbose: bool, locations: Option<(&Path, &SourceMap)>) {
     if verbose {
         println!("CSAF Version: {}", result.version);
     }
@@ -121,7 +648,7 @@ pub fn print_validation_result(result: &ValidationResult, verbose: bool) {
         if verbose {
             print_individual_test_result(test_result);
         }
-        print_individual_test_failures(test_result);
+        print_individual_test_failures(test_result, locations);
     }
 
     // Print summary
@@ -151,10 +678,14 @@ fn print_individual_test_result(test_result: &TestResult) {
     print!("{prefix}");
 
     match &test_result.status {
-        Success => {
+        Passed(csaf::validation::Passed::Success) => {
             // Yay, success!
             println!("✅  Success");
         },
+        Passed(_) => {
+            // Passed without actually checking anything, show why
+            println!("✅  {}", test_result.status);
+        },
         Failure {
             errors,
             warnings,
@@ -172,15 +703,15 @@ fn print_individual_test_result(test_result: &TestResult) {
             // Test not found
             println!("❓  Test not found");
         },
-        Skipped => {
-            // Test skipped
-            println!("⏭️  Test skipped");
+        Skipped(_) => {
+            // Test skipped, show why
+            println!("⏭️  {}", test_result.status);
         },
     }
 }
 
 /// Print individual information about test failures, warnings, and infos.
-fn print_individual_test_failures(test_result: &TestResult) {
+fn print_individual_test_failures(test_result: &TestResult, locations: Option<(&Path, &SourceMap)>) {
     if let Failure {
         errors,
         warnings,
@@ -191,15 +722,27 @@ fn print_individual_test_failures(test_result: &TestResult) {
         let path_color = anstyle::Style::new().dimmed();
         for ValidationError { message, instance_path } in errors {
             let color = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Red.into()));
-            println!(" {path_color}{instance_path}{path_color:#}: {color}{message} [Error {test_id}]{color:#}");
+            let location = format_location(instance_path, locations);
+            println!(" {path_color}{location}{path_color:#}: {color}{message} [Error {test_id}]{color:#}");
         }
         for ValidationError { message, instance_path } in warnings {
             let color = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Yellow.into()));
-            println!(" {path_color}{instance_path}{path_color:#}: {color}{message} [Warning {test_id}]{color:#}");
+            let location = format_location(instance_path, locations);
+            println!(" {path_color}{location}{path_color:#}: {color}{message} [Warning {test_id}]{color:#}");
         }
         for ValidationError { message, instance_path } in infos {
             let color = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Blue.into()));
-            println!(" {path_color}{instance_path}{path_color:#}: {color}{message} [Info {test_id}]{color:#}");
+            let location = format_location(instance_path, locations);
+            println!(" {path_color}{location}{path_color:#}: {color}{message} [Info {test_id}]{color:#}");
         }
     }
 }
+
+/// Format the location of a finding as `file:line:column instance_path`, or just the instance path
+/// if the location in the source file is unknown.
+fn format_location(instance_path: &str, locations: Option<(&Path, &SourceMap)>) -> String {
+    match locations.and_then(|(path, source_map)| Some((path, source_map.resolve(instance_path)?))) {
+        Some((path, span)) => format!("{}:{} {instance_path}", path.display(), span.start),
+        None => instance_path.to_string(),
+    }
+}
@@ -0,0 +1,51 @@
+//! Output of the `provider` subcommand: the distribution requirements a provider directory passed or failed
+
+use anstream::println;
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use csaf::provider_directory::check_provider_directory;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Format of the provider check results printed to stdout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ProviderOutputFormat {
+    /// Human-readable text
+    Text,
+    /// The provider report with the findings of each requirement as JSON
+    Json,
+}
+
+/// Check the provider directory tree below `dir` and print the result of each requirement as text or JSON.
+///
+/// Exits with 1 if a requirement failed.
+pub fn check(dir: &Path, format: ProviderOutputFormat) -> Result<ExitCode> {
+    let report = check_provider_directory(dir).with_context(|| format!("could not read {}", dir.display()))?;
+    match format {
+        ProviderOutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        ProviderOutputFormat::Text => {
+            let dimmed = anstyle::Style::new().dimmed();
+            for result in &report.requirements {
+                if result.passed() {
+                    println!("✅  {}", result.requirement);
+                } else {
+                    println!("❌  {} ({} finding(s))", result.requirement, result.findings.len());
+                }
+                for finding in &result.findings {
+                    println!("    {dimmed}{}:{dimmed:#} {}", finding.path.display(), finding.message);
+                }
+            }
+            let passed = report.requirements.iter().filter(|result| result.passed()).count();
+            println!(
+                "\n{passed} of {} requirements passed for {} document(s)",
+                report.requirements.len(),
+                report.documents
+            );
+        },
+    }
+    Ok(if report.passed() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,391 @@
+//! Output of validation results as a SARIF 2.1.0 log, see
+//! https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+//!
+//! All validated files are reported in a single run. Each executed test becomes a rule and each
+//! finding a result, located by the file and (for JSON files) the line and column range of the
+//! value its `instance_path` points to.
+
+use crate::ValidatedFile;
+use csaf::custom_rules::RuleRegistry;
+use csaf::source_map::SourceMap;
+use csaf::test_info::TestCategory;
+use csaf::validation::{TestResultStatus, ValidationError};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://docs.oasis-open.org/sarif/sarif/v2.1.0/errata01/os/schemas/sarif-schema-2.1.0.json";
+
+/// A SARIF log with a single run, which files are added to one by one.
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    invocations: [Invocation; 1],
+    /// Columns of the source map count characters, not UTF-16 code units (the SARIF default)
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+    /// Index of each rule in `tool.driver.rules` by test ID
+    #[serde(skip)]
+    rule_indices: HashMap<String, usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<Message>,
+    default_configuration: Configuration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<RuleProperties>,
+}
+
+#[derive(Debug, Serialize)]
+struct Configuration {
+    level: Level,
+}
+
+#[derive(Debug, Serialize)]
+struct RuleProperties {
+    category: TestCategory,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Invocation {
+    execution_successful: bool,
+    tool_execution_notifications: Vec<Notification>,
+}
+
+#[derive(Debug, Serialize)]
+struct Notification {
+    level: Level,
+    message: Message,
+    locations: Vec<Location>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: Level,
+    message: Message,
+    locations: Vec<Location>,
+}
+
+#[derive(Debug, Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    logical_locations: Vec<LogicalLocation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Debug, Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// The `instance_path` of a finding, which is a JSON pointer into the document
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LogicalLocation {
+    fully_qualified_name: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl From<TestCategory> for Level {
+    fn from(category: TestCategory) -> Self {
+        match category {
+            TestCategory::Mandatory => Level::Error,
+            TestCategory::Recommended => Level::Warning,
+            TestCategory::Informative => Level::Note,
+        }
+    }
+}
+
+impl Default for SarifLog {
+    fn default() -> Self {
+        SarifLog {
+            schema: SARIF_SCHEMA,
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: env!("CARGO_PKG_NAME"),
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules: vec![],
+                    },
+                },
+                invocations: [Invocation {
+                    execution_successful: true,
+                    tool_execution_notifications: vec![],
+                }],
+                column_kind: "unicodeCodePoints",
+                results: vec![],
+                rule_indices: HashMap::new(),
+            }],
+        }
+    }
+}
+
+impl SarifLog {
+    /// Add the results of a validated file to the log.
+    ///
+    /// Files that could not be validated at all are reported as tool execution notifications and
+    /// mark the invocation as unsuccessful. The titles and categories of registered rules are
+    /// looked up in `rules`.
+    pub fn add_file(&mut self, path: &Path, validated: &anyhow::Result<ValidatedFile>, rules: &RuleRegistry) {
+        let run = &mut self.runs[0];
+        let validated = match validated {
+            Ok(validated) => validated,
+            Err(err) => {
+                run.invocations[0].execution_successful = false;
+                run.notify(Level::Error, format!("{err:#}"), path);
+                return;
+            },
+        };
+
+        for change in &validated.conversion_report.changes {
+            run.notify(Level::Warning, format!("Conversion: {change}"), path);
+        }
+
+        let version = &validated.result.version;
+        for test_result in &validated.result.test_results {
+            if test_result.status == TestResultStatus::NotFound {
+                let message = format!("Test {} was not found", test_result.test_id);
+                run.notify(Level::Warning, message, path);
+                continue;
+            }
+            let rule_index = run.rule_index(version, &test_result.test_id, rules);
+            let TestResultStatus::Failure {
+                errors,
+                warnings,
+                infos,
+            } = &test_result.status
+            else {
+                continue;
+            };
+            for (level, findings) in [(Level::Error, errors), (Level::Warning, warnings), (Level::Note, infos)] {
+                for ValidationError { message, instance_path } in findings {
+                    run.results.push(SarifResult {
+                        rule_id: test_result.test_id.clone(),
+                        rule_index,
+                        level,
+                        message: Message { text: message.clone() },
+                        locations: vec![Location::of_finding(path, instance_path, validated.source_map.as_ref())],
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Run {
+    /// Returns the index of the rule for the given test, adding it if it does not exist yet.
+    fn rule_index(&mut self, version: &str, test_id: &str, registry: &RuleRegistry) -> usize {
+        let rules = &mut self.tool.driver.rules;
+        *self.rule_indices.entry(test_id.to_string()).or_insert_with(|| {
+            let category = registry.test_category(test_id);
+            rules.push(Rule {
+                id: test_id.to_string(),
+                short_description: registry.test_title(version, test_id).map(|title| Message {
+                    text: title.to_string(),
+                }),
+                default_configuration: Configuration {
+                    level: category.map_or(Level::Error, Level::from),
+                },
+                properties: category.map(|category| RuleProperties { category }),
+            });
+            rules.len() - 1
+        })
+    }
+
+    fn notify(&mut self, level: Level, text: String, path: &Path) {
+        self.invocations[0].tool_execution_notifications.push(Notification {
+            level,
+            message: Message { text },
+            locations: vec![Location::of_file(path)],
+        });
+    }
+}
+
+impl Location {
+    fn of_file(path: &Path) -> Self {
+        Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: artifact_uri(path),
+                },
+                region: None,
+            },
+            logical_locations: vec![],
+        }
+    }
+
+    fn of_finding(path: &Path, instance_path: &str, source_map: Option<&SourceMap>) -> Self {
+        let mut location = Location::of_file(path);
+        location.physical_location.region =
+            source_map
+                .and_then(|source_map| source_map.resolve(instance_path))
+                .map(|span| Region {
+                    start_line: span.start.line,
+                    start_column: span.start.column,
+                    end_line: span.end.line,
+                    end_column: span.end.column,
+                });
+        location.logical_locations.push(LogicalLocation {
+            fully_qualified_name: instance_path.to_string(),
+        });
+        location
+    }
+}
+
+/// Converts a path to a URI reference, which is relative for relative paths and a `file` URI for
+/// absolute ones.
+fn artifact_uri(path: &Path) -> String {
+    let mut uri = String::new();
+    if path.is_absolute() {
+        uri.push_str("file://");
+        if !path.starts_with("/") {
+            // Windows paths start with the drive letter
+            uri.push('/');
+        }
+    }
+    for char in path.to_string_lossy().chars() {
+        match char {
+            '\\' => uri.push('/'),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' | '_' | '~' | '/' | ':' => uri.push(char),
+            _ => {
+                let mut buffer = [0; 4];
+                for byte in char.encode_utf8(&mut buffer).bytes() {
+                    uri.push_str(&format!("%{byte:02X}"));
+                }
+            },
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csaf::converter::ConversionReport;
+    use csaf::validation::{TestResult, ValidationResult};
+
+    #[test]
+    fn test_artifact_uri() {
+        assert_eq!(artifact_uri(Path::new("dir/doc 1.json")), "dir/doc%201.json");
+        assert_eq!(artifact_uri(Path::new("/tmp/ä.json")), "file:///tmp/%C3%A4.json");
+    }
+
+    #[test]
+    fn test_add_file() {
+        let error = ValidationError {
+            message: "Missing definition of product_id: CSAFPID-1".to_string(),
+            instance_path: "/document/notes/0/product_ids/0".to_string(),
+        };
+        let validated = ValidatedFile {
+            result: ValidationResult {
+                success: false,
+                version: "2.1".to_string(),
+                test_results: vec![
+                    TestResult {
+                        test_id: "6.1.1".to_string(),
+                        status: TestResultStatus::Failure {
+                            errors: vec![error],
+                            warnings: vec![],
+                            infos: vec![],
+                        },
+                    },
+                    TestResult {
+                        test_id: "6.3.3".to_string(),
+                        status: TestResultStatus::Passed(csaf::validation::Passed::Success),
+                    },
+                ],
+                num_errors: 1,
+                num_warnings: 0,
+                num_infos: 0,
+                num_not_found: 0,
+            },
+            unknown_tests: vec![],
+            source_map: SourceMap::parse("{\"document\": {\"notes\": [{\"product_ids\": [\"CSAFPID-1\"]}]}}").ok(),
+            conversion_report: ConversionReport::default(),
+            hash_errors: vec![],
+        };
+
+        let mut log = SarifLog::default();
+        log.add_file(Path::new("doc.json"), &Ok(validated), &RuleRegistry::new());
+        let log = serde_json::to_value(&log).unwrap();
+        let run = &log["runs"][0];
+
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "6.1.1");
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "6.3.3");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["defaultConfiguration"]["level"],
+            "note"
+        );
+        assert_eq!(run["results"].as_array().unwrap().len(), 1);
+        let location = &run["results"][0]["locations"][0];
+        assert_eq!(location["physicalLocation"]["artifactLocation"]["uri"], "doc.json");
+        assert_eq!(location["physicalLocation"]["region"]["startColumn"], 42);
+        assert_eq!(
+            location["logicalLocations"][0]["fullyQualifiedName"],
+            "/document/notes/0/product_ids/0"
+        );
+    }
+}
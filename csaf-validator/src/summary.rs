@@ -0,0 +1,103 @@
+//! Aggregate summary over all validated files
+
+use crate::ValidatedFile;
+use anstream::println;
+use csaf::validation::TestResultStatus;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Number of findings of a single test over all files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct TestFindings {
+    errors: usize,
+    warnings: usize,
+    infos: usize,
+    /// Number of files the test found anything in
+    files: usize,
+}
+
+/// Aggregate summary over all validated files.
+#[derive(Debug, Default)]
+pub struct Summary {
+    valid: usize,
+    invalid: usize,
+    unparseable: usize,
+    findings: HashMap<String, TestFindings>,
+}
+
+impl Summary {
+    pub fn new(files: &[(PathBuf, anyhow::Result<ValidatedFile>)]) -> Self {
+        let mut summary = Summary::default();
+        for (_, validated) in files {
+            let validated = match validated {
+                Ok(validated) => validated,
+                Err(_) => {
+                    summary.unparseable += 1;
+                    continue;
+                },
+            };
+            if validated.result.success && validated.hash_errors.is_empty() {
+                summary.valid += 1;
+            } else {
+                summary.invalid += 1;
+            }
+            for test_result in &validated.result.test_results {
+                if let TestResultStatus::Failure {
+                    errors,
+                    warnings,
+                    infos,
+                } = &test_result.status
+                {
+                    let findings = summary.findings.entry(test_result.test_id.clone()).or_default();
+                    findings.errors += errors.len();
+                    findings.warnings += warnings.len();
+                    findings.infos += infos.len();
+                    findings.files += 1;
+                }
+            }
+        }
+        summary
+    }
+
+    /// Print the summary to stdout.
+    pub fn print(&self) {
+        let bold = anstyle::Style::new().bold();
+        println!(
+            "{bold}Summary:{bold:#} {} file(s) validated, {} valid, {} invalid, {} unparseable",
+            self.valid + self.invalid + self.unparseable,
+            self.valid,
+            self.invalid,
+            self.unparseable
+        );
+
+        let mut findings: Vec<_> = self.findings.iter().collect();
+        findings.sort_by_cached_key(|(test_id, _)| sort_key(test_id));
+        for (test_id, findings) in findings {
+            println!(
+                "  {test_id:10} {} error(s), {} warning(s) and {} info(s) in {} file(s)",
+                findings.errors, findings.warnings, findings.infos, findings.files
+            );
+        }
+        println!();
+    }
+}
+
+/// Sort test IDs by their numeric parts, so 6.1.2 comes before 6.1.10.
+fn sort_key(test_id: &str) -> Vec<u32> {
+    test_id.split('.').map(|part| part.parse().unwrap_or(0)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_key() {
+        let mut test_ids = vec!["6.1.10", "6.2.1", "6.1.27.10", "6.1.2", "6.1.27.2", "schema"];
+        test_ids.sort_by_key(|id| sort_key(id));
+        assert_eq!(
+            test_ids,
+            vec!["schema", "6.1.2", "6.1.10", "6.1.27.2", "6.1.27.10", "6.2.1"]
+        );
+    }
+}
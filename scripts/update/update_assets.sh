@@ -27,6 +27,8 @@ rsync -c csaf/csaf_2.0/json_schema/csaf_json_schema.json type-generator/assets/c
 rsync -c csaf/csaf_2.1/json_schema/csaf.json type-generator/assets/csaf_2.1_json_schema.json
 rsync -c csaf/csaf_2.0/test/validator/testcases_json_schema.json type-generator/assets/csaf_2.0_testcases_json_schema.json
 rsync -c csaf/csaf_2.1/test/validator/testcases_json_schema.json type-generator/assets/csaf_2.1_testcases_json_schema.json
+rsync -c csaf/csaf_2.0/json_schema/provider_json_schema.json type-generator/assets/provider_2.0_json_schema.json
+rsync -c csaf/csaf_2.1/json_schema/provider.json type-generator/assets/provider_2.1_json_schema.json
 
 rsync -c csaf/csaf_2.1/language_specific_translation/translations.json type-generator/assets/language_specific_translations/translations.json
 
@@ -11,7 +11,7 @@ use crate::build_errors::BuildError;
 use crate::language_tags::generate_language_tags;
 use crate::rvisc::generate_registry;
 use crate::schema::build_schema;
-use crate::schema::config::{get_schemas, get_testcases_schemas};
+use crate::schema::config::{get_provider_schemas, get_schemas, get_testcases_schemas};
 use crate::testcases::{generate_testcases, get_testcase_configs};
 use crate::translation::generate_translations;
 use crate::validation_schemas::generate_validation_schemas;
@@ -28,6 +28,10 @@ struct Args {
     #[arg(long, default_value_t = false)]
     test_schema: bool,
 
+    /// Generate provider metadata schema definitions
+    #[arg(long, default_value_t = false)]
+    provider_schema: bool,
+
     /// Generate test definitions
     #[arg(long, default_value_t = false)]
     test_definitions: bool,
@@ -59,6 +63,7 @@ fn main() -> Result<(), BuildError> {
     // If no specific generation is requested, run all
     let run_all = !args.schema
         && !args.test_schema
+        && !args.provider_schema
         && !args.test_definitions
         && !args.language_tags
         && !args.validation_schemas
@@ -77,6 +82,12 @@ fn main() -> Result<(), BuildError> {
         }
     }
 
+    if run_all || args.provider_schema {
+        for schema in &get_provider_schemas() {
+            build_schema(schema, &args.target_folder)?;
+        }
+    }
+
     if run_all || args.test_definitions {
         for config in &get_testcase_configs() {
             generate_testcases(config, &args.target_folder)?;
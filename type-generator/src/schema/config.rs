@@ -1,8 +1,11 @@
 use json_dotpath::DotPaths;
 use serde_json::{Value, json};
 
+use std::path::Path;
+
 use super::SchemaConfig;
 use crate::build_errors::BuildError;
+use crate::utils::read_write_fs::read_file_to_string;
 
 pub fn get_schemas() -> Vec<SchemaConfig> {
     vec![
@@ -34,6 +37,21 @@ pub fn get_testcases_schemas() -> Vec<SchemaConfig> {
     ]
 }
 
+pub fn get_provider_schemas() -> Vec<SchemaConfig> {
+    vec![
+        SchemaConfig {
+            input: "assets/provider_2.0_json_schema.json",
+            output: "csaf2_0/provider_metadata.rs",
+            patch: Some(&fix_2_0_provider_schema),
+        },
+        SchemaConfig {
+            input: "assets/provider_2.1_json_schema.json",
+            output: "csaf2_1/provider_metadata.rs",
+            patch: Some(&fix_2_1_provider_schema),
+        },
+    ]
+}
+
 /// Patches (unsupported) external schemas to the plain object type for CSAF 2.0.
 fn fix_2_0_schema(value: &mut Value) -> Result<(), BuildError> {
     let prefix = "properties.vulnerabilities.items.properties.scores.items.properties";
@@ -65,6 +83,44 @@ fn fix_2_1_schema(value: &mut Value) -> Result<(), BuildError> {
     Ok(())
 }
 
+/// Inlines the external publisher reference and simplifies the URL constraints of the
+/// CSAF 2.0 provider metadata schema.
+fn fix_2_0_provider_schema(value: &mut Value) -> Result<(), BuildError> {
+    fix_provider_schema(value, "assets/csaf_2.0_json_schema.json")
+}
+
+/// Inlines the external publisher reference and simplifies the URL constraints of the
+/// CSAF 2.1 provider metadata schema.
+fn fix_2_1_provider_schema(value: &mut Value) -> Result<(), BuildError> {
+    fix_provider_schema(value, "assets/csaf_2.1_json_schema.json")
+}
+
+/// Replaces the publisher reference into the CSAF schema by its definition, and the URL and
+/// fingerprint patterns (which are checked by `csaf::provider`) by plain strings.
+fn fix_provider_schema(value: &mut Value, csaf_schema_path: &str) -> Result<(), BuildError> {
+    let content = read_file_to_string(Path::new(csaf_schema_path))?;
+    let csaf_schema: Value = serde_json::from_str(&content)?;
+    let publisher = csaf_schema
+        .dot_get::<Value>("properties.document.properties.publisher")
+        .ok()
+        .flatten()
+        .ok_or_else(|| BuildError::SchemaPatch(format!("no publisher definition found in '{csaf_schema_path}'")))?;
+    patch_dot_set(value, "properties.publisher", publisher)?;
+
+    let distribution = "properties.distributions.items.properties";
+    let fix_paths = [
+        "properties.canonical_url".to_string(),
+        format!("{distribution}.directory_url"),
+        "properties.public_openpgp_keys.items.properties.fingerprint".to_string(),
+    ];
+    for path in fix_paths {
+        patch_dot_set(value, &path, json!({"type": "string"}))?;
+    }
+    remove_format(value, "date-time");
+    remove_format(value, "uri");
+    Ok(())
+}
+
 /// Applies `dot_set` and returns a structured error on failure.
 fn patch_dot_set(value: &mut Value, path: &str, replacement: Value) -> Result<(), BuildError> {
     value.dot_set(path, replacement).map_err(|e| {